@@ -0,0 +1,140 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::blend::Blend;
+use crate::pixelcolor::{PixelColor, Rgba8888};
+use crate::raster::ReadPixel;
+use crate::Drawing;
+use core::iter;
+use core::marker::PhantomData;
+
+/// Draw colors with an alpha channel by blending them with the pixels already on the wrapped
+/// display
+///
+/// Items are drawn in [`Rgba8888`](../pixelcolor/struct.Rgba8888.html) or any other color type
+/// that converts to it, like [`Argb8888`](../pixelcolor/struct.Argb8888.html). Each pixel is read
+/// back from the display and mixed with the new color by its alpha using
+/// [`Blend`](../pixelcolor/blend/trait.Blend.html), so the display has to be able to read back
+/// its pixels, like a [`Framebuffer`](../framebuffer/struct.Framebuffer.html). Fully transparent
+/// pixels are skipped and fully opaque ones are drawn without being read. Pixels the display can't
+/// read back, usually because they're off screen, are dropped.
+///
+/// Adapters are usually created with
+/// [`DrawingExt::alpha_blended`](./trait.DrawingExt.html#method.alpha_blended).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::pixelcolor::{Rgb565, Rgba8888};
+/// use embedded_graphics::egrectangle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let display = MockDisplay([[Rgb565::from((0xff, 0xff, 0xff)); 24]; 16]);
+///
+/// let mut display = display.alpha_blended();
+///
+/// // Darken everything behind a dialog with a half transparent black scrim
+/// display.draw(egrectangle!((0, 0), (23, 15), fill = Some(Rgba8888::new(0, 0, 0, 0x80))));
+///
+/// assert_eq!(display.into_inner().0[4][4], Rgb565::from((0x7f, 0x7f, 0x7f)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AlphaBlended<D, C> {
+    display: D,
+    color_type: PhantomData<C>,
+}
+
+impl<D, C> AlphaBlended<D, C> {
+    /// Wrap a display, blending colors drawn to it with its existing pixels
+    pub fn new(display: D) -> Self {
+        Self {
+            display,
+            color_type: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, C, S> Drawing<S> for AlphaBlended<D, C>
+where
+    D: Drawing<C> + ReadPixel<C>,
+    C: PixelColor + Blend + From<Rgba8888>,
+    S: PixelColor + Into<Rgba8888>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<S>>,
+    {
+        // Each pixel has to be drawn before the next one is read, in case an item covers the
+        // same pixel more than once
+        for Pixel(coord, color) in item {
+            let color: Rgba8888 = color.into();
+
+            let color = match color.a() {
+                0 => continue,
+                255 => C::from(color),
+                alpha => match self.display.pixel(coord) {
+                    Some(existing) => existing.blend(C::from(color), alpha),
+                    None => continue,
+                },
+            };
+
+            self.display.draw(iter::once(Pixel(coord, color)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{Argb8888, Rgb565};
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn blends_by_alpha() {
+        let white = Rgb565::from((0xff, 0xff, 0xff));
+        let red = Rgb565::from((0xff, 0, 0));
+        let mut display = MockDisplay([[white; 24]; 16]).alpha_blended();
+
+        for (x, &alpha) in [0u8, 0x40, 0xc0, 0xff].iter().enumerate() {
+            let x = x as i32;
+            display.draw(
+                Rectangle::new(Coord::new(x, 0), Coord::new(x, 0))
+                    .fill(Some(Rgba8888::new(0xff, 0, 0, alpha))),
+            );
+        }
+        display.draw(
+            Rectangle::new(Coord::new(0, 1), Coord::new(1, 1))
+                .fill(Some(Argb8888::new(0xff, 0, 0, 0x40))),
+        );
+
+        let display = display.into_inner();
+        assert_eq!(
+            display.0[0][..5],
+            [
+                white,
+                white.blend(red, 0x40),
+                white.blend(red, 0xc0),
+                red,
+                white
+            ]
+        );
+        let tinted = white.blend(red, 0x40);
+        assert_eq!(display.0[1][..3], [tinted, tinted, white]);
+    }
+}
@@ -0,0 +1,540 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::coord::Coord;
+use crate::drawable::{Clip, Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::ContainsPoint;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use crate::{run_end, Drawing};
+
+/// Only draw the pixels that fall inside an area of the wrapped display
+///
+/// Pixels outside the area from `top_left` to `bottom_right`, inclusive, are dropped. Items drawn
+/// with [`draw`](../trait.Drawing.html#tymethod.draw) still have every pixel worked out before it
+/// is dropped. Items drawn with [`draw_clipped`](#method.draw_clipped) are told about the area
+/// first, so filled shapes that lie mostly outside of it skip those rows and columns entirely. To
+/// clip to a circle or any other shape, use [`ShapeClipped`](./struct.ShapeClipped.html) instead.
+///
+/// Adapters are usually created with [`DrawingExt::clipped`](./trait.DrawingExt.html#method.clipped).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::primitives::Circle;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = Display::default().clipped(Coord::new(0, 0), Coord::new(9, 9));
+///
+/// // Only the 10 by 10 pixel corner of this huge circle that is visible gets visited
+/// display.draw_clipped(Circle::new(Coord::new(0, 0), 1000).fill(Some(1u8)));
+///
+/// let display = display.into_inner();
+/// assert_eq!(display.0[9][9], 1);
+/// assert_eq!(display.0[10][10], 0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Clipped<D> {
+    display: D,
+    top_left: Coord,
+    bottom_right: Coord,
+}
+
+impl<D> Clipped<D> {
+    /// Wrap a display, only drawing pixels between `top_left` and `bottom_right`, inclusive
+    pub fn new(display: D, top_left: Coord, bottom_right: Coord) -> Self {
+        Self {
+            display,
+            top_left: Coord::new(
+                top_left[0].min(bottom_right[0]),
+                top_left[1].min(bottom_right[1]),
+            ),
+            bottom_right: Coord::new(
+                top_left[0].max(bottom_right[0]),
+                top_left[1].max(bottom_right[1]),
+            ),
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Draw an item, letting it skip the pixels outside the clipping area without working them out
+    pub fn draw_clipped<T, C>(&mut self, item: T)
+    where
+        D: Drawing<C>,
+        T: IntoIterator<Item = Pixel<C>>,
+        T::IntoIter: Clip,
+        C: PixelColor,
+    {
+        let mut pixels = item.into_iter();
+        pixels.clip(self.top_left, self.bottom_right);
+
+        self.draw(pixels);
+    }
+}
+
+/// Whether `point` is between `top_left` and `bottom_right`, inclusive
+fn inside(point: UnsignedCoord, top_left: Coord, bottom_right: Coord) -> bool {
+    let between = |value: u32, min: i32, max: i32| {
+        i64::from(value) >= i64::from(min) && i64::from(value) <= i64::from(max)
+    };
+
+    between(point[0], top_left[0], bottom_right[0])
+        && between(point[1], top_left[1], bottom_right[1])
+}
+
+impl<D, C> Drawing<C> for Clipped<D>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (tl, br) = (self.top_left, self.bottom_right);
+
+        self.display.draw(
+            item.into_iter()
+                .filter(move |Pixel(coord, _)| inside(*coord, tl, br)),
+        );
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let (tl, br) = (self.top_left, self.bottom_right);
+
+        self.display
+            .fill_runs(runs.into_iter().filter_map(move |(start, length, color)| {
+                let left = start[0].max(tl[0]);
+                let right = run_end(start, length)[0].min(br[0]);
+
+                if start[1] < tl[1] || start[1] > br[1] || left > right {
+                    return None;
+                }
+
+                let length = (i64::from(right) - i64::from(left) + 1) as u32;
+
+                Some((Coord::new(left, start[1]), length, color))
+            }));
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, C> async_drawing::AsyncDrawing<C> for Clipped<D>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (tl, br) = (self.top_left, self.bottom_right);
+
+        self.display
+            .draw(
+                item.into_iter()
+                    .filter(move |Pixel(coord, _)| inside(*coord, tl, br)),
+            )
+            .await;
+    }
+}
+
+/// Only draw the pixels that fall inside a shape on the wrapped display
+///
+/// This works like [`Clipped`](./struct.Clipped.html), but the clipping area can be any shape
+/// that implements [`ContainsPoint`](../primitives/trait.ContainsPoint.html), like a
+/// [`Circle`](../primitives/circle/struct.Circle.html) for a round watch face or a
+/// [`RoundedRectangle`](../primitives/rounded_rectangle/struct.RoundedRectangle.html) for a
+/// window with rounded corners. Pixels are kept if the shape would fill them when drawn; its
+/// style is ignored.
+///
+/// Adapters are usually created with
+/// [`DrawingExt::clipped_to_shape`](./trait.DrawingExt.html#method.clipped_to_shape).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::primitives::{Circle, Rectangle};
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let face = Circle::<u8>::new(Coord::new(8, 8), 6);
+/// let mut display = Display::default().clipped_to_shape(face);
+///
+/// display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(23, 15)).fill(Some(1u8)));
+///
+/// let display = display.into_inner();
+/// assert_eq!(display.0[8][8], 1);
+/// assert_eq!(display.0[2][2], 0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ShapeClipped<D, S> {
+    display: D,
+    shape: S,
+}
+
+impl<D, S> ShapeClipped<D, S>
+where
+    S: ContainsPoint + Dimensions,
+{
+    /// Wrap a display, only drawing pixels inside `shape`
+    pub fn new(display: D, shape: S) -> Self {
+        Self { display, shape }
+    }
+
+    /// Get the shape pixels are clipped to
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// Change the shape used for items drawn from now on
+    pub fn set_shape(&mut self, shape: S) {
+        self.shape = shape;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Draw an item, letting it skip the pixels outside the bounding box of the shape without
+    /// working them out
+    pub fn draw_clipped<T, C>(&mut self, item: T)
+    where
+        D: Drawing<C>,
+        T: IntoIterator<Item = Pixel<C>>,
+        T::IntoIter: Clip,
+        C: PixelColor,
+    {
+        let mut pixels = item.into_iter();
+        pixels.clip(self.shape.top_left(), self.shape.bottom_right());
+
+        self.draw(pixels);
+    }
+}
+
+/// Parts of a run that are inside a shape
+///
+/// The run must already be clipped to the bounding box of the shape, so only pixels that can be
+/// inside it are tested.
+struct RunsInside<'a, S, C> {
+    shape: &'a S,
+    x: i64,
+    right: i64,
+    y: i32,
+    color: C,
+}
+
+impl<'a, S, C> Iterator for RunsInside<'a, S, C>
+where
+    S: ContainsPoint,
+    C: PixelColor,
+{
+    type Item = (Coord, u32, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (shape, y) = (self.shape, self.y);
+        let contains = |x: i64| shape.contains(Coord::new(x as i32, y));
+
+        while self.x <= self.right && !contains(self.x) {
+            self.x += 1;
+        }
+
+        let left = self.x;
+        while self.x <= self.right && contains(self.x) {
+            self.x += 1;
+        }
+
+        if self.x > left {
+            Some((
+                Coord::new(left as i32, self.y),
+                (self.x - left) as u32,
+                self.color,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<D, S, C> Drawing<C> for ShapeClipped<D, S>
+where
+    D: Drawing<C>,
+    S: ContainsPoint + Dimensions,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let shape = &self.shape;
+
+        self.display.draw(
+            item.into_iter()
+                .filter(|Pixel(coord, _)| shape.contains(coord.to_signed())),
+        );
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let shape = &self.shape;
+        let (tl, br) = (shape.top_left(), shape.bottom_right());
+
+        self.display.fill_runs(
+            runs.into_iter()
+                .filter(move |(start, _, _)| start[1] >= tl[1] && start[1] <= br[1])
+                .flat_map(move |(start, length, color)| RunsInside {
+                    shape,
+                    x: i64::from(start[0].max(tl[0])),
+                    right: i64::from(run_end(start, length)[0].min(br[0])),
+                    y: start[1],
+                    color,
+                }),
+        );
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, S, C> async_drawing::AsyncDrawing<C> for ShapeClipped<D, S>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    S: ContainsPoint + Dimensions,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let shape = &self.shape;
+
+        self.display
+            .draw(
+                item.into_iter()
+                    .filter(|Pixel(coord, _)| shape.contains(coord.to_signed())),
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::mock_display::Display;
+    use crate::primitives::rounded_rectangle::CornerRadii;
+    use crate::primitives::{Circle, Polygon, Rectangle, RoundedRectangle, Triangle};
+    use crate::style::WithStyle;
+
+    /// Draw `item` both ways and check the results match drawing it unclipped and cropping
+    fn check<T>(item: T, top_left: Coord, bottom_right: Coord)
+    where
+        T: IntoIterator<Item = Pixel<u8>> + Copy,
+        T::IntoIter: Clip,
+    {
+        let mut expected = Display::default();
+        expected.draw(item);
+        for (y, row) in expected.0.iter_mut().enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                let (x, y) = (x as i32, y as i32);
+
+                if x < top_left[0] || x > bottom_right[0] || y < top_left[1] || y > bottom_right[1]
+                {
+                    *pixel = 0;
+                }
+            }
+        }
+
+        let mut display = Display::default().clipped(top_left, bottom_right);
+        display.draw(item);
+        assert_eq!(display.into_inner(), expected);
+
+        let mut display = Display::default().clipped(top_left, bottom_right);
+        display.draw_clipped(item);
+        assert_eq!(display.into_inner(), expected);
+    }
+
+    #[test]
+    fn clips_runs() {
+        let mut display = Display::default().clipped(Coord::new(2, 1), Coord::new(6, 3));
+
+        display.fill_runs(
+            [
+                (Coord::new(-3, 1), 6, 1u8),
+                (Coord::new(4, 2), 100, 2),
+                (Coord::new(0, 3), 2, 3),
+                (Coord::new(3, 0), 3, 4),
+                (Coord::new(5, 3), 0, 5),
+                (Coord::new(i32::MAX - 1, 2), u32::MAX, 6),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        let display = display.into_inner();
+        assert_eq!(display.0[0][..8], [0; 8]);
+        assert_eq!(display.0[1][..8], [0, 0, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(display.0[2][..8], [0, 0, 0, 0, 2, 2, 2, 0]);
+        assert_eq!(display.0[3][..8], [0; 8]);
+    }
+
+    #[test]
+    fn clips_to_shapes() {
+        let window =
+            RoundedRectangle::<u8>::new(Coord::new(2, 1), Coord::new(20, 13), CornerRadii::new(4));
+        let face = Circle::<u8>::new(Coord::new(10, 8), 6);
+        let background = Rectangle::new(Coord::new(-2, -2), Coord::new(30, 20)).fill(Some(1u8));
+
+        let mut display = Display::default().clipped_to_shape(window);
+        display.draw(background);
+
+        let mut display = display.into_inner().clipped_to_shape(face);
+        display.draw_clipped(
+            Rectangle::new(Coord::new(0, 0), Coord::new(23, 15))
+                .stroke(Some(2u8))
+                .fill(Some(2u8)),
+        );
+
+        let display = display.into_inner();
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let point = Coord::new(x as i32, y as i32);
+                let expected = if face.contains(point) {
+                    2
+                } else if window.contains(point) {
+                    1
+                } else {
+                    0
+                };
+
+                assert_eq!(pixel, expected, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn shape_clipped_runs_match_pixels() {
+        let face = Circle::<u8>::new(Coord::new(10, 8), 6);
+        let runs = [
+            (Coord::new(-5, 8), 40, 1u8),
+            (Coord::new(10, 3), 2, 2),
+            (Coord::new(0, 14), 20, 3),
+            (Coord::new(0, 15), 20, 4),
+        ];
+
+        let mut expected = Display::default().clipped_to_shape(face);
+        for &(start, length, color) in runs.iter() {
+            expected.draw(
+                Rectangle::new(start, run_end(start, length))
+                    .fill(Some(color))
+                    .into_iter()
+                    .filter(|Pixel(p, _)| p[0] < 24),
+            );
+        }
+
+        let mut display = Display::default().clipped_to_shape(face);
+        display.fill_runs(runs.iter().cloned());
+
+        assert_eq!(display.into_inner(), expected.into_inner());
+    }
+
+    #[test]
+    fn clips_primitives() {
+        let areas = [
+            (Coord::new(3, 2), Coord::new(15, 11)),
+            (Coord::new(0, 0), Coord::new(5, 5)),
+            (Coord::new(12, 8), Coord::new(23, 15)),
+            (Coord::new(30, 30), Coord::new(40, 40)),
+        ];
+        let vertices = [
+            Coord::new(1, 1),
+            Coord::new(20, 3),
+            Coord::new(12, 14),
+            Coord::new(4, 9),
+        ];
+
+        for &(tl, br) in areas.iter() {
+            check(
+                Circle::new(Coord::new(10, 8), 7)
+                    .stroke(Some(1u8))
+                    .fill(Some(2u8)),
+                tl,
+                br,
+            );
+            check(
+                Rectangle::new(Coord::new(2, 1), Coord::new(20, 13))
+                    .stroke(Some(1u8))
+                    .stroke_width(2)
+                    .fill(Some(2u8)),
+                tl,
+                br,
+            );
+            check(
+                Triangle::new(Coord::new(1, 1), Coord::new(22, 4), Coord::new(8, 14))
+                    .stroke(Some(1u8))
+                    .fill(Some(2u8)),
+                tl,
+                br,
+            );
+            check(
+                Polygon::new(&vertices).stroke(Some(1u8)).fill(Some(2u8)),
+                tl,
+                br,
+            );
+            check(
+                RoundedRectangle::new(Coord::new(1, 1), Coord::new(22, 14), CornerRadii::new(4))
+                    .stroke(Some(1u8))
+                    .fill(Some(2u8)),
+                tl,
+                br,
+            );
+        }
+    }
+
+    #[test]
+    fn skips_offscreen_rows() {
+        let circle = Circle::new(Coord::new(500, 500), 500).fill(Some(1u8));
+        let mut pixels = circle.into_iter();
+
+        pixels.clip(Coord::new(0, 495), Coord::new(23, 504));
+
+        // Only the visible rows and columns are visited
+        assert!(pixels.count() <= 24 * 10);
+    }
+
+    #[test]
+    fn normalises_corners() {
+        let mut display = Display::default().clipped(Coord::new(5, 5), Coord::new(2, 2));
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(10, 10)).fill(Some(1u8)));
+
+        let display = display.into_inner();
+        assert_eq!(display.count(1), 16);
+    }
+}
@@ -0,0 +1,265 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::drawable::Pixel;
+use crate::pixelcolor::conversion::Luma;
+use crate::pixelcolor::PixelColor;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+use core::fmt;
+
+/// Conversion from one color type to another, used by the
+/// [`ColorConverted`](./struct.ColorConverted.html) adapter
+///
+/// The position of each pixel is passed along with its color, so a conversion can dither or
+/// otherwise vary with position. This trait is implemented for closures taking the position and
+/// color, and for [`LumaLut`](./struct.LumaLut.html).
+pub trait ColorConversion<C> {
+    /// Color type produced by the conversion
+    type Output;
+
+    /// Convert `color`, drawn at `point`, to the output color type
+    fn convert(&mut self, point: UnsignedCoord, color: C) -> Self::Output;
+}
+
+impl<F, C, O> ColorConversion<C> for F
+where
+    F: FnMut(UnsignedCoord, C) -> O,
+{
+    type Output = O;
+
+    fn convert(&mut self, point: UnsignedCoord, color: C) -> O {
+        self(point, color)
+    }
+}
+
+/// Convert colors by looking up their BT.601 [`luma`](../pixelcolor/conversion/trait.Luma.html)
+/// in a table
+///
+/// The table can have any length. The luma range `0..=255` is split evenly between its entries,
+/// so a table of 16 levels maps luma `0..=15` to the first entry and `240..=255` to the last. This
+/// lets a custom tone curve be used to draw colored images to a greyscale or e-paper display.
+///
+/// ```rust
+/// use embedded_graphics::adapter::{ColorConversion, LumaLut};
+/// use embedded_graphics::pixelcolor::{Gray2, Rgb565};
+/// use embedded_graphics::unsignedcoord::UnsignedCoord;
+///
+/// // Lift the shadows so dark parts of the image stay visible
+/// let mut lut = LumaLut::new(&[Gray2(0), Gray2(2), Gray2(3), Gray2(3)]);
+/// let point = UnsignedCoord::new(0, 0);
+///
+/// assert_eq!(lut.convert(point, Rgb565(0)), Gray2(0));
+/// assert_eq!(lut.convert(point, Rgb565::from((0x50, 0x50, 0x50))), Gray2(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LumaLut<'a, O> {
+    table: &'a [O],
+}
+
+impl<'a, O> LumaLut<'a, O> {
+    /// Create a lookup table from a non-empty slice of output colors, darkest first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` is empty.
+    pub fn new(table: &'a [O]) -> Self {
+        assert!(!table.is_empty(), "Luma lookup table must not be empty");
+
+        Self { table }
+    }
+
+    /// Get the table of output colors
+    pub fn table(&self) -> &'a [O] {
+        self.table
+    }
+}
+
+impl<'a, C, O> ColorConversion<C> for LumaLut<'a, O>
+where
+    C: Luma,
+    O: Copy,
+{
+    type Output = O;
+
+    fn convert(&mut self, _point: UnsignedCoord, color: C) -> O {
+        let index = usize::from(color.luma()) * self.table.len() / 256;
+
+        self.table[index]
+    }
+}
+
+/// Convert the color of every pixel to the color type of the wrapped display using a
+/// [`ColorConversion`](./trait.ColorConversion.html)
+///
+/// Unlike [`ColorMapped`](./struct.ColorMapped.html), the conversion is also given the position of
+/// each pixel, so it can apply a weighted or dithered mapping instead of relying on `From`. This
+/// makes it possible to draw full color assets to a greyscale e-paper panel without banding.
+///
+/// Adapters are usually created with
+/// [`DrawingExt::color_converted_with`](./trait.DrawingExt.html#method.color_converted_with).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::pixelcolor::conversion::Luma;
+/// use embedded_graphics::pixelcolor::dither::BayerMatrix;
+/// use embedded_graphics::pixelcolor::{Gray4, Rgba8888};
+/// use embedded_graphics::unsignedcoord::UnsignedCoord;
+/// use embedded_graphics::egrectangle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let display = MockDisplay([[Gray4(0); 24]; 16]);
+///
+/// // Round the luma of each pixel up or down to one of 16 levels in a 4x4 dither pattern
+/// let mut display = display.color_converted_with(|p: UnsignedCoord, c: Rgba8888| {
+///     let threshold = BayerMatrix::Bayer4x4.threshold(p[0], p[1]);
+///     let level = (u32::from(c.luma()) * 15 + u32::from(threshold)) / 255;
+///
+///     Gray4::new(level as u8)
+/// });
+///
+/// display.draw(egrectangle!((0, 0), (3, 3), fill = Some(Rgba8888::new(0x80, 0x40, 0x20, 0xff))));
+/// ```
+pub struct ColorConverted<D, F> {
+    display: D,
+    conversion: F,
+}
+
+impl<D, F> ColorConverted<D, F> {
+    /// Wrap a display, converting the color of every pixel drawn to it with `conversion`
+    pub fn new(display: D, conversion: F) -> Self {
+        Self {
+            display,
+            conversion,
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, F> fmt::Debug for ColorConverted<D, F>
+where
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColorConverted")
+            .field("display", &self.display)
+            .finish()
+    }
+}
+
+impl<D, F, C> Drawing<C> for ColorConverted<D, F>
+where
+    D: Drawing<F::Output>,
+    F: ColorConversion<C>,
+    F::Output: PixelColor,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let conversion = &mut self.conversion;
+
+        self.display.draw(
+            item.into_iter()
+                .map(|Pixel(coord, color)| Pixel(coord, conversion.convert(coord, color))),
+        );
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, F, C> async_drawing::AsyncDrawing<C> for ColorConverted<D, F>
+where
+    D: async_drawing::AsyncDrawing<F::Output>,
+    F: ColorConversion<C>,
+    F::Output: PixelColor,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let conversion = &mut self.conversion;
+
+        self.display
+            .draw(
+                item.into_iter()
+                    .map(|Pixel(coord, color)| Pixel(coord, conversion.convert(coord, color))),
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{Gray2, Gray4, Gray8, Rgb565};
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn conversion_sees_position() {
+        let mut display =
+            MockDisplay([[Gray4(0); 24]; 16]).color_converted_with(|p: UnsignedCoord, c: Gray8| {
+                if (p[0] ^ p[1]) & 1 == 0 {
+                    Gray4::new(c.0 >> 4)
+                } else {
+                    Gray4(0)
+                }
+            });
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(2, 1)).fill(Some(Gray8(0xa0))));
+
+        let display = display.into_inner();
+        assert_eq!(
+            display.0[0][..4],
+            [Gray4(0xa), Gray4(0), Gray4(0xa), Gray4(0)]
+        );
+        assert_eq!(
+            display.0[1][..4],
+            [Gray4(0), Gray4(0xa), Gray4(0), Gray4(0)]
+        );
+    }
+
+    #[test]
+    fn luma_lookup_table() {
+        let table = [Gray2(3), Gray2(2), Gray2(1), Gray2(0)];
+        let mut display =
+            MockDisplay([[Gray2(0); 24]; 16]).color_converted_with(LumaLut::new(&table));
+
+        let colors = [
+            Rgb565(0),
+            Rgb565::from((0x40, 0x40, 0x40)),
+            Rgb565::from((0x80, 0x80, 0x80)),
+            Rgb565(0xffff),
+        ];
+        for (x, &color) in colors.iter().enumerate() {
+            let x = x as i32;
+            display.draw(Rectangle::new(Coord::new(x, 0), Coord::new(x, 0)).fill(Some(color)));
+        }
+
+        assert_eq!(display.inner().0[0][..4], table);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_table() {
+        LumaLut::<Gray2>::new(&[]);
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// Skip every pixel of one color instead of drawing it to the wrapped display
+///
+/// The skipped color works as a transparent color key, so sprites with a background color can be
+/// drawn over other content on displays that can't read back or blend pixels.
+///
+/// Adapters are usually created with
+/// [`DrawingExt::color_keyed`](./trait.DrawingExt.html#method.color_keyed).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::image::Image8BPP;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// // A 3x1 sprite with a magenta background
+/// let sprite: Image8BPP<u8> = Image8BPP::new(&[0xf8, 1, 0xf8], 3, 1);
+///
+/// let mut display = Display::default().color_keyed(0xf8);
+/// display.draw(&sprite);
+///
+/// assert_eq!(display.into_inner().0[0][..3], [0, 1, 0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ColorKeyed<D, C> {
+    display: D,
+    key: C,
+}
+
+impl<D, C> ColorKeyed<D, C>
+where
+    C: Copy,
+{
+    /// Wrap a display, skipping every pixel drawn to it in the color `key`
+    pub fn new(display: D, key: C) -> Self {
+        Self { display, key }
+    }
+
+    /// Get the color that is skipped
+    pub fn key(&self) -> C {
+        self.key
+    }
+
+    /// Change the color skipped for items drawn from now on
+    pub fn set_key(&mut self, key: C) {
+        self.key = key;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, C> Drawing<C> for ColorKeyed<D, C>
+where
+    D: Drawing<C>,
+    C: PixelColor + PartialEq,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let key = self.key;
+
+        self.display.draw(
+            item.into_iter()
+                .filter(move |Pixel(_, color)| *color != key),
+        );
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let key = self.key;
+
+        self.display
+            .fill_runs(runs.into_iter().filter(move |(_, _, color)| *color != key));
+    }
+}
+
+impl<D, C> ReadPixel<C> for ColorKeyed<D, C>
+where
+    D: ReadPixel<C>,
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.display.pixel(point)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, C> async_drawing::AsyncDrawing<C> for ColorKeyed<D, C>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    C: PixelColor + PartialEq,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let key = self.key;
+
+        self.display
+            .draw(
+                item.into_iter()
+                    .filter(move |Pixel(_, color)| *color != key),
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::mock_display::Display;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn skips_key_color() {
+        let mut display = Display::default().color_keyed(0u8);
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(3, 1)).fill(Some(5u8)));
+        display.draw(
+            Rectangle::new(Coord::new(1, 0), Coord::new(2, 1))
+                .stroke(Some(7u8))
+                .fill(Some(0u8)),
+        );
+        display.fill_runs(
+            [(Coord::new(0, 2), 2, 0u8), (Coord::new(2, 2), 2, 9)]
+                .iter()
+                .cloned(),
+        );
+
+        assert_eq!(display.key(), 0);
+        assert_eq!(display.pixel(UnsignedCoord::new(1, 0)), Some(7));
+
+        let display = display.into_inner();
+        assert_eq!(display.0[0][..5], [5, 7, 7, 5, 0]);
+        assert_eq!(display.0[2][..5], [0, 0, 9, 9, 0]);
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::Drawing;
+use core::fmt;
+
+/// Apply a closure to the color of every pixel before drawing it to the wrapped display
+///
+/// The closure can return a different color type to the one being drawn, so this adapter can also
+/// be used to draw items in one color type to a display that uses another. Common uses are
+/// inverting colors, tinting, scaling brightness, or shifting everything to red for a night mode.
+///
+/// Adapters are usually created with [`DrawingExt::color_map`](./trait.DrawingExt.html#method.color_map).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = Display::default().color_map(|c: u8| 255 - c);
+///
+/// display.draw(egline!((0, 0), (1, 0), stroke = Some(200u8)));
+///
+/// assert_eq!(display.into_inner().0[0][..2], [55, 55]);
+/// ```
+pub struct ColorMapped<D, F> {
+    display: D,
+    map: F,
+}
+
+impl<D, F> ColorMapped<D, F> {
+    /// Wrap a display, passing the color of every pixel drawn to it through `map`
+    pub fn new(display: D, map: F) -> Self {
+        Self { display, map }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, F> fmt::Debug for ColorMapped<D, F>
+where
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColorMapped")
+            .field("display", &self.display)
+            .finish()
+    }
+}
+
+impl<D, F, C, O> Drawing<C> for ColorMapped<D, F>
+where
+    D: Drawing<O>,
+    F: FnMut(C) -> O,
+    C: PixelColor,
+    O: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let map = &mut self.map;
+
+        self.display.draw(
+            item.into_iter()
+                .map(|Pixel(coord, color)| Pixel(coord, map(color))),
+        );
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let map = &mut self.map;
+
+        self.display.fill_runs(
+            runs.into_iter()
+                .map(|(start, length, color)| (start, length, map(color))),
+        );
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, F, C, O> async_drawing::AsyncDrawing<C> for ColorMapped<D, F>
+where
+    D: async_drawing::AsyncDrawing<O>,
+    F: FnMut(C) -> O,
+    C: PixelColor,
+    O: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let map = &mut self.map;
+
+        self.display
+            .draw(
+                item.into_iter()
+                    .map(|Pixel(coord, color)| Pixel(coord, map(color))),
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::{Display, MockDisplay};
+    use crate::pixelcolor::{BinaryColor, Gray8};
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn maps_colors() {
+        let mut display = Display::default().color_map(|c: u8| c / 2);
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(1, 1)).fill(Some(100u8)));
+
+        let display = display.into_inner();
+        assert_eq!(display.0[0][..3], [50, 50, 0]);
+        assert_eq!(display.0[1][..3], [50, 50, 0]);
+    }
+
+    #[test]
+    fn changes_color_type() {
+        let mut display = MockDisplay([[Gray8(0); 24]; 16])
+            .color_map(|c: BinaryColor| c.select(Gray8(10), Gray8(20)));
+
+        let on = Rectangle::new(Coord::new(0, 0), Coord::new(2, 0)).fill(Some(BinaryColor::On));
+        let off = Rectangle::new(Coord::new(1, 0), Coord::new(1, 0)).fill(Some(BinaryColor::Off));
+
+        display.draw(on);
+        display.draw(off);
+
+        assert_eq!(
+            display.inner().0[0][..4],
+            [Gray8(20), Gray8(10), Gray8(20), Gray8(0)]
+        );
+    }
+
+    #[test]
+    fn stateful_closure() {
+        let mut count = 0;
+
+        {
+            let mut display = Display::default().color_map(|c: u8| {
+                count += 1;
+                c
+            });
+
+            display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(3, 3)).fill(Some(1u8)));
+        }
+
+        assert_eq!(count, 16);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::{run_end, Drawing};
+
+/// Area of a display that has been drawn to, as recorded by
+/// [`DamageTracking`](./struct.DamageTracking.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct DirtyRegion {
+    left: u32,
+    top: u32,
+    right: u32,
+    bottom: u32,
+}
+
+impl DirtyRegion {
+    /// An unused region, for initializing region buffers
+    pub const EMPTY: DirtyRegion = DirtyRegion {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+
+    /// Create a region covering the pixels from `top_left` to `bottom_right`, inclusive
+    pub fn new(top_left: UnsignedCoord, bottom_right: UnsignedCoord) -> Self {
+        Self {
+            left: top_left[0].min(bottom_right[0]),
+            top: top_left[1].min(bottom_right[1]),
+            right: top_left[0].max(bottom_right[0]),
+            bottom: top_left[1].max(bottom_right[1]),
+        }
+    }
+
+    /// Top left corner of the region
+    pub fn top_left(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.left, self.top)
+    }
+
+    /// Bottom right corner of the region, inclusive
+    pub fn bottom_right(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.right, self.bottom)
+    }
+
+    /// Width and height of the region in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.right - self.left + 1, self.bottom - self.top + 1)
+    }
+
+    /// Smallest region that covers both `self` and `other`
+    pub fn union(&self, other: &DirtyRegion) -> DirtyRegion {
+        DirtyRegion {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
+    /// Whether the two regions overlap or share an edge, so merging them covers no extra pixels
+    /// along that edge
+    fn touches(&self, other: &DirtyRegion) -> bool {
+        let apart = |max: u32, min: u32| max.saturating_add(1) < min;
+
+        !(apart(self.right, other.left)
+            || apart(other.right, self.left)
+            || apart(self.bottom, other.top)
+            || apart(other.bottom, self.top))
+    }
+
+    /// Number of pixels in the region
+    fn area(&self) -> u64 {
+        let size = self.size();
+
+        u64::from(size[0]) * u64::from(size[1])
+    }
+}
+
+/// Record the areas of the wrapped display that have been drawn to
+///
+/// The bounding box of every item drawn is added to a list of dirty regions stored in a buffer
+/// passed to [`new`](#method.new). Regions that overlap or touch are merged, and once the buffer is
+/// full each new region is merged with the one it grows the least. A driver can then call
+/// [`take_dirty_regions`](#method.take_dirty_regions) when it flushes its framebuffer and only
+/// send the windows that changed, which saves a lot of time over a slow bus like SPI.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::{DamageTracking, DirtyRegion};
+/// use embedded_graphics::{egcircle, egline};
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut regions = [DirtyRegion::EMPTY; 4];
+/// let mut display = DamageTracking::new(Display::default(), &mut regions);
+///
+/// display.draw(egline!((1, 1), (5, 1), stroke = Some(1u8)));
+/// display.draw(egcircle!((16, 10), 3, fill = Some(1u8)));
+///
+/// for region in display.take_dirty_regions() {
+///     // Send the pixels from `region.top_left()` to `region.bottom_right()` to the display
+/// }
+///
+/// assert!(display.take_dirty_regions().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct DamageTracking<'a, D> {
+    display: D,
+    regions: &'a mut [DirtyRegion],
+    len: usize,
+}
+
+impl<'a, D> DamageTracking<'a, D> {
+    /// Wrap a display, recording dirty regions in `regions`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `regions` is empty.
+    pub fn new(display: D, regions: &'a mut [DirtyRegion]) -> Self {
+        assert!(!regions.is_empty(), "Region buffer must not be empty");
+
+        Self {
+            display,
+            regions,
+            len: 0,
+        }
+    }
+
+    /// Get the regions drawn to since the last call to
+    /// [`take_dirty_regions`](#method.take_dirty_regions), without clearing them
+    pub fn dirty_regions(&self) -> &[DirtyRegion] {
+        &self.regions[..self.len]
+    }
+
+    /// Get the smallest region covering everything drawn since the last call to
+    /// [`take_dirty_regions`](#method.take_dirty_regions), or `None` if nothing has been drawn
+    pub fn dirty_bounds(&self) -> Option<DirtyRegion> {
+        let mut regions = self.dirty_regions().iter();
+        let first = *regions.next()?;
+
+        Some(regions.fold(first, |bounds, region| bounds.union(region)))
+    }
+
+    /// Get the regions drawn to since the last call to this method, and start recording again with
+    /// no dirty regions
+    pub fn take_dirty_regions(&mut self) -> &[DirtyRegion] {
+        let len = self.len;
+        self.len = 0;
+
+        &self.regions[..len]
+    }
+
+    /// Mark a region as dirty without drawing anything, for example after changing the contents of
+    /// the wrapped display directly
+    pub fn mark_dirty(&mut self, mut region: DirtyRegion) {
+        loop {
+            // Absorb every region that touches the new one. Each merge can make the new region
+            // touch ones already checked, so start again after every merge.
+            let mut i = 0;
+            while i < self.len {
+                if self.regions[i].touches(&region) {
+                    region = region.union(&self.regions[i]);
+                    self.remove(i);
+                    i = 0;
+                } else {
+                    i += 1;
+                }
+            }
+
+            if self.len < self.regions.len() {
+                self.regions[self.len] = region;
+                self.len += 1;
+
+                return;
+            }
+
+            // The buffer is full, so merge with the region that grows the least and add the
+            // result back in case it now touches others
+            let growth = |r: &DirtyRegion| r.union(&region).area() - r.area();
+            let closest = (0..self.len)
+                .min_by_key(|&i| growth(&self.regions[i]))
+                .unwrap_or(0);
+
+            region = region.union(&self.regions[closest]);
+            self.remove(closest);
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Remove the region at `index`, moving the last region into its place
+    fn remove(&mut self, index: usize) {
+        self.len -= 1;
+        self.regions[index] = self.regions[self.len];
+    }
+}
+
+impl<'a, D, C> Drawing<C> for DamageTracking<'a, D>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let mut bounds: Option<DirtyRegion> = None;
+
+        self.display
+            .draw(item.into_iter().inspect(|Pixel(coord, _)| {
+                let pixel = DirtyRegion::new(*coord, *coord);
+
+                bounds = Some(bounds.map_or(pixel, |bounds| bounds.union(&pixel)));
+            }));
+
+        if let Some(bounds) = bounds {
+            self.mark_dirty(bounds);
+        }
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let mut bounds: Option<DirtyRegion> = None;
+
+        self.display
+            .fill_runs(runs.into_iter().inspect(|&(start, length, _)| {
+                let end = run_end(start, length);
+
+                if start[1] < 0 || end[0] < 0 || end[0] < start[0] {
+                    return;
+                }
+
+                let run = DirtyRegion::new(
+                    Coord::new(start[0].max(0), start[1]).to_unsigned(),
+                    end.to_unsigned(),
+                );
+
+                bounds = Some(bounds.map_or(run, |bounds| bounds.union(&run)));
+            }));
+
+        if let Some(bounds) = bounds {
+            self.mark_dirty(bounds);
+        }
+    }
+}
+
+impl<'a, D, C> ReadPixel<C> for DamageTracking<'a, D>
+where
+    D: ReadPixel<C>,
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.display.pixel(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Line, Rectangle};
+    use crate::style::WithStyle;
+
+    fn region(top_left: (u32, u32), bottom_right: (u32, u32)) -> DirtyRegion {
+        DirtyRegion::new(
+            UnsignedCoord::new(top_left.0, top_left.1),
+            UnsignedCoord::new(bottom_right.0, bottom_right.1),
+        )
+    }
+
+    #[test]
+    fn records_bounding_boxes() {
+        let mut regions = [DirtyRegion::EMPTY; 4];
+        let mut display = DamageTracking::new(Display::default(), &mut regions);
+
+        display.draw(Line::new(Coord::new(2, 3), Coord::new(6, 5)).stroke(Some(1u8)));
+        display.draw(Circle::new(Coord::new(16, 10), 2).fill(Some(1u8)));
+        display.draw(Rectangle::new(Coord::new(-5, -5), Coord::new(-1, -1)).fill(Some(1u8)));
+
+        assert_eq!(
+            display.dirty_regions(),
+            [region((2, 3), (6, 5)), region((14, 8), (18, 12))]
+        );
+        assert_eq!(display.dirty_bounds(), Some(region((2, 3), (18, 12))));
+
+        assert_eq!(display.take_dirty_regions().len(), 2);
+        assert_eq!(display.dirty_regions(), []);
+        assert_eq!(display.dirty_bounds(), None);
+    }
+
+    #[test]
+    fn records_runs() {
+        let mut regions = [DirtyRegion::EMPTY; 4];
+        let mut display = DamageTracking::new(Display::default(), &mut regions);
+
+        display.fill_runs(
+            [
+                (Coord::new(-2, 4), 5, 1u8),
+                (Coord::new(1, 5), 8, 1),
+                (Coord::new(-5, 6), 2, 1),
+                (Coord::new(1, -1), 2, 1),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        assert_eq!(display.dirty_regions(), [region((0, 4), (8, 5))]);
+        assert_eq!(display.inner().0[4][..4], [1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn merges_touching_regions() {
+        let mut regions = [DirtyRegion::EMPTY; 4];
+        let mut display = DamageTracking::new(Display::default(), &mut regions);
+
+        display.mark_dirty(region((0, 0), (2, 2)));
+        display.mark_dirty(region((10, 0), (12, 2)));
+        display.mark_dirty(region((3, 1), (9, 1)));
+
+        assert_eq!(display.dirty_regions(), [region((0, 0), (12, 2))]);
+    }
+
+    #[test]
+    fn full_buffer_merges_closest() {
+        let mut regions = [DirtyRegion::EMPTY; 2];
+        let mut display = DamageTracking::new(Display::default(), &mut regions);
+
+        display.mark_dirty(region((0, 0), (1, 1)));
+        display.mark_dirty(region((20, 10), (21, 11)));
+        display.mark_dirty(region((4, 0), (5, 1)));
+
+        assert_eq!(
+            display.dirty_regions(),
+            [region((20, 10), (21, 11)), region((0, 0), (5, 1))]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use core::marker::PhantomData;
+
+use crate::drawable::Pixel;
+use crate::pixelcolor::dither::{BayerMatrix, DitherTarget};
+use crate::pixelcolor::{PixelColor, Rgba8888};
+use crate::Drawing;
+
+/// Convert pixels to the color type of the wrapped display using ordered dithering
+///
+/// Each pixel is converted to `T` by rounding its channels up or down depending on its position in
+/// a [`BayerMatrix`](../pixelcolor/dither/enum.BayerMatrix.html). This hides the banding that
+/// appears when drawing gradients to a display with few bits per channel.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::Dither;
+/// use embedded_graphics::pixelcolor::dither::BayerMatrix;
+/// use embedded_graphics::pixelcolor::{Gray2, Rgb565};
+/// use embedded_graphics::egrectangle;
+/// # use embedded_graphics::Drawing;
+/// #
+/// # #[derive(Default)]
+/// # struct Gray2Display(Vec<Pixel<Gray2>>);
+/// #
+/// # impl Drawing<Gray2> for Gray2Display {
+/// #     fn draw<T>(&mut self, item: T) where T: IntoIterator<Item = Pixel<Gray2>> {
+/// #         self.0.extend(item);
+/// #     }
+/// # }
+///
+/// let mut display: Dither<_, Gray2> = Dither::new(Gray2Display::default(), BayerMatrix::Bayer4x4);
+///
+/// display.draw(egrectangle!((0, 0), (3, 3), fill = Some(Rgb565::from((0x40, 0x40, 0x40)))));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Dither<D, T> {
+    display: D,
+    matrix: BayerMatrix,
+    target: PhantomData<T>,
+}
+
+impl<D, T> Dither<D, T>
+where
+    D: Drawing<T>,
+    T: PixelColor + DitherTarget,
+{
+    /// Wrap a display, dithering with the given matrix
+    pub fn new(display: D, matrix: BayerMatrix) -> Self {
+        Self {
+            display,
+            matrix,
+            target: PhantomData,
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, T, C> Drawing<C> for Dither<D, T>
+where
+    D: Drawing<T>,
+    T: PixelColor + DitherTarget,
+    C: PixelColor + Into<Rgba8888>,
+{
+    fn draw<I>(&mut self, item: I)
+    where
+        I: IntoIterator<Item = Pixel<C>>,
+    {
+        let matrix = self.matrix;
+
+        self.display
+            .draw(item.into_iter().map(|Pixel(coord, color)| {
+                let threshold = matrix.threshold(coord[0], coord[1]);
+
+                Pixel(coord, T::dither(color.into(), threshold))
+            }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn half_grey_is_checkered() {
+        let mut display: Dither<_, BinaryColor> =
+            Dither::new(MockDisplay::default(), BayerMatrix::Bayer4x4);
+
+        display.draw(
+            Rectangle::new(Coord::new(0, 0), Coord::new(3, 3)).fill(Some(Rgba8888::from(128u8))),
+        );
+
+        let display = display.into_inner();
+        let on = display.0[..4]
+            .iter()
+            .flat_map(|row| row[..4].iter())
+            .filter(|c| c.is_on())
+            .count();
+
+        assert_eq!(on, 8);
+        assert_eq!(display.0[0][0], BinaryColor::On);
+        assert_eq!(display.0[0][1], BinaryColor::Off);
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::{run_end, Drawing};
+
+/// Coordinates mirrored by the [`Flipped`](./struct.Flipped.html) adapter
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlipAxis {
+    /// Mirror x coordinates, swapping the left and right edges
+    X,
+
+    /// Mirror y coordinates, swapping the top and bottom edges
+    Y,
+
+    /// Mirror both coordinates, which is the same as rotating by 180 degrees
+    Both,
+}
+
+/// Mirror everything drawn to the wrapped display
+///
+/// This is useful for displays that are mounted backwards or viewed through a mirror, like a head
+/// up display. Displays don't report their own size, so the size of the display has to be given.
+/// Pixels outside the display are dropped.
+///
+/// Adapters are usually created with [`DrawingExt::flipped`](./trait.DrawingExt.html#method.flipped).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::{DrawingExt, FlipAxis};
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = Display::default().flipped(FlipAxis::X, UnsignedCoord::new(24, 16));
+///
+/// display.draw(egline!((0, 2), (2, 2), stroke = Some(1u8)));
+///
+/// assert_eq!(display.into_inner().0[2][20..], [0, 1, 1, 1]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Flipped<D> {
+    display: D,
+    axis: FlipAxis,
+    size: UnsignedCoord,
+}
+
+impl<D> Flipped<D> {
+    /// Wrap a display that is `size` pixels, mirroring everything drawn to it along `axis`
+    pub fn new(display: D, axis: FlipAxis, size: UnsignedCoord) -> Self {
+        Self {
+            display,
+            axis,
+            size,
+        }
+    }
+
+    /// Get the mirrored axis
+    pub fn axis(&self) -> FlipAxis {
+        self.axis
+    }
+
+    /// Change the axis mirrored for items drawn from now on
+    pub fn set_axis(&mut self, axis: FlipAxis) {
+        self.axis = axis;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Mirror a point onto the wrapped display, or return `None` if it is outside the display
+    fn mirror(axis: FlipAxis, size: UnsignedCoord, point: UnsignedCoord) -> Option<UnsignedCoord> {
+        let (x, y) = (point[0], point[1]);
+
+        if x >= size[0] || y >= size[1] {
+            return None;
+        }
+
+        let (x, y) = match axis {
+            FlipAxis::X => (size[0] - 1 - x, y),
+            FlipAxis::Y => (x, size[1] - 1 - y),
+            FlipAxis::Both => (size[0] - 1 - x, size[1] - 1 - y),
+        };
+
+        Some(UnsignedCoord::new(x, y))
+    }
+}
+
+impl<D, C> Drawing<C> for Flipped<D>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (axis, size) = (self.axis, self.size);
+
+        self.display
+            .draw(item.into_iter().filter_map(|Pixel(coord, color)| {
+                Self::mirror(axis, size, coord).map(|p| Pixel(p, color))
+            }));
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let (axis, size) = (self.axis, self.size);
+
+        self.display
+            .fill_runs(runs.into_iter().filter_map(move |(start, length, color)| {
+                let left = start[0].max(0);
+                let right = i64::from(run_end(start, length)[0]).min(i64::from(size[0]) - 1);
+
+                if start[1] < 0 || i64::from(left) > right {
+                    return None;
+                }
+
+                // Mirroring the ends of a run gives the ends of the mirrored run, possibly swapped
+                let y = start[1] as u32;
+                let a = Self::mirror(axis, size, UnsignedCoord::new(left as u32, y))?;
+                let b = Self::mirror(axis, size, UnsignedCoord::new(right as u32, y))?;
+                let length = (right - i64::from(left) + 1) as u32;
+
+                Some((
+                    Coord::new(a[0].min(b[0]) as i32, a[1] as i32),
+                    length,
+                    color,
+                ))
+            }));
+    }
+}
+
+impl<D, C> ReadPixel<C> for Flipped<D>
+where
+    D: ReadPixel<C>,
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        Self::mirror(self.axis, self.size, point).and_then(|point| self.display.pixel(point))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, C> async_drawing::AsyncDrawing<C> for Flipped<D>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (axis, size) = (self.axis, self.size);
+
+        self.display
+            .draw(item.into_iter().filter_map(move |Pixel(coord, color)| {
+                Self::mirror(axis, size, coord).map(|p| Pixel(p, color))
+            }))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::{Rectangle, Triangle};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn matches_mirrored_drawing() {
+        let triangle =
+            Triangle::new(Coord::new(1, 1), Coord::new(15, 3), Coord::new(4, 12)).fill(Some(1u8));
+        let mut plain = Display::default();
+        plain.draw(triangle);
+
+        for &axis in &[FlipAxis::X, FlipAxis::Y, FlipAxis::Both] {
+            let mut display = Display::default().flipped(axis, UnsignedCoord::new(24, 16));
+            display.draw(triangle);
+            let display = display.into_inner();
+
+            for (y, row) in plain.0.iter().enumerate() {
+                for (x, &pixel) in row.iter().enumerate() {
+                    let (fx, fy) = match axis {
+                        FlipAxis::X => (23 - x, y),
+                        FlipAxis::Y => (x, 15 - y),
+                        FlipAxis::Both => (23 - x, 15 - y),
+                    };
+                    assert_eq!(display.0[fy][fx], pixel, "{:?} ({}, {})", axis, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn runs_match_mirrored_drawing() {
+        for &axis in &[FlipAxis::X, FlipAxis::Y, FlipAxis::Both] {
+            let mut expected = Display::default().flipped(axis, UnsignedCoord::new(24, 16));
+            expected.draw(Rectangle::new(Coord::new(0, 3), Coord::new(3, 3)).fill(Some(1u8)));
+            expected.draw(Rectangle::new(Coord::new(20, 5), Coord::new(23, 5)).fill(Some(2u8)));
+
+            let mut display = Display::default().flipped(axis, UnsignedCoord::new(24, 16));
+            display.fill_runs(
+                [
+                    (Coord::new(-2, 3), 6, 1u8),
+                    (Coord::new(20, 5), 10, 2),
+                    (Coord::new(4, 16), 3, 3),
+                ]
+                .iter()
+                .cloned(),
+            );
+
+            assert_eq!(display.into_inner(), expected.into_inner(), "{:?}", axis);
+        }
+    }
+
+    #[test]
+    fn drops_pixels_outside_display() {
+        let mut display = Display::default().flipped(FlipAxis::Both, UnsignedCoord::new(10, 10));
+
+        display.draw(Rectangle::new(Coord::new(8, 8), Coord::new(12, 12)).fill(Some(1u8)));
+        assert_eq!(display.pixel(UnsignedCoord::new(9, 9)), Some(1));
+        assert_eq!(display.pixel(UnsignedCoord::new(10, 9)), None);
+
+        let display = display.into_inner();
+        assert_eq!(display.count(1), 4);
+        assert_eq!(display.0[0][..3], [1, 1, 0]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::conversion::Luma;
+use crate::pixelcolor::{BinaryColor, PixelColor, Rgba8888};
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// Convert pixels to [`BinaryColor`](../pixelcolor/enum.BinaryColor.html) using Floyd-Steinberg
+/// error diffusion
+///
+/// Each pixel's luma is compared against a threshold, and the difference between the original and
+/// the displayed level is spread over the neighbouring pixels to the right and below. This gives
+/// much better results than plain thresholding when drawing photos to monochrome displays.
+///
+/// Error diffusion only works when pixels arrive in raster order (left to right, top to bottom),
+/// which is the case for images and filled rectangles. The adapter resets its state at the start
+/// of every `draw()` call and whenever a pixel doesn't follow on from the previous one.
+///
+/// No memory is allocated. Instead, the caller provides an error buffer with one entry per pixel in
+/// a row of the display. Pixels to the right of the buffer are thresholded without dithering.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::FloydSteinberg;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::egrectangle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+///
+/// let mut errors = [0i16; 24];
+/// let mut display = FloydSteinberg::new(MockDisplay::<BinaryColor>::default(), &mut errors);
+///
+/// display.draw(egrectangle!((0, 0), (7, 7), fill = Some(64u8)));
+/// ```
+#[derive(Debug)]
+pub struct FloydSteinberg<'a, D> {
+    display: D,
+    errors: &'a mut [i16],
+    threshold: u8,
+}
+
+impl<'a, D> FloydSteinberg<'a, D>
+where
+    D: Drawing<BinaryColor>,
+{
+    /// Wrap a display, using `errors` to store the error for the next row of pixels
+    ///
+    /// `errors` should have at least as many entries as the display is wide.
+    pub fn new(display: D, errors: &'a mut [i16]) -> Self {
+        Self {
+            display,
+            errors,
+            threshold: BinaryColor::DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Set the luma level at or above which a pixel is on
+    pub fn with_threshold(self, threshold: u8) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<'a, D, C> Drawing<C> for FloydSteinberg<'a, D>
+where
+    D: Drawing<BinaryColor>,
+    C: PixelColor + Into<Rgba8888>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for e in self.errors.iter_mut() {
+            *e = 0;
+        }
+
+        let diffuser = Diffuser {
+            pixels: item.into_iter(),
+            errors: self.errors,
+            threshold: self.threshold,
+            previous: None,
+            carry: 0,
+            pending: 0,
+        };
+
+        self.display.draw(diffuser);
+    }
+}
+
+/// Iterator that applies error diffusion to each pixel as it's drawn
+struct Diffuser<'a, I> {
+    pixels: I,
+    errors: &'a mut [i16],
+    threshold: u8,
+
+    /// The coordinate of the last pixel
+    previous: Option<UnsignedCoord>,
+
+    /// Error to add to the next pixel in this row
+    carry: i16,
+
+    /// Error to add to the pixel below the next pixel in this row
+    pending: i16,
+}
+
+impl<'a, I> Diffuser<'a, I> {
+    /// Reset the carried errors if `coord` doesn't directly follow the previous pixel, and clear
+    /// the error buffer if it isn't on the same or the next row
+    fn sync(&mut self, coord: UnsignedCoord) {
+        if let Some(previous) = self.previous {
+            if coord[1] != previous[1] || coord[0] != previous[0] + 1 {
+                self.carry = 0;
+                self.pending = 0;
+            }
+
+            if coord[1] != previous[1] && coord[1] != previous[1] + 1 {
+                for e in self.errors.iter_mut() {
+                    *e = 0;
+                }
+            }
+        }
+
+        self.previous = Some(coord);
+    }
+}
+
+impl<'a, I, C> Iterator for Diffuser<'a, I>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor + Into<Rgba8888>,
+{
+    type Item = Pixel<BinaryColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(coord, color) = self.pixels.next()?;
+        let luma = i16::from(color.into().luma());
+
+        self.sync(coord);
+
+        let x = coord[0] as usize;
+
+        if x >= self.errors.len() {
+            return Some(Pixel(coord, (luma >= i16::from(self.threshold)).into()));
+        }
+
+        let value = luma + self.errors[x] + self.carry;
+        let on = value >= i16::from(self.threshold);
+        let error = value - if on { 255 } else { 0 };
+
+        if x > 0 {
+            self.errors[x - 1] += error * 3 / 16;
+        }
+        self.errors[x] = self.pending + error * 5 / 16;
+        self.pending = error / 16;
+        self.carry = error * 7 / 16;
+
+        Some(Pixel(coord, on.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::mock_display::MockDisplay;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    #[test]
+    fn solid_colors() {
+        let mut errors = [0i16; 24];
+        let mut display = FloydSteinberg::new(MockDisplay::default(), &mut errors);
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(7, 7)).fill(Some(255u8)));
+        assert_eq!(display.inner().count(BinaryColor::On), 64);
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(7, 7)).fill(Some(0u8)));
+        assert_eq!(display.inner().count(BinaryColor::On), 0);
+    }
+
+    #[test]
+    fn density_matches_level() {
+        for level in [64u8, 128, 192].iter() {
+            let mut errors = [0i16; 24];
+            let mut display = FloydSteinberg::new(MockDisplay::default(), &mut errors);
+
+            display.draw(
+                Rectangle::new(Coord::new(0, 0), Coord::new(15, 15))
+                    .fill(Some(Rgba8888::from(*level))),
+            );
+
+            let on = display.inner().count(BinaryColor::On);
+            let expected = usize::from(*level) * 256 / 255;
+
+            assert!(
+                (on as isize - expected as isize).abs() <= 8,
+                "level {}: {} on, expected {}",
+                level,
+                on,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn pixels_outside_buffer_are_thresholded() {
+        let mut errors = [0i16; 2];
+        let mut display = FloydSteinberg::new(MockDisplay::default(), &mut errors);
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(7, 0)).fill(Some(100u8)));
+
+        assert!(display.inner().0[0][2..8].iter().all(|c| c.is_off()));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::gamma::GammaCorrect;
+use crate::pixelcolor::PixelColor;
+use crate::Drawing;
+
+/// Gamma correct every pixel before drawing it to the wrapped display
+///
+/// Colors are converted from perceptual values to linear light with
+/// [`gamma_decode`](../pixelcolor/gamma/fn.gamma_decode.html). This makes mid-tones look right on
+/// displays with a linear response, like LED matrices driven by PWM.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::GammaCorrected;
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = GammaCorrected::new(Display::default());
+///
+/// display.draw(egline!((0, 0), (1, 0), stroke = Some(128u8)));
+///
+/// assert_eq!(display.into_inner().0[0][..2], [56, 56]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GammaCorrected<D> {
+    display: D,
+}
+
+impl<D> GammaCorrected<D> {
+    /// Wrap a display
+    pub fn new(display: D) -> Self {
+        Self { display }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<D, C> Drawing<C> for GammaCorrected<D>
+where
+    D: Drawing<C>,
+    C: PixelColor + GammaCorrect,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        self.display.draw(
+            item.into_iter()
+                .map(|Pixel(coord, color)| Pixel(coord, color.gamma_decode())),
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Adapters that wrap a display to change how items are drawn to it
+//!
+//! Each adapter owns the display it wraps and implements [`Drawing`](../trait.Drawing.html) itself,
+//! so it can be used anywhere a display can. Use `into_inner()` to get the wrapped display back.
+
+mod alpha;
+mod clipped;
+mod color_convert;
+mod color_key;
+mod color_map;
+mod damage;
+mod dither;
+mod flipped;
+mod floyd_steinberg;
+mod gamma;
+mod palette;
+mod raster_op;
+mod rotated;
+mod scaled;
+mod tee;
+
+pub use self::alpha::AlphaBlended;
+pub use self::clipped::{Clipped, ShapeClipped};
+pub use self::color_convert::{ColorConversion, ColorConverted, LumaLut};
+pub use self::color_key::ColorKeyed;
+pub use self::color_map::ColorMapped;
+pub use self::damage::{DamageTracking, DirtyRegion};
+pub use self::dither::Dither;
+pub use self::flipped::{FlipAxis, Flipped};
+pub use self::floyd_steinberg::FloydSteinberg;
+pub use self::gamma::GammaCorrected;
+pub use self::palette::PaletteAdapter;
+pub use self::raster_op::RasterOp;
+pub use self::rotated::{Rotated, Rotation};
+pub use self::scaled::Scaled;
+pub use self::tee::Tee;
+
+use crate::coord::Coord;
+use crate::drawable::Dimensions;
+use crate::pixelcolor::PixelColor;
+use crate::primitives::ContainsPoint;
+use crate::style::DrawMode;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// Extension methods to wrap a display in an adapter
+///
+/// This trait is implemented for every type that implements [`Drawing`](../trait.Drawing.html).
+pub trait DrawingExt<C>: Drawing<C> + Sized
+where
+    C: PixelColor,
+{
+    /// Wrap the display in a [`ColorMapped`](./struct.ColorMapped.html) adapter that passes the
+    /// color of every pixel through `map` before drawing it
+    fn color_map<F, I>(self, map: F) -> ColorMapped<Self, F>
+    where
+        F: FnMut(I) -> C,
+        I: PixelColor,
+    {
+        ColorMapped::new(self, map)
+    }
+
+    /// Wrap the display in a [`ColorConverted`](./struct.ColorConverted.html) adapter that
+    /// converts the color of every pixel with `conversion`, which is also given its position
+    ///
+    /// The input color type is chosen by the items drawn to the adapter, so one adapter using a
+    /// [`LumaLut`](./struct.LumaLut.html) can draw items of several color types.
+    fn color_converted_with<F>(self, conversion: F) -> ColorConverted<Self, F> {
+        ColorConverted::new(self, conversion)
+    }
+
+    /// Wrap the display in a [`ColorKeyed`](./struct.ColorKeyed.html) adapter that skips every
+    /// pixel drawn in the color `key`
+    fn color_keyed(self, key: C) -> ColorKeyed<Self, C> {
+        ColorKeyed::new(self, key)
+    }
+
+    /// Wrap the display in a [`Clipped`](./struct.Clipped.html) adapter that only draws pixels
+    /// between `top_left` and `bottom_right`, inclusive
+    fn clipped(self, top_left: Coord, bottom_right: Coord) -> Clipped<Self> {
+        Clipped::new(self, top_left, bottom_right)
+    }
+
+    /// Wrap the display in a [`ShapeClipped`](./struct.ShapeClipped.html) adapter that only draws
+    /// pixels inside `shape`, like a circle or a rounded rectangle
+    fn clipped_to_shape<S>(self, shape: S) -> ShapeClipped<Self, S>
+    where
+        S: ContainsPoint + Dimensions,
+    {
+        ShapeClipped::new(self, shape)
+    }
+
+    /// Wrap the display in a [`RasterOp`](./struct.RasterOp.html) adapter that combines every
+    /// drawn pixel with the color already on the display using `mode`
+    fn raster_op(self, mode: DrawMode) -> RasterOp<Self> {
+        RasterOp::new(self, mode)
+    }
+
+    /// Wrap the display in an [`AlphaBlended`](./struct.AlphaBlended.html) adapter that blends
+    /// colors with an alpha channel into the pixels already on the display
+    fn alpha_blended(self) -> AlphaBlended<Self, C> {
+        AlphaBlended::new(self)
+    }
+
+    /// Wrap the display in a [`Rotated`](./struct.Rotated.html) adapter that rotates everything
+    /// drawn to it by `rotation`
+    ///
+    /// `size` is the size of the display in its unrotated orientation.
+    fn rotated(self, rotation: Rotation, size: UnsignedCoord) -> Rotated<Self> {
+        Rotated::new(self, rotation, size)
+    }
+
+    /// Wrap the display in a [`Flipped`](./struct.Flipped.html) adapter that mirrors everything
+    /// drawn to it along `axis`
+    ///
+    /// `size` is the size of the display.
+    fn flipped(self, axis: FlipAxis, size: UnsignedCoord) -> Flipped<Self> {
+        Flipped::new(self, axis, size)
+    }
+
+    /// Wrap the display in a [`Scaled`](./struct.Scaled.html) adapter that draws every pixel as a
+    /// block `factor` pixels wide and high
+    fn scaled(self, factor: u32) -> Scaled<Self> {
+        Scaled::new(self, factor)
+    }
+
+    /// Wrap the display in a [`Tee`](./struct.Tee.html) adapter that draws everything to this
+    /// display and then to `other`
+    fn tee<D>(self, other: D) -> Tee<Self, D>
+    where
+        D: Drawing<C>,
+    {
+        Tee::new(self, other)
+    }
+}
+
+impl<D, C> DrawingExt<C> for D
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+}
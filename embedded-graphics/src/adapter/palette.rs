@@ -0,0 +1,107 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::{Indexed, Palette, PixelColor};
+use crate::Drawing;
+
+/// Draw [`Indexed`](../pixelcolor/struct.Indexed.html) colors to a display using a palette
+///
+/// Each pixel's index is looked up in the palette before being passed to the wrapped display.
+/// Pixels with an index outside the palette are not drawn.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::PaletteAdapter;
+/// use embedded_graphics::pixelcolor::{Indexed, Palette};
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let palette = Palette::new(&[0u8, 10, 20]);
+/// let mut display = PaletteAdapter::new(Display::default(), palette);
+///
+/// display.draw(egline!((0, 0), (3, 0), stroke = Some(Indexed(2))));
+///
+/// assert_eq!(display.into_inner().0[0][..4], [20, 20, 20, 20]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PaletteAdapter<'a, D, C> {
+    display: D,
+    palette: Palette<'a, C>,
+}
+
+impl<'a, D, C> PaletteAdapter<'a, D, C>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    /// Wrap a display with the given palette
+    pub fn new(display: D, palette: Palette<'a, C>) -> Self {
+        Self { display, palette }
+    }
+
+    /// Get the palette used to look up colors
+    pub fn palette(&self) -> Palette<'a, C> {
+        self.palette
+    }
+
+    /// Replace the palette used to look up colors
+    pub fn set_palette(&mut self, palette: Palette<'a, C>) {
+        self.palette = palette;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+impl<'a, D, C> Drawing<Indexed> for PaletteAdapter<'a, D, C>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<Indexed>>,
+    {
+        let palette = self.palette;
+
+        self.display.draw(
+            item.into_iter()
+                .filter_map(|Pixel(coord, index)| palette.get(index).map(|c| Pixel(coord, c))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::unsignedcoord::UnsignedCoord;
+
+    #[test]
+    fn out_of_range_indices_are_skipped() {
+        let mut display = PaletteAdapter::new(Display::default(), Palette::new(&[5u8, 6]));
+
+        display.draw(
+            [
+                Pixel(UnsignedCoord::new(0, 0), Indexed(1)),
+                Pixel(UnsignedCoord::new(1, 0), Indexed(2)),
+                Pixel(UnsignedCoord::new(2, 0), Indexed(0)),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        let display = display.into_inner();
+        assert_eq!(display.0[0][..3], [6, 0, 5]);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::drawable::Pixel;
+use crate::pixelcolor::raw::IntoStorage;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::style::{DrawMode, Styled};
+use crate::Drawing;
+use core::iter;
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// Combine every drawn pixel with the color already on the wrapped display
+///
+/// Each pixel is read back from the display and combined with the new color using a
+/// [`DrawMode`](../style/enum.DrawMode.html) before it's drawn. Pixels the display can't read back,
+/// usually because they're off screen, are dropped. With [`DrawMode::Copy`] pixels are passed
+/// straight through without being read.
+///
+/// Items drawn with [`draw`](../trait.Drawing.html#tymethod.draw) use the adapter's mode. Items
+/// drawn with [`draw_styled`](#method.draw_styled) use the
+/// [`draw_mode`](../style/struct.Style.html#structfield.draw_mode) from their own style instead.
+/// Filled and stroked shapes draw each pixel once by default (see
+/// [`StrokeOverlap`](../style/enum.StrokeOverlap.html)), so XOR drawing a shape twice always puts
+/// the background back.
+///
+/// Adapters are usually created with [`DrawingExt::raster_op`](./trait.DrawingExt.html#method.raster_op).
+///
+/// [`DrawMode::Copy`]: ../style/enum.DrawMode.html#variant.Copy
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::primitives::{Circle, Rectangle};
+/// use embedded_graphics::style::DrawMode;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = Display::default().raster_op(DrawMode::Xor);
+/// display.draw(Circle::new(Coord::new(8, 8), 6).fill(Some(0b01u8)));
+///
+/// let background = *display.inner();
+/// let cursor = Rectangle::new(Coord::new(4, 4), Coord::new(14, 10))
+///     .stroke(Some(0b11u8))
+///     .fill(Some(0b10u8));
+///
+/// // Draw the cursor, then draw it again to erase it
+/// display.draw(cursor);
+/// assert_ne!(*display.inner(), background);
+/// display.draw(cursor);
+/// assert_eq!(*display.inner(), background);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RasterOp<D> {
+    display: D,
+    mode: DrawMode,
+}
+
+impl<D> RasterOp<D> {
+    /// Wrap a display, combining drawn pixels with the existing ones using `mode`
+    pub fn new(display: D, mode: DrawMode) -> Self {
+        Self { display, mode }
+    }
+
+    /// Get the mode used by [`draw`](../trait.Drawing.html#tymethod.draw)
+    pub fn mode(&self) -> DrawMode {
+        self.mode
+    }
+
+    /// Set the mode used by [`draw`](../trait.Drawing.html#tymethod.draw)
+    pub fn set_mode(&mut self, mode: DrawMode) {
+        self.mode = mode;
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Draw an item using the draw mode from its style instead of the adapter's mode
+    pub fn draw_styled<T, C>(&mut self, item: T)
+    where
+        D: Drawing<C> + ReadPixel<C>,
+        T: IntoIterator<Item = Pixel<C>> + Styled<C>,
+        C: PixelColor + IntoStorage,
+        C::Storage: BitAnd<Output = C::Storage>
+            + BitOr<Output = C::Storage>
+            + BitXor<Output = C::Storage>
+            + Not<Output = C::Storage>,
+    {
+        let mode = item.current_style().draw_mode;
+
+        self.draw_with(item, mode);
+    }
+
+    fn draw_with<T, C>(&mut self, item: T, mode: DrawMode)
+    where
+        D: Drawing<C> + ReadPixel<C>,
+        T: IntoIterator<Item = Pixel<C>>,
+        C: PixelColor + IntoStorage,
+        C::Storage: BitAnd<Output = C::Storage>
+            + BitOr<Output = C::Storage>
+            + BitXor<Output = C::Storage>
+            + Not<Output = C::Storage>,
+    {
+        if mode == DrawMode::Copy {
+            self.display.draw(item);
+
+            return;
+        }
+
+        // Each pixel has to be drawn before the next one is read, in case an item covers the
+        // same pixel more than once
+        for Pixel(coord, color) in item {
+            if let Some(existing) = self.display.pixel(coord) {
+                self.display
+                    .draw(iter::once(Pixel(coord, mode.apply(color, existing))));
+            }
+        }
+    }
+}
+
+impl<D, C> Drawing<C> for RasterOp<D>
+where
+    D: Drawing<C> + ReadPixel<C>,
+    C: PixelColor + IntoStorage,
+    C::Storage: BitAnd<Output = C::Storage>
+        + BitOr<Output = C::Storage>
+        + BitXor<Output = C::Storage>
+        + Not<Output = C::Storage>,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let mode = self.mode;
+
+        self.draw_with(item, mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Line, Polygon, Rectangle, Triangle};
+    use crate::style::WithStyle;
+
+    fn background() -> Display {
+        let mut display = Display::default();
+        for (y, row) in display.0.iter_mut().enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = (x * 7 + y * 3) as u8;
+            }
+        }
+
+        display
+    }
+
+    #[test]
+    fn xor_twice_restores_background() {
+        let vertices = [
+            Coord::new(1, 1),
+            Coord::new(20, 3),
+            Coord::new(12, 14),
+            Coord::new(4, 9),
+        ];
+        let mut display = background().raster_op(DrawMode::Xor);
+
+        for _ in 0..2 {
+            display.draw(
+                Circle::new(Coord::new(10, 8), 7)
+                    .stroke(Some(0x0f))
+                    .fill(Some(0xf0)),
+            );
+            display.draw(
+                Triangle::new(Coord::new(1, 1), Coord::new(22, 4), Coord::new(8, 14))
+                    .stroke(Some(0x55))
+                    .fill(Some(0xaa)),
+            );
+            display.draw(Polygon::new(&vertices).stroke(Some(0x33)).fill(Some(0xcc)));
+        }
+
+        assert_eq!(display.into_inner(), background());
+    }
+
+    #[test]
+    fn bitwise_modes() {
+        let line = Line::new(Coord::new(0, 0), Coord::new(5, 0)).stroke(Some(0b1010u8));
+
+        let check = |mode, expected: u8| {
+            let mut display = Display::default();
+            display.0[0][2] = 0b0110;
+
+            let mut display = display.raster_op(mode);
+            display.draw(line);
+
+            assert_eq!(display.inner().0[0][2], expected, "{:?}", mode);
+        };
+
+        check(DrawMode::Copy, 0b1010);
+        check(DrawMode::Xor, 0b1100);
+        check(DrawMode::And, 0b0010);
+        check(DrawMode::Or, 0b1110);
+        check(DrawMode::Invert, !0b0110);
+    }
+
+    #[test]
+    fn offscreen_pixels_are_dropped() {
+        let mut display = Display::default().raster_op(DrawMode::Or);
+
+        display.draw(Line::new(Coord::new(-5, 3), Coord::new(30, 3)).stroke(Some(1u8)));
+
+        let display = display.into_inner();
+        assert!(display.0[3].iter().all(|&p| p == 1));
+    }
+
+    #[test]
+    fn draw_styled_uses_item_mode() {
+        let rect = Rectangle::new(Coord::new(2, 2), Coord::new(5, 5)).fill(Some(0b11u8));
+        let mut display = background().raster_op(DrawMode::Copy);
+
+        display.draw_styled(rect.draw_mode(DrawMode::Invert));
+        assert_eq!(display.inner().0[3][3], !background().0[3][3]);
+        assert_eq!(display.inner().0[1][1], background().0[1][1]);
+
+        display.draw_styled(rect.draw_mode(DrawMode::Invert));
+        assert_eq!(display.into_inner(), background());
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// Clockwise rotation applied by the [`Rotated`](./struct.Rotated.html) adapter
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rotation {
+    /// No rotation
+    Deg0,
+
+    /// A quarter turn clockwise, so the top of the drawing faces the right edge of the display
+    Deg90,
+
+    /// Upside down
+    Deg180,
+
+    /// A quarter turn anticlockwise, so the top of the drawing faces the left edge of the display
+    Deg270,
+}
+
+impl Rotation {
+    /// Whether this rotation swaps the width and height of the display
+    pub fn is_quarter_turn(self) -> bool {
+        match self {
+            Rotation::Deg90 | Rotation::Deg270 => true,
+            Rotation::Deg0 | Rotation::Deg180 => false,
+        }
+    }
+}
+
+/// Rotate everything drawn to the wrapped display
+///
+/// Items are drawn in the rotated coordinate system, which has its origin in the top left corner
+/// as the display is held. This lets an application switch between portrait and landscape when the
+/// display driver can't rotate in hardware. Displays don't report their own size, so the physical
+/// size of the display has to be given. Pixels outside the rotated area are dropped.
+///
+/// Adapters are usually created with [`DrawingExt::rotated`](./trait.DrawingExt.html#method.rotated).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::{DrawingExt, Rotation};
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// // The mock display is 24 pixels wide and 16 high, so in portrait it is 16 wide and 24 high
+/// let mut display = Display::default().rotated(Rotation::Deg90, UnsignedCoord::new(24, 16));
+/// assert_eq!(display.size(), UnsignedCoord::new(16, 24));
+///
+/// display.draw(egline!((0, 0), (2, 0), stroke = Some(1u8)));
+///
+/// let display = display.into_inner();
+/// assert_eq!([display.0[0][23], display.0[1][23], display.0[2][23]], [1, 1, 1]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rotated<D> {
+    display: D,
+    rotation: Rotation,
+    size: UnsignedCoord,
+}
+
+impl<D> Rotated<D> {
+    /// Wrap a display that is `size` pixels in its unrotated orientation, rotating everything drawn
+    /// to it by `rotation`
+    pub fn new(display: D, rotation: Rotation, size: UnsignedCoord) -> Self {
+        Self {
+            display,
+            rotation,
+            size,
+        }
+    }
+
+    /// Get the rotation
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Change the rotation used for items drawn from now on
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Size of the display as seen through the adapter, with the width and height swapped for
+    /// quarter turns
+    pub fn size(&self) -> UnsignedCoord {
+        if self.rotation.is_quarter_turn() {
+            UnsignedCoord::new(self.size[1], self.size[0])
+        } else {
+            self.size
+        }
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+
+    /// Move a point in the rotated coordinate system to the wrapped display, or return `None` if it
+    /// is outside the display
+    fn to_physical(&self, point: UnsignedCoord) -> Option<UnsignedCoord> {
+        let (width, height) = (self.size[0], self.size[1]);
+        let size = self.size();
+        let (x, y) = (point[0], point[1]);
+
+        if x >= size[0] || y >= size[1] {
+            return None;
+        }
+
+        let (x, y) = match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (width - 1 - y, x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (y, height - 1 - x),
+        };
+
+        Some(UnsignedCoord::new(x, y))
+    }
+}
+
+impl<D, C> Drawing<C> for Rotated<D>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let rotated = Rotated::new((), self.rotation, self.size);
+
+        self.display.draw(
+            item.into_iter().filter_map(|Pixel(coord, color)| {
+                rotated.to_physical(coord).map(|p| Pixel(p, color))
+            }),
+        );
+    }
+}
+
+impl<D, C> ReadPixel<C> for Rotated<D>
+where
+    D: ReadPixel<C>,
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.to_physical(point)
+            .and_then(|point| self.display.pixel(point))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, C> async_drawing::AsyncDrawing<C> for Rotated<D>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let rotated = Rotated::new((), self.rotation, self.size);
+
+        self.display
+            .draw(item.into_iter().filter_map(move |Pixel(coord, color)| {
+                rotated.to_physical(coord).map(|p| Pixel(p, color))
+            }))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+
+    /// Size of the mock display
+    fn size() -> UnsignedCoord {
+        UnsignedCoord::new(24, 16)
+    }
+
+    #[test]
+    fn corners() {
+        let corner = Rectangle::new(Coord::new(0, 0), Coord::new(1, 0)).fill(Some(1u8));
+        let check = |rotation, (x, y): (usize, usize), (dx, dy): (isize, isize)| {
+            let mut display = Display::default().rotated(rotation, size());
+            display.draw(corner);
+
+            let display = display.into_inner();
+            let next = ((x as isize + dx) as usize, (y as isize + dy) as usize);
+            assert_eq!(display.0[y][x], 1, "{:?}", rotation);
+            assert_eq!(display.0[next.1][next.0], 1, "{:?}", rotation);
+            assert_eq!(display.count(1), 2);
+        };
+
+        check(Rotation::Deg0, (0, 0), (1, 0));
+        check(Rotation::Deg90, (23, 0), (0, 1));
+        check(Rotation::Deg180, (23, 15), (-1, 0));
+        check(Rotation::Deg270, (0, 15), (0, -1));
+    }
+
+    #[test]
+    fn size_and_clipping() {
+        let mut display = Display::default().rotated(Rotation::Deg270, size());
+        assert_eq!(display.size(), UnsignedCoord::new(16, 24));
+
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(30, 30)).fill(Some(1u8)));
+        assert!(display.inner().0.iter().flatten().all(|&p| p == 1));
+
+        display.set_rotation(Rotation::Deg180);
+        assert_eq!(display.size(), size());
+    }
+
+    #[test]
+    fn reads_back_rotated() {
+        let mut display = Display::default().rotated(Rotation::Deg90, size());
+        display.draw(Rectangle::new(Coord::new(3, 5), Coord::new(3, 5)).fill(Some(7u8)));
+
+        assert_eq!(display.pixel(UnsignedCoord::new(3, 5)), Some(7));
+        assert_eq!(display.inner().0[3][18], 7);
+        assert_eq!(display.pixel(UnsignedCoord::new(16, 0)), None);
+    }
+}
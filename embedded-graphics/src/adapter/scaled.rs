@@ -0,0 +1,245 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::{run_end, Drawing};
+
+/// Draw every pixel as a square block of pixels on the wrapped display
+///
+/// Each pixel at (`x`, `y`) fills the block from (`x * factor`, `y * factor`) to
+/// (`x * factor + factor - 1`, `y * factor + factor - 1`). This lets a layout designed for a low
+/// resolution display be reused on a higher resolution one without changing any coordinates.
+/// Pixels whose block would be outside the range of `u32` coordinates are dropped.
+///
+/// Adapters are usually created with [`DrawingExt::scaled`](./trait.DrawingExt.html#method.scaled).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::egline;
+/// # use embedded_graphics::mock_display::Display;
+///
+/// let mut display = Display::default().scaled(2);
+///
+/// display.draw(egline!((1, 1), (2, 1), stroke = Some(1u8)));
+///
+/// let display = display.into_inner();
+/// assert_eq!(display.0[1][..7], [0, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(display.0[2][..7], [0, 0, 1, 1, 1, 1, 0]);
+/// assert_eq!(display.0[3][..7], [0, 0, 1, 1, 1, 1, 0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<D> {
+    display: D,
+    factor: u32,
+}
+
+impl<D> Scaled<D> {
+    /// Wrap a display, drawing every pixel as a block `factor` pixels wide and high
+    ///
+    /// A `factor` of 0 is treated as 1.
+    pub fn new(display: D, factor: u32) -> Self {
+        Self {
+            display,
+            factor: factor.max(1),
+        }
+    }
+
+    /// Get the width and height of the block drawn for each pixel
+    pub fn factor(&self) -> u32 {
+        self.factor
+    }
+
+    /// Get a reference to the wrapped display
+    pub fn inner(&self) -> &D {
+        &self.display
+    }
+
+    /// Get a mutable reference to the wrapped display
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Consume the adapter, returning the wrapped display
+    pub fn into_inner(self) -> D {
+        self.display
+    }
+}
+
+/// Top left corner of the block drawn for `point`, if the whole block fits in `u32` coordinates
+fn block_origin(point: UnsignedCoord, factor: u32) -> Option<UnsignedCoord> {
+    let scale = |v: u32| {
+        v.checked_mul(factor)
+            .filter(|v| v.checked_add(factor - 1).is_some())
+    };
+
+    Some(UnsignedCoord::new(scale(point[0])?, scale(point[1])?))
+}
+
+/// Pixels of the blocks drawn for every pixel in `item`
+fn blocks<T, C>(item: T, factor: u32) -> impl Iterator<Item = Pixel<C>>
+where
+    T: IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    item.into_iter()
+        .filter_map(move |Pixel(coord, color)| {
+            block_origin(coord, factor).map(|origin| (origin, color))
+        })
+        .flat_map(move |(origin, color)| {
+            (0..factor).flat_map(move |dy| {
+                (0..factor).map(move |dx| Pixel(origin + UnsignedCoord::new(dx, dy), color))
+            })
+        })
+}
+
+impl<D, C> Drawing<C> for Scaled<D>
+where
+    D: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        self.display.draw(blocks(item, self.factor));
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let factor = i64::from(self.factor);
+        let max = i64::from(i32::MAX);
+
+        self.display.fill_runs(
+            runs.into_iter()
+                .filter_map(move |(start, length, color)| {
+                    // Pixels with negative coordinates have no block, so drop them like `draw` does
+                    let left = i64::from(start[0]).max(0);
+                    let right = i64::from(run_end(start, length)[0]);
+                    let top = i64::from(start[1]) * factor;
+
+                    if start[1] < 0 || left > right || left * factor > max || top > max {
+                        return None;
+                    }
+
+                    let length = ((right - left + 1) * factor).min(i64::from(u32::MAX)) as u32;
+
+                    Some((left * factor, top, length, color))
+                })
+                .flat_map(move |(left, top, length, color)| {
+                    (top..(top + factor).min(max + 1))
+                        .map(move |y| (Coord::new(left as i32, y as i32), length, color))
+                }),
+        );
+    }
+}
+
+impl<D, C> ReadPixel<C> for Scaled<D>
+where
+    D: ReadPixel<C>,
+    C: PixelColor,
+{
+    /// Color of the top left pixel in the block drawn for `point`
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        block_origin(point, self.factor).and_then(|origin| self.display.pixel(origin))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, C> async_drawing::AsyncDrawing<C> for Scaled<D>
+where
+    D: async_drawing::AsyncDrawing<C>,
+    C: PixelColor,
+{
+    async fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        self.display.draw(blocks(item, self.factor)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Rectangle};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn blocks_match_unscaled_pixels() {
+        let circle = Circle::new(Coord::new(3, 3), 3)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+        let mut plain = Display::default();
+        plain.draw(circle);
+
+        let mut display = Display::default().scaled(3);
+        display.draw(circle);
+
+        let display = display.into_inner();
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                assert_eq!(pixel, plain.0[y / 3][x / 3], "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_factor_and_read_back() {
+        let mut display = Display::default().scaled(0);
+        assert_eq!(display.factor(), 1);
+
+        display = Display::default().scaled(4);
+        display.draw(Rectangle::new(Coord::new(2, 1), Coord::new(2, 1)).fill(Some(5u8)));
+
+        assert_eq!(display.pixel(UnsignedCoord::new(2, 1)), Some(5));
+        assert_eq!(display.pixel(UnsignedCoord::new(1, 1)), Some(0));
+        assert_eq!(display.pixel(UnsignedCoord::new(6, 0)), None);
+        assert_eq!(display.inner().0[7][11], 5);
+        assert_eq!(display.inner().0[8][11], 0);
+    }
+
+    #[test]
+    fn runs_match_blocks() {
+        let mut expected = Display::default().scaled(3);
+        expected.draw(Rectangle::new(Coord::new(0, 1), Coord::new(1, 1)).fill(Some(1u8)));
+        expected.draw(Rectangle::new(Coord::new(5, 3), Coord::new(6, 3)).fill(Some(2u8)));
+
+        let mut display = Display::default().scaled(3);
+        display.fill_runs(
+            [
+                (Coord::new(-1, 1), 3, 1u8),
+                (Coord::new(5, 3), 2, 2),
+                (Coord::new(2, -1), 2, 3),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        assert_eq!(display.into_inner(), expected.into_inner());
+    }
+
+    #[test]
+    fn overflowing_blocks_are_dropped() {
+        let mut display = Display::default().scaled(2);
+
+        display.draw(
+            [
+                Pixel(UnsignedCoord::new(u32::MAX / 2, 0), 1u8),
+                Pixel(UnsignedCoord::new(0, 0), 1u8),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        assert_eq!(display.into_inner().0[1][..3], [1, 1, 0]);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// Number of pixels or runs buffered before they are sent to both displays
+const CHUNK_LEN: usize = 32;
+
+/// Draw everything to two displays at once
+///
+/// Every item drawn to the adapter is drawn to the first display and then to the second one. This
+/// makes it easy to mirror a physical display to a [`MockDisplay`](../mock_display/struct.MockDisplay.html)
+/// or a logger, to debug a driver on the device or capture golden images for tests. Items are
+/// only iterated once: their pixels are buffered in small chunks on the stack and each chunk is
+/// sent to both displays, so neither display sees the other's pixels out of order.
+///
+/// Adapters are usually created with [`DrawingExt::tee`](./trait.DrawingExt.html#method.tee).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::adapter::DrawingExt;
+/// use embedded_graphics::egcircle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let display = Display::default();
+///
+/// let mut display = display.tee(Display::default());
+///
+/// display.draw(egcircle!((8, 8), 4, fill = Some(1u8)));
+///
+/// let (display, capture) = display.into_inner();
+/// assert_eq!(display, capture);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Tee<A, B> {
+    /// Wrap two displays, drawing everything to `first` and then to `second`
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Get a reference to the first display
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Get a mutable reference to the first display
+    pub fn first_mut(&mut self) -> &mut A {
+        &mut self.first
+    }
+
+    /// Get a reference to the second display
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Get a mutable reference to the second display
+    pub fn second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+
+    /// Consume the adapter, returning both displays
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+/// Call `f` with consecutive chunks of up to `CHUNK_LEN` items from `items`
+fn for_each_chunk<I, F>(items: I, mut f: F)
+where
+    I: IntoIterator,
+    I::Item: Copy,
+    F: FnMut(&[I::Item]),
+{
+    let mut items = items.into_iter();
+    let first = match items.next() {
+        Some(item) => item,
+        None => return,
+    };
+
+    let mut chunk = [first; CHUNK_LEN];
+    let mut len = 1;
+
+    for item in items {
+        if len == CHUNK_LEN {
+            f(&chunk);
+            len = 0;
+        }
+
+        chunk[len] = item;
+        len += 1;
+    }
+
+    f(&chunk[..len]);
+}
+
+impl<A, B, C> Drawing<C> for Tee<A, B>
+where
+    A: Drawing<C>,
+    B: Drawing<C>,
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let (first, second) = (&mut self.first, &mut self.second);
+
+        for_each_chunk(item, |pixels| {
+            first.draw(pixels.iter().cloned());
+            second.draw(pixels.iter().cloned());
+        });
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let (first, second) = (&mut self.first, &mut self.second);
+
+        for_each_chunk(runs, |runs| {
+            first.fill_runs(runs.iter().cloned());
+            second.fill_runs(runs.iter().cloned());
+        });
+    }
+}
+
+impl<A, B, C> ReadPixel<C> for Tee<A, B>
+where
+    A: ReadPixel<C>,
+    C: PixelColor,
+{
+    /// Color of the pixel at `point` on the first display
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.first.pixel(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::{DamageTracking, DirtyRegion, DrawingExt};
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Line};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn both_displays_match() {
+        let circle = Circle::new(Coord::new(10, 8), 7)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+        let mut expected = Display::default();
+        expected.draw(circle);
+
+        let mut display = Display::default().tee(Display::default().color_map(|c: u8| c + 10));
+        display.draw(circle);
+        display.fill_runs(
+            [(Coord::new(0, 15), 30, 3u8), (Coord::new(-4, 14), 6, 4)]
+                .iter()
+                .cloned(),
+        );
+        expected.fill_runs(
+            [(Coord::new(0, 15), 30, 3u8), (Coord::new(-4, 14), 6, 4)]
+                .iter()
+                .cloned(),
+        );
+
+        assert_eq!(display.pixel(UnsignedCoord::new(10, 8)), Some(2));
+
+        let (first, second) = display.into_inner();
+        assert_eq!(first, expected);
+        for (row, expected) in second.into_inner().0.iter().zip(expected.0.iter()) {
+            for (&pixel, &expected) in row.iter().zip(expected.iter()) {
+                assert_eq!(pixel, if expected == 0 { 0 } else { expected + 10 });
+            }
+        }
+    }
+
+    #[test]
+    fn items_are_forwarded_in_chunks() {
+        let mut regions = [DirtyRegion::EMPTY; 4];
+        let mut display =
+            DamageTracking::new(Display::default(), &mut regions).tee(Display::default());
+
+        // 70 pixels are sent to the first display as three separate chunks
+        display.draw(Line::new(Coord::new(0, 0), Coord::new(69, 0)).stroke(Some(1u8)));
+
+        let (first, second) = display.into_inner();
+        assert_eq!(
+            first.dirty_regions(),
+            [DirtyRegion::new(
+                UnsignedCoord::new(0, 0),
+                UnsignedCoord::new(69, 0)
+            )]
+        );
+        assert_eq!(second.0[0], [1; 24]);
+    }
+}
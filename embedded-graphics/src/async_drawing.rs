@@ -0,0 +1,145 @@
+//! Drawing to displays with asynchronous drivers
+//!
+//! Drivers that talk to their display over an asynchronous bus, like an async SPI implementation,
+//! can implement [`AsyncDrawing`](./trait.AsyncDrawing.html) instead of
+//! [`Drawing`](../trait.Drawing.html) so that drawing an item awaits the bus instead of blocking
+//! on it. The adapters in [`adapter`](../adapter/index.html) that only change pixels on their way
+//! to the display implement `AsyncDrawing` when the display they wrap does.
+//!
+//! This module is only available with the `async` feature.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::async_drawing::AsyncDrawing;
+//! use embedded_graphics::egcircle;
+//!
+//! # struct Spi;
+//! # impl Spi {
+//! #     async fn write(&mut self, _bytes: &[u8]) {}
+//! # }
+//! struct AsyncDisplay {
+//!     spi: Spi,
+//! }
+//!
+//! impl AsyncDrawing<u8> for AsyncDisplay {
+//!     async fn draw<T>(&mut self, item: T)
+//!     where
+//!         T: IntoIterator<Item = Pixel<u8>>,
+//!     {
+//!         for Pixel(coord, color) in item {
+//!             self.spi
+//!                 .write(&[coord[0] as u8, coord[1] as u8, color])
+//!                 .await;
+//!         }
+//!     }
+//! }
+//!
+//! async fn draw_ui(display: &mut AsyncDisplay) {
+//!     display.draw(egcircle!((32, 32), 10, fill = Some(1u8))).await;
+//! }
+//! ```
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::unsignedcoord::UnsignedCoord;
+use core::future::Future;
+
+/// A display that items can be drawn to asynchronously
+///
+/// This is the asynchronous version of [`Drawing`](../trait.Drawing.html).
+pub trait AsyncDrawing<C>
+where
+    C: PixelColor,
+{
+    /// Draw an object from an iterator over its pixels
+    fn draw<T>(&mut self, item: T) -> impl Future<Output = ()>
+    where
+        T: IntoIterator<Item = Pixel<C>>;
+
+    /// Set the pixels in the `size` pixel area at `top_left` to the colors from `colors`, row by
+    /// row
+    ///
+    /// Colors for pixels with negative coordinates are skipped. The default implementation draws
+    /// the area as pixels, but drivers can override it to send the colors to a window of the
+    /// display without any coordinates.
+    fn fill_contiguous<I>(
+        &mut self,
+        top_left: Coord,
+        size: UnsignedCoord,
+        colors: I,
+    ) -> impl Future<Output = ()>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let (width, height) = (size[0] as i32, size[1] as i32);
+        let points = (0..height).flat_map(move |y| (0..width).map(move |x| Coord::new(x, y)));
+
+        self.draw(
+            points
+                .zip(colors)
+                .map(move |(point, color)| (top_left + point, color))
+                .filter(|(point, _)| point[0] >= 0 && point[1] >= 0)
+                .map(|(point, color)| Pixel(point.to_unsigned(), color)),
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::adapter::DrawingExt;
+    use crate::mock_display::Display;
+    use crate::primitives::Circle;
+    use crate::style::WithStyle;
+    use crate::Drawing;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Run a future that never waits to completion
+    pub(crate) fn block_on<F>(future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn matches_blocking_drawing() {
+        let circle = Circle::new(Coord::new(10, 8), 6)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut expected = Display::default()
+            .scaled(2)
+            .clipped(Coord::new(3, 3), Coord::new(18, 12));
+        Drawing::draw(&mut expected, circle);
+
+        let mut display = Display::default()
+            .scaled(2)
+            .clipped(Coord::new(3, 3), Coord::new(18, 12));
+        block_on(AsyncDrawing::draw(&mut display, circle));
+
+        assert_eq!(
+            display.into_inner().into_inner(),
+            expected.into_inner().into_inner()
+        );
+    }
+
+    #[test]
+    fn fill_contiguous_skips_offscreen_colors() {
+        let mut display = Display::default();
+
+        block_on(display.fill_contiguous(Coord::new(-1, 0), UnsignedCoord::new(3, 2), 1..));
+
+        assert_eq!(display.0[0][..3], [2, 3, 0]);
+        assert_eq!(display.0[1][..3], [5, 6, 0]);
+    }
+}
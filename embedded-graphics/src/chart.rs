@@ -0,0 +1,951 @@
+//! Simple charts for showing data on small displays
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::edges::{is_inner_border, styled_pixel, EdgeStroke, FillArea, PointPairs};
+use crate::primitives::rectangle::{Rectangle, RectangleIterator};
+use crate::primitives::thick::ThickStrokePixels;
+use crate::style::{Style, WithStyle};
+use crate::transform::Transform;
+use crate::trig::{Sweep, DEGREE};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Pie chart
+///
+/// Each slice is a `(value, style)` pair, and takes up a share of the circle proportional to its
+/// value. Slices are laid out clockwise, starting straight up from the center unless a different
+/// [`start_angle`](#method.start_angle) is set.
+///
+/// Every pixel in the circle belongs to exactly one slice, so neighbouring slices always meet
+/// without gaps or overlaps and together fill the whole circle. Each slice is filled and stroked
+/// with its own style, with the stroke drawn inside the slice's outline. Dash patterns are not
+/// supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::chart::PieChart;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let fill = |color: u8| Style {
+///     fill_color: Some(color),
+///     ..Style::default()
+/// };
+/// let slices = [(50, fill(1)), (30, fill(2)), (20, fill(3))];
+///
+/// display.draw(PieChart::new(Coord::new(12, 8), 7, &slices));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct PieChart<'a, C: PixelColor> {
+    /// Center point of the chart
+    pub center: Coord,
+
+    /// Radius of the chart
+    pub radius: u32,
+
+    /// Value and style of each slice
+    pub slices: &'a [(u32, Style<C>)],
+
+    /// Clockwise angle of the start of the first slice in degrees
+    pub start_angle: i32,
+}
+
+impl<'a, C> PieChart<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new pie chart
+    pub fn new(center: Coord, radius: u32, slices: &'a [(u32, Style<C>)]) -> Self {
+        PieChart {
+            center,
+            radius,
+            slices,
+            start_angle: 0,
+        }
+    }
+
+    /// Set the clockwise angle of the start of the first slice in degrees
+    pub fn start_angle(mut self, angle: i32) -> Self {
+        self.start_angle = angle;
+
+        self
+    }
+
+    /// Returns `true` if the circle covers the pixel at `point`
+    fn in_circle(&self, point: Coord) -> bool {
+        let dx = i64::from(point[0]) - i64::from(self.center[0]);
+        let dy = i64::from(point[1]) - i64::from(self.center[1]);
+        let diameter = 2 * i64::from(self.radius) + 1;
+
+        4 * (dx * dx + dy * dy) < diameter * diameter
+    }
+
+    /// Index of the slice that covers the pixel at `point`, which must be inside the circle
+    fn slice_at(&self, point: Coord) -> Option<usize> {
+        let total: u64 = self.slices.iter().map(|&(value, _)| u64::from(value)).sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        let dx = i64::from(point[0]) - i64::from(self.center[0]);
+        let dy = i64::from(point[1]) - i64::from(self.center[1]);
+
+        // Slice boundaries are rounded from the running total, so rounding errors never add up
+        // and the last slice always ends where the first one started
+        let full_turn = 360 * DEGREE as u64;
+        let start_angle = self.start_angle.rem_euclid(360) * DEGREE;
+        let boundary = |sum: u64| start_angle + ((sum * full_turn + total / 2) / total) as i32;
+
+        let mut sum = 0;
+
+        self.slices.iter().position(|&(value, _)| {
+            let start = boundary(sum);
+            sum += u64::from(value);
+
+            Sweep::new(start, boundary(sum) - start).contains(dx, dy)
+        })
+    }
+
+    /// Pixel drawn at `point`, if any, followed by a stroke pixel to draw on top of it
+    fn pixels_at(&self, point: Coord) -> Option<(Pixel<C>, Option<Pixel<C>>)> {
+        if !self.in_circle(point) {
+            return None;
+        }
+
+        let index = self.slice_at(point)?;
+        let style = self.slices[index].1;
+        let width = i32::from(style.stroke_width);
+        let in_slice = |p: Coord| self.in_circle(p) && self.slice_at(p) == Some(index);
+
+        let stroke = style
+            .stroke_color
+            .filter(|_| width > 0 && is_inner_border(in_slice, point, width));
+
+        styled_pixel(&style, point, stroke, true)
+    }
+}
+
+impl<'a, C> Dimensions for PieChart<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.center - Coord::new(self.radius as i32, self.radius as i32)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.center + Coord::new(self.radius as i32, self.radius as i32)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.radius * 2, self.radius * 2)
+    }
+}
+
+impl<'a, C> IntoIterator for PieChart<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PieChartIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &PieChart<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PieChartIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PieChartIterator {
+            chart: *self,
+            area: FillArea::new(Some((
+                self.top_left(),
+                self.bottom_right() + Coord::new(1, 1),
+            ))),
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in a pie chart
+#[derive(Debug, Clone, Copy)]
+pub struct PieChartIterator<'a, C>
+where
+    C: PixelColor,
+{
+    chart: PieChart<'a, C>,
+    area: FillArea,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<'a, C> Iterator for PieChartIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let chart = self.chart;
+
+        let (pixel, over) = self.area.find_map(|p| chart.pixels_at(p))?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<'a, C> Drawable for PieChart<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for PieChart<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the chart's center by (x, y) pixels, returning a new `PieChart`. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the chart's center by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+/// Maps data values to rows of a chart, with larger values further up
+#[derive(Debug, Copy, Clone)]
+struct Scale {
+    min: i32,
+    max: i32,
+    top: i32,
+    bottom: i32,
+}
+
+impl Scale {
+    /// Scale from `range` to the rows covered by `bounds`, or from the range of `data` widened to
+    /// include `baseline` if no range is given
+    fn new<C>(
+        bounds: &Rectangle<C>,
+        range: Option<(i32, i32)>,
+        data: &[i32],
+        baseline: Option<i32>,
+    ) -> Self
+    where
+        C: PixelColor,
+    {
+        let (min, max) = range.unwrap_or_else(|| {
+            let start = baseline.or_else(|| data.first().copied()).unwrap_or(0);
+
+            data.iter()
+                .fold((start, start), |(min, max), &v| (min.min(v), max.max(v)))
+        });
+
+        Scale {
+            min: min.min(max),
+            max: max.max(min),
+            top: bounds.top_left[1],
+            bottom: bounds.bottom_right[1],
+        }
+    }
+
+    /// Row for `value`, clamped to the range of the scale
+    fn y(&self, value: i32) -> i32 {
+        if self.max == self.min {
+            return self.bottom;
+        }
+
+        let value = i64::from(value.clamp(self.min, self.max)) - i64::from(self.min);
+        let range = i64::from(self.max) - i64::from(self.min);
+        let height = i64::from(self.bottom - self.top);
+
+        self.bottom - ((value * height + range / 2) / range) as i32
+    }
+}
+
+/// Bar chart
+///
+/// Draws one bar for each value in `data`, side by side from left to right across the `bounds`
+/// rectangle. Bars grow up from zero, or down for negative values. The range of values shown
+/// covers all of the data and zero, unless a fixed [`range`](#method.range) is set. Values outside
+/// of a fixed range are clamped to it.
+///
+/// The style of `bounds` is used to draw a frame or background behind the bars, and each bar is
+/// drawn as a rectangle with `bar_style`.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::chart::BarChart;
+/// use embedded_graphics::primitives::Rectangle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let readings = [3, 7, 4, 9, 6];
+/// let bounds = Rectangle::new(Coord::new(0, 0), Coord::new(23, 15));
+/// let bars = Style {
+///     fill_color: Some(1u8),
+///     ..Style::default()
+/// };
+///
+/// display.draw(BarChart::new(&readings, bounds, bars).spacing(1).range(0, 10));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct BarChart<'a, C: PixelColor> {
+    /// Values to show
+    pub data: &'a [i32],
+
+    /// Area covered by the chart
+    pub bounds: Rectangle<C>,
+
+    /// Style of each bar
+    pub bar_style: Style<C>,
+
+    /// Gap between bars in pixels
+    pub spacing: u32,
+
+    /// Values shown at the bottom and top of the chart, or `None` to fit the data
+    pub range: Option<(i32, i32)>,
+}
+
+impl<'a, C> BarChart<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new bar chart
+    pub fn new(data: &'a [i32], bounds: Rectangle<C>, bar_style: Style<C>) -> Self {
+        BarChart {
+            data,
+            bounds,
+            bar_style,
+            spacing: 0,
+            range: None,
+        }
+    }
+
+    /// Set the gap between bars in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+
+        self
+    }
+
+    /// Set the values shown at the bottom and top of the chart
+    pub fn range(mut self, min: i32, max: i32) -> Self {
+        self.range = Some((min, max));
+
+        self
+    }
+
+    /// Bar for the value at `index`, or `None` if the spacing leaves no room for it
+    fn bar(&self, index: usize, scale: &Scale) -> Option<Rectangle<C>> {
+        let left = self.bounds.top_left[0];
+        let width = i64::from(self.bounds.bottom_right[0] - left + 1);
+        let count = self.data.len() as i64;
+
+        // Spread any leftover pixels between the bars instead of leaving them at the end
+        let start = left + (index as i64 * width / count) as i32;
+        let end = left + ((index as i64 + 1) * width / count) as i32 - self.spacing as i32;
+
+        let baseline = scale.y(0);
+        let top = scale.y(self.data[index]);
+
+        if end <= start {
+            return None;
+        }
+
+        Some(
+            Rectangle::new(
+                Coord::new(start, top.min(baseline)),
+                Coord::new(end - 1, top.max(baseline)),
+            )
+            .style(self.bar_style),
+        )
+    }
+}
+
+impl<'a, C> Dimensions for BarChart<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds.top_left
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds.bottom_right
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        self.bounds.size()
+    }
+}
+
+impl<'a, C> IntoIterator for BarChart<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = BarChartIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &BarChart<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = BarChartIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BarChartIterator {
+            chart: *self,
+            scale: Scale::new(&self.bounds, self.range, self.data, Some(0)),
+            frame: self.bounds.into_iter(),
+            index: 0,
+            bar: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in a bar chart
+#[derive(Debug, Clone, Copy)]
+pub struct BarChartIterator<'a, C>
+where
+    C: PixelColor,
+{
+    chart: BarChart<'a, C>,
+    scale: Scale,
+    frame: RectangleIterator<C>,
+    index: usize,
+    bar: Option<RectangleIterator<C>>,
+}
+
+impl<'a, C> Iterator for BarChartIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.frame.next() {
+            return Some(pixel);
+        }
+
+        loop {
+            if let Some(pixel) = self.bar.as_mut().and_then(Iterator::next) {
+                return Some(pixel);
+            }
+
+            if self.index >= self.chart.data.len() {
+                return None;
+            }
+
+            self.bar = self
+                .chart
+                .bar(self.index, &self.scale)
+                .map(IntoIterator::into_iter);
+            self.index += 1;
+        }
+    }
+}
+
+impl<'a, C> Drawable for BarChart<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for BarChart<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the chart by (x, y) pixels, returning a new `BarChart`. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            bounds: self.bounds.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the chart by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.bounds.translate_mut(by);
+
+        self
+    }
+}
+
+/// Sparkline
+///
+/// A small line graph without axes, drawn through the values in `data` from the left edge of the
+/// `bounds` rectangle to its right edge. The range of values shown covers all of the data, unless
+/// a fixed [`range`](#method.range) is set. Values outside of a fixed range are clamped to it.
+///
+/// The style of `bounds` is used to draw a frame or background behind the line, and the line is
+/// drawn with the stroke color, width, dash pattern, line join and stroke cap of `line_style`.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::chart::Sparkline;
+/// use embedded_graphics::primitives::Rectangle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let temperatures = [18, 19, 21, 24, 23, 20, 19];
+/// let bounds = Rectangle::new(Coord::new(0, 0), Coord::new(23, 7));
+///
+/// display.draw(Sparkline::new(&temperatures, bounds, Style::stroke(1u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Sparkline<'a, C: PixelColor> {
+    /// Values to show
+    pub data: &'a [i32],
+
+    /// Area covered by the chart
+    pub bounds: Rectangle<C>,
+
+    /// Style of the line
+    pub line_style: Style<C>,
+
+    /// Values shown at the bottom and top of the chart, or `None` to fit the data
+    pub range: Option<(i32, i32)>,
+}
+
+impl<'a, C> Sparkline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new sparkline
+    pub fn new(data: &'a [i32], bounds: Rectangle<C>, line_style: Style<C>) -> Self {
+        Sparkline {
+            data,
+            bounds,
+            line_style,
+            range: None,
+        }
+    }
+
+    /// Set the values shown at the bottom and top of the chart
+    pub fn range(mut self, min: i32, max: i32) -> Self {
+        self.range = Some((min, max));
+
+        self
+    }
+
+    fn points(&self) -> SparklinePoints<'a> {
+        SparklinePoints {
+            data: self.data,
+            index: 0,
+            left: self.bounds.top_left[0],
+            width: self.bounds.bottom_right[0] - self.bounds.top_left[0],
+            scale: Scale::new(&self.bounds, self.range, self.data, None),
+        }
+    }
+}
+
+/// Iterator over the vertices of a sparkline
+#[derive(Debug, Clone, Copy)]
+struct SparklinePoints<'a> {
+    data: &'a [i32],
+    index: usize,
+    left: i32,
+    width: i32,
+    scale: Scale,
+}
+
+impl Iterator for SparklinePoints<'_> {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = *self.data.get(self.index)?;
+        let steps = (self.data.len() as i64 - 1).max(1);
+        let x = (self.index as i64 * i64::from(self.width) + steps / 2) / steps;
+
+        self.index += 1;
+
+        Some(Coord::new(self.left + x as i32, self.scale.y(value)))
+    }
+}
+
+impl<'a, C> Dimensions for Sparkline<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds.top_left
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds.bottom_right
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        self.bounds.size()
+    }
+}
+
+impl<'a, C> IntoIterator for Sparkline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SparklineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Sparkline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SparklineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let width = self.line_style.stroke_width;
+
+        SparklineIterator {
+            frame: self.bounds.into_iter(),
+            thin: if width == 1 {
+                Some(EdgeStroke::new(
+                    PointPairs::new(self.points()),
+                    self.line_style,
+                ))
+            } else {
+                None
+            },
+            thick: if width > 1 {
+                Some(ThickStrokePixels::new(self.points(), &self.line_style))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in a sparkline
+#[derive(Debug, Clone)]
+pub struct SparklineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    frame: RectangleIterator<C>,
+    /// Bresenham stroke for lines one pixel wide
+    thin: Option<EdgeStroke<C, PointPairs<SparklinePoints<'a>>>>,
+    /// Rasterizer for wider lines
+    thick: Option<ThickStrokePixels<C, SparklinePoints<'a>>>,
+}
+
+impl<'a, C> Iterator for SparklineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.frame.next() {
+            Some(pixel)
+        } else if let Some(thin) = &mut self.thin {
+            thin.next()
+        } else if let Some(thick) = &mut self.thick {
+            thick.next()
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C> Drawable for Sparkline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for Sparkline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the chart by (x, y) pixels, returning a new `Sparkline`. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            bounds: self.bounds.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the chart by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.bounds.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::RingSector;
+    use crate::Drawing;
+
+    fn fill(color: u8) -> Style<u8> {
+        Style {
+            fill_color: Some(color),
+            ..Style::default()
+        }
+    }
+
+    #[test]
+    fn slices_fill_whole_circle() {
+        // Thirds can't be represented exactly, so boundaries have to be rounded
+        let slices = [(1, fill(1)), (1, fill(2)), (1, fill(3))];
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(12, 8), 7, &slices));
+
+        let mut circle = Display::default();
+        circle.draw(RingSector::new(Coord::new(12, 8), 7, 0, 0, 360).fill(Some(1u8)));
+
+        for (drawn, expected) in display.0.iter().flatten().zip(circle.0.iter().flatten()) {
+            assert_eq!(*drawn != 0, *expected != 0);
+        }
+
+        // Roughly equal shares
+        for color in 1..=3 {
+            let pixels = display.count(color);
+            assert!(pixels > 52 && pixels < 66, "{} pixels", pixels);
+        }
+    }
+
+    #[test]
+    fn half_and_half() {
+        let slices = [(5, fill(1)), (5, fill(2))];
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(8, 8), 6, &slices));
+
+        // The first slice runs clockwise from the top, so it covers the right half
+        assert_eq!(display.0[8][13], 1);
+        assert_eq!(display.0[8][3], 2);
+        assert_eq!(display.0[4][10], 1);
+        assert_eq!(display.0[12][6], 2);
+    }
+
+    #[test]
+    fn start_angle() {
+        let slices = [(1, fill(1)), (3, fill(2))];
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(8, 8), 6, &slices).start_angle(90));
+
+        // First quarter runs from the right to the bottom
+        assert_eq!(display.0[11][11], 1);
+        assert_eq!(display.0[5][11], 2);
+
+        // Whole turns make no difference, however many there are
+        for &angle in [90 + 360 * 5_000_000, 90 - 360 * 5_000_000].iter() {
+            let mut turned = Display::default();
+            turned.draw(PieChart::new(Coord::new(8, 8), 6, &slices).start_angle(angle));
+
+            assert_eq!(turned, display);
+        }
+    }
+
+    #[test]
+    fn empty_slices() {
+        let slices = [(0, fill(1)), (4, fill(2)), (0, fill(3))];
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(8, 8), 6, &slices));
+
+        assert_eq!(display.count(1), 0);
+        assert_eq!(display.count(3), 0);
+        assert!(display.count(2) > 100);
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(8, 8), 6, &slices[..1]));
+        assert_eq!(display.count(0), 24 * 16);
+    }
+
+    #[test]
+    fn slice_strokes() {
+        let outlined = Style {
+            stroke_color: Some(3),
+            ..fill(1)
+        };
+        let slices = [(1, outlined), (1, fill(2))];
+
+        let mut display = Display::default();
+        display.draw(PieChart::new(Coord::new(8, 8), 6, &slices));
+
+        // Outline of the first slice only, along the arc and the dividing line
+        assert_eq!(display.0[8][14], 3);
+        assert_eq!(display.0[5][8], 3);
+        assert_eq!(display.0[8][11], 1);
+        assert_eq!(display.0[8][2], 2);
+    }
+
+    #[test]
+    fn dimensions() {
+        let slices = [(1, fill(1))];
+        let chart = PieChart::new(Coord::new(8, 8), 6, &slices).translate(Coord::new(2, 1));
+
+        assert_eq!(chart.top_left(), Coord::new(4, 3));
+        assert_eq!(chart.bottom_right(), Coord::new(16, 15));
+        assert_eq!(chart.size(), UnsignedCoord::new(12, 12));
+    }
+
+    fn rect(x1: i32, y1: i32, x2: i32, y2: i32) -> Rectangle<u8> {
+        Rectangle::new(Coord::new(x1, y1), Coord::new(x2, y2))
+    }
+
+    #[test]
+    fn bars() {
+        let data = [1, 2, 3, 4];
+
+        let mut display = Display::default();
+        display.draw(BarChart::new(&data, rect(0, 0, 11, 9), fill(1)));
+
+        let mut expected = Display::default();
+        expected.draw(rect(0, 7, 2, 9).fill(Some(1u8)));
+        expected.draw(rect(3, 4, 5, 9).fill(Some(1u8)));
+        expected.draw(rect(6, 2, 8, 9).fill(Some(1u8)));
+        expected.draw(rect(9, 0, 11, 9).fill(Some(1u8)));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn negative_bars() {
+        let data = [-2, 2];
+
+        let mut display = Display::default();
+        display.draw(BarChart::new(&data, rect(0, 0, 3, 8), fill(1)));
+
+        let mut expected = Display::default();
+        expected.draw(rect(0, 4, 1, 8).fill(Some(1u8)));
+        expected.draw(rect(2, 0, 3, 4).fill(Some(1u8)));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn bar_spacing_and_range() {
+        let data = [5, 20, 10];
+        let frame = rect(0, 0, 10, 10).stroke(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(BarChart::new(&data, frame, fill(1)).spacing(1).range(0, 10));
+
+        // Gaps between bars show the frame, and the second bar is clamped to the top
+        assert_eq!(display.0[10][1], 1);
+        assert_eq!(display.0[10][2], 2);
+        assert_eq!(display.0[5][1], 1);
+        assert_eq!(display.0[4][1], 0);
+        assert_eq!(display.0[0][4], 1);
+
+        // Frame is drawn first
+        assert_eq!(display.0[0][10], 2);
+    }
+
+    #[test]
+    fn sparkline() {
+        let data = [0, 4, 0];
+
+        let mut display = Display::default();
+        display.draw(Sparkline::new(&data, rect(0, 0, 8, 4), Style::stroke(1u8)));
+
+        assert_eq!(display.0[4][0], 1);
+        assert_eq!(display.0[0][4], 1);
+        assert_eq!(display.0[4][8], 1);
+        assert_eq!(display.count(1), 9);
+    }
+
+    #[test]
+    fn sparkline_range() {
+        let data = [0, 10, 20];
+        let line = Sparkline::new(&data, rect(2, 2, 12, 12), Style::stroke(1u8)).range(0, 10);
+        let points: [Coord; 3] = {
+            let mut points = line.points();
+            [
+                points.next().unwrap(),
+                points.next().unwrap(),
+                points.next().unwrap(),
+            ]
+        };
+
+        assert_eq!(
+            points,
+            [Coord::new(2, 12), Coord::new(7, 2), Coord::new(12, 2)]
+        );
+    }
+
+    #[test]
+    fn extreme_values() {
+        let data = [i32::MIN, 0, i32::MAX];
+        let line = Sparkline::new(&data, rect(0, 0, 8, 4), Style::stroke(1u8));
+        let points: [Coord; 3] = {
+            let mut points = line.points();
+            [
+                points.next().unwrap(),
+                points.next().unwrap(),
+                points.next().unwrap(),
+            ]
+        };
+
+        assert_eq!(
+            points,
+            [Coord::new(0, 4), Coord::new(4, 2), Coord::new(8, 0)]
+        );
+
+        let mut display = Display::default();
+        display.draw(BarChart::new(&data, rect(0, 0, 2, 4), fill(1)));
+
+        assert_eq!(display.0[4][0], 1);
+        assert_eq!(display.0[0][2], 1);
+    }
+
+    #[test]
+    fn flat_data() {
+        let data = [3, 3, 3];
+
+        let mut display = Display::default();
+        display.draw(Sparkline::new(&data, rect(0, 0, 10, 5), Style::stroke(1u8)));
+
+        assert_eq!(display.count(1), 11);
+        assert_eq!(display.0[5][0], 1);
+
+        let empty: [i32; 0] = [];
+        let mut display = Display::default();
+        display.draw(Sparkline::new(
+            &empty,
+            rect(0, 0, 10, 5),
+            Style::stroke(1u8),
+        ));
+        display.draw(BarChart::new(&empty, rect(0, 0, 10, 5), fill(1)));
+        assert_eq!(display.count(0), 24 * 16);
+    }
+}
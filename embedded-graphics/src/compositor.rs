@@ -0,0 +1,289 @@
+//! Layers composited onto a display
+//!
+//! A [`Compositor`](./struct.Compositor.html) draws a stack of [`Layer`](./struct.Layer.html)s
+//! onto a display, bottom layer first. Each layer is either the contents of a
+//! [`Framebuffer`](../framebuffer/struct.Framebuffer.html) or a list of pixels, and can be drawn
+//! opaque, see-through by a per-layer alpha, or with one color key treated as transparent. Because
+//! the layers keep their own pixels, a status bar can be shown over the main content, moved or
+//! hidden again without redrawing that content.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::compositor::{Compositor, Layer};
+//! use embedded_graphics::framebuffer::Framebuffer;
+//! use embedded_graphics::{egcircle, egrectangle};
+//! # use embedded_graphics::mock_display::Display;
+//! # let mut display = Display::default();
+//!
+//! let mut content = [0u8; 24 * 16];
+//! let mut content = Framebuffer::new(&mut content, 24);
+//! content.draw(egcircle!((12, 8), 6, fill = Some(200u8)));
+//!
+//! let mut status = [0u8; 24 * 3];
+//! let mut status = Framebuffer::new(&mut status, 24);
+//! status.draw(egrectangle!((1, 1), (4, 1), fill = Some(255u8)));
+//!
+//! let mut layers = [
+//!     Layer::framebuffer(content.as_image()),
+//!     Layer::framebuffer(status.as_image()).with_alpha(128),
+//! ];
+//! let mut compositor = Compositor::new(&mut layers);
+//! compositor.composite(&mut display);
+//!
+//! // Hide the status bar without redrawing the content
+//! compositor.layers_mut()[1].set_visible(false);
+//! compositor.composite(&mut display);
+//! ```
+
+use crate::drawable::Pixel;
+use crate::framebuffer::FramebufferImage;
+use crate::pixelcolor::blend::Blend;
+use crate::pixelcolor::PixelColor;
+use crate::raster::ReadPixel;
+use crate::Drawing;
+use core::iter;
+
+/// Pixels drawn by a [`Layer`](./struct.Layer.html)
+#[derive(Debug, Clone, Copy)]
+pub enum LayerSource<'a, C>
+where
+    C: PixelColor,
+{
+    /// Every pixel of a framebuffer, at the offset of the image
+    Framebuffer(FramebufferImage<'a, C>),
+
+    /// A list of pixels, for example from items rendered once into an array
+    Pixels(&'a [Pixel<C>]),
+}
+
+/// One layer of a [`Compositor`](./struct.Compositor.html)
+///
+/// Layers are created opaque and visible. [`with_alpha`](#method.with_alpha) mixes the layer with
+/// the layers below it, and [`with_color_key`](#method.with_color_key) skips every pixel of one
+/// color so the layers below show through.
+#[derive(Debug, Clone, Copy)]
+pub struct Layer<'a, C>
+where
+    C: PixelColor,
+{
+    source: LayerSource<'a, C>,
+    alpha: u8,
+    color_key: Option<C>,
+    visible: bool,
+}
+
+impl<'a, C> Layer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a layer from the pixels of a framebuffer
+    ///
+    /// The layer is drawn at the [`offset`](../framebuffer/struct.FramebufferImage.html#structfield.offset)
+    /// of the image, so it can be moved by translating the image.
+    pub fn framebuffer(image: FramebufferImage<'a, C>) -> Self {
+        Self::new(LayerSource::Framebuffer(image))
+    }
+
+    /// Create a layer from a list of pixels
+    pub fn pixels(pixels: &'a [Pixel<C>]) -> Self {
+        Self::new(LayerSource::Pixels(pixels))
+    }
+
+    /// Create an opaque, visible layer
+    pub fn new(source: LayerSource<'a, C>) -> Self {
+        Self {
+            source,
+            alpha: 255,
+            color_key: None,
+            visible: true,
+        }
+    }
+
+    /// Set the opacity of the layer, from `0` for invisible to `255` for opaque
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+
+        self
+    }
+
+    /// Treat every pixel of `color` in the layer as transparent
+    pub fn with_color_key(mut self, color: C) -> Self {
+        self.color_key = Some(color);
+
+        self
+    }
+
+    /// Get the pixels drawn by the layer
+    pub fn source(&self) -> &LayerSource<'a, C> {
+        &self.source
+    }
+
+    /// Replace the pixels drawn by the layer, for example with a moved framebuffer image
+    pub fn set_source(&mut self, source: LayerSource<'a, C>) {
+        self.source = source;
+    }
+
+    /// Get the opacity of the layer
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /// Change the opacity of the layer
+    pub fn set_alpha(&mut self, alpha: u8) {
+        self.alpha = alpha;
+    }
+
+    /// Get the color treated as transparent, if any
+    pub fn color_key(&self) -> Option<C> {
+        self.color_key
+    }
+
+    /// Change the color treated as transparent
+    pub fn set_color_key(&mut self, color_key: Option<C>) {
+        self.color_key = color_key;
+    }
+
+    /// Whether the layer is drawn
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the layer
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Pixels of the layer that aren't keyed out
+    fn opaque_pixels(&self) -> impl Iterator<Item = Pixel<C>> + '_
+    where
+        C: PartialEq,
+    {
+        let (image, pixels) = match self.source {
+            LayerSource::Framebuffer(image) => (Some(image), None),
+            LayerSource::Pixels(pixels) => (None, Some(pixels)),
+        };
+        let key = self.color_key;
+
+        image
+            .into_iter()
+            .flatten()
+            .chain(pixels.into_iter().flatten().cloned())
+            .filter(move |Pixel(_, color)| Some(*color) != key)
+    }
+}
+
+/// Draw a stack of layers onto a display
+///
+/// Layers are stored in a slice owned by the caller and drawn in order, so the first layer is at
+/// the bottom. Layers that aren't opaque are mixed with the pixels already on the display using
+/// [`Blend`](../pixelcolor/blend/trait.Blend.html), so the display has to implement
+/// [`ReadPixel`](../raster/trait.ReadPixel.html). Layers are composited straight onto the
+/// display, so draw to a framebuffer and send it to the display afterwards to avoid flicker.
+#[derive(Debug)]
+pub struct Compositor<'l, 'a, C>
+where
+    C: PixelColor,
+{
+    layers: &'l mut [Layer<'a, C>],
+}
+
+impl<'l, 'a, C> Compositor<'l, 'a, C>
+where
+    C: PixelColor + Blend + PartialEq,
+{
+    /// Create a compositor drawing `layers`, bottom layer first
+    pub fn new(layers: &'l mut [Layer<'a, C>]) -> Self {
+        Self { layers }
+    }
+
+    /// Get the layers, bottom layer first
+    pub fn layers(&self) -> &[Layer<'a, C>] {
+        self.layers
+    }
+
+    /// Get the layers to change them, bottom layer first
+    pub fn layers_mut(&mut self) -> &mut [Layer<'a, C>] {
+        self.layers
+    }
+
+    /// Draw every visible layer onto `target`, bottom layer first
+    ///
+    /// Pixels of see-through layers that are outside `target` are skipped.
+    pub fn composite<D>(&self, target: &mut D)
+    where
+        D: Drawing<C> + ReadPixel<C>,
+    {
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            match layer.alpha {
+                0 => {}
+                255 => target.draw(layer.opaque_pixels()),
+                alpha => {
+                    // Each pixel has to be read before it is drawn, so pixels are drawn one at a
+                    // time
+                    for Pixel(coord, color) in layer.opaque_pixels() {
+                        if let Some(below) = target.pixel(coord) {
+                            target.draw(iter::once(Pixel(coord, below.blend(color, alpha))));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::framebuffer::Framebuffer;
+    use crate::mock_display::Display;
+    use crate::primitives::Rectangle;
+    use crate::style::WithStyle;
+    use crate::transform::Transform;
+    use crate::unsignedcoord::UnsignedCoord;
+
+    #[test]
+    fn layers_are_drawn_in_order() {
+        let mut content = [100u8; 4 * 2];
+        let content = Framebuffer::new(&mut content, 4);
+        let dots = [
+            Pixel(UnsignedCoord::new(1, 0), 7u8),
+            Pixel(UnsignedCoord::new(5, 0), 8),
+        ];
+
+        let mut layers = [
+            Layer::framebuffer(content.as_image().translate(Coord::new(1, 0))),
+            Layer::pixels(&dots),
+        ];
+        let mut display = Display::default();
+        Compositor::new(&mut layers).composite(&mut display);
+
+        assert_eq!(display.0[0][..7], [0, 7, 100, 100, 100, 8, 0]);
+        assert_eq!(display.0[1][..7], [0, 100, 100, 100, 100, 0, 0]);
+    }
+
+    #[test]
+    fn alpha_and_color_key() {
+        let mut overlay = [0u8; 4];
+        let mut overlay = Framebuffer::new(&mut overlay, 4);
+        overlay.draw(Rectangle::new(Coord::new(1, 0), Coord::new(2, 0)).fill(Some(200u8)));
+
+        let mut layers = [
+            Layer::framebuffer(overlay.as_image())
+                .with_alpha(128)
+                .with_color_key(0),
+            Layer::framebuffer(overlay.as_image()).with_alpha(0),
+        ];
+        let mut compositor = Compositor::new(&mut layers);
+
+        let mut display = Display::default();
+        display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(3, 0)).fill(Some(100u8)));
+        compositor.composite(&mut display);
+        assert_eq!(display.0[0][..4], [100, 150, 150, 100]);
+
+        compositor.layers_mut()[0].set_visible(false);
+        compositor.layers_mut()[1].set_alpha(255);
+        compositor.composite(&mut display);
+        assert_eq!(display.0[0][..4], [0, 200, 200, 0]);
+    }
+}
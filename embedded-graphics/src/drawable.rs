@@ -11,6 +11,19 @@ pub struct Pixel<C: PixelColor>(pub UnsignedCoord, pub C);
 /// Marks an object as "drawable". Must be implemented for all graphics objects
 pub trait Drawable {}
 
+/// Pixel iterators that can skip the pixels outside an area without working them out first
+///
+/// This lets a large shape that is mostly off screen be drawn without visiting every one of its
+/// pixels. Clipping is only a hint: some pixels outside the area may still be returned, so they must
+/// be discarded by whatever draws them, for example the
+/// [`Clipped`](../adapter/struct.Clipped.html) adapter. Pixels inside the area are never affected.
+pub trait Clip {
+    /// Skip pixels outside the area from `top_left` to `bottom_right`, inclusive, where possible
+    ///
+    /// This should be called before the first pixel is taken from the iterator.
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord);
+}
+
 /// Adds the ability to get the dimensions/position of a graphics object
 ///
 /// This **should** be implemented for all builtin embedded-graphics primitives and fonts. Third party
@@ -0,0 +1,1378 @@
+//! Fonts in the Glyph Bitmap Distribution Format (BDF)
+//!
+//! BDF is a plain text format supported by most bitmap font editors, and many free fonts are
+//! available as BDF files. Embed one with `include_bytes!` and pass it to
+//! [`BdfFont::new`](./struct.BdfFont.html#method.new) to use it without converting it first.
+//!
+//! BDF has no kerning information, but kerning pairs can be added to a font with
+//! [`BdfFont::kerning`](./struct.BdfFont.html#method.kerning).
+//!
+//! Glyphs are placed using their whole pixel `DWIDTH` by default. Fonts converted from scalable
+//! outlines usually also have a fractional `SWIDTH`, which can be used instead with
+//! [`BdfFont::subpixel_positioning`](./struct.BdfFont.html#method.subpixel_positioning).
+//! Positions are then kept in 26.6 fixed point, 64ths of a pixel, and each glyph is rounded to the
+//! nearest pixel on its own, so long strings don't drift from their intended width.
+//!
+//! Characters missing from one font can be drawn from others by combining several fonts into a
+//! [`FontFallback`](./struct.FontFallback.html), for example a Latin font with a CJK font and a
+//! symbol font.
+//!
+//! Nothing is copied out of the font data. Glyphs are found by searching the data each time a
+//! character is drawn, so drawing is faster with fonts that only contain the characters that are
+//! needed.
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::edges::FillArea;
+use crate::style::{DrawMode, Style, Styled, WithStyle};
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use core::str;
+
+/// Error returned when BDF font data can't be used
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BdfError {
+    /// The data is not text, or doesn't start with `STARTFONT`
+    NotBdf,
+
+    /// The `FONTBOUNDINGBOX` line is missing or invalid
+    InvalidBoundingBox,
+}
+
+/// Font loaded from BDF data
+///
+/// The text is drawn with the stroke color, defaulting to `1` like the
+/// [built in fonts](../index.html), and the background of each character is drawn with the fill
+/// color, if any.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::bdf::BdfFont;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Usually `include_bytes!("my_font.bdf")`
+/// let data = b"STARTFONT 2.1
+/// FONTBOUNDINGBOX 3 5 0 0
+/// CHARS 1
+/// STARTCHAR L
+/// ENCODING 76
+/// DWIDTH 4 0
+/// BBX 3 5 0 0
+/// BITMAP
+/// 80
+/// 80
+/// 80
+/// 80
+/// E0
+/// ENDCHAR
+/// ENDFONT
+/// ";
+///
+/// let font = BdfFont::new(data).expect("Invalid font");
+/// let text = font.render_str("LL").stroke(Some(1u8)).translate(Coord::new(2, 2));
+///
+/// assert_eq!(text.size(), UnsignedCoord::new(8, 5));
+/// display.draw(text);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BdfFont<'a> {
+    /// Font data, starting after the header
+    glyphs: &'a str,
+
+    /// Distance from the top of a line of text to the baseline
+    ascent: i32,
+
+    /// Height of a line of text
+    line_height: u32,
+
+    /// Code point of the glyph drawn for characters that aren't in the font
+    default_char: Option<u32>,
+
+    /// Pairs of characters and the number of pixels to add between them
+    kerning: &'a [(char, char, i32)],
+
+    /// Width of an em square in 64ths of a pixel, from the `SIZE` line
+    em_width: Option<u32>,
+
+    /// Use the `SWIDTH` of glyphs to place them instead of the `DWIDTH`
+    subpixel: bool,
+}
+
+impl<'a> BdfFont<'a> {
+    /// Load a font from BDF data
+    ///
+    /// Only the header is checked here. Characters with invalid glyphs are skipped when they are
+    /// drawn.
+    pub fn new(data: &'a [u8]) -> Result<Self, BdfError> {
+        let data = str::from_utf8(data).map_err(|_| BdfError::NotBdf)?;
+        if !data.trim_start().starts_with("STARTFONT") {
+            return Err(BdfError::NotBdf);
+        }
+
+        let mut bounding_box = None;
+        let (mut ascent, mut descent, mut default_char) = (None, None, None);
+        let mut size = None;
+        let mut glyphs = "";
+
+        let mut rest = data;
+        while let Some(line) = next_line(&mut rest) {
+            let mut values = line.split_whitespace();
+
+            match values.next() {
+                Some("FONTBOUNDINGBOX") => bounding_box = parse_ints(values),
+                Some("SIZE") => {
+                    size = values
+                        .next()
+                        .and_then(|v| v.parse::<u32>().ok())
+                        .zip(values.next().and_then(|v| v.parse::<u32>().ok()))
+                }
+                Some("FONT_ASCENT") => ascent = values.next().and_then(|v| v.parse().ok()),
+                Some("FONT_DESCENT") => descent = values.next().and_then(|v| v.parse().ok()),
+                Some("DEFAULT_CHAR") => default_char = values.next().and_then(|v| v.parse().ok()),
+                Some("CHARS") => {
+                    glyphs = rest;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let [_, height, _, y_offset] = bounding_box.ok_or(BdfError::InvalidBoundingBox)?;
+        let ascent: i32 = match ascent {
+            Some(ascent) => ascent,
+            None => height
+                .checked_add(y_offset)
+                .ok_or(BdfError::InvalidBoundingBox)?,
+        };
+        let descent: i32 = match descent {
+            Some(descent) => descent,
+            None => y_offset.checked_neg().ok_or(BdfError::InvalidBoundingBox)?,
+        };
+        let line_height = ascent
+            .checked_add(descent)
+            .filter(|&line_height| height >= 0 && line_height >= 0)
+            .ok_or(BdfError::InvalidBoundingBox)?;
+
+        Ok(Self {
+            glyphs,
+            ascent,
+            line_height: line_height as u32,
+            default_char,
+            kerning: &[],
+            em_width: size.map(|(point_size, resolution)| {
+                let em_width = (u64::from(point_size) * u64::from(resolution)).saturating_mul(64);
+
+                (em_width / 72).min(u64::from(u32::MAX)) as u32
+            }),
+            subpixel: false,
+        })
+    }
+
+    /// Place glyphs using their fractional `SWIDTH` instead of their whole pixel `DWIDTH`
+    ///
+    /// The pen position is kept in 26.6 fixed point and each glyph is drawn at the nearest whole
+    /// pixel, so small proportional fonts keep their designed spacing across long strings instead
+    /// of gaining or losing a fraction of a pixel with every character. Glyphs without an
+    /// `SWIDTH`, and fonts without a `SIZE` line, still use the `DWIDTH`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::bdf::BdfFont;
+    /// # let data = b"STARTFONT 2.1\nSIZE 6 75 75\nFONTBOUNDINGBOX 2 2 0 0\nCHARS 1\n\
+    /// #     STARTCHAR i\nENCODING 105\nSWIDTH 500 0\nDWIDTH 3 0\nBBX 1 2 0 0\nBITMAP\n80\n80\n\
+    /// #     ENDCHAR\nENDFONT\n";
+    ///
+    /// // Each `i` is 3.125 pixels wide at 6 points and 75 DPI
+    /// let font = BdfFont::new(data).expect("Invalid font");
+    ///
+    /// assert_eq!(font.render_str::<u8>("iiiiiiii").size()[0], 24);
+    /// assert_eq!(
+    ///     font.subpixel_positioning(true).render_str::<u8>("iiiiiiii").size()[0],
+    ///     25
+    /// );
+    /// ```
+    pub fn subpixel_positioning(mut self, enabled: bool) -> Self {
+        self.subpixel = enabled;
+
+        self
+    }
+
+    /// Move pairs of characters closer together or further apart
+    ///
+    /// Each entry is a left character, a right character and the number of pixels to add to the
+    /// distance between them. Negative values move the right character closer, so proportional
+    /// fonts can tuck pairs like `AV` or `To` together. Character cells that overlap are only
+    /// drawn once, so the glyphs of both characters are kept.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::bdf::BdfFont;
+    /// # let data = b"STARTFONT 2.1\nFONTBOUNDINGBOX 4 4 0 0\nCHARS 2\n\
+    /// #     STARTCHAR A\nENCODING 65\nDWIDTH 5 0\nBBX 4 4 0 0\nBITMAP\n60\n90\nF0\n90\nENDCHAR\n\
+    /// #     STARTCHAR V\nENCODING 86\nDWIDTH 5 0\nBBX 4 4 0 0\nBITMAP\n90\n90\n90\n60\nENDCHAR\n\
+    /// #     ENDFONT\n";
+    ///
+    /// let font = BdfFont::new(data)
+    ///     .expect("Invalid font")
+    ///     .kerning(&[('A', 'V', -1), ('V', 'A', -1)]);
+    ///
+    /// assert_eq!(font.render_str::<u8>("AV").size(), UnsignedCoord::new(9, 4));
+    /// ```
+    pub fn kerning(mut self, pairs: &'a [(char, char, i32)]) -> Self {
+        self.kerning = pairs;
+
+        self
+    }
+
+    /// Number of pixels added to the distance between `left` and `right` when they are drawn
+    /// next to each other
+    pub fn kerning_between(&self, left: char, right: char) -> i32 {
+        self.kerning
+            .iter()
+            .find(|&&(l, r, _)| l == left && r == right)
+            .map_or(0, |&(_, _, adjust)| adjust)
+    }
+
+    /// Height of a line of text in pixels
+    pub fn line_height(&self) -> u32 {
+        self.line_height
+    }
+
+    /// Find the glyph for a character
+    ///
+    /// Returns `None` if the character isn't in the font or its glyph is invalid.
+    pub fn glyph(&self, c: char) -> Option<BdfGlyph<'a>> {
+        let mut rest = self.glyphs;
+
+        while let Some(line) = next_line(&mut rest) {
+            if line.split_whitespace().next() == Some("STARTCHAR") {
+                if let Some(glyph) = BdfGlyph::parse(&mut rest, c as u32) {
+                    return Some(glyph);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Glyph drawn for a character, falling back to the font's default character or `?`
+    fn glyph_or_default(&self, c: char) -> Option<BdfGlyph<'a>> {
+        self.glyph(c)
+            .or_else(|| {
+                self.default_char
+                    .and_then(core::char::from_u32)
+                    .and_then(|c| self.glyph(c))
+            })
+            .or_else(|| self.glyph('?'))
+    }
+
+    /// Next character in `chars` that can be drawn, and its glyph
+    fn next_glyph(&self, chars: &mut str::Chars<'a>) -> Option<(char, BdfGlyph<'a>)> {
+        chars.find_map(|c| self.glyph_or_default(c).map(|glyph| (c, glyph)))
+    }
+
+    /// Distance to move the pen after drawing `left`, if `right` is drawn after it, in 26.6 fixed
+    /// point
+    ///
+    /// Widths too large for 26.6 fixed point are clamped to `i32::MAX`.
+    fn advance(
+        &self,
+        (left, glyph): (char, BdfGlyph<'a>),
+        right: Option<(char, BdfGlyph<'a>)>,
+    ) -> i32 {
+        let kerning = right.map_or(0, |(right, _)| self.kerning_between(left, right));
+        let width = match (self.subpixel, glyph.scalable_width, self.em_width) {
+            (true, Some(scalable_width), Some(em_width)) => {
+                i64::from(scalable_width).saturating_mul(i64::from(em_width)) / 1000
+            }
+            _ => i64::from(glyph.device_width) * 64,
+        };
+
+        (width + i64::from(kerning) * 64).clamp(0, i64::from(i32::MAX)) as i32
+    }
+
+    /// Returns `true` if `glyph` sets the pixel at (`x`, `y`) from the top left corner of its cell
+    fn ink(&self, glyph: BdfGlyph<'a>, x: i32, y: i32) -> bool {
+        // Position inside the glyph's bitmap, which can be far outside the cell for glyphs with
+        // extreme offsets
+        let top = i64::from(self.ascent) - i64::from(glyph.y_offset) - i64::from(glyph.height);
+        let bitmap_x = i64::from(x) - i64::from(glyph.x_offset);
+        let bitmap_y = i64::from(y) - top;
+
+        (0..i64::from(glyph.width)).contains(&bitmap_x)
+            && (0..i64::from(glyph.height)).contains(&bitmap_y)
+            && glyph.pixel(bitmap_x as u32, bitmap_y as u32)
+    }
+
+    /// Render a string in this font
+    pub fn render_str<C>(&self, text: &'a str) -> BdfText<'a, C>
+    where
+        C: PixelColor,
+    {
+        BdfText {
+            pos: Coord::new(0, 0),
+            font: *self,
+            text,
+            style: Style::default(),
+        }
+    }
+}
+
+/// Round a 26.6 fixed point distance to the nearest whole pixel
+fn round_26_6(value: i32) -> i32 {
+    value.saturating_add(32) >> 6
+}
+
+/// Split the first line off `rest`, returning it without its line ending
+fn next_line<'b>(rest: &mut &'b str) -> Option<&'b str> {
+    if rest.is_empty() {
+        return None;
+    }
+
+    let (line, remainder) = match rest.find('\n') {
+        Some(end) => (&rest[..end], &rest[end + 1..]),
+        None => (*rest, ""),
+    };
+    *rest = remainder;
+
+    Some(line.trim_end_matches('\r'))
+}
+
+/// Parse the four integers of a `FONTBOUNDINGBOX` or `BBX` line
+fn parse_ints<'b>(mut values: impl Iterator<Item = &'b str>) -> Option<[i32; 4]> {
+    let mut ints = [0; 4];
+    for int in ints.iter_mut() {
+        *int = values.next()?.parse().ok()?;
+    }
+
+    Some(ints)
+}
+
+/// Bitmap of a single character in a [`BdfFont`](./struct.BdfFont.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BdfGlyph<'a> {
+    /// Width of the bitmap in pixels
+    pub width: u32,
+
+    /// Height of the bitmap in pixels
+    pub height: u32,
+
+    /// Distance from the pen position to the left edge of the bitmap
+    pub x_offset: i32,
+
+    /// Distance from the baseline up to the bottom edge of the bitmap
+    pub y_offset: i32,
+
+    /// Distance to move the pen to the right after drawing the character
+    pub device_width: u32,
+
+    /// Distance to move the pen in thousandths of the point size, if the glyph has an `SWIDTH`
+    pub scalable_width: Option<u32>,
+
+    /// Rows of the bitmap, one line of hex digits per row
+    bitmap: &'a str,
+}
+
+impl<'a> BdfGlyph<'a> {
+    /// Parse the glyph starting after a `STARTCHAR` line, if it has the code point `encoding`
+    fn parse(rest: &mut &'a str, encoding: u32) -> Option<Self> {
+        let (mut bbx, mut device_width, mut scalable_width) = (None, None, None);
+
+        while let Some(line) = next_line(rest) {
+            let mut values = line.split_whitespace();
+
+            match values.next() {
+                Some("ENCODING") if values.next()?.parse::<i64>().ok()? != i64::from(encoding) => {
+                    return None
+                }
+                Some("DWIDTH") => device_width = values.next()?.parse().ok(),
+                Some("SWIDTH") => scalable_width = values.next()?.parse().ok(),
+                Some("BBX") => bbx = parse_ints(values),
+                Some("BITMAP") => break,
+                Some("ENDCHAR") => return None,
+                _ => {}
+            }
+        }
+
+        let [width, height, x_offset, y_offset] = bbx?;
+        if width < 0 || height < 0 {
+            return None;
+        }
+
+        // Rows are the lines between BITMAP and ENDCHAR
+        let end = rest.find("ENDCHAR")?;
+        let bitmap = &rest[..end];
+        if bitmap.lines().count() < height as usize {
+            return None;
+        }
+
+        Some(Self {
+            width: width as u32,
+            height: height as u32,
+            x_offset,
+            y_offset,
+            device_width: device_width.unwrap_or(width as u32),
+            scalable_width,
+            bitmap,
+        })
+    }
+
+    /// Returns `true` if the pixel at (`x`, `y`) from the top left corner of the bitmap is set
+    pub fn pixel(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+
+        self.bitmap
+            .lines()
+            .nth(y as usize)
+            .and_then(|row| row.trim().get((x / 4) as usize..(x / 4 + 1) as usize))
+            .and_then(|digit| u8::from_str_radix(digit, 16).ok())
+            .is_some_and(|nibble| nibble & (0b1000 >> (x % 4)) != 0)
+    }
+}
+
+/// Text drawn in a [`BdfFont`](./struct.BdfFont.html)
+///
+/// Created by [`BdfFont::render_str`](./struct.BdfFont.html#method.render_str). Only the
+/// stroke and fill colors of the style are used.
+#[derive(Debug, Copy, Clone)]
+pub struct BdfText<'a, C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the text
+    pub pos: Coord,
+
+    font: BdfFont<'a>,
+    text: &'a str,
+    style: Style<C>,
+}
+
+impl<C> Dimensions for BdfText<'_, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    /// Get the bounding box of the text
+    ///
+    /// The width is the distance the pen moves while drawing the text, including any
+    /// [kerning](./struct.BdfFont.html#method.kerning), rounded to the nearest pixel. It will give
+    /// `(0, 0)` if the string to render is empty.
+    fn size(&self) -> UnsignedCoord {
+        let font = self.font;
+        let mut chars = self.text.chars();
+        let mut pen: i32 = 0;
+
+        let mut glyph = font.next_glyph(&mut chars);
+        while let Some(current) = glyph {
+            glyph = font.next_glyph(&mut chars);
+            pen = pen.saturating_add(font.advance(current, glyph));
+        }
+        let width = round_26_6(pen) as u32;
+        let height = if self.text.is_empty() {
+            0
+        } else {
+            font.line_height
+        };
+
+        UnsignedCoord::new(width, height)
+    }
+}
+
+impl<C> WithStyle<C> for BdfText<'_, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(self, _width: u8) -> Self {
+        // Noop
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for BdfText<'_, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for BdfText<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = BdfTextIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &BdfText<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = BdfTextIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut chars = self.text.chars();
+        let glyph = self.font.next_glyph(&mut chars);
+        let next = self.font.next_glyph(&mut chars);
+
+        let mut iter = BdfTextIterator {
+            font: self.font,
+            chars,
+            glyph,
+            next,
+            advance: 0,
+            previous: None,
+            pen: 0,
+            cell: self.pos,
+            x: 0,
+            y: 0,
+            style: self.style,
+        };
+        iter.advance = iter.cell_width();
+
+        iter
+    }
+}
+
+/// Pixel iterator for the `BdfText` object
+#[derive(Debug, Clone)]
+pub struct BdfTextIterator<'a, C>
+where
+    C: PixelColor,
+{
+    font: BdfFont<'a>,
+    chars: str::Chars<'a>,
+
+    /// Character and glyph being drawn
+    glyph: Option<(char, BdfGlyph<'a>)>,
+
+    /// Character and glyph drawn after the current one
+    next: Option<(char, BdfGlyph<'a>)>,
+
+    /// Width of the current cell in whole pixels, after kerning
+    advance: u32,
+
+    /// Glyph drawn before the current one and the width of its cell, so the parts of it that
+    /// overlap the current cell aren't drawn over
+    previous: Option<(BdfGlyph<'a>, u32)>,
+
+    /// Distance from the start of the text to the pen position of the glyph being drawn, in 26.6
+    /// fixed point
+    pen: i32,
+
+    /// Top left corner of the cell of the glyph being drawn
+    cell: Coord,
+
+    /// Position inside the cell
+    x: u32,
+    y: u32,
+
+    style: Style<C>,
+}
+
+impl<'a, C> BdfTextIterator<'a, C>
+where
+    C: PixelColor,
+{
+    /// Move the pen to the cell of the next glyph
+    fn next_cell(&mut self, glyph: BdfGlyph<'a>) {
+        self.x = 0;
+        self.y = 0;
+        self.cell += Coord::new(self.advance as i32, 0);
+        self.previous = Some((glyph, self.advance));
+        if let Some(current) = self.glyph {
+            self.pen = self
+                .pen
+                .saturating_add(self.font.advance(current, self.next));
+        }
+
+        self.glyph = self.next;
+        self.next = self.font.next_glyph(&mut self.chars);
+        self.advance = self.cell_width();
+    }
+
+    /// Width of the cell of the current glyph in whole pixels
+    ///
+    /// Both edges of the cell are rounded from the exact pen positions, so rounding errors don't
+    /// add up along the text.
+    fn cell_width(&self) -> u32 {
+        self.glyph.map_or(0, |glyph| {
+            let end = self.pen.saturating_add(self.font.advance(glyph, self.next));
+
+            (round_26_6(end) - round_26_6(self.pen)) as u32
+        })
+    }
+}
+
+impl<C> Iterator for BdfTextIterator<'_, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (_, glyph) = self.glyph?;
+            if self.advance == 0 {
+                self.next_cell(glyph);
+                continue;
+            }
+
+            let (x, y) = (self.x, self.y);
+            let point = self.cell + Coord::new(x as i32, y as i32);
+
+            // Kerned glyphs can reach into the cell after them
+            let set = self.font.ink(glyph, x as i32, y as i32)
+                || self.previous.is_some_and(|(previous, advance)| {
+                    self.font.ink(previous, (x + advance) as i32, y as i32)
+                });
+
+            self.x += 1;
+            if self.x >= self.advance {
+                self.x = 0;
+                self.y += 1;
+            }
+            if self.y >= self.font.line_height {
+                self.next_cell(glyph);
+            }
+
+            let color = if set {
+                Some(self.style.stroke_color.unwrap_or_else(|| 1.into()))
+            } else {
+                self.style.fill_color
+            };
+
+            if let Some(color) = color {
+                if point[0] >= 0 && point[1] >= 0 {
+                    return Some(Pixel(point.to_unsigned(), color));
+                }
+            }
+        }
+    }
+}
+
+impl<C> Drawable for BdfText<'_, C> where C: PixelColor {}
+
+impl<C> Transform for BdfText<'_, C>
+where
+    C: PixelColor,
+{
+    /// Translate the text from its current position to a new position by (x, y) pixels, returning
+    /// a new `BdfText`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the text from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+/// Several fonts used together, each drawing the characters missing from the ones before it
+///
+/// Each character is drawn from the first font that has a glyph for it. Characters that none of
+/// the fonts have are drawn as the replacement character `U+FFFD` from the first font that has
+/// one, or as the default character of the first font otherwise. Runs of characters from the same
+/// font are drawn as [`BdfText`](./struct.BdfText.html), so kerning applies inside a run but not
+/// between characters from different fonts.
+///
+/// The baselines of all the fonts are lined up, and lines are tall enough for the tallest ascent
+/// and the deepest descent of the fonts.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::bdf::{BdfFont, FontFallback};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+/// # let latin_data = b"STARTFONT 2.1\nFONTBOUNDINGBOX 3 4 0 0\nCHARS 1\n\
+/// #     STARTCHAR A\nENCODING 65\nDWIDTH 4 0\nBBX 3 4 0 0\nBITMAP\n40\nA0\nE0\nA0\nENDCHAR\n\
+/// #     ENDFONT\n";
+/// # let symbol_data = b"STARTFONT 2.1\nFONTBOUNDINGBOX 5 5 0 0\nCHARS 1\n\
+/// #     STARTCHAR arrowright\nENCODING 8594\nDWIDTH 6 0\nBBX 5 5 0 0\nBITMAP\n20\n10\nF8\n\
+/// #     10\n20\nENDCHAR\nENDFONT\n";
+///
+/// // Usually `include_bytes!` of two BDF files
+/// let fonts = [
+///     BdfFont::new(latin_data).expect("Invalid font"),
+///     BdfFont::new(symbol_data).expect("Invalid font"),
+/// ];
+/// let fonts = FontFallback::new(&fonts);
+///
+/// let text = fonts.render_str("A\u{2192}A").stroke(Some(1u8));
+/// assert_eq!(text.size(), UnsignedCoord::new(14, 5));
+///
+/// display.draw(text);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FontFallback<'a> {
+    fonts: &'a [BdfFont<'a>],
+}
+
+impl<'a> FontFallback<'a> {
+    /// Combine `fonts`, in the order they are tried
+    pub fn new(fonts: &'a [BdfFont<'a>]) -> Self {
+        Self { fonts }
+    }
+
+    /// Distance from the top of a line of text to the baseline
+    fn ascent(&self) -> i32 {
+        self.fonts.iter().map(|font| font.ascent).max().unwrap_or(0)
+    }
+
+    /// Height of a line of text in pixels
+    pub fn line_height(&self) -> u32 {
+        let descent = self
+            .fonts
+            .iter()
+            .map(|font| font.line_height as i32 - font.ascent)
+            .max()
+            .unwrap_or(0);
+
+        (self.ascent() + descent).max(0) as u32
+    }
+
+    /// Font a character is drawn from
+    ///
+    /// Returns `None` if there are no fonts.
+    pub fn font_for(&self, c: char) -> Option<BdfFont<'a>> {
+        self.choose(c).map(|(font, _)| font)
+    }
+
+    /// Font a character is drawn from, and the replacement character to draw instead of it if
+    /// none of the fonts have it
+    fn choose(&self, c: char) -> Option<(BdfFont<'a>, Option<&'static str>)> {
+        let find = |c| {
+            self.fonts
+                .iter()
+                .find(|font| font.glyph(c).is_some())
+                .copied()
+        };
+
+        find(c)
+            .map(|font| (font, None))
+            .or_else(|| find(REPLACEMENT_CHARACTER).map(|font| (font, Some("\u{FFFD}"))))
+            .or_else(|| self.fonts.first().map(|&font| (font, None)))
+    }
+
+    /// Split the next run of characters drawn from the same font off `rest`
+    fn next_run(&self, rest: &mut &'a str) -> Option<(BdfFont<'a>, &'a str)> {
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+        let (font, replacement) = self.choose(first)?;
+
+        let end = match replacement {
+            Some(_) => first.len_utf8(),
+            None => chars
+                .find(|&(_, c)| self.choose(c) != Some((font, None)))
+                .map_or(rest.len(), |(end, _)| end),
+        };
+        let run = replacement.unwrap_or(&rest[..end]);
+        *rest = &rest[end..];
+
+        Some((font, run))
+    }
+
+    /// Render a string using these fonts
+    pub fn render_str<C>(&self, text: &'a str) -> FallbackText<'a, C>
+    where
+        C: PixelColor,
+    {
+        FallbackText {
+            pos: Coord::new(0, 0),
+            fonts: *self,
+            text,
+            style: Style::default(),
+        }
+    }
+}
+
+/// Character drawn in place of characters that aren't in any font
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Text drawn in a [`FontFallback`](./struct.FontFallback.html)
+///
+/// Created by [`FontFallback::render_str`](./struct.FontFallback.html#method.render_str). Only
+/// the stroke and fill colors of the style are used.
+#[derive(Debug, Copy, Clone)]
+pub struct FallbackText<'a, C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the text
+    pub pos: Coord,
+    fonts: FontFallback<'a>,
+    text: &'a str,
+    style: Style<C>,
+}
+
+impl<'a, C> FallbackText<'a, C>
+where
+    C: PixelColor,
+{
+    /// Text of a run of characters from `font`, with its top left corner at `pen`
+    fn run(&self, font: BdfFont<'a>, text: &'a str, pen: Coord) -> BdfText<'a, C> {
+        BdfText {
+            pos: pen + Coord::new(0, self.fonts.ascent() - font.ascent),
+            font,
+            text,
+            style: self.style,
+        }
+    }
+}
+
+impl<C> Dimensions for FallbackText<'_, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    /// Get the bounding box of the text
+    ///
+    /// It will give `(0, 0)` if the string to render is empty.
+    fn size(&self) -> UnsignedCoord {
+        let mut rest = self.text;
+        let mut width = 0;
+
+        while let Some((font, run)) = self.fonts.next_run(&mut rest) {
+            width += font.render_str::<C>(run).size()[0];
+        }
+        let height = if self.text.is_empty() {
+            0
+        } else {
+            self.fonts.line_height()
+        };
+
+        UnsignedCoord::new(width, height)
+    }
+}
+
+impl<C> WithStyle<C> for FallbackText<'_, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(self, _width: u8) -> Self {
+        // Noop
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for FallbackText<'_, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for FallbackText<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = FallbackTextIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &FallbackText<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = FallbackTextIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FallbackTextIterator {
+            text: *self,
+            rest: self.text,
+            pen: self.pos,
+            run: None,
+            gaps: [FillArea::new(None); 2],
+        }
+    }
+}
+
+/// Pixel iterator for the `FallbackText` object
+#[derive(Debug, Clone)]
+pub struct FallbackTextIterator<'a, C>
+where
+    C: PixelColor,
+{
+    text: FallbackText<'a, C>,
+
+    /// Characters after the current run
+    rest: &'a str,
+
+    /// Top left corner of the next run
+    pen: Coord,
+
+    /// Pixels of the current run
+    run: Option<BdfTextIterator<'a, C>>,
+
+    /// Parts of the line above and below the current run, filled with the fill color
+    gaps: [FillArea; 2],
+}
+
+impl<C> Iterator for FallbackTextIterator<'_, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pixel) = self.run.as_mut().and_then(Iterator::next) {
+                return Some(pixel);
+            }
+
+            if let Some(fill) = self.text.style.fill_color {
+                if let Some(point) = self.gaps.iter_mut().find_map(Iterator::next) {
+                    return Some(Pixel(point.to_unsigned(), fill));
+                }
+            }
+
+            // Move on to the next run
+            let (font, run) = self.text.fonts.next_run(&mut self.rest)?;
+            let run = self.text.run(font, run, self.pen);
+            let size = run.size().to_signed();
+            let bottom = self.pen[1] + self.text.fonts.line_height() as i32;
+
+            self.gaps = [
+                FillArea::new(Some((
+                    self.pen,
+                    Coord::new(self.pen[0] + size[0], run.pos[1]),
+                ))),
+                FillArea::new(Some((
+                    Coord::new(self.pen[0], run.pos[1] + size[1]),
+                    Coord::new(self.pen[0] + size[0], bottom),
+                ))),
+            ];
+            self.pen += Coord::new(size[0], 0);
+            self.run = Some(run.into_iter());
+        }
+    }
+}
+
+impl<C> Drawable for FallbackText<'_, C> where C: PixelColor {}
+
+impl<C> Transform for FallbackText<'_, C>
+where
+    C: PixelColor,
+{
+    /// Translate the text from its current position to a new position by (x, y) pixels, returning
+    /// a new `FallbackText`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the text from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    const FONT: &[u8] = b"STARTFONT 2.1
+FONT -test-tiny-medium-r-normal--6-60-75-75-c-40-iso10646-1
+SIZE 6 75 75
+FONTBOUNDINGBOX 4 6 0 -1
+STARTPROPERTIES 3
+FONT_ASCENT 5
+FONT_DESCENT 1
+DEFAULT_CHAR 46
+ENDPROPERTIES
+CHARS 3
+STARTCHAR L
+ENCODING 76
+SWIDTH 500 0
+DWIDTH 4 0
+BBX 3 5 0 0
+BITMAP
+80
+80
+80
+80
+E0
+ENDCHAR
+STARTCHAR j
+ENCODING 106
+SWIDTH 500 0
+DWIDTH 3 0
+BBX 2 6 0 -1
+BITMAP
+40
+00
+40
+40
+40
+80
+ENDCHAR
+STARTCHAR period
+ENCODING 46
+SWIDTH 250 0
+DWIDTH 2 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn header() {
+        let font = BdfFont::new(FONT).unwrap();
+
+        assert_eq!(font.line_height(), 6);
+        assert_eq!(font.ascent, 5);
+        assert_eq!(font.default_char, Some(46));
+    }
+
+    #[test]
+    fn invalid_data() {
+        assert_eq!(BdfFont::new(b"hello"), Err(BdfError::NotBdf));
+        assert_eq!(BdfFont::new(&[0xff, 0xfe]), Err(BdfError::NotBdf));
+        assert_eq!(
+            BdfFont::new(b"STARTFONT 2.1\nCHARS 0\nENDFONT\n"),
+            Err(BdfError::InvalidBoundingBox)
+        );
+    }
+
+    #[test]
+    fn out_of_range_header() {
+        let fonts: [&[u8]; 3] = [
+            b"STARTFONT 2.1\nFONTBOUNDINGBOX 1 2147483647 0 1\nCHARS 0\nENDFONT\n",
+            b"STARTFONT 2.1\nFONTBOUNDINGBOX 1 1 0 -2147483648\nCHARS 0\nENDFONT\n",
+            b"STARTFONT 2.1\nFONTBOUNDINGBOX 1 1 0 0\nFONT_ASCENT 2147483647\n\
+              FONT_DESCENT 5\nCHARS 0\nENDFONT\n",
+        ];
+
+        for data in fonts.iter() {
+            assert_eq!(BdfFont::new(data), Err(BdfError::InvalidBoundingBox));
+        }
+    }
+
+    #[test]
+    fn glyphs() {
+        let font = BdfFont::new(FONT).unwrap();
+        let l = font.glyph('L').unwrap();
+
+        assert_eq!((l.width, l.height, l.device_width), (3, 5, 4));
+        assert!(l.pixel(0, 0));
+        assert!(!l.pixel(1, 0));
+        assert!(l.pixel(2, 4));
+        assert!(!l.pixel(3, 4));
+
+        assert_eq!(font.glyph('j').unwrap().y_offset, -1);
+        assert_eq!(font.glyph('x'), None);
+    }
+
+    #[test]
+    fn draws_text() {
+        let font = BdfFont::new(FONT).unwrap();
+
+        let mut display = Display::default();
+        display.draw(
+            font.render_str("Lj?")
+                .stroke(Some(1u8))
+                .fill(Some(2u8))
+                .translate(Coord::new(1, 1)),
+        );
+
+        #[rustfmt::skip]
+        let expected: [[u8; 11]; 8] = [
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 1, 2, 2, 2, 2, 1, 2, 2, 2, 0],
+            [0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0],
+            [0, 1, 2, 2, 2, 2, 1, 2, 2, 2, 0],
+            [0, 1, 2, 2, 2, 2, 1, 2, 2, 2, 0],
+            [0, 1, 1, 1, 2, 2, 1, 2, 1, 2, 0],
+            [0, 2, 2, 2, 2, 1, 2, 2, 2, 2, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ];
+
+        for (y, row) in expected.iter().enumerate() {
+            assert_eq!(display.0[y][..11], row[..], "row {}", y);
+        }
+    }
+
+    #[test]
+    fn kerning() {
+        let font = BdfFont::new(FONT)
+            .unwrap()
+            .kerning(&[('L', 'j', -2), ('j', 'L', 1)]);
+        let text = font
+            .render_str("Lj")
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .translate(Coord::new(1, 1));
+
+        assert_eq!(font.kerning_between('L', 'j'), -2);
+        assert_eq!(font.kerning_between('j', 'j'), 0);
+        assert_eq!(text.size(), UnsignedCoord::new(5, 6));
+        assert_eq!(font.render_str::<u8>("jL").size(), UnsignedCoord::new(8, 6));
+
+        // Every pixel of the two cells is drawn once
+        assert_eq!(text.into_iter().count(), 5 * 6);
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // The foot of the `L` reaches into the cell of the `j`
+        #[rustfmt::skip]
+        let expected: [[u8; 7]; 8] = [
+            [0, 0, 0, 0, 0, 0, 0],
+            [0, 1, 2, 2, 1, 2, 0],
+            [0, 1, 2, 2, 2, 2, 0],
+            [0, 1, 2, 2, 1, 2, 0],
+            [0, 1, 2, 2, 1, 2, 0],
+            [0, 1, 1, 1, 1, 2, 0],
+            [0, 2, 2, 1, 2, 2, 0],
+            [0, 0, 0, 0, 0, 0, 0],
+        ];
+
+        for (y, row) in expected.iter().enumerate() {
+            assert_eq!(display.0[y][..7], row[..], "row {}", y);
+        }
+    }
+
+    #[test]
+    fn subpixel_positioning() {
+        // `L` has an `SWIDTH` of 500, which is 3.125 pixels at 6 points and 75 DPI
+        let font = BdfFont::new(FONT).unwrap().subpixel_positioning(true);
+        let text = font.render_str("LLLLLLL").stroke(Some(1u8));
+
+        assert_eq!(font.em_width, Some(400));
+        assert_eq!(text.size(), UnsignedCoord::new(22, 6));
+        assert_eq!(
+            font.render_str::<u8>("LLLLLLLL").size(),
+            UnsignedCoord::new(25, 6)
+        );
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // Pens at 0, 3.125, 6.25, 9.375, 12.5, 15.625 and 18.75 pixels, rounded
+        let stems: [usize; 7] = [0, 3, 6, 9, 13, 16, 19];
+        for x in 0..24 {
+            assert_eq!(display.0[0][x] == 1, stems.contains(&x), "column {}", x);
+        }
+
+        // Without it the whole pixel `DWIDTH` is used
+        assert_eq!(
+            font.subpixel_positioning(false)
+                .render_str::<u8>("LLLLLLLL")
+                .size(),
+            UnsignedCoord::new(32, 6)
+        );
+    }
+
+    /// Font with a taller ascent than `FONT`, a right arrow and a replacement character
+    const SYMBOLS: &[u8] = b"STARTFONT 2.1
+FONTBOUNDINGBOX 3 7 0 0
+FONT_ASCENT 7
+FONT_DESCENT 0
+CHARS 2
+STARTCHAR arrowright
+ENCODING 8594
+DWIDTH 3 0
+BBX 3 3 0 0
+BITMAP
+40
+E0
+40
+ENDCHAR
+STARTCHAR uniFFFD
+ENCODING 65533
+DWIDTH 2 0
+BBX 1 7 0 0
+BITMAP
+80
+80
+80
+80
+80
+80
+80
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn huge_advances() {
+        let data = b"STARTFONT 2.1\nSIZE 4294967295 4294967295 75\nFONTBOUNDINGBOX 1 1 0 0\n\
+            CHARS 3\n\
+            STARTCHAR A\nENCODING 65\nDWIDTH 40000000 0\nBBX 1 1 0 0\nBITMAP\n80\nENDCHAR\n\
+            STARTCHAR B\nENCODING 66\nDWIDTH 4294967295 0\nBBX 1 1 0 0\nBITMAP\n80\nENDCHAR\n\
+            STARTCHAR C\nENCODING 67\nSWIDTH 4294967295 0\nDWIDTH 1 0\nBBX 1 1 0 0\nBITMAP\n80\n\
+            ENDCHAR\nENDFONT\n";
+        let font = BdfFont::new(data).unwrap();
+        let max_width = (i32::MAX >> 6) as u32;
+
+        assert_eq!(font.render_str::<u8>("AB").size()[0], max_width);
+        assert_eq!(font.render_str::<u8>("B").size()[0], max_width);
+        assert_eq!(
+            font.subpixel_positioning(true)
+                .render_str::<u8>("CC")
+                .size()[0],
+            max_width
+        );
+        assert!(font.render_str::<u8>("AB").into_iter().next().is_some());
+    }
+
+    #[test]
+    fn extreme_offsets() {
+        let data = b"STARTFONT 2.1\nFONTBOUNDINGBOX 2 2 0 0\nCHARS 2\n\
+            STARTCHAR A\nENCODING 65\nDWIDTH 2 0\nBBX 1 1 -2147483648 0\nBITMAP\n80\nENDCHAR\n\
+            STARTCHAR B\nENCODING 66\nDWIDTH 2 0\nBBX 1 1 0 -2147483648\nBITMAP\n80\nENDCHAR\n\
+            ENDFONT\n";
+        let font = BdfFont::new(data).unwrap();
+
+        let mut display = Display::default();
+        display.draw(font.render_str("AB").stroke(Some(1u8)));
+
+        assert_eq!(display.count(1), 0);
+    }
+
+    #[test]
+    fn font_fallback() {
+        let fonts = [BdfFont::new(FONT).unwrap(), BdfFont::new(SYMBOLS).unwrap()];
+        let fonts = FontFallback::new(&fonts);
+        let text = fonts
+            .render_str("L\u{2192}x")
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .translate(Coord::new(1, 1));
+
+        assert_eq!(fonts.line_height(), 8);
+        assert_eq!(fonts.font_for('L'), Some(BdfFont::new(FONT).unwrap()));
+        assert_eq!(fonts.font_for('x'), Some(BdfFont::new(SYMBOLS).unwrap()));
+        assert_eq!(text.size(), UnsignedCoord::new(9, 8));
+
+        // Every pixel of the line is drawn once
+        assert_eq!(text.into_iter().count(), 9 * 8);
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // `L` and the arrow are on the same baseline, and `x` is replaced by `U+FFFD`
+        #[rustfmt::skip]
+        let expected: [[u8; 11]; 10] = [
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 2, 2, 2, 2, 2, 2, 2, 1, 2, 0],
+            [0, 2, 2, 2, 2, 2, 2, 2, 1, 2, 0],
+            [0, 1, 2, 2, 2, 2, 2, 2, 1, 2, 0],
+            [0, 1, 2, 2, 2, 2, 2, 2, 1, 2, 0],
+            [0, 1, 2, 2, 2, 2, 1, 2, 1, 2, 0],
+            [0, 1, 2, 2, 2, 1, 1, 1, 1, 2, 0],
+            [0, 1, 1, 1, 2, 2, 1, 2, 1, 2, 0],
+            [0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ];
+
+        for (y, row) in expected.iter().enumerate() {
+            assert_eq!(display.0[y][..11], row[..], "row {}", y);
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        let font = BdfFont::new(FONT).unwrap();
+        let text = font.render_str::<u8>("LLj").translate(Coord::new(3, 4));
+
+        assert_eq!(text.size(), UnsignedCoord::new(11, 6));
+        assert_eq!(text.bottom_right(), Coord::new(14, 10));
+        assert_eq!(font.render_str::<u8>("").size(), UnsignedCoord::new(0, 0));
+    }
+}
@@ -1,11 +1,14 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
 
+/// Configuration of the [`Font12x16`](./type.Font12x16.html) font, used to get its
+/// [glyphs](./font_builder/struct.Glyph.html)
 #[derive(Debug, Copy, Clone)]
 pub enum Font12x16Conf {}
 impl FontBuilderConf for Font12x16Conf {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font12x16_1bpp.raw");
     const CHAR_HEIGHT: u32 = 16;
     const CHAR_WIDTH: u32 = 12;
+    const CHAR_BASELINE: u32 = 14;
     const FONT_IMAGE_WIDTH: u32 = 480;
     fn char_offset(c: char) -> u32 {
         let fallback = '?' as u32 - ' ' as u32;
@@ -97,6 +100,23 @@ mod tests {
     use crate::unsignedcoord::UnsignedCoord;
     use crate::Drawing;
 
+    #[test]
+    fn text_lines_scale_with_font() {
+        let text = Font12x16::render_str("ab")
+            .underline(Some(2u8))
+            .strikethrough(Some(3u8));
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // 2 pixels thick for a 16 pixel high font
+        for &(row, color) in &[(7, 3), (8, 3), (14, 2), (15, 2)] {
+            assert_eq!(display.0[row], [color; 24], "row {}", row);
+        }
+        assert!(display.0[6].iter().all(|&c| c != 3));
+        assert!(display.0[13].iter().all(|&c| c != 2));
+    }
+
     #[test]
     fn off_screen_text_does_not_infinite_loop() {
         let text: Font12x16<u8> = Font12x16::render_str("Hello World!")
@@ -237,7 +257,7 @@ mod tests {
         );
 
         let mut display = Display::default();
-        display.draw(Font12x16::render_str("\0\n").stroke(Some(1)));
+        display.draw(Font12x16::render_str("\0\x1b").stroke(Some(1)));
         assert_eq!(display, two_question_marks);
 
         let mut display = Display::default();
@@ -1,11 +1,14 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
 
+/// Configuration of the [`Font6x12`](./type.Font6x12.html) font, used to get its
+/// [glyphs](./font_builder/struct.Glyph.html)
 #[derive(Debug, Copy, Clone)]
 pub enum Font6x12Conf {}
 impl FontBuilderConf for Font6x12Conf {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font6x12_1bpp.raw");
     const CHAR_HEIGHT: u32 = 12;
     const CHAR_WIDTH: u32 = 6;
+    const CHAR_BASELINE: u32 = 10;
     const FONT_IMAGE_WIDTH: u32 = 96;
     fn char_offset(c: char) -> u32 {
         let fallback = '?' as u32 - ' ' as u32;
@@ -241,7 +244,7 @@ mod tests {
         );
 
         let mut display = Display::default();
-        display.draw(Font6x12::render_str("\0\n").stroke(Some(1)));
+        display.draw(Font6x12::render_str("\0\x1b").stroke(Some(1)));
         assert_eq!(display, two_question_marks);
 
         let mut display = Display::default();
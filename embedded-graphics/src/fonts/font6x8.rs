@@ -1,27 +1,26 @@
-use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::font_builder::{FontBuilder, FontBuilderConf, SparseGlyphMap};
 
+/// Configuration of the [`Font6x8`](./type.Font6x8.html) font, used to get its
+/// [glyphs](./font_builder/struct.Glyph.html)
 #[derive(Debug, Copy, Clone)]
 pub enum Font6x8Conf {}
 impl FontBuilderConf for Font6x8Conf {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font6x8_1bpp.raw");
     const CHAR_HEIGHT: u32 = 8;
     const CHAR_WIDTH: u32 = 6;
+    const CHAR_BASELINE: u32 = 7;
     const FONT_IMAGE_WIDTH: u32 = 240;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPHS.index(c)
     }
 }
 
+/// Printable ASCII followed by the printable part of Latin-1
+const GLYPHS: SparseGlyphMap = SparseGlyphMap::new(
+    &[(' ', '~', 0), ('¡', 'ÿ', '¡' as u32 - ' ' as u32 - 34)],
+    '?' as u32 - ' ' as u32,
+);
+
 /// 6x8 pixel monospace font
 ///
 /// There is also the [`text_6x8`] macro to provide an easier to use interface.
@@ -92,8 +91,11 @@ mod tests {
     use super::*;
     use crate::coord::Coord;
     use crate::drawable::Dimensions;
-    use crate::fonts::Font;
+    use crate::drawable::Pixel;
+    use crate::fonts::font_builder::Glyph;
+    use crate::fonts::{Font, LineHeight, TabStops, TextDirection, TextRotation};
     use crate::mock_display::Display;
+    use crate::primitives::Rectangle;
     use crate::style::Style;
     use crate::style::WithStyle;
     use crate::transform::Transform;
@@ -129,6 +131,404 @@ mod tests {
         assert_eq!(empty.size(), UnsignedCoord::new(0, 0));
     }
 
+    #[test]
+    fn multiline_dimensions() {
+        let text: Font6x8<u8> = Font6x8::render_str("Hello\nWorld!\n");
+        let wrapped: Font6x8<u8> = Font6x8::render_str("Hello World!").max_width(Some(40));
+
+        assert_eq!(text.size(), UnsignedCoord::new(36, 16));
+        assert_eq!(wrapped.size(), UnsignedCoord::new(36, 16));
+    }
+
+    #[test]
+    fn wrapped_lines() {
+        let mut wrapped = Display::default();
+        wrapped.draw(
+            Font6x8::render_str("ab cd ef")
+                .max_width(Some(18))
+                .stroke(Some(1u8)),
+        );
+
+        let mut expected = Display::default();
+        expected.draw(Font6x8::render_str("ab").stroke(Some(1u8)));
+        expected.draw(
+            Font6x8::render_str("cd")
+                .stroke(Some(1u8))
+                .translate(Coord::new(0, 8)),
+        );
+
+        // "ef" doesn't fit on the display
+        assert_eq!(wrapped, expected);
+    }
+
+    #[test]
+    fn truncated_text() {
+        let text = Font6x8::render_str("Hello world")
+            .truncate(Some(24))
+            .stroke(Some(1u8));
+
+        let mut truncated = Display::default();
+        truncated.draw(text);
+
+        let mut expected = Display::default();
+        expected.draw(Font6x8::render_str("H...").stroke(Some(1u8)));
+
+        assert_eq!(truncated, expected);
+        assert_eq!(text.rendered_chars(), 1);
+    }
+
+    #[test]
+    fn rotated_text() {
+        let text = Font6x8::render_str("Ag").stroke(Some(1u8)).fill(Some(2u8));
+
+        let mut plain = Display::default();
+        plain.draw(text);
+
+        for &(rotation, size) in &[
+            (TextRotation::Deg0, (12, 8)),
+            (TextRotation::Deg90, (8, 12)),
+            (TextRotation::Deg180, (12, 8)),
+            (TextRotation::Deg270, (8, 12)),
+        ] {
+            let rotated = text.rotation(rotation).translate(Coord::new(2, 1));
+            assert_eq!(rotated.size(), UnsignedCoord::new(size.0, size.1));
+
+            let mut display = Display::default();
+            display.draw(rotated);
+
+            for y in 0..8 {
+                for x in 0..12 {
+                    let (rx, ry) = match rotation {
+                        TextRotation::Deg0 => (x, y),
+                        TextRotation::Deg90 => (7 - y, x),
+                        TextRotation::Deg180 => (11 - x, 7 - y),
+                        TextRotation::Deg270 => (y, 11 - x),
+                    };
+
+                    assert_eq!(
+                        display.0[ry + 1][rx + 2],
+                        plain.0[y][x],
+                        "{:?} ({}, {})",
+                        rotation,
+                        x,
+                        y
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn vertical_text() {
+        let text = Font6x8::render_str("ab\ncd")
+            .vertical(true)
+            .stroke(Some(1u8))
+            .translate(Coord::new(3, 0));
+        assert_eq!(text.size(), UnsignedCoord::new(12, 16));
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        let mut expected = Display::default();
+        for &(c, x, y) in &[("a", 3, 0), ("b", 3, 8), ("c", 9, 0), ("d", 9, 8)] {
+            expected.draw(
+                Font6x8::render_str(c)
+                    .stroke(Some(1u8))
+                    .translate(Coord::new(x, y)),
+            );
+        }
+
+        assert_eq!(display, expected);
+
+        // Wrapping limits the height of vertical text
+        let wrapped: Font6x8<u8> = Font6x8::render_str("abc")
+            .vertical(true)
+            .max_width(Some(16));
+        assert_eq!(wrapped.size(), UnsignedCoord::new(12, 16));
+    }
+
+    #[test]
+    fn tab_stops() {
+        let text = Font6x8::render_str("a\tb\nab\tc")
+            .tab_stops(Some(TabStops::Pixels(20)))
+            .stroke(Some(1u8));
+        assert_eq!(text.size(), UnsignedCoord::new(24, 16));
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // 20 pixels is rounded down to 3 characters
+        let mut expected = Display::default();
+        expected.draw(Font6x8::render_str("a  b\nab c").stroke(Some(1u8)));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn padding_and_border() {
+        let chip = Font6x8::render_str("Ag")
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .padding(1)
+            .border(Some(3u8))
+            .translate(Coord::new(1, 1));
+        assert_eq!(chip.size(), UnsignedCoord::new(16, 12));
+
+        // Every pixel of the background is drawn once
+        assert_eq!(chip.into_iter().count(), 16 * 12);
+
+        let mut display = Display::default();
+        display.draw(chip);
+
+        let mut expected = Display::default();
+        expected.draw(
+            Rectangle::new(Coord::new(1, 1), Coord::new(16, 12))
+                .stroke(Some(3u8))
+                .fill(Some(2u8)),
+        );
+        expected.draw(
+            Font6x8::render_str("Ag")
+                .stroke(Some(1u8))
+                .translate(Coord::new(3, 3)),
+        );
+
+        assert_eq!(display, expected);
+
+        // Without a fill, only the border is drawn around the text
+        let outline = Font6x8::render_str("A")
+            .stroke(Some(1u8))
+            .padding(2)
+            .border(Some(3u8));
+        let mut display = Display::default();
+        display.draw(outline);
+
+        assert_eq!(display.0[0][..12], [3; 12]);
+        assert_eq!(display.0[1][..3], [3, 0, 0]);
+        assert_eq!(display.0[13][11], 3);
+        assert_eq!(display.0[14][0], 0);
+    }
+
+    #[test]
+    fn underline_and_strikethrough() {
+        let text = Font6x8::render_str("a b")
+            .stroke(Some(1u8))
+            .underline(Some(2u8))
+            .strikethrough(Some(3u8));
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        // 1 pixel thick lines across the whole text, on the bottom row and through the middle
+        assert_eq!(display.0[7][..18], [2; 18]);
+        assert_eq!(display.0[3][..18], [3; 18]);
+        assert_eq!(display.0[7][18], 0);
+        assert!(display.0[2..7]
+            .iter()
+            .all(|row| row[..18].iter().all(|&c| c != 2)));
+
+        let moved = text.underline_position(5, 2).strikethrough(None);
+        let mut display = Display::default();
+        display.draw(moved);
+
+        assert_eq!(display.0[5][..18], [2; 18]);
+        assert_eq!(display.0[6][..18], [2; 18]);
+        assert!(display.0[7][..18].iter().all(|&c| c != 2));
+        assert!(display.0[3][..18].iter().all(|&c| c != 3));
+    }
+
+    #[test]
+    fn glyph_pixels() {
+        let glyph = Glyph::<Font6x8Conf>::new('A');
+        assert_eq!(glyph.size(), UnsignedCoord::new(6, 8));
+        assert_eq!(glyph.coverage(6, 0), 0);
+
+        let mut display = Display::default();
+        display.draw(glyph.pixels().map(|(p, coverage)| Pixel(p, coverage)));
+
+        let mut expected = Display::default();
+        expected.draw(Font6x8::render_str("A").stroke(Some(255u8)));
+
+        assert_eq!(display, expected);
+        assert!(glyph.is_set(0, 2));
+        assert_eq!(Glyph::<Font6x8Conf>::new('\u{1}'), Glyph::new('?'));
+    }
+
+    #[test]
+    fn outlined_text() {
+        let text = Font6x8::render_str("Ag\nj").stroke(Some(1u8));
+        let outlined = text.outline(Some(2u8)).translate(Coord::new(2, -1));
+        assert_eq!(outlined.size(), UnsignedCoord::new(14, 18));
+
+        let mut plain = Display::default();
+        plain.draw(text.translate(Coord::new(3, 0)));
+
+        let mut display = Display::default();
+        display.draw(outlined);
+
+        // Each pixel is drawn once
+        let mut counts = Display::default();
+        for Pixel(p, _) in outlined.into_iter().filter(|Pixel(p, _)| p[1] < 16) {
+            counts.0[p[1] as usize][p[0] as usize] += 1;
+        }
+        assert!(counts.0.iter().flatten().all(|&count| count <= 1));
+
+        for y in 0..16 {
+            for x in 0..24 {
+                let near_text = (-1..=1).any(|dy: i32| {
+                    (-1..=1).any(|dx: i32| {
+                        let (x, y) = (x as i32 + dx, y as i32 + dy);
+
+                        x >= 0 && y >= 0 && x < 24 && y < 16 && plain.0[y as usize][x as usize] == 1
+                    })
+                });
+                let expected = match plain.0[y][x] {
+                    1 => 1,
+                    _ if near_text => 2,
+                    _ => 0,
+                };
+
+                assert_eq!(display.0[y][x], expected, "({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_text() {
+        let text = Font6x8::render_str("Ag").stroke(Some(1u8)).fill(Some(2u8));
+        let scaled = text.scale(2).translate(Coord::new(-3, 0));
+        assert_eq!(scaled.size(), UnsignedCoord::new(24, 16));
+
+        let mut plain = Display::default();
+        plain.draw(text);
+
+        let mut display = Display::default();
+        display.draw(scaled);
+
+        for y in 0..16 {
+            for x in 0..21 {
+                assert_eq!(
+                    display.0[y][x],
+                    plain.0[y / 2][(x + 3) / 2],
+                    "({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+
+        // Wrapping widths are in display pixels
+        let wrapped: Font6x8<u8> = Font6x8::render_str("ab cd").scale(2).max_width(Some(30));
+        assert_eq!(wrapped.size(), UnsignedCoord::new(24, 32));
+    }
+
+    #[test]
+    fn glyph_indices() {
+        assert_eq!(Font6x8Conf::char_offset(' '), 0);
+        assert_eq!(Font6x8Conf::char_offset('~'), 94);
+        assert_eq!(Font6x8Conf::char_offset('¡'), 95);
+        assert_eq!(Font6x8Conf::char_offset('ÿ'), 189);
+
+        for &c in ['\n', '\u{7f}', '\u{a0}', 'Ā', '\u{2192}'].iter() {
+            assert_eq!(Font6x8Conf::char_offset(c), Font6x8Conf::char_offset('?'));
+        }
+    }
+
+    #[test]
+    fn metrics() {
+        let style: Font6x8<u8> = Font6x8::render_str("")
+            .max_width(Some(96))
+            .scale(2)
+            .padding(1)
+            .translate(Coord::new(4, 5));
+
+        let metrics = style.measure_string("abc def ghi\njklm");
+        assert_eq!(metrics.lines, 3);
+        assert_eq!(metrics.advance, 4 * 12);
+        assert_eq!(metrics.top_left, Coord::new(4, 5));
+        assert_eq!(metrics.size, UnsignedCoord::new(7 * 12 + 2, 3 * 16 + 2));
+        assert_eq!(metrics.ascent, 14);
+        assert_eq!(metrics.descent, 2);
+        assert_eq!(metrics.line_height, 16);
+
+        // Lines are measured along the text when it's stacked vertically
+        let metrics = Font6x8::<u8>::render_str("ab\nc").vertical(true).metrics();
+        assert_eq!((metrics.lines, metrics.advance), (2, 8));
+        assert_eq!(metrics.line_height, 6);
+        assert_eq!(metrics.size, UnsignedCoord::new(12, 16));
+
+        let metrics = style.measure_string("");
+        assert_eq!((metrics.lines, metrics.advance), (0, 0));
+    }
+
+    #[test]
+    fn right_to_left() {
+        let text = Font6x8::render_str("ab\ncde")
+            .stroke(Some(1u8))
+            .direction(TextDirection::RightToLeft);
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        let mut expected = Display::default();
+        expected.draw(
+            Font6x8::render_str("ab")
+                .stroke(Some(1u8))
+                .translate(Coord::new(6, 0)),
+        );
+        expected.draw(
+            Font6x8::render_str("cde")
+                .stroke(Some(1u8))
+                .translate(Coord::new(0, 8)),
+        );
+
+        assert_eq!(display, expected);
+        assert_eq!(text.metrics().advance, 18);
+    }
+
+    #[test]
+    fn line_and_paragraph_spacing() {
+        let text = Font6x8::render_str("ab c\nd")
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .max_width(Some(12))
+            .line_height(LineHeight::Pixels(6))
+            .paragraph_spacing(3);
+
+        // Wrapped lines are 6 pixels apart, and the line after the newline 3 pixels further
+        assert_eq!(text.size(), UnsignedCoord::new(12, 23));
+        assert_eq!(text.metrics().line_height, 6);
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        let mut expected = Display::default();
+        for &(line, y) in [("ab", 0), ("c", 6), ("d", 15)].iter() {
+            expected.draw(
+                Font6x8::render_str(line)
+                    .stroke(Some(1u8))
+                    .fill(Some(2u8))
+                    .translate(Coord::new(0, y)),
+            );
+        }
+        assert_eq!(display, expected);
+
+        // Only lines that fit completely are drawn
+        let spread: Font6x8<u8> = Font6x8::render_str("a\nb\nc")
+            .line_height(LineHeight::Percent(200))
+            .wrap_in(&Rectangle::new(Coord::new(0, 0), Coord::new(11, 23)));
+        assert_eq!(spread.size(), UnsignedCoord::new(6, 24));
+        assert_eq!(spread.rendered_chars(), 2);
+    }
+
+    #[test]
+    fn wrap_in_area() {
+        let area = Rectangle::new(Coord::new(2, 1), Coord::new(21, 16));
+        let text: Font6x8<u8> = Font6x8::render_str("one two three four").wrap_in(&area);
+
+        assert_eq!(text.top_left(), Coord::new(2, 1));
+        assert_eq!(text.size(), UnsignedCoord::new(18, 16));
+    }
+
     #[test]
     fn text_corners() {
         let hello: Font6x8<u8> = Font6x8::render_str("Hello World!").translate(Coord::new(5, -20));
@@ -378,7 +778,7 @@ mod tests {
         );
 
         let mut display = Display::default();
-        display.draw(Font6x8::render_str("\0\n").stroke(Some(1)));
+        display.draw(Font6x8::render_str("\0\x1b").stroke(Some(1)));
         assert_eq!(display, two_question_marks);
 
         let mut display = Display::default();
@@ -1,27 +1,26 @@
-use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::font_builder::{FontBuilder, FontBuilderConf, SparseGlyphMap};
 
+/// Configuration of the [`Font8x16`](./type.Font8x16.html) font, used to get its
+/// [glyphs](./font_builder/struct.Glyph.html)
 #[derive(Debug, Copy, Clone)]
 pub enum Font8x16Conf {}
 impl FontBuilderConf for Font8x16Conf {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font8x16_1bpp.raw");
     const CHAR_HEIGHT: u32 = 16;
     const CHAR_WIDTH: u32 = 8;
+    const CHAR_BASELINE: u32 = 12;
     const FONT_IMAGE_WIDTH: u32 = 240;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPHS.index(c)
     }
 }
 
+/// Printable ASCII followed by the printable part of Latin-1
+const GLYPHS: SparseGlyphMap = SparseGlyphMap::new(
+    &[(' ', '~', 0), ('¡', 'ÿ', '¡' as u32 - ' ' as u32 - 34)],
+    '?' as u32 - ' ' as u32,
+);
+
 /// 8x16 pixel monospace font
 ///
 /// There is also the [`text_8x16`] macro to provide an easier to use interface.
@@ -268,7 +267,7 @@ mod tests {
         );
 
         let mut display = Display::default();
-        display.draw(Font8x16::render_str("\0\n").stroke(Some(1)));
+        display.draw(Font8x16::render_str("\0\x1b").stroke(Some(1)));
         assert_eq!(display, two_question_marks);
 
         let mut display = Display::default();
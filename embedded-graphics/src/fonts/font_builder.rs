@@ -7,10 +7,13 @@ use crate::coord::ToUnsigned;
 use crate::drawable::Dimensions;
 use crate::drawable::Drawable;
 use crate::drawable::Pixel;
-use crate::fonts::Font;
+use crate::fonts::layout::TextLayout;
+use crate::fonts::{Font, LineHeight, TabStops, TextDirection, TextMetrics, TextRotation};
 use crate::pixelcolor::PixelColor;
-use crate::style::Style;
+use crate::primitives::edges::FillArea;
+use crate::primitives::Rectangle;
 use crate::style::WithStyle;
+use crate::style::{DrawMode, Style, Styled};
 use crate::transform::Transform;
 use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 use core::marker::PhantomData;
@@ -24,12 +27,279 @@ pub trait FontBuilderConf {
 
     /// `char` width of the font
     const CHAR_WIDTH: u32;
-    /// Font image width, must be divisible by `8` and `CHAR_WIDTH`.
+    /// Distance from the top of a `char` down to the baseline the characters sit on, used for
+    /// [text metrics](./struct.FontBuilder.html#method.metrics). Defaults to the bottom of the
+    /// `char`.
+    const CHAR_BASELINE: u32 = Self::CHAR_HEIGHT;
+    /// Font image width, must be divisible by `CHAR_WIDTH`. Each row of the image must fill a
+    /// whole number of bytes.
     const FONT_IMAGE_WIDTH: u32 = 240;
+    /// Number of bits per pixel in the font image
+    ///
+    /// `1` is a plain bitmap. `2` and `4` store antialiased glyphs, where each pixel is how much
+    /// of it the glyph covers, from `0` (none) up to all bits set (fully covered). Antialiased
+    /// glyphs are blended into the background when drawn
+    /// [`antialiased`](../../primitives/smooth/trait.Antialias.html#tymethod.antialiased), and
+    /// drawn with pixels that are at least half covered otherwise.
+    const FONT_IMAGE_BPP: u32 = 1;
     /// Returns the index in the font of the correponding `char`
+    ///
+    /// Fonts that only cover scattered characters can look them up in a
+    /// [`SparseGlyphMap`](./struct.SparseGlyphMap.html).
     fn char_offset(_: char) -> u32;
 }
 
+/// Table of the characters a font has glyphs for, for fonts that cover scattered code points
+///
+/// The table is a list of ranges of characters with consecutive glyphs in the font image, sorted
+/// by their first character. Each range is given as its first character, its last character and
+/// the index of the glyph of the first character. A font of arrows, box drawing characters and
+/// icons only needs one entry for each run of characters it has, no matter how far apart they
+/// are, and characters are found with a binary search.
+///
+/// ```rust
+/// use embedded_graphics::fonts::font_builder::{FontBuilderConf, SparseGlyphMap};
+///
+/// const GLYPHS: SparseGlyphMap = SparseGlyphMap::new(
+///     &[
+///         (' ', '9', 0),
+///         ('\u{2190}', '\u{2193}', 26), // Arrows
+///         ('\u{2500}', '\u{2503}', 30), // Box drawing
+///         ('\u{2713}', '\u{2713}', 34), // Check mark
+///     ],
+///     31, // Draw `?` for anything else
+/// );
+///
+/// pub enum SymbolFontConf {}
+/// impl FontBuilderConf for SymbolFontConf {
+///     const FONT_IMAGE: &'static [u8] = &[0; 8 * 35];
+///     const CHAR_HEIGHT: u32 = 8;
+///     const CHAR_WIDTH: u32 = 8;
+///     const FONT_IMAGE_WIDTH: u32 = 8;
+///     fn char_offset(c: char) -> u32 {
+///         GLYPHS.index(c)
+///     }
+/// }
+///
+/// assert_eq!(SymbolFontConf::char_offset('1'), 17);
+/// assert_eq!(SymbolFontConf::char_offset('\u{2192}'), 28);
+/// assert_eq!(SymbolFontConf::char_offset('\u{2713}'), 34);
+/// assert_eq!(SymbolFontConf::char_offset('A'), GLYPHS.fallback());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SparseGlyphMap {
+    /// First character, last character and glyph index of the first character of each range
+    ranges: &'static [(char, char, u32)],
+
+    /// Glyph index used for characters that aren't in any range
+    fallback: u32,
+}
+
+impl SparseGlyphMap {
+    /// Create a map from ranges of characters, sorted by their first character, and the glyph
+    /// index of the character drawn for characters that aren't in the map
+    ///
+    /// Ranges must not overlap. Characters in ranges that are out of order may not be found.
+    pub const fn new(ranges: &'static [(char, char, u32)], fallback: u32) -> Self {
+        Self { ranges, fallback }
+    }
+
+    /// Glyph index of a character, or the fallback index if it isn't in the map
+    pub fn index(&self, c: char) -> u32 {
+        self.get(c).unwrap_or(self.fallback)
+    }
+
+    /// Glyph index of a character, if it's in the map
+    pub fn get(&self, c: char) -> Option<u32> {
+        // Index of the first range that starts after `c`
+        let after = self.ranges.partition_point(|&(first, _, _)| first <= c);
+        let &(first, last, start) = self.ranges.get(after.checked_sub(1)?)?;
+
+        if c <= last {
+            Some(start + (c as u32 - first as u32))
+        } else {
+            None
+        }
+    }
+
+    /// Glyph index used for characters that aren't in the map
+    pub fn fallback(&self) -> u32 {
+        self.fallback
+    }
+}
+
+/// Bitmap of a single character of a font
+///
+/// Glyphs give access to the pixels of a character without drawing any text, to build effects
+/// like outlines or textured text from. Characters the font doesn't have give the same glyph as
+/// the font draws for them, usually `?`.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::font_builder::Glyph;
+/// use embedded_graphics::fonts::Font6x8Conf;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Draw a `+` with a copy of it shifted one pixel in each direction behind it as an outline
+/// let glyph = Glyph::<Font6x8Conf>::new('+');
+/// let offsets = [(0, 1), (2, 1), (1, 0), (1, 2)];
+///
+/// for &(dx, dy) in offsets.iter() {
+///     display.draw(glyph.pixels().map(|(p, _)| {
+///         Pixel(p + UnsignedCoord::new(dx, dy), 2u8)
+///     }));
+/// }
+/// display.draw(glyph.pixels().map(|(p, _)| Pixel(p + UnsignedCoord::new(1, 1), 1u8)));
+/// ```
+#[derive(Debug)]
+pub struct Glyph<Conf> {
+    /// Index of the character in the font image
+    char_offset: u32,
+    _conf: PhantomData<Conf>,
+}
+
+impl<Conf> Copy for Glyph<Conf> {}
+impl<Conf> Clone for Glyph<Conf> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf> PartialEq for Glyph<Conf> {
+    fn eq(&self, other: &Self) -> bool {
+        self.char_offset == other.char_offset
+    }
+}
+impl<Conf> Eq for Glyph<Conf> {}
+
+impl<Conf> Glyph<Conf>
+where
+    Conf: FontBuilderConf,
+{
+    /// Get the glyph the font draws for `c`
+    pub fn new(c: char) -> Self {
+        Self {
+            char_offset: Conf::char_offset(c),
+            _conf: PhantomData,
+        }
+    }
+
+    /// Get the glyph at `index` in the font image, counting along each row of glyphs in turn
+    ///
+    /// This is mostly useful for fonts of symbols that aren't looked up by character, like an
+    /// [`IconFont`](../trait.IconFont.html).
+    pub fn from_index(index: u32) -> Self {
+        Self {
+            char_offset: index,
+            _conf: PhantomData,
+        }
+    }
+
+    /// Width and height of the glyph in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(Conf::CHAR_WIDTH, Conf::CHAR_HEIGHT)
+    }
+
+    /// How much of the pixel at (`x`, `y`) from the top left corner the glyph covers, from `0`
+    /// to `255`
+    ///
+    /// Fonts with [1 bit per pixel](./trait.FontBuilderConf.html#associatedconstant.FONT_IMAGE_BPP)
+    /// only give `0` or `255`. Pixels outside the glyph give `0`.
+    pub fn coverage(&self, x: u32, y: u32) -> u8 {
+        if x >= Conf::CHAR_WIDTH || y >= Conf::CHAR_HEIGHT {
+            return 0;
+        }
+
+        let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
+        let max_value = (1 << Conf::FONT_IMAGE_BPP) - 1;
+
+        // Char _code_ offset from first char, most often a space
+        // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
+        let char_offset = self.char_offset;
+        let row = char_offset / char_per_row;
+
+        // Top left corner of character, in pixels
+        let char_x = (char_offset - (row * char_per_row)) * Conf::CHAR_WIDTH;
+        let char_y = row * Conf::CHAR_HEIGHT;
+
+        // Pixel index
+        // = X pixel offset for char
+        // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
+        // + X offset for the pixel block that comprises this char
+        // + Y offset for pixel block
+        let bitmap_pixel_index =
+            char_x + (Conf::FONT_IMAGE_WIDTH * char_y) + x + (y * Conf::FONT_IMAGE_WIDTH);
+
+        // Pixels are packed from the most significant bit of each byte
+        let bitmap_bit_index = bitmap_pixel_index * Conf::FONT_IMAGE_BPP;
+        let bitmap_byte = bitmap_bit_index / 8;
+        let bitmap_shift = 8 - Conf::FONT_IMAGE_BPP - (bitmap_bit_index % 8);
+
+        let value = u32::from(Conf::FONT_IMAGE[bitmap_byte as usize] >> bitmap_shift) & max_value;
+
+        (value * 255 / max_value) as u8
+    }
+
+    /// Returns `true` if the pixel at (`x`, `y`) from the top left corner is drawn in the stroke
+    /// color when the glyph is drawn without antialiasing
+    pub fn is_set(&self, x: u32, y: u32) -> bool {
+        self.coverage(x, y) >= 128
+    }
+
+    /// Iterate over the pixels the glyph covers at all, and how much it covers each one
+    ///
+    /// Pixels are returned row by row from the top left corner of the glyph.
+    pub fn pixels(&self) -> GlyphPixels<Conf> {
+        GlyphPixels {
+            glyph: *self,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+/// Iterator over the pixels of a [`Glyph`](./struct.Glyph.html)
+#[derive(Debug)]
+pub struct GlyphPixels<Conf> {
+    glyph: Glyph<Conf>,
+    x: u32,
+    y: u32,
+}
+
+impl<Conf> Copy for GlyphPixels<Conf> {}
+impl<Conf> Clone for GlyphPixels<Conf> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf> Iterator for GlyphPixels<Conf>
+where
+    Conf: FontBuilderConf,
+{
+    type Item = (UnsignedCoord, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.y < Conf::CHAR_HEIGHT {
+            let (x, y) = (self.x, self.y);
+
+            self.x += 1;
+            if self.x >= Conf::CHAR_WIDTH {
+                self.x = 0;
+                self.y += 1;
+            }
+
+            let coverage = self.glyph.coverage(x, y);
+            if coverage > 0 {
+                return Some((UnsignedCoord::new(x, y), coverage));
+            }
+        }
+
+        None
+    }
+}
+
 /// The font builder
 ///
 /// This is a helper struct to reduce code duplication when implementing fonts. View the [module
@@ -45,6 +315,55 @@ pub struct FontBuilder<'a, C: PixelColor, Conf> {
     /// Style of the font
     style: Style<C>,
 
+    /// Width in pixels to wrap lines of text to
+    max_width: Option<u32>,
+
+    /// Height in pixels to cut the text off at
+    max_height: Option<u32>,
+
+    /// Width in pixels to truncate lines of text to
+    truncate_width: Option<u32>,
+
+    /// Direction the text is drawn in
+    rotation: TextRotation,
+
+    /// Stack characters downwards instead of across
+    vertical: bool,
+
+    /// Base direction of the text
+    direction: TextDirection,
+
+    /// Distance between lines, and the extra space after each paragraph in pixels of the font
+    line_height: LineHeight,
+    paragraph_spacing: u32,
+
+    /// Distance between tab stops
+    tab_stops: Option<TabStops>,
+
+    /// Space in pixels between the text and the edge of its background
+    padding: u32,
+
+    /// Color of the border around the background
+    border: Option<C>,
+
+    /// Color of the line under the text
+    underline: Option<C>,
+
+    /// Offset from the top of a character and thickness of the underline, in pixels
+    underline_position: Option<(u32, u32)>,
+
+    /// Color of the line through the text
+    strikethrough: Option<C>,
+
+    /// Offset from the top of a character and thickness of the strikethrough, in pixels
+    strikethrough_position: Option<(u32, u32)>,
+
+    /// Color of the outline around each character
+    outline: Option<C>,
+
+    /// Width and height of the block of pixels each pixel of the font is drawn as
+    scale: u32,
+
     _conf: PhantomData<Conf>,
 }
 
@@ -55,6 +374,23 @@ impl<'a, C: PixelColor + Clone, Conf> Clone for FontBuilder<'a, C, Conf> {
             pos: self.pos,
             text: self.text,
             style: self.style.clone(),
+            max_width: self.max_width,
+            max_height: self.max_height,
+            truncate_width: self.truncate_width,
+            rotation: self.rotation,
+            vertical: self.vertical,
+            direction: self.direction,
+            line_height: self.line_height,
+            paragraph_spacing: self.paragraph_spacing,
+            tab_stops: self.tab_stops,
+            padding: self.padding,
+            border: self.border,
+            underline: self.underline,
+            underline_position: self.underline_position,
+            strikethrough: self.strikethrough,
+            strikethrough_position: self.strikethrough_position,
+            outline: self.outline,
+            scale: self.scale,
             _conf: Default::default(),
         }
     }
@@ -75,14 +411,517 @@ where
 
     /// Get the bounding box of a piece of text
     ///
-    /// Currently does not handle newlines (but neither does the rasteriser). It will give `(0, 0)`
-    /// if the string to render is empty.
+    /// Takes newlines, [word wrapping](#method.max_width), [rotation](#method.rotation),
+    /// [padding](#method.padding) and [borders](#method.border) into account. It will give
+    /// `(0, 0)` if the string to render is empty and there is no padding or border.
     fn size(&self) -> UnsignedCoord {
-        // TODO: Handle height of text with newlines in it
-        let width = Conf::CHAR_WIDTH * self.text.len() as u32;
-        let height = if width > 0 { Conf::CHAR_HEIGHT } else { 0 };
+        let (width, height) = self.rotation.size(self.unrotated_size());
+        let inset = 2 * self.inset();
 
-        UnsignedCoord::new(width, height)
+        UnsignedCoord::new(width * self.scale + inset, height * self.scale + inset)
+    }
+}
+
+impl<'a, C, Conf> FontBuilder<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    /// Wrap lines of text that are wider than `width` pixels
+    ///
+    /// Lines are broken at the whitespace in front of the first word that doesn't fit, and the
+    /// whitespace at the break isn't drawn. Words wider than `width` are broken at the last
+    /// character that fits. Lines also always end at a `\n`, with or without a maximum width.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    ///
+    /// let text: Font6x8<u8> = Font6x8::render_str("Hello wide world").max_width(Some(60));
+    ///
+    /// // "Hello wide" on the first line, "world" on the second
+    /// assert_eq!(text.size(), UnsignedCoord::new(60, 16));
+    /// ```
+    pub fn max_width(mut self, width: Option<u32>) -> Self {
+        self.max_width = width;
+
+        self
+    }
+
+    /// Wrap text to fit inside `area`, leaving out any lines that don't fit below it
+    ///
+    /// The text is moved to the top left corner of `area`.
+    pub fn wrap_in(mut self, area: &Rectangle<C>) -> Self {
+        let size = area.size();
+
+        self.pos = area.top_left();
+        self.max_width = Some(size[0] + 1);
+        self.max_height = Some(size[1] + 1);
+
+        self
+    }
+
+    /// Cut short lines of text that are wider than `width` pixels, ending them with an ellipsis
+    ///
+    /// The fonts don't have a `…` character, so the ellipsis is drawn as three full stops. Lines
+    /// are truncated before they are [wrapped](#method.max_width).
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    ///
+    /// let text: Font6x8<u8> = Font6x8::render_str("Battery: charging").truncate(Some(60));
+    ///
+    /// // Draws "Battery..."
+    /// assert_eq!(text.size(), UnsignedCoord::new(60, 8));
+    /// assert_eq!(text.rendered_chars(), 7);
+    /// ```
+    pub fn truncate(mut self, width: Option<u32>) -> Self {
+        self.truncate_width = width;
+
+        self
+    }
+
+    /// Number of characters of the text that are drawn
+    ///
+    /// Characters cut off by [truncation](#method.truncate) or by the bottom of the
+    /// [wrapping area](#method.wrap_in), and whitespace dropped at line breaks, aren't counted.
+    /// Neither is the ellipsis at the end of a truncated line.
+    pub fn rendered_chars(&self) -> usize {
+        self.layout().rendered_chars()
+    }
+
+    /// Draw the text rotated clockwise in 90 degree steps
+    ///
+    /// The top left corner of the rotated text stays at the position of the text. Widths given to
+    /// [`max_width`](#method.max_width) and [`truncate`](#method.truncate) are measured along the
+    /// lines of text, so they limit the height of text rotated by 90 or 270 degrees.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font6x8, TextRotation};
+    ///
+    /// // A y axis label, reading from bottom to top
+    /// let label: Font6x8<u8> = Font6x8::render_str("Volts").rotation(TextRotation::Deg270);
+    ///
+    /// assert_eq!(label.size(), UnsignedCoord::new(8, 30));
+    /// ```
+    pub fn rotation(mut self, rotation: TextRotation) -> Self {
+        self.rotation = rotation;
+
+        self
+    }
+
+    /// Stack the characters of each line of text downwards, with each line in a column to the
+    /// right of the one before it
+    ///
+    /// The characters stay upright, unlike [rotated](#method.rotation) text. Widths given to
+    /// [`max_width`](#method.max_width) and [`truncate`](#method.truncate) are measured along the
+    /// lines, so they limit the height of vertical text. The same goes for the width and height of
+    /// the area given to [`wrap_in`](#method.wrap_in).
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    ///
+    /// // A side label, one letter per row
+    /// let label: Font6x8<u8> = Font6x8::render_str("RPM").vertical(true);
+    ///
+    /// assert_eq!(label.size(), UnsignedCoord::new(6, 24));
+    /// ```
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+
+        self
+    }
+
+    /// Set the base direction of the text
+    ///
+    /// Right to left text starts at the right edge of the text and its lines are lined up on the
+    /// right. Characters from right to left scripts are drawn in the right order whatever the base
+    /// direction is, as long as the font has glyphs for them. See
+    /// [`TextDirection`](../enum.TextDirection.html) for what is supported.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font6x8, TextDirection};
+    ///
+    /// // A font with Hebrew glyphs draws this as "שלום 42", with the 4 on the left
+    /// let text: Font6x8<u8> =
+    ///     Font6x8::render_str("42 שלום").direction(TextDirection::RightToLeft);
+    ///
+    /// assert_eq!(text.size(), UnsignedCoord::new(42, 8));
+    /// ```
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+
+        self
+    }
+
+    /// Set the distance from the top of one line of text to the top of the next
+    ///
+    /// Lines can be spread out to make a block of text easier to read, or pulled closer together
+    /// to fit more lines in, without switching to another font. Lines closer together than the
+    /// height of the font overlap, with each line drawn over the bottom of the line above it.
+    /// Line heights are measured across the lines of [vertical](#method.vertical) text. Outlined
+    /// text always uses the height of the font.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font6x8, LineHeight};
+    ///
+    /// let text: Font6x8<u8> = Font6x8::render_str("Line one\nLine two\nLine three")
+    ///     .line_height(LineHeight::Percent(150));
+    ///
+    /// // Lines start 12 pixels apart, and the last one is 8 pixels high
+    /// assert_eq!(text.size(), UnsignedCoord::new(60, 32));
+    /// ```
+    pub fn line_height(mut self, line_height: LineHeight) -> Self {
+        self.line_height = line_height;
+
+        self
+    }
+
+    /// Add `spacing` pixels of the font after each `\n`, on top of the
+    /// [line height](#method.line_height)
+    ///
+    /// Lines that are [wrapped](#method.max_width) aren't spaced out, so paragraphs stand apart
+    /// from each other.
+    pub fn paragraph_spacing(mut self, spacing: u32) -> Self {
+        self.paragraph_spacing = spacing;
+
+        self
+    }
+
+    /// Move characters after a tab to the next tab stop
+    ///
+    /// Tabs are drawn as spaces up to the next tab stop. Without tab stops, tabs are drawn like
+    /// any other character the font doesn't have.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font6x8, TabStops};
+    ///
+    /// let table: Font6x8<u8> =
+    ///     Font6x8::render_str("CPU\t45C\nBattery\t80%").tab_stops(Some(TabStops::Columns(8)));
+    ///
+    /// // Both values start 8 characters in
+    /// assert_eq!(table.size(), UnsignedCoord::new(6 * 11, 16));
+    /// ```
+    pub fn tab_stops(mut self, tab_stops: Option<TabStops>) -> Self {
+        self.tab_stops = tab_stops;
+
+        self
+    }
+
+    /// Add `padding` pixels of space around each side of the text
+    ///
+    /// The padding is filled with the fill color, if there is one, so the text can be drawn as a
+    /// label on a solid background in one go. The text is moved right and down by the padding,
+    /// keeping the top left corner of the background at the position of the text.
+    ///
+    /// The background behind the characters is still only drawn in each character's cell, so
+    /// lines of text that are shorter than the longest one leave a gap at their end.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// let chip = Font6x8::render_str("ON")
+    ///     .stroke(Some(1u8))
+    ///     .fill(Some(2u8))
+    ///     .padding(2)
+    ///     .border(Some(3u8));
+    ///
+    /// // 2 pixels of padding and a 1 pixel border on each side
+    /// assert_eq!(chip.size(), UnsignedCoord::new(18, 14));
+    /// display.draw(chip);
+    /// ```
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// Draw a 1 pixel wide border in `color` around the outside of the padding
+    pub fn border(mut self, color: Option<C>) -> Self {
+        self.border = color;
+
+        self
+    }
+
+    /// Draw a line in `color` under the text
+    ///
+    /// The underline is drawn across every character of each line of text, including spaces.
+    /// It's drawn over the bottom rows of each character, and is thicker in taller fonts. Use
+    /// [`underline_position`](#method.underline_position) to move it.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font12x16;
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// let link = Font12x16::render_str("Help")
+    ///     .stroke(Some(1u8))
+    ///     .underline(Some(2u8))
+    ///     .underline_position(14, 2);
+    ///
+    /// display.draw(link);
+    /// ```
+    pub fn underline(mut self, color: Option<C>) -> Self {
+        self.underline = color;
+
+        self
+    }
+
+    /// Draw the underline `offset` pixels down from the top of each character, `thickness`
+    /// pixels thick
+    pub fn underline_position(mut self, offset: u32, thickness: u32) -> Self {
+        self.underline_position = Some((offset, thickness));
+
+        self
+    }
+
+    /// Draw a line in `color` through the middle of the text
+    ///
+    /// Like the [underline](#method.underline), it's thicker in taller fonts. Use
+    /// [`strikethrough_position`](#method.strikethrough_position) to move it.
+    pub fn strikethrough(mut self, color: Option<C>) -> Self {
+        self.strikethrough = color;
+
+        self
+    }
+
+    /// Draw the strikethrough `offset` pixels down from the top of each character, `thickness`
+    /// pixels thick
+    pub fn strikethrough_position(mut self, offset: u32, thickness: u32) -> Self {
+        self.strikethrough_position = Some((offset, thickness));
+
+        self
+    }
+
+    /// Draw a 1 pixel wide outline in `color` around the characters
+    ///
+    /// The outline covers every pixel next to a character, including diagonally, that the
+    /// character doesn't draw in the stroke color. It makes text readable over busy backgrounds,
+    /// like images. The outline reaches 1 pixel outside the character cells, so outlined text is
+    /// 2 pixels wider and taller, and the characters are moved 1 pixel right and down.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// let caption = Font6x8::render_str("Lake")
+    ///     .stroke(Some(255u8))
+    ///     .outline(Some(0u8));
+    ///
+    /// assert_eq!(caption.size(), UnsignedCoord::new(26, 10));
+    /// display.draw(caption);
+    /// ```
+    pub fn outline(mut self, color: Option<C>) -> Self {
+        self.outline = color;
+
+        self
+    }
+
+    /// Draw each pixel of the font as a `scale` by `scale` block of pixels
+    ///
+    /// Scaling lets one font be used for both small captions and large readouts. Everything
+    /// measured in pixels of the font is scaled with it, including [outlines](#method.outline)
+    /// and the [underline position](#method.underline_position), but not
+    /// [padding](#method.padding) or [borders](#method.border). Widths given to
+    /// [`max_width`](#method.max_width) and [`truncate`](#method.truncate) are in pixels of the
+    /// display. A scale of `0` is treated as `1`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    ///
+    /// let readout: Font6x8<u8> = Font6x8::render_str("42").scale(3);
+    ///
+    /// assert_eq!(readout.size(), UnsignedCoord::new(36, 24));
+    /// ```
+    pub fn scale(mut self, scale: u32) -> Self {
+        self.scale = scale.max(1);
+
+        self
+    }
+
+    /// Default thickness of underlines and strikethroughs, 1 pixel for every 8 pixels of height
+    fn line_thickness() -> u32 {
+        (Conf::CHAR_HEIGHT / 8).max(1)
+    }
+
+    /// Color, offset and thickness of each line drawn over the text, strikethrough first
+    fn text_lines(&self) -> [Option<TextLine<C>>; 2] {
+        let thickness = Self::line_thickness();
+        let strikethrough = self
+            .strikethrough_position
+            .unwrap_or((Conf::CHAR_HEIGHT.saturating_sub(thickness) / 2, thickness));
+        let underline = self
+            .underline_position
+            .unwrap_or((Conf::CHAR_HEIGHT.saturating_sub(thickness), thickness));
+
+        [
+            self.strikethrough.map(|color| (color, strikethrough)),
+            self.underline.map(|color| (color, underline)),
+        ]
+    }
+
+    /// Distance in pixels from the edge of the background to the text
+    fn inset(&self) -> u32 {
+        self.padding + self.border.map_or(0, |_| 1)
+    }
+
+    /// Measure the text without drawing it
+    ///
+    /// The metrics take every setting of the text into account, including
+    /// [wrapping](#method.max_width), [scaling](#method.scale) and [padding](#method.padding),
+    /// so space can be reserved for text before it's drawn.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::Font6x8;
+    ///
+    /// let text: Font6x8<u8> = Font6x8::render_str("Hello world").max_width(Some(36));
+    /// let metrics = text.metrics();
+    ///
+    /// assert_eq!(metrics.lines, 2);
+    /// assert_eq!(metrics.advance, 30);
+    /// assert_eq!(metrics.size, UnsignedCoord::new(30, 16));
+    /// assert_eq!((metrics.ascent, metrics.descent, metrics.line_height), (7, 1, 8));
+    /// ```
+    pub fn metrics(&self) -> TextMetrics {
+        // Size of a character along the lines of text
+        let along = if self.vertical {
+            Conf::CHAR_HEIGHT
+        } else {
+            Conf::CHAR_WIDTH
+        };
+
+        let (lines, last_columns) =
+            self.layout()
+                .vertical(false)
+                .fold((0, 0), |(lines, columns), (_, _, line)| {
+                    if line + 1 > lines {
+                        (line + 1, 1)
+                    } else {
+                        (lines, columns + 1)
+                    }
+                });
+
+        TextMetrics {
+            advance: last_columns * along * self.scale,
+            top_left: self.top_left(),
+            size: self.size(),
+            ascent: Conf::CHAR_BASELINE * self.scale,
+            descent: (Conf::CHAR_HEIGHT - Conf::CHAR_BASELINE) * self.scale,
+            line_height: self.line_spacing().pitch * self.scale,
+            lines,
+        }
+    }
+
+    /// Measure another string as if it was drawn with the same font and settings as this text
+    ///
+    /// This is the same as the [`metrics`](#method.metrics) of this text with its string
+    /// replaced by `text`, so one text can be set up as a style and used to measure many strings.
+    pub fn measure_string(&self, text: &'a str) -> TextMetrics {
+        Self { text, ..*self }.metrics()
+    }
+
+    /// Width and height in pixels of the text before it's rotated
+    fn unrotated_size(&self) -> (u32, u32) {
+        let spacing = self.line_spacing();
+        let (along, across) = if self.vertical {
+            (Conf::CHAR_HEIGHT, Conf::CHAR_WIDTH)
+        } else {
+            (Conf::CHAR_WIDTH, Conf::CHAR_HEIGHT)
+        };
+
+        // Number of characters in the longest line, and the distance to the end of the last line
+        let mut layout = self.layout();
+        let (mut columns, mut lines_end) = (0, 0);
+        while let Some((_, column, line)) = layout.next() {
+            let (column, line) = if self.vertical {
+                (line, column)
+            } else {
+                (column, line)
+            };
+
+            columns = columns.max(column + 1);
+            lines_end = lines_end.max(spacing.offset(line, layout.newlines()) + across);
+        }
+
+        let outline = if self.outline.is_some() && columns > 0 {
+            2
+        } else {
+            0
+        };
+        let (width, height) = if self.vertical {
+            (lines_end, along * columns)
+        } else {
+            (along * columns, lines_end)
+        };
+
+        (width + outline, height + outline)
+    }
+
+    /// Distance between lines and paragraphs
+    fn line_spacing(&self) -> LineSpacing {
+        let across = if self.vertical {
+            Conf::CHAR_WIDTH
+        } else {
+            Conf::CHAR_HEIGHT
+        };
+
+        let (pitch, paragraph) = match (self.outline, self.line_height) {
+            (Some(_), _) => (across, 0),
+            (None, LineHeight::Percent(percent)) => {
+                (across * percent / 100, self.paragraph_spacing)
+            }
+            (None, LineHeight::Pixels(pixels)) => (pixels, self.paragraph_spacing),
+        };
+
+        LineSpacing {
+            pitch: pitch.max(1),
+            paragraph,
+            vertical: self.vertical,
+        }
+    }
+
+    /// Position of each character in columns and rows
+    fn layout(&self) -> TextLayout<'a> {
+        // Size of a character along and across the lines of text
+        let (along, across) = if self.vertical {
+            (Conf::CHAR_HEIGHT, Conf::CHAR_WIDTH)
+        } else {
+            (Conf::CHAR_WIDTH, Conf::CHAR_HEIGHT)
+        };
+
+        let (along, across) = (along * self.scale, across * self.scale);
+        let pitch = self.line_spacing().pitch * self.scale;
+
+        TextLayout::new(
+            self.text,
+            self.max_width.map(|width| width / along),
+            self.max_height.map(|height| {
+                height
+                    .checked_sub(across)
+                    .map_or(0, |rest| rest / pitch + 1)
+            }),
+            self.truncate_width.map(|width| width / along),
+        )
+        .tab_columns(self.tab_stops.map(|tab_stops| match tab_stops {
+            TabStops::Columns(columns) => columns,
+            TabStops::Pixels(pixels) => pixels / along,
+        }))
+        .vertical(self.vertical)
+        .direction(self.direction)
     }
 }
 
@@ -96,6 +935,23 @@ where
             pos: Coord::new(0, 0),
             text,
             style: Style::default(),
+            max_width: None,
+            max_height: None,
+            truncate_width: None,
+            rotation: TextRotation::default(),
+            vertical: false,
+            direction: TextDirection::LeftToRight,
+            line_height: LineHeight::default(),
+            paragraph_spacing: 0,
+            tab_stops: None,
+            padding: 0,
+            border: None,
+            underline: None,
+            underline_position: None,
+            strikethrough: None,
+            strikethrough_position: None,
+            outline: None,
+            scale: 1,
             _conf: Default::default(),
         }
     }
@@ -123,6 +979,12 @@ where
         self
     }
 
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
     fn fill(mut self, color: Option<C>) -> Self {
         self.style.fill_color = color;
 
@@ -130,6 +992,117 @@ where
     }
 }
 
+impl<'a, C, Conf> Styled<C> for FontBuilder<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+/// Cell of the character grid being drawn in outlined text, and the glyphs around it
+#[derive(Debug)]
+struct OutlineCells<Conf> {
+    /// Column and row of the cell, starting from the cells above and left of the text
+    cell: (i32, i32),
+
+    /// Column and row of the cell below and right of the last character
+    last_cell: (i32, i32),
+
+    /// Glyphs of the cell and the cells next to it, row by row
+    glyphs: [[Option<Glyph<Conf>>; 3]; 3],
+}
+
+impl<Conf> Copy for OutlineCells<Conf> {}
+impl<Conf> Clone for OutlineCells<Conf> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Conf> OutlineCells<Conf>
+where
+    Conf: FontBuilderConf,
+{
+    /// Move to the next cell next to at least one character, wrapping to the next row at the end
+    /// of each one
+    fn next_cell(&mut self, layout: TextLayout<'_>) {
+        loop {
+            let (column, row) = &mut self.cell;
+
+            *column += 1;
+            if *column > self.last_cell.0 {
+                *column = -1;
+                *row += 1;
+            }
+            if *row > self.last_cell.1 {
+                return;
+            }
+
+            if self.load(layout) {
+                return;
+            }
+        }
+    }
+
+    /// Find the glyphs in and around the current cell, returning `true` if there are any
+    fn load(&mut self, layout: TextLayout<'_>) -> bool {
+        let (column, row) = self.cell;
+        self.glyphs = [[None; 3]; 3];
+
+        let mut found = false;
+        for (c, char_column, char_row) in layout {
+            let (dx, dy) = (char_column as i32 - column, char_row as i32 - row);
+
+            if dx.abs() <= 1 && dy.abs() <= 1 {
+                self.glyphs[(dy + 1) as usize][(dx + 1) as usize] = Some(Glyph::new(c));
+                found = true;
+            }
+        }
+
+        found
+    }
+
+    /// Returns `true` if the pixel at (`x`, `y`) from the top left corner of the cell is set,
+    /// for pixels in the cell or up to 1 pixel outside it
+    fn is_set(&self, x: i32, y: i32) -> bool {
+        let (width, height) = (Conf::CHAR_WIDTH as i32, Conf::CHAR_HEIGHT as i32);
+        let (dx, dy) = ((x + width) / width - 1, (y + height) / height - 1);
+
+        self.glyphs[(dy + 1) as usize][(dx + 1) as usize]
+            .is_some_and(|glyph| glyph.is_set((x - dx * width) as u32, (y - dy * height) as u32))
+    }
+}
+
+/// Position of a pixel of text before it's scaled and moved into place, how much of it the glyph
+/// covers, and the color of any line drawn over it
+type GlyphPixel<C> = ((u32, u32), u8, Option<C>);
+
+/// Distance between lines of text, in pixels of the font
+#[derive(Debug, Clone, Copy)]
+struct LineSpacing {
+    /// Distance from the start of one line to the start of the next
+    pitch: u32,
+
+    /// Extra distance after each newline
+    paragraph: u32,
+
+    /// `true` if lines are stacked across instead of downwards
+    vertical: bool,
+}
+
+impl LineSpacing {
+    /// Distance from the start of the text to the start of line number `line`, after `newlines`
+    /// newlines
+    fn offset(self, line: u32, newlines: u32) -> u32 {
+        line * self.pitch + newlines * self.paragraph
+    }
+}
+
+/// Color, offset from the top of a character and thickness of an underline or strikethrough
+type TextLine<C> = (C, (u32, u32));
+
 /// Pixel iterator for the `FontBuilder` object
 #[derive(Debug, Clone, Copy)]
 pub struct FontBuilderIterator<'a, C, Conf>
@@ -138,10 +1111,43 @@ where
 {
     char_walk_x: u32,
     char_walk_y: u32,
-    current_char: Option<char>,
-    idx: usize,
+    current_char: Option<(char, u32, u32)>,
+    layout: TextLayout<'a>,
+
+    /// Distance between lines, and from the start of the text to the line of the current char
+    spacing: LineSpacing,
+    line_offset: u32,
     pos: Coord,
-    text: &'a str,
+    rotation: TextRotation,
+
+    /// Width and height of the text before it's rotated or scaled
+    size: (u32, u32),
+
+    /// Width and height of the block of pixels each pixel of the glyphs is drawn as
+    scale: u32,
+
+    /// Pixel of the glyphs being drawn as a block, and the position in the block
+    block: Option<GlyphPixel<C>>,
+    block_walk: u32,
+
+    /// Underline and strikethrough
+    lines: [Option<TextLine<C>>; 2],
+
+    /// Outline color, and the state of drawing outlined text
+    ///
+    /// Outlined text is drawn cell by cell across the grid of characters and the cells around
+    /// it, instead of character by character.
+    outline: Option<C>,
+    outline_cells: OutlineCells<Conf>,
+
+    /// Padding and border around the text
+    frame: FillArea,
+    border: Option<C>,
+
+    /// Top left and bottom right corners of the background and the text inside it, inclusive
+    outer: (Coord, Coord),
+    inner: (Coord, Coord),
+
     style: Style<C>,
     _conf: PhantomData<Conf>,
 }
@@ -155,20 +1161,11 @@ where
     type IntoIter = FontBuilderIterator<'a, C, Conf>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            pos: self.pos,
-            style: self.style,
-            _conf: Default::default(),
-        }
+        (&self).into_iter()
     }
 }
 
-impl<'a, C, Conf> IntoIterator for &'a FontBuilder<'a, C, Conf>
+impl<'a, C, Conf> IntoIterator for &FontBuilder<'a, C, Conf>
 where
     C: PixelColor,
     Conf: FontBuilderConf,
@@ -177,96 +1174,261 @@ where
     type Item = Pixel<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
+        let layout = self.layout();
+        let bottom_right = self.bottom_right();
+
+        // Skip text that is entirely off the top or left of the display
+        let offscreen = bottom_right[0] <= 0 || bottom_right[1] <= 0;
+
+        let (columns, rows) = layout.size();
+        let mut outline_cells = OutlineCells {
+            cell: (-1, -1),
+            last_cell: (columns as i32, rows as i32),
+            glyphs: [[None; 3]; 3],
+        };
+
+        if self.outline.is_none() || offscreen {
+            outline_cells.cell = (-1, rows as i32 + 1);
+        } else if !outline_cells.load(layout) {
+            outline_cells.next_cell(layout);
+        }
+
+        let inset = self.inset() as i32;
+        let inner = (
+            self.pos + Coord::new(inset, inset),
+            bottom_right - Coord::new(inset + 1, inset + 1),
+        );
+        let outer = (self.pos, bottom_right - Coord::new(1, 1));
+
+        let mut iter = Self::IntoIter {
+            current_char: None,
+            layout,
+            spacing: self.line_spacing(),
+            line_offset: 0,
             char_walk_x: 0,
             char_walk_y: 0,
-            pos: self.pos,
+            pos: inner.0,
+            rotation: self.rotation,
+            size: self.unrotated_size(),
+            scale: self.scale,
+            block: None,
+            block_walk: 0,
+            lines: self.text_lines(),
+            outline: self.outline,
+            outline_cells,
+            frame: FillArea::new(Some((self.pos, bottom_right)).filter(|_| inset > 0)),
+            border: self.border,
+            outer,
+            inner,
             style: self.style,
             _conf: Default::default(),
+        };
+        if !offscreen && self.outline.is_none() {
+            iter.next_char();
         }
+
+        iter
     }
 }
 
-impl<'a, C, Conf> Iterator for FontBuilderIterator<'a, C, Conf>
+impl<'a, C, Conf> FontBuilderIterator<'a, C, Conf>
 where
     C: PixelColor,
     Conf: FontBuilderConf,
 {
-    type Item = Pixel<C>;
+    /// Move on to the next character of the layout
+    fn next_char(&mut self) {
+        self.current_char = self.layout.next();
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.pos[0] + (self.text.len() as i32 * Conf::CHAR_WIDTH as i32) <= 0
-            || self.pos[1] + (Conf::CHAR_HEIGHT as i32) <= 0
-        {
-            return None;
+        if let Some((_, column, line)) = self.current_char {
+            let line = if self.spacing.vertical { column } else { line };
+
+            self.line_offset = self.spacing.offset(line, self.layout.newlines());
         }
+    }
 
-        let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
+    /// Next pixel of the padding and border around the text
+    pub(crate) fn next_frame(&mut self) -> Option<Pixel<C>> {
+        let (outer, inner) = (self.outer, self.inner);
+        let (border, fill) = (self.border, self.style.fill_color);
+        let within = |p: Coord, (tl, br): (Coord, Coord)| {
+            p[0] >= tl[0] && p[1] >= tl[1] && p[0] <= br[0] && p[1] <= br[1]
+        };
 
-        let pixel = loop {
-            if let Some(current_char) = self.current_char {
-                // Char _code_ offset from first char, most often a space
-                // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
-                let char_offset = Conf::char_offset(current_char);
-                let row = char_offset / char_per_row;
-
-                // Top left corner of character, in pixels
-                let char_x = (char_offset - (row * char_per_row)) * Conf::CHAR_WIDTH;
-                let char_y = row * Conf::CHAR_HEIGHT;
-
-                // Bit index
-                // = X pixel offset for char
-                // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
-                // + X offset for the pixel block that comprises this char
-                // + Y offset for pixel block
-                let bitmap_bit_index = char_x
-                    + (Conf::FONT_IMAGE_WIDTH * char_y)
-                    + self.char_walk_x
-                    + (self.char_walk_y * Conf::FONT_IMAGE_WIDTH);
-
-                let bitmap_byte = bitmap_bit_index / 8;
-                let bitmap_bit = 7 - (bitmap_bit_index % 8);
-
-                let color = if Conf::FONT_IMAGE[bitmap_byte as usize] & (1 << bitmap_bit) != 0 {
-                    Some(self.style.stroke_color.unwrap_or(1.into())) // white
-                } else {
-                    self.style.fill_color
-                };
-
-                let x = self.pos[0]
-                    + (Conf::CHAR_WIDTH * self.idx as u32) as i32
-                    + self.char_walk_x as i32;
-                let y = self.pos[1] + self.char_walk_y as i32;
-
-                self.char_walk_x += 1;
-
-                if self.char_walk_x >= Conf::CHAR_WIDTH {
-                    self.char_walk_x = 0;
-                    self.char_walk_y += 1;
-
-                    // Done with this char, move on to the next one
-                    if self.char_walk_y >= Conf::CHAR_HEIGHT {
-                        self.char_walk_y = 0;
-                        self.idx += 1;
-                        self.current_char = self.text.chars().skip(self.idx).next();
-                    }
+        self.frame.find_map(|p| {
+            if within(p, inner) {
+                return None;
+            }
+
+            let on_edge = !within(p, (outer.0 + Coord::new(1, 1), outer.1 - Coord::new(1, 1)));
+            let color = match border {
+                Some(border) if on_edge => border,
+                _ => fill?,
+            };
+
+            Some(Pixel(p.to_unsigned(), color))
+        })
+    }
+
+    /// Color of the underline or strikethrough on row `y` of a character, if any
+    fn text_line(&self, y: u32) -> Option<C> {
+        self.lines.iter().find_map(|text_line| {
+            text_line
+                .filter(|&(_, (offset, thickness))| y >= offset && y - offset < thickness)
+                .map(|(color, _)| color)
+        })
+    }
+
+    /// The same as [`next_glyph_pixel`](#method.next_glyph_pixel), for outlined text
+    ///
+    /// Pixels outside character cells are only returned if they are part of the outline.
+    fn next_outlined(&mut self, outline: C) -> Option<GlyphPixel<C>> {
+        loop {
+            let (column, row) = self.outline_cells.cell;
+            if row > self.outline_cells.last_cell.1 {
+                return None;
+            }
+
+            let (walk_x, walk_y) = (self.char_walk_x, self.char_walk_y);
+
+            let cells = &self.outline_cells;
+            let glyph = cells.glyphs[1][1];
+            let (x, y) = (walk_x as i32, walk_y as i32);
+            let is_outline = !cells.is_set(x, y)
+                && (-1..=1).any(|dy| (-1..=1).any(|dx| cells.is_set(x + dx, y + dy)));
+
+            let text_line = glyph.and_then(|_| self.text_line(walk_y));
+            let color = text_line.or_else(|| Some(outline).filter(|_| is_outline));
+
+            self.char_walk_x += 1;
+            if self.char_walk_x >= Conf::CHAR_WIDTH {
+                self.char_walk_x = 0;
+                self.char_walk_y += 1;
+
+                if self.char_walk_y >= Conf::CHAR_HEIGHT {
+                    self.char_walk_y = 0;
+                    self.outline_cells.next_cell(self.layout);
                 }
+            }
 
-                // Skip to next coord if pixel is transparent
-                if let Some(color) = color {
-                    if x >= 0 && y >= 0 {
-                        break Some(Pixel(Coord::new(x, y).to_unsigned(), color));
-                    }
+            if glyph.is_none() && color.is_none() {
+                continue;
+            }
+
+            // Pixels outside the cells are at most 1 pixel away, inside the outline margin
+            let point = self.rotation.apply(
+                (column * Conf::CHAR_WIDTH as i32 + x + 1) as u32,
+                (row * Conf::CHAR_HEIGHT as i32 + y + 1) as u32,
+                self.size,
+            );
+            let coverage = glyph.map_or(0, |glyph| glyph.coverage(walk_x, walk_y));
+
+            break Some((point, coverage, color));
+        }
+    }
+
+    /// Position of the next pixel of the text, how much of it the glyph covers from `0` to
+    /// `255`, and the color of the underline, strikethrough or outline drawn over it, if any
+    ///
+    /// Every pixel of each character cell is returned, except ones off the top or left of the
+    /// display.
+    pub(crate) fn next_coverage(&mut self) -> Option<(UnsignedCoord, u8, Option<C>)> {
+        let scale = self.scale;
+
+        loop {
+            // Each pixel of the glyphs is drawn as a square block of pixels
+            let ((x, y), coverage, text_line) = match self.block {
+                Some(pixel) => pixel,
+                None => {
+                    let pixel = match self.outline {
+                        Some(outline) => self.next_outlined(outline),
+                        None => self.next_glyph_pixel(),
+                    }?;
+                    self.block = Some(pixel);
+                    self.block_walk = 0;
+
+                    pixel
                 }
-            } else {
-                break None;
+            };
+
+            let (block_x, block_y) = (self.block_walk % scale, self.block_walk / scale);
+            self.block_walk += 1;
+            if self.block_walk >= scale * scale {
+                self.block = None;
+            }
+
+            let x = self.pos[0] + (x * scale + block_x) as i32;
+            let y = self.pos[1] + (y * scale + block_y) as i32;
+
+            if x >= 0 && y >= 0 {
+                break Some((Coord::new(x, y).to_unsigned(), coverage, text_line));
             }
+        }
+    }
+
+    /// Position of the next pixel of the glyphs from the top left corner of the text, how much of
+    /// it the glyph covers, and the color of the underline or strikethrough drawn over it, if any
+    fn next_glyph_pixel(&mut self) -> Option<GlyphPixel<C>> {
+        let (current_char, column, line) = self.current_char?;
+        let coverage =
+            Glyph::<Conf>::new(current_char).coverage(self.char_walk_x, self.char_walk_y);
+
+        let text_line = self.text_line(self.char_walk_y);
+
+        let (x, y) = if self.spacing.vertical {
+            (self.line_offset, Conf::CHAR_HEIGHT * line)
+        } else {
+            (Conf::CHAR_WIDTH * column, self.line_offset)
         };
+        let point = self
+            .rotation
+            .apply(x + self.char_walk_x, y + self.char_walk_y, self.size);
+
+        self.char_walk_x += 1;
+
+        if self.char_walk_x >= Conf::CHAR_WIDTH {
+            self.char_walk_x = 0;
+            self.char_walk_y += 1;
+
+            // Done with this char, move on to the next one
+            if self.char_walk_y >= Conf::CHAR_HEIGHT {
+                self.char_walk_y = 0;
+                self.next_char();
+            }
+        }
 
-        pixel
+        Some((point, coverage, text_line))
+    }
+}
+
+impl<'a, C, Conf> Iterator for FontBuilderIterator<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.next_frame() {
+            return Some(pixel);
+        }
+
+        loop {
+            let (point, coverage, text_line) = self.next_coverage()?;
+
+            let color = if text_line.is_some() {
+                text_line
+            } else if coverage >= 128 {
+                Some(self.style.stroke_color.unwrap_or(1.into())) // white
+            } else {
+                self.style.fill_color
+            };
+
+            // Skip to next coord if pixel is transparent
+            if let Some(color) = color {
+                break Some(Pixel(point, color));
+            }
+        }
     }
 }
 
@@ -0,0 +1,368 @@
+//! Symbols drawn from icon fonts
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::fonts::font_builder::{FontBuilderConf, Glyph};
+use crate::pixelcolor::PixelColor;
+use crate::style::{DrawMode, Style, Styled, WithStyle};
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+
+/// Set of icons stored as the glyphs of a bitmap font
+///
+/// Implement this for an enum with a variant for each icon. Each variant is mapped straight to
+/// the index of its glyph in the font image, so icons are never looked up through text and can't
+/// be drawn by accident as part of a string. A whole set of symbols, like battery levels, signal
+/// strengths and connection states, ships as one font image instead of dozens of separate images.
+///
+/// Icons are drawn with [`Icon`](./struct.Icon.html).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::font_builder::FontBuilderConf;
+/// use embedded_graphics::fonts::{Icon, IconFont};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Two 8x8 icons side by side in a 16 pixel wide image
+/// pub enum BatteryConf {}
+/// impl FontBuilderConf for BatteryConf {
+///     #[rustfmt::skip]
+///     const FONT_IMAGE: &'static [u8] = &[
+///         0x00, 0x00,
+///         0xfe, 0xfe,
+///         0x82, 0xfe,
+///         0x83, 0xff,
+///         0x83, 0xff,
+///         0x82, 0xfe,
+///         0xfe, 0xfe,
+///         0x00, 0x00,
+///     ];
+///     const CHAR_HEIGHT: u32 = 8;
+///     const CHAR_WIDTH: u32 = 8;
+///     const FONT_IMAGE_WIDTH: u32 = 16;
+///     fn char_offset(_: char) -> u32 {
+///         0
+///     }
+/// }
+///
+/// #[derive(Debug, Copy, Clone, PartialEq)]
+/// pub enum Battery {
+///     Empty,
+///     Full,
+/// }
+///
+/// impl IconFont for Battery {
+///     type Conf = BatteryConf;
+///
+///     const ICONS: &'static [(&'static str, Self)] =
+///         &[("battery-empty", Battery::Empty), ("battery-full", Battery::Full)];
+///
+///     fn glyph_index(self) -> u32 {
+///         self as u32
+///     }
+/// }
+///
+/// let icon = Icon::new(Battery::Full).stroke(Some(1u8)).translate(Coord::new(4, 4));
+/// assert_eq!(icon.size(), UnsignedCoord::new(8, 8));
+/// display.draw(icon);
+///
+/// // Icons can also be found by name, for example from a configuration file
+/// let icon: Icon<u8, Battery> = Icon::from_name("battery-empty").expect("Unknown icon");
+/// assert_eq!(icon.icon(), Battery::Empty);
+/// ```
+pub trait IconFont: Copy + 'static {
+    /// Configuration of the font the icons are stored in
+    type Conf: FontBuilderConf;
+
+    /// Every icon in the set and its name
+    const ICONS: &'static [(&'static str, Self)];
+
+    /// Index of the glyph of this icon in the font image
+    fn glyph_index(self) -> u32;
+
+    /// Find an icon by its name
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ICONS
+            .iter()
+            .find(|&&(icon_name, _)| icon_name == name)
+            .map(|&(_, icon)| icon)
+    }
+
+    /// Glyph drawn for this icon
+    fn glyph(self) -> Glyph<Self::Conf> {
+        Glyph::from_index(self.glyph_index())
+    }
+}
+
+/// Single icon from an [`IconFont`](./trait.IconFont.html)
+///
+/// Set pixels of the icon are drawn with the stroke color, defaulting to `1` like text, and the
+/// rest of the icon's cell is drawn with the fill color, if any.
+#[derive(Debug, Copy, Clone)]
+pub struct Icon<C, I>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the icon
+    pub pos: Coord,
+
+    icon: I,
+    style: Style<C>,
+}
+
+impl<C, I> Icon<C, I>
+where
+    C: PixelColor,
+    I: IconFont,
+{
+    /// Draw `icon` at the origin
+    pub fn new(icon: I) -> Self {
+        Self {
+            pos: Coord::new(0, 0),
+            icon,
+            style: Style::default(),
+        }
+    }
+
+    /// Draw the icon called `name` at the origin, if there is one
+    pub fn from_name(name: &str) -> Option<Self> {
+        I::from_name(name).map(Self::new)
+    }
+
+    /// Icon that is drawn
+    pub fn icon(&self) -> I {
+        self.icon
+    }
+}
+
+impl<C, I> Dimensions for Icon<C, I>
+where
+    C: PixelColor,
+    I: IconFont,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        self.icon.glyph().size()
+    }
+}
+
+impl<C, I> WithStyle<C> for Icon<C, I>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(self, _width: u8) -> Self {
+        // Noop
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+}
+
+impl<C, I> Styled<C> for Icon<C, I>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C, I> IntoIterator for Icon<C, I>
+where
+    C: PixelColor,
+    I: IconFont,
+{
+    type Item = Pixel<C>;
+    type IntoIter = IconIterator<C, I::Conf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C, I> IntoIterator for &Icon<C, I>
+where
+    C: PixelColor,
+    I: IconFont,
+{
+    type Item = Pixel<C>;
+    type IntoIter = IconIterator<C, I::Conf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IconIterator {
+            glyph: self.icon.glyph(),
+            pos: self.pos,
+            x: 0,
+            y: 0,
+            style: self.style,
+        }
+    }
+}
+
+/// Pixel iterator for the `Icon` object
+#[derive(Debug, Copy, Clone)]
+pub struct IconIterator<C, Conf>
+where
+    C: PixelColor,
+{
+    glyph: Glyph<Conf>,
+    pos: Coord,
+
+    /// Position inside the icon
+    x: u32,
+    y: u32,
+
+    style: Style<C>,
+}
+
+impl<C, Conf> Iterator for IconIterator<C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.y < Conf::CHAR_HEIGHT {
+            let (x, y) = (self.x, self.y);
+
+            self.x += 1;
+            if self.x >= Conf::CHAR_WIDTH {
+                self.x = 0;
+                self.y += 1;
+            }
+
+            let color = if self.glyph.is_set(x, y) {
+                Some(self.style.stroke_color.unwrap_or_else(|| 1.into()))
+            } else {
+                self.style.fill_color
+            };
+            let point = self.pos + Coord::new(x as i32, y as i32);
+
+            if let Some(color) = color {
+                if point[0] >= 0 && point[1] >= 0 {
+                    return Some(Pixel(point.to_unsigned(), color));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<C, I> Drawable for Icon<C, I> where C: PixelColor {}
+
+impl<C, I> Transform for Icon<C, I>
+where
+    C: PixelColor,
+    I: Copy,
+{
+    /// Translate the icon from its current position to a new position by (x, y) pixels, returning
+    /// a new `Icon`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the icon from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font6x8, Font6x8Conf};
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    /// Icons borrowed from the glyphs of the 6x8 font
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    enum Sign {
+        Plus,
+        Minus,
+    }
+
+    impl IconFont for Sign {
+        type Conf = Font6x8Conf;
+
+        const ICONS: &'static [(&'static str, Self)] =
+            &[("plus", Sign::Plus), ("minus", Sign::Minus)];
+
+        fn glyph_index(self) -> u32 {
+            match self {
+                Sign::Plus => Font6x8Conf::char_offset('+'),
+                Sign::Minus => Font6x8Conf::char_offset('-'),
+            }
+        }
+    }
+
+    #[test]
+    fn draws_glyph() {
+        let icon = Icon::new(Sign::Minus)
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .translate(Coord::new(3, -1));
+
+        let mut display = Display::default();
+        display.draw(icon);
+
+        let mut expected = Display::default();
+        expected.draw(
+            Font6x8::render_str("-")
+                .stroke(Some(1u8))
+                .fill(Some(2u8))
+                .translate(Coord::new(3, -1)),
+        );
+
+        assert_eq!(display, expected);
+        assert_eq!(icon.size(), UnsignedCoord::new(6, 8));
+        assert_eq!(icon.bottom_right(), Coord::new(9, 7));
+    }
+
+    #[test]
+    fn named_lookup() {
+        assert_eq!(Sign::from_name("plus"), Some(Sign::Plus));
+        assert_eq!(Sign::from_name("Plus"), None);
+
+        let icon: Icon<u8, Sign> = Icon::from_name("minus").unwrap();
+        assert_eq!(icon.icon(), Sign::Minus);
+        assert!(Icon::<u8, Sign>::from_name("times").is_none());
+    }
+}
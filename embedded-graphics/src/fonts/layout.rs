@@ -0,0 +1,742 @@
+//! Line breaking for monospace text
+
+use crate::fonts::TextDirection;
+use core::iter;
+
+/// Number of full stops drawn at the end of a truncated line
+const ELLIPSIS_LEN: u32 = 3;
+
+/// Characters of a string, with lines longer than `max_columns` cut short and ended with an
+/// ellipsis
+///
+/// If `tab_columns` is set, tabs are replaced by spaces up to the next multiple of that many
+/// columns. Each character is returned with a flag that is `true` for characters that aren't in
+/// the text: the full stops of an ellipsis, and every space of a tab but the first.
+#[derive(Debug, Clone, Copy)]
+struct TruncatedChars<'a> {
+    /// Text left to return
+    text: &'a str,
+    max_columns: u32,
+    tab_columns: Option<u32>,
+    column: u32,
+
+    /// Column the ellipsis starts at on the current line, if it is too long
+    cut_at: Option<u32>,
+
+    /// Number of full stops left to return for the current ellipsis
+    dots: u32,
+
+    /// Number of spaces left to return for the current tab
+    spaces: u32,
+}
+
+impl<'a> TruncatedChars<'a> {
+    fn new(text: &'a str, max_columns: Option<u32>) -> Self {
+        let mut chars = Self {
+            text,
+            max_columns: max_columns.unwrap_or(u32::MAX),
+            tab_columns: None,
+            column: 0,
+            cut_at: None,
+            dots: 0,
+            spaces: 0,
+        };
+        chars.start_line();
+
+        chars
+    }
+
+    fn tab_columns(mut self, columns: Option<u32>) -> Self {
+        self.tab_columns = columns.map(|columns| columns.max(1));
+        self.start_line();
+
+        self
+    }
+
+    /// Column of the tab stop after `column`
+    fn tab_stop(tab_columns: u32, column: usize) -> usize {
+        (column / tab_columns as usize + 1) * tab_columns as usize
+    }
+
+    fn start_line(&mut self) {
+        let tab_columns = self.tab_columns;
+        let length = self
+            .text
+            .chars()
+            .take_while(|&c| c != '\n')
+            .fold(0, |column, c| match tab_columns {
+                Some(tab_columns) if c == '\t' => Self::tab_stop(tab_columns, column),
+                _ => column + 1,
+            });
+
+        self.column = 0;
+        self.cut_at = if length > self.max_columns as usize {
+            Some(self.max_columns.saturating_sub(ELLIPSIS_LEN))
+        } else {
+            None
+        };
+    }
+}
+
+impl Iterator for TruncatedChars<'_> {
+    type Item = (char, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cut_at == Some(self.column) {
+            // Drop the rest of the line, leaving room for the ellipsis
+            self.text = self.text.find('\n').map_or("", |end| &self.text[end..]);
+            self.cut_at = None;
+            self.dots = ELLIPSIS_LEN.min(self.max_columns);
+            self.spaces = 0;
+        }
+
+        if self.dots > 0 {
+            self.dots -= 1;
+            self.column += 1;
+
+            return Some(('.', true));
+        }
+
+        if self.spaces > 0 {
+            self.spaces -= 1;
+            self.column += 1;
+
+            return Some((' ', true));
+        }
+
+        let c = self.text.chars().next()?;
+        self.text = &self.text[c.len_utf8()..];
+
+        if let (Some(tab_columns), '\t') = (self.tab_columns, c) {
+            let stop = Self::tab_stop(tab_columns, self.column as usize) as u32;
+            self.spaces = stop - self.column - 1;
+            self.column += 1;
+
+            return Some((' ', false));
+        }
+
+        if c == '\n' {
+            self.start_line();
+        } else {
+            self.column += 1;
+        }
+
+        Some((c, false))
+    }
+}
+
+/// Iterator over the characters of a string and the column and row each one is drawn at
+///
+/// Rows always end at `\n`. If `max_columns` is set, lines are also broken at the whitespace
+/// in front of a word that would not fit on the current row. The whitespace at the break is
+/// dropped. Words longer than a whole row are broken wherever the row ends. Rows past `max_rows`
+/// are left out.
+///
+/// Lines longer than `truncate_columns` are cut short before they are broken into rows, and end
+/// with an ellipsis of three full stops. Tabs are replaced by spaces up to the next
+/// [tab stop](#method.tab_columns), if there are any, before anything else.
+///
+/// Rows with a [right to left](#method.direction) base direction, or that contain right to left
+/// characters, are reordered into the order they are seen in, and right to left text is lined up
+/// with the right edge of the widest row.
+///
+/// [Vertical](#method.vertical) layouts swap the columns and rows of the result, so each row of
+/// text is stacked downwards in its own column.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextLayout<'a> {
+    /// Characters in the order they are in the text
+    rows: Rows<'a>,
+
+    /// `true` if columns and rows are swapped
+    vertical: bool,
+
+    /// Base direction of each row
+    direction: TextDirection,
+
+    /// Number of the row being laid out
+    row: Option<u32>,
+
+    /// Number of columns in the row being laid out, and whether it has any right to left
+    /// characters
+    row_info: (u32, bool),
+
+    /// Runs of the row being laid out after the current one
+    runs: BidiRuns<'a>,
+
+    /// Direction, start and end of the run containing the last character laid out
+    run: (bool, u32, u32),
+
+    /// Number of columns of the widest row
+    width: Option<u32>,
+}
+
+/// Characters of a string broken into rows, in the order they are in the string
+#[derive(Debug, Clone, Copy)]
+struct Rows<'a> {
+    /// Characters left to lay out
+    chars: TruncatedChars<'a>,
+    max_columns: u32,
+    max_rows: u32,
+    column: u32,
+    row: u32,
+
+    /// Number of characters left in the run of whitespace and word currently being laid out
+    run: usize,
+
+    /// Number of whitespace characters to drop at a line break
+    skip: usize,
+
+    /// Number of newlines passed
+    newlines: u32,
+}
+
+impl<'a> TextLayout<'a> {
+    pub(crate) fn new(
+        text: &'a str,
+        max_columns: Option<u32>,
+        max_rows: Option<u32>,
+        truncate_columns: Option<u32>,
+    ) -> Self {
+        let rows = Rows {
+            chars: TruncatedChars::new(text, truncate_columns),
+            max_columns: max_columns.unwrap_or(u32::MAX),
+            max_rows: max_rows.unwrap_or(u32::MAX),
+            column: 0,
+            row: 0,
+            run: 0,
+            skip: 0,
+            newlines: 0,
+        };
+
+        Self {
+            rows,
+            vertical: false,
+            direction: TextDirection::LeftToRight,
+            row: None,
+            row_info: (0, false),
+            runs: BidiRuns::new(rows, 0, false),
+            run: (false, 0, 0),
+            width: None,
+        }
+    }
+
+    /// Put a tab stop every `columns` columns
+    ///
+    /// Without tab stops, tabs are laid out like any other character.
+    pub(crate) fn tab_columns(mut self, columns: Option<u32>) -> Self {
+        self.rows.chars = self.rows.chars.tab_columns(columns);
+
+        self
+    }
+
+    /// Swap columns and rows if `vertical` is `true`
+    pub(crate) fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+
+        self
+    }
+
+    /// Set the base direction of each row
+    pub(crate) fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+
+        self
+    }
+
+    /// Number of newlines in the text before the last character returned
+    pub(crate) fn newlines(&self) -> u32 {
+        self.rows.newlines
+    }
+
+    /// Number of columns and rows covered by the laid out text
+    pub(crate) fn size(self) -> (u32, u32) {
+        self.fold((0, 0), |(columns, rows), (_, column, row)| {
+            (columns.max(column + 1), rows.max(row + 1))
+        })
+    }
+
+    /// Number of characters of the text that are drawn, not counting any ellipses or the extra
+    /// spaces tabs are replaced with
+    pub(crate) fn rendered_chars(self) -> usize {
+        let mut rows = self.rows;
+
+        iter::from_fn(|| rows.next_char())
+            .filter(|&(_, inserted, _, _)| !inserted)
+            .count()
+    }
+
+    /// Column a character is seen at, from the column it has in the order of the text
+    fn visual_column(&mut self, column: u32, row: u32, start: Rows<'a>) -> u32 {
+        let rtl_base = self.direction == TextDirection::RightToLeft;
+
+        if self.row != Some(row) {
+            let mut rows = start;
+            let row_chars = iter::from_fn(move || rows.next_char())
+                .take_while(|&(_, _, _, r)| r == row)
+                .map(|(c, _, _, _)| c);
+
+            self.row = Some(row);
+            self.row_info = row_chars.fold((0, false), |(columns, rtl), c| {
+                (columns + 1, rtl || is_rtl(c))
+            });
+            self.runs = BidiRuns::new(start, row, rtl_base);
+            self.run = (rtl_base, 0, 0);
+        }
+
+        let (columns, has_rtl) = self.row_info;
+        if !rtl_base && !has_rtl {
+            return column;
+        }
+
+        // Characters are laid out in the order of the text, so the runs of each row only have to
+        // be found once
+        while column >= self.run.2 {
+            match self.runs.next() {
+                Some(run) => self.run = run,
+                None => break,
+            }
+        }
+        let (rtl, run_start, run_end) = self.run;
+
+        match (rtl_base, rtl) {
+            (false, false) => column,
+            (false, true) => run_start + (run_end - 1 - column),
+            (true, true) => self.width.unwrap_or(columns) - 1 - column,
+            (true, false) => self.width.unwrap_or(columns) - run_end + (column - run_start),
+        }
+    }
+}
+
+impl<'a> Rows<'a> {
+    fn new_row(&mut self) {
+        self.row += 1;
+        self.column = 0;
+    }
+
+    /// Next character, whether it's part of an ellipsis or tab instead of the text, and its column
+    /// and row
+    fn next_char(&mut self) -> Option<(char, bool, u32, u32)> {
+        let (c, inserted) = loop {
+            let (c, inserted) = self.chars.next()?;
+
+            if c == '\n' {
+                self.new_row();
+                self.run = 0;
+                self.skip = 0;
+                self.newlines += 1;
+                continue;
+            }
+
+            if self.skip > 0 {
+                self.skip -= 1;
+                continue;
+            }
+
+            if self.run == 0 {
+                let is_space = |c: &char| c.is_whitespace() && *c != '\n';
+                let rest = iter::once(c).chain(self.chars.map(|(c, _)| c));
+                let spaces = rest.clone().take_while(is_space).count();
+                let word = rest.skip(spaces).take_while(|c| !c.is_whitespace()).count();
+                let end = (self.column as usize).saturating_add(spaces + word);
+
+                if end > self.max_columns as usize && (self.column > 0 || word == 0) {
+                    // Trailing whitespace that doesn't fit is dropped without starting a new row
+                    if word > 0 {
+                        self.new_row();
+                    }
+
+                    self.run = word;
+                    if spaces > 0 {
+                        self.skip = spaces - 1;
+                        continue;
+                    }
+                } else {
+                    self.run = spaces + word;
+                }
+            }
+
+            self.run -= 1;
+            break (c, inserted);
+        };
+
+        // Words too long for a whole row are broken where the row ends
+        if self.column > 0 && self.column >= self.max_columns {
+            self.new_row();
+        }
+
+        if self.row >= self.max_rows {
+            return None;
+        }
+
+        let column = self.column;
+        self.column += 1;
+
+        Some((c, inserted, column, self.row))
+    }
+}
+
+impl Iterator for TextLayout<'_> {
+    type Item = (char, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.direction == TextDirection::RightToLeft && self.width.is_none() {
+            let mut rows = self.rows;
+            let width = iter::from_fn(|| rows.next_char())
+                .fold(0, |width, (_, _, column, _)| width.max(column + 1));
+
+            self.width = Some(width);
+        }
+
+        let start = self.rows;
+        let (c, _, column, row) = self.rows.next_char()?;
+        let column = self.visual_column(column, row, start);
+
+        Some(if self.vertical {
+            (c, row, column)
+        } else {
+            (c, column, row)
+        })
+    }
+}
+
+/// Returns `true` for characters of scripts written from right to left, like Hebrew and Arabic
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08ff}' | '\u{fb1d}'..='\u{fdff}' | '\u{fe70}'..='\u{feff}'
+    )
+}
+
+/// Runs of characters of a row drawn in the same direction, given as their direction, start and
+/// end
+///
+/// This is a simplified form of the Unicode bidirectional algorithm. Letters and digits set the
+/// direction of the text around them. Whitespace and punctuation between characters going the
+/// same way go that way too, and take the base direction of the row otherwise. Runs are found one
+/// at a time, reading only as far into the row as needed.
+#[derive(Debug, Clone, Copy)]
+struct BidiRuns<'a> {
+    /// Characters from the next one to read
+    rows: Rows<'a>,
+    row: u32,
+    rtl_base: bool,
+
+    /// Index of the next character to read
+    index: u32,
+
+    /// Direction of the last letter or digit
+    last_strong: bool,
+
+    /// Index of the first character after the last letter or digit
+    neutrals_start: u32,
+
+    /// Range of characters read but not added to a run yet
+    pending: Option<(bool, u32, u32)>,
+
+    /// Run being built
+    run: Option<(bool, u32, u32)>,
+
+    /// `true` once the end of the row is reached
+    ended: bool,
+}
+
+impl<'a> BidiRuns<'a> {
+    /// Runs of `row`, for `rows` starting at its first character
+    fn new(rows: Rows<'a>, row: u32, rtl_base: bool) -> Self {
+        Self {
+            rows,
+            row,
+            rtl_base,
+            index: 0,
+            last_strong: rtl_base,
+            neutrals_start: 0,
+            pending: None,
+            run: None,
+            ended: false,
+        }
+    }
+
+    /// Add a range of characters to the run being built, returning the previous run if the range
+    /// starts a new one
+    fn push(&mut self, (rtl, start, end): (bool, u32, u32)) -> Option<(bool, u32, u32)> {
+        if start == end {
+            return None;
+        }
+
+        match self.run {
+            Some((run_rtl, run_start, run_end)) if run_rtl == rtl && run_end == start => {
+                self.run = Some((rtl, run_start, end));
+
+                None
+            }
+            previous => {
+                self.run = Some((rtl, start, end));
+
+                previous
+            }
+        }
+    }
+}
+
+impl Iterator for BidiRuns<'_> {
+    type Item = (bool, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(range) = self.pending.take() {
+                if let Some(run) = self.push(range) {
+                    return Some(run);
+                }
+            }
+
+            if self.ended {
+                return self.run.take();
+            }
+
+            let row = self.row;
+            let c = match self.rows.next_char().filter(|&(_, _, _, r)| r == row) {
+                Some((c, _, _, _)) => c,
+                None => {
+                    self.ended = true;
+                    self.pending = Some((self.rtl_base, self.neutrals_start, self.index));
+
+                    continue;
+                }
+            };
+
+            let i = self.index;
+            self.index += 1;
+            if !c.is_alphanumeric() {
+                continue;
+            }
+
+            let rtl = is_rtl(c);
+            let neutrals = if self.last_strong == rtl {
+                rtl
+            } else {
+                self.rtl_base
+            };
+            let neutrals_start = self.neutrals_start;
+
+            self.last_strong = rtl;
+            self.neutrals_start = i + 1;
+            self.pending = Some((rtl, i, i + 1));
+
+            if let Some(run) = self.push((neutrals, neutrals_start, i)) {
+                return Some(run);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check the layout of `text` against `expected`, one string per row
+    fn check(text: &str, max_columns: Option<u32>, expected: &[&str]) {
+        check_rows(TextLayout::new(text, max_columns, None, None), expected);
+    }
+
+    fn check_rows(mut layout: TextLayout<'_>, expected: &[&str]) {
+        for (row, line) in expected.iter().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                assert_eq!(
+                    layout.next(),
+                    Some((c, column as u32, row as u32)),
+                    "row {}",
+                    row
+                );
+            }
+        }
+
+        assert_eq!(layout.next(), None);
+    }
+
+    #[test]
+    fn no_wrapping() {
+        check("Hello world", None, &["Hello world"]);
+        check("Hello  world ", Some(20), &["Hello  world "]);
+    }
+
+    #[test]
+    fn newlines() {
+        check("ab\ncd\n\nef", None, &["ab", "cd", "", "ef"]);
+        check("ab\n  cd", Some(4), &["ab", "  cd"]);
+    }
+
+    #[test]
+    fn breaks_between_words() {
+        check("the quick brown fox", Some(10), &["the quick", "brown fox"]);
+        check("the quick brown fox", Some(9), &["the quick", "brown fox"]);
+        check(
+            "the quick brown fox",
+            Some(8),
+            &["the", "quick", "brown", "fox"],
+        );
+        check("a b c", Some(3), &["a b", "c"]);
+        check("a  b   c", Some(3), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn drops_trailing_whitespace_at_break() {
+        check("abc    \ndef", Some(4), &["abc", "def"]);
+        check("abc    ", Some(4), &["abc"]);
+    }
+
+    #[test]
+    fn long_words() {
+        check("abcdefghij", Some(4), &["abcd", "efgh", "ij"]);
+        check("ab cdefghij", Some(4), &["ab", "cdef", "ghij"]);
+        check("abc", Some(0), &["a", "b", "c"]);
+    }
+
+    #[test]
+    fn max_rows() {
+        let layout = TextLayout::new("one two three", Some(5), Some(2), None);
+
+        assert_eq!(layout.size(), (3, 2));
+        assert_eq!(layout.map(|(c, _, _)| c).last(), Some('o'));
+    }
+
+    #[test]
+    fn tab_stops() {
+        check("a\tb", None, &["a\tb"]);
+
+        let layout = |text| TextLayout::new(text, None, None, None).tab_columns(Some(4));
+        check_rows(layout("a\tb\tc"), &["a   b   c"]);
+        check_rows(layout("\tab\tc\ndefg\th"), &["    ab  c", "defg    h"]);
+        assert_eq!(layout("a\t\tb").rendered_chars(), 4);
+
+        check_rows(
+            TextLayout::new("a\tb\tc", Some(6), None, None).tab_columns(Some(4)),
+            &["a   b", "c"],
+        );
+        check_rows(
+            TextLayout::new("ab\tcdef", None, None, Some(7)).tab_columns(Some(4)),
+            &["ab  ..."],
+        );
+    }
+
+    /// Check where the characters of `layout` are seen against `expected`, one string per row
+    fn check_visual(layout: TextLayout<'_>, expected: &[&str]) {
+        let mut grid = [[' '; 16]; 4];
+        for (c, column, row) in layout {
+            grid[row as usize][column as usize] = c;
+        }
+
+        for (row, line) in expected.iter().enumerate() {
+            let seen = grid[row].iter().take(line.chars().count());
+            assert!(seen.copied().eq(line.chars()), "row {}", row);
+        }
+    }
+
+    #[test]
+    fn right_to_left_runs() {
+        let ltr = |text| TextLayout::new(text, None, None, None);
+        let rtl = |text| ltr(text).direction(TextDirection::RightToLeft);
+
+        check_visual(
+            ltr("ab \u{5d0}\u{5d1}\u{5d2} 12"),
+            &["ab \u{5d2}\u{5d1}\u{5d0} 12"],
+        );
+        check_visual(
+            rtl("\u{5d0}\u{5d1} 12 \u{5d2}"),
+            &["\u{5d2} 12 \u{5d1}\u{5d0}"],
+        );
+        check_visual(rtl("abc"), &["abc"]);
+
+        // Right to left rows are lined up on the right
+        check_visual(
+            rtl("\u{5d0}\u{5d1}\n\u{5d2}"),
+            &["\u{5d1}\u{5d0}", " \u{5d2}"],
+        );
+        check_visual(
+            TextLayout::new("ab cde", Some(3), None, None).direction(TextDirection::RightToLeft),
+            &[" ab", "cde"],
+        );
+        assert_eq!(rtl("ab\ncde").size(), (3, 2));
+    }
+
+    #[test]
+    fn bidi_runs() {
+        let runs = |text, rtl_base| {
+            let rows = TextLayout::new(text, None, None, None).rows;
+
+            BidiRuns::new(rows, 0, rtl_base)
+        };
+
+        assert!(runs("ab \u{5d0} \u{5d1} cd\nxy", false).eq([
+            (false, 0, 3),
+            (true, 3, 6),
+            (false, 6, 9)
+        ]
+        .iter()
+        .cloned()));
+        assert!(runs("\u{5d0}, 12", true).eq([(true, 0, 3), (false, 3, 5)].iter().cloned()));
+        assert_eq!(runs("", false).next(), None);
+    }
+
+    #[test]
+    fn vertical() {
+        let layout = TextLayout::new("ab\nc", None, None, None).vertical(true);
+
+        assert!(layout.eq([('a', 0, 0), ('b', 0, 1), ('c', 1, 0)].iter().cloned()));
+        assert_eq!(layout.size(), (2, 2));
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(TextLayout::new("", Some(5), None, None).size(), (0, 0));
+        assert_eq!(TextLayout::new("ab\nc\n", None, None, None).size(), (2, 2));
+        assert_eq!(
+            TextLayout::new("the quick brown fox", Some(8), None, None).size(),
+            (5, 4)
+        );
+    }
+
+    /// Lay out `text` truncated to `columns` and return it as rows of characters
+    fn truncated(text: &str, columns: u32) -> ([[char; 8]; 3], usize) {
+        let mut rows = [[' '; 8]; 3];
+        let layout = TextLayout::new(text, None, None, Some(columns));
+
+        for (c, column, row) in layout {
+            rows[row as usize][column as usize] = c;
+        }
+
+        (rows, layout.rendered_chars())
+    }
+
+    #[test]
+    fn truncation() {
+        let (rows, count) = truncated("Hello world", 8);
+        assert_eq!(rows[0], ['H', 'e', 'l', 'l', 'o', '.', '.', '.']);
+        assert_eq!(count, 5);
+
+        let (rows, count) = truncated("fits\nis too long\nok", 8);
+        assert_eq!(rows[0][..5], ['f', 'i', 't', 's', ' ']);
+        assert_eq!(rows[1], ['i', 's', ' ', 't', 'o', '.', '.', '.']);
+        assert_eq!(rows[2][..3], ['o', 'k', ' ']);
+        assert_eq!(count, 4 + 5 + 2);
+    }
+
+    #[test]
+    fn truncation_edge_cases() {
+        // Exactly fits
+        assert_eq!(truncated("12345678", 8).1, 8);
+
+        let (rows, count) = truncated("abcdef", 2);
+        assert_eq!(rows[0][..3], ['.', '.', ' ']);
+        assert_eq!(count, 0);
+
+        assert_eq!(TextLayout::new("abc", None, None, Some(0)).count(), 0);
+    }
+
+    #[test]
+    fn truncation_before_wrapping() {
+        check_rows(
+            TextLayout::new("one two three four", Some(7), None, Some(12)),
+            &["one two", "t..."],
+        );
+    }
+}
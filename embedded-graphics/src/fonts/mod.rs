@@ -1,18 +1,149 @@
 //! Pixel based fonts
 
+pub mod bdf;
 mod font12x16;
 mod font6x12;
 mod font6x8;
 mod font8x16;
 pub mod font_builder;
+mod icon;
+mod layout;
+mod rich_text;
+mod scrolling_text;
+mod seven_segment;
 
-pub use self::font12x16::Font12x16;
-pub use self::font6x12::Font6x12;
-pub use self::font6x8::Font6x8;
-pub use self::font8x16::Font8x16;
+pub use self::font12x16::{Font12x16, Font12x16Conf};
+pub use self::font6x12::{Font6x12, Font6x12Conf};
+pub use self::font6x8::{Font6x8, Font6x8Conf};
+pub use self::font8x16::{Font8x16, Font8x16Conf};
+pub use self::icon::{Icon, IconFont};
+pub use self::rich_text::RichText;
+pub use self::scrolling_text::ScrollingText;
+pub use self::seven_segment::SevenSegment;
+use crate::coord::Coord;
 use crate::drawable::Dimensions;
 use crate::pixelcolor::PixelColor;
 use crate::style::WithStyle;
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Direction text is drawn in, as a clockwise rotation of ordinary left to right text
+///
+/// Rotated text keeps the top left corner of its bounding box at the position of the text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TextRotation {
+    /// Left to right
+    #[default]
+    Deg0,
+
+    /// Top to bottom, with the tops of the characters facing right
+    Deg90,
+
+    /// Right to left and upside down
+    Deg180,
+
+    /// Bottom to top, with the tops of the characters facing left, like the label of a y axis
+    Deg270,
+}
+
+impl TextRotation {
+    /// Move the pixel at (`x`, `y`) in unrotated text of the given `size` to where it is drawn
+    /// in the rotated text
+    pub(crate) fn apply(self, x: u32, y: u32, (width, height): (u32, u32)) -> (u32, u32) {
+        match self {
+            TextRotation::Deg0 => (x, y),
+            TextRotation::Deg90 => (height - 1 - y, x),
+            TextRotation::Deg180 => (width - 1 - x, height - 1 - y),
+            TextRotation::Deg270 => (y, width - 1 - x),
+        }
+    }
+
+    /// Size of text with the given unrotated `size` once it's rotated
+    pub(crate) fn size(self, (width, height): (u32, u32)) -> (u32, u32) {
+        match self {
+            TextRotation::Deg0 | TextRotation::Deg180 => (width, height),
+            TextRotation::Deg90 | TextRotation::Deg270 => (height, width),
+        }
+    }
+}
+
+/// Base direction of text, set with
+/// [`FontBuilder::direction`](./font_builder/struct.FontBuilder.html#method.direction)
+///
+/// Right to left text, like Hebrew or Arabic, is drawn starting from the right edge of the text,
+/// and lines are lined up on the right. Runs of left to right characters inside right to left text,
+/// like numbers or Latin words, and the other way round, are still drawn in their own direction.
+/// Characters aren't shaped, so scripts with joined letters need a font with the final forms of
+/// each letter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left to right, like English
+    #[default]
+    LeftToRight,
+
+    /// Right to left, like Hebrew or Arabic
+    RightToLeft,
+}
+
+/// Distance from the top of one line of text to the top of the next, set with
+/// [`FontBuilder::line_height`](./font_builder/struct.FontBuilder.html#method.line_height)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineHeight {
+    /// Percentage of the height of the font, where `100` puts lines right under each other
+    Percent(u32),
+
+    /// Number of pixels of the font, before it's
+    /// [scaled](./font_builder/struct.FontBuilder.html#method.scale)
+    Pixels(u32),
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        LineHeight::Percent(100)
+    }
+}
+
+/// Distance between tab stops
+///
+/// Tabs move the next character to the next tab stop. Text with tab stops set can be aligned
+/// into columns, like `"Voltage:\t3.3V"`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TabStops {
+    /// A tab stop every this many characters
+    Columns(u32),
+
+    /// A tab stop every this many pixels, rounded down to a whole number of characters
+    Pixels(u32),
+}
+
+/// Measurements of a block of text, from
+/// [`FontBuilder::metrics`](./font_builder/struct.FontBuilder.html#method.metrics)
+///
+/// Horizontal measurements are along the lines of text and vertical ones across them, before the
+/// text is rotated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextMetrics {
+    /// Distance from the start of the last line to where the next character would be drawn
+    pub advance: u32,
+
+    /// Top left corner of the bounding box of the text
+    pub top_left: Coord,
+
+    /// Size of the bounding box of the text, as given by
+    /// [`Dimensions::size`](../drawable/trait.Dimensions.html#tymethod.size)
+    pub size: UnsignedCoord,
+
+    /// Distance from the top of a line to the baseline the characters sit on
+    pub ascent: u32,
+
+    /// Distance from the baseline to the bottom of a line
+    pub descent: u32,
+
+    /// Distance from the top of one line to the top of the next
+    pub line_height: u32,
+
+    /// Number of lines, after wrapping
+    pub lines: u32,
+}
 
 /// Common methods for all fonts
 pub trait Font<'a, C>: WithStyle<C> + Dimensions
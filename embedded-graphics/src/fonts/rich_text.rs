@@ -0,0 +1,257 @@
+//! Lines of text made of spans with different styles
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::fonts::Font;
+use crate::pixelcolor::PixelColor;
+use crate::style::Style;
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use core::marker::PhantomData;
+use core::slice;
+
+/// A line of text made of spans, each drawn with its own style
+///
+/// Each span is drawn straight after the one before it, so a line that mixes colors doesn't need
+/// the position of every span worked out by hand. All spans are drawn in the same font `F`, so
+/// they share a baseline. Spans shouldn't contain newlines, as the next span carries on from the
+/// right edge of the span before it, not from the end of its last line.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::{Font6x8, RichText};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let spans = [
+///     ("Temp: ", Style::stroke(1u8)),
+///     ("81C", Style {
+///         fill_color: Some(3u8),
+///         ..Style::stroke(2u8)
+///     }),
+/// ];
+/// let text: RichText<u8, Font6x8<u8>> = RichText::new(&spans).translate(Coord::new(0, 4));
+///
+/// assert_eq!(text.size(), UnsignedCoord::new(54, 8));
+/// display.draw(text);
+/// ```
+#[derive(Debug)]
+pub struct RichText<'a, C, F>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the text
+    pub pos: Coord,
+
+    /// Text and style of each span
+    spans: &'a [(&'a str, Style<C>)],
+
+    _font: PhantomData<F>,
+}
+
+impl<'a, C, F> Copy for RichText<'a, C, F> where C: PixelColor {}
+
+impl<'a, C, F> Clone for RichText<'a, C, F>
+where
+    C: PixelColor,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C, F> RichText<'a, C, F>
+where
+    C: PixelColor,
+    F: Font<'a, C>,
+{
+    /// Create a line of text from spans of text and the style to draw each one with
+    pub fn new(spans: &'a [(&'a str, Style<C>)]) -> Self {
+        Self {
+            pos: Coord::new(0, 0),
+            spans,
+            _font: PhantomData,
+        }
+    }
+}
+
+/// Text of a span, drawn in the font `F` at `pen`
+fn span<'a, C, F>(&(text, style): &(&'a str, Style<C>), pen: Coord) -> F
+where
+    C: PixelColor,
+    F: Font<'a, C> + Transform,
+{
+    F::render_str(text).style(style).translate(pen)
+}
+
+impl<'a, C, F> Dimensions for RichText<'a, C, F>
+where
+    C: PixelColor,
+    F: Font<'a, C> + Transform,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    /// Get the bounding box of the text
+    ///
+    /// The width is the total width of the spans, and the height is the height of the tallest
+    /// span.
+    fn size(&self) -> UnsignedCoord {
+        self.spans
+            .iter()
+            .map(|s| span::<C, F>(s, Coord::new(0, 0)).size())
+            .fold(UnsignedCoord::new(0, 0), |total, size| {
+                UnsignedCoord::new(total[0] + size[0], total[1].max(size[1]))
+            })
+    }
+}
+
+impl<'a, C, F> IntoIterator for RichText<'a, C, F>
+where
+    C: PixelColor,
+    F: Font<'a, C> + Transform + IntoIterator<Item = Pixel<C>>,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RichTextIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C, F> IntoIterator for &RichText<'a, C, F>
+where
+    C: PixelColor,
+    F: Font<'a, C> + Transform + IntoIterator<Item = Pixel<C>>,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RichTextIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RichTextIterator {
+            spans: self.spans.iter(),
+            pen: self.pos,
+            pixels: None,
+        }
+    }
+}
+
+/// Pixel iterator for the `RichText` object
+#[derive(Debug)]
+pub struct RichTextIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: IntoIterator,
+{
+    spans: slice::Iter<'a, (&'a str, Style<C>)>,
+
+    /// Top left corner of the next span
+    pen: Coord,
+
+    /// Pixels of the span being drawn
+    pixels: Option<F::IntoIter>,
+}
+
+impl<'a, C, F> Iterator for RichTextIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: Font<'a, C> + Transform + IntoIterator<Item = Pixel<C>>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pixel) = self.pixels.as_mut().and_then(Iterator::next) {
+                return Some(pixel);
+            }
+
+            let text = span::<C, F>(self.spans.next()?, self.pen);
+            self.pen += Coord::new(text.size()[0] as i32, 0);
+            self.pixels = Some(text.into_iter());
+        }
+    }
+}
+
+impl<'a, C, F> Drawable for RichText<'a, C, F> where C: PixelColor {}
+
+impl<'a, C, F> Transform for RichText<'a, C, F>
+where
+    C: PixelColor,
+{
+    /// Translate the text from its current position to a new position by (x, y) pixels, returning
+    /// a new `RichText`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the text from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font6x8, Font8x16};
+    use crate::mock_display::Display;
+    use crate::style::WithStyle;
+    use crate::Drawing;
+
+    #[test]
+    fn spans_follow_each_other() {
+        let spans = [
+            ("ab", Style::stroke(1u8)),
+            ("", Style::stroke(4u8)),
+            (
+                "c",
+                Style {
+                    fill_color: Some(3u8),
+                    ..Style::stroke(2u8)
+                },
+            ),
+        ];
+        let text: RichText<u8, Font6x8<u8>> = RichText::new(&spans).translate(Coord::new(1, 2));
+
+        let mut display = Display::default();
+        display.draw(text);
+
+        let mut expected = Display::default();
+        expected.draw(
+            Font6x8::render_str("ab")
+                .stroke(Some(1u8))
+                .translate(Coord::new(1, 2)),
+        );
+        expected.draw(
+            Font6x8::render_str("c")
+                .stroke(Some(2u8))
+                .fill(Some(3u8))
+                .translate(Coord::new(13, 2)),
+        );
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn dimensions() {
+        let spans = [("abc", Style::default()), ("de", Style::default())];
+        let text: RichText<u8, Font8x16<u8>> = RichText::new(&spans).translate(Coord::new(3, 4));
+
+        assert_eq!(text.size(), UnsignedCoord::new(40, 16));
+        assert_eq!(text.bottom_right(), Coord::new(43, 20));
+
+        let empty: RichText<u8, Font8x16<u8>> = RichText::new(&[]);
+        assert_eq!(empty.size(), UnsignedCoord::new(0, 0));
+        assert_eq!(empty.into_iter().count(), 0);
+    }
+}
@@ -0,0 +1,328 @@
+//! Text that scrolls sideways through a fixed width window
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::fonts::font_builder::{FontBuilder, FontBuilderConf, FontBuilderIterator};
+use crate::pixelcolor::PixelColor;
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+
+/// Text scrolled sideways through a window, like a news ticker
+///
+/// The window starts at the position of the text and is `width` pixels wide. The text repeats
+/// forever, with a gap between the end of one copy and the start of the next that defaults to the
+/// width of the window. Call [`advance`](#method.advance) between frames to scroll it. Glyphs that
+/// are only partly inside the window are clipped at its edges.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::{Font6x8, ScrollingText};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let text = Font6x8::render_str("Breaking news: it's raining")
+///     .stroke(Some(1u8))
+///     .translate(Coord::new(0, 4));
+/// let mut ticker = ScrollingText::new(text, 24);
+///
+/// for _ in 0..3 {
+///     display.draw(ticker);
+///     ticker.advance(1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the window
+    pub pos: Coord,
+
+    /// Text to scroll, positioned at the origin
+    text: FontBuilder<'a, C, Conf>,
+
+    /// Width of the window in pixels
+    width: u32,
+
+    /// Number of pixels between the end of the text and the start of the next copy of it
+    gap: u32,
+
+    /// Number of pixels the text has scrolled to the left
+    offset: u32,
+}
+
+impl<'a, C, Conf> Copy for ScrollingText<'a, C, Conf> where C: PixelColor {}
+
+impl<'a, C, Conf> Clone for ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C, Conf> ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    /// Scroll `text` through a window `width` pixels wide, starting at the position of the text
+    pub fn new(text: FontBuilder<'a, C, Conf>, width: u32) -> Self {
+        Self {
+            pos: text.pos,
+            text: text.translate(-text.pos),
+            width,
+            gap: width,
+            offset: 0,
+        }
+    }
+
+    /// Set the number of pixels between the end of the text and the start of the next copy of it
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self.offset %= self.period();
+
+        self
+    }
+
+    /// Number of pixels the text has scrolled to the left
+    ///
+    /// This goes back to zero once the next copy of the text has scrolled into the place of the
+    /// first one.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Scroll the text to `offset` pixels to the left of the start of the window
+    pub fn set_offset(&mut self, offset: u32) {
+        self.offset = offset % self.period();
+    }
+
+    /// Scroll the text `by` pixels further to the left
+    pub fn advance(&mut self, by: u32) {
+        self.offset = ((u64::from(self.offset) + u64::from(by)) % u64::from(self.period())) as u32;
+    }
+
+    /// Distance in pixels from the start of one copy of the text to the start of the next
+    fn period(&self) -> u32 {
+        (self.text.size()[0] + self.gap).max(1)
+    }
+}
+
+impl<'a, C, Conf> Dimensions for ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.text.size()[1])
+    }
+}
+
+impl<'a, C, Conf> IntoIterator for ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ScrollingTextIterator<'a, C, Conf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C, Conf> IntoIterator for &ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ScrollingTextIterator<'a, C, Conf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ScrollingTextIterator {
+            scrolling: *self,
+            pixels: (&self.text).into_iter(),
+            copy: 0,
+        }
+    }
+}
+
+/// Pixel iterator for the `ScrollingText` object
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollingTextIterator<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    scrolling: ScrollingText<'a, C, Conf>,
+    pixels: FontBuilderIterator<'a, C, Conf>,
+
+    /// Which copy of the text is being drawn, counting from the one the window starts in
+    copy: u32,
+}
+
+impl<'a, C, Conf> ScrollingTextIterator<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    /// Distance of the start of the current copy of the text from the left edge of the window
+    fn copy_x(&self) -> i64 {
+        i64::from(self.copy) * i64::from(self.scrolling.period()) - i64::from(self.scrolling.offset)
+    }
+}
+
+impl<'a, C, Conf> Iterator for ScrollingTextIterator<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = i64::from(self.scrolling.width);
+        let pos = self.scrolling.pos;
+
+        loop {
+            let copy_x = self.copy_x();
+            if copy_x >= width {
+                return None;
+            }
+
+            for Pixel(point, color) in &mut self.pixels {
+                let x = copy_x + i64::from(point[0]);
+                if x < 0 || x >= width {
+                    continue;
+                }
+
+                let x = i64::from(pos[0]) + x;
+                let y = i64::from(pos[1]) + i64::from(point[1]);
+                if x >= 0 && y >= 0 {
+                    return Some(Pixel(Coord::new(x as i32, y as i32).to_unsigned(), color));
+                }
+            }
+
+            self.copy += 1;
+            self.pixels = (&self.scrolling.text).into_iter();
+        }
+    }
+}
+
+impl<'a, C, Conf> Drawable for ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+    Conf: FontBuilderConf,
+{
+}
+
+impl<'a, C, Conf> Transform for ScrollingText<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    /// Move the window by (x, y) pixels, returning a new `ScrollingText`. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Move the window by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Font6x8};
+    use crate::mock_display::Display;
+    use crate::style::WithStyle;
+    use crate::Drawing;
+
+    /// Draw `text` and keep only the pixels from `left` to `right`, inclusive
+    fn cropped(text: Font6x8<'_, u8>, left: usize, right: usize) -> Display {
+        let mut display = Display::default();
+        display.draw(text);
+
+        for row in display.0.iter_mut() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                if x < left || x > right {
+                    *pixel = 0;
+                }
+            }
+        }
+
+        display
+    }
+
+    #[test]
+    fn clips_partial_glyphs() {
+        let text = Font6x8::render_str("ABCDEFGH")
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .translate(Coord::new(2, 3));
+        let mut ticker = ScrollingText::new(text, 15);
+        ticker.advance(4);
+
+        let mut display = Display::default();
+        display.draw(ticker);
+
+        let expected = cropped(text.translate(Coord::new(-4, 0)), 2, 16);
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn next_copy_follows_gap() {
+        let text = Font6x8::render_str("AB").stroke(Some(1u8));
+        let mut ticker = ScrollingText::new(text, 20).gap(2);
+        ticker.set_offset(8);
+
+        let mut display = Display::default();
+        display.draw(ticker);
+
+        // The text is 12 pixels wide, so the next copy starts 14 pixels after the first
+        let mut expected = cropped(text.translate(Coord::new(-8, 0)), 0, 19);
+        expected.draw(text.translate(Coord::new(6, 0)));
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn advance_wraps_around() {
+        let text: Font6x8<'_, u8> = Font6x8::render_str("AB");
+        let mut ticker = ScrollingText::new(text, 10);
+
+        ticker.advance(21);
+        assert_eq!(ticker.offset(), 21);
+
+        ticker.advance(2);
+        assert_eq!(ticker.offset(), 1);
+
+        ticker.set_offset(u32::MAX);
+        ticker.advance(u32::MAX);
+        assert!(ticker.offset() < 22);
+    }
+
+    #[test]
+    fn dimensions() {
+        let text: Font6x8<'_, u8> = Font6x8::render_str("Hello").translate(Coord::new(5, 6));
+        let ticker = ScrollingText::new(text, 20);
+
+        assert_eq!(ticker.top_left(), Coord::new(5, 6));
+        assert_eq!(ticker.size(), UnsignedCoord::new(20, 8));
+    }
+}
@@ -0,0 +1,421 @@
+//! Numbers drawn with seven segment digits
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::edges::FillArea;
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+
+/// Segments lit for each character, with bit 0 for the top segment `a` up to bit 6 for the middle
+/// segment `g`
+fn segments(c: char) -> u8 {
+    match c {
+        '0' | 'O' => 0x3f,
+        '1' => 0x06,
+        '2' => 0x5b,
+        '3' => 0x4f,
+        '4' => 0x66,
+        '5' | 'S' => 0x6d,
+        '6' => 0x7d,
+        '7' => 0x07,
+        '8' => 0x7f,
+        '9' => 0x6f,
+        'A' | 'a' => 0x77,
+        'B' | 'b' => 0x7c,
+        'C' => 0x39,
+        'c' => 0x58,
+        'D' | 'd' => 0x5e,
+        'E' | 'e' => 0x79,
+        'F' | 'f' => 0x71,
+        'H' => 0x76,
+        'L' => 0x38,
+        'P' => 0x73,
+        'U' => 0x3e,
+        '-' => 0x40,
+        '_' => 0x08,
+        _ => 0,
+    }
+}
+
+/// Numbers drawn with seven segment digits, like the display of a clock or a meter
+///
+/// Digits are drawn from lines instead of a bitmap font, so they can be any size without storing
+/// a large font just for digits. Each digit is `digit_size` pixels, with segments
+/// [`segment_width`](#method.segment_width) pixels thick and beveled ends.
+///
+/// The digits `0` to `9`, the hexadecimal letters `A` to `F`, and `-`, `_`, `C`, `H`, `L`, `O`,
+/// `P`, `S` and `U` are drawn with their usual segments. Any other character is drawn as a digit
+/// with no segments lit, so a space keeps the place of a digit. `:` and `.` are drawn in a narrow
+/// cell between digits, as a colon and a decimal point.
+///
+/// Lit segments are drawn in the color given to [`new`](#method.new). Segments that aren't lit
+/// can be drawn in an [`unlit_color`](#method.unlit_color), like the faint segments of a real
+/// display, and the rest of the area can be filled with a [`background`](#method.background).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::SevenSegment;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let clock = SevenSegment::new("12:30", UnsignedCoord::new(4, 7), 1u8)
+///     .segment_width(1)
+///     .unlit_color(Some(2u8));
+///
+/// assert_eq!(clock.size(), UnsignedCoord::new(21, 7));
+/// display.draw(clock);
+///
+/// // Hide the colon every other second to make it blink
+/// display.draw(clock.colon(false));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct SevenSegment<'a, C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the first digit
+    pub pos: Coord,
+
+    /// Characters to draw
+    text: &'a str,
+
+    /// Width and height of each digit
+    digit_size: UnsignedCoord,
+
+    /// Thickness of each segment
+    segment_width: u32,
+
+    /// Space between neighbouring digits
+    spacing: u32,
+
+    /// Color of lit segments
+    color: C,
+
+    /// Color of segments that aren't lit
+    unlit_color: Option<C>,
+
+    /// Color of the area around the segments
+    background: Option<C>,
+
+    /// Whether colons are lit
+    colon: bool,
+}
+
+impl<'a, C> SevenSegment<'a, C>
+where
+    C: PixelColor,
+{
+    /// Draw `text` with digits `digit_size` pixels big, in `color`
+    ///
+    /// Segments default to a fifth of the width of a digit thick, and digits are one segment
+    /// width apart.
+    pub fn new(text: &'a str, digit_size: UnsignedCoord, color: C) -> Self {
+        let segment_width = (digit_size[0] / 5).max(1);
+
+        Self {
+            pos: Coord::new(0, 0),
+            text,
+            digit_size,
+            segment_width,
+            spacing: segment_width,
+            color,
+            unlit_color: None,
+            background: None,
+            colon: true,
+        }
+    }
+
+    /// Set the thickness of each segment in pixels
+    pub fn segment_width(mut self, width: u32) -> Self {
+        self.segment_width = width.max(1);
+
+        self
+    }
+
+    /// Set the space in pixels between neighbouring digits
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+
+        self
+    }
+
+    /// Draw segments that aren't lit in `color`, or leave them out if it's `None`
+    pub fn unlit_color(mut self, color: Option<C>) -> Self {
+        self.unlit_color = color;
+
+        self
+    }
+
+    /// Fill the area around the segments with `color`, or leave it alone if it's `None`
+    pub fn background(mut self, color: Option<C>) -> Self {
+        self.background = color;
+
+        self
+    }
+
+    /// Light colons if `lit` is `true`, or draw them like unlit segments otherwise
+    pub fn colon(mut self, lit: bool) -> Self {
+        self.colon = lit;
+
+        self
+    }
+
+    /// Width of the cell of a character, not counting the spacing after it
+    fn cell_width(&self, c: char) -> u32 {
+        match c {
+            ':' | '.' => self.segment_width,
+            _ => self.digit_size[0],
+        }
+    }
+
+    /// Whether the pixel at (`x`, `y`) from the top left corner of the cell of `c` is part of a
+    /// segment, and if so whether the segment is lit
+    fn segment_at(&self, c: char, x: u32, y: u32) -> Option<bool> {
+        let (width, height) = (self.digit_size[0], self.digit_size[1]);
+        let thickness = self.segment_width;
+
+        match c {
+            ':' => {
+                // Dots a third and two thirds of the way down
+                let dot = |center: u32| {
+                    let top = center.saturating_sub(thickness / 2);
+                    y >= top && y < top + thickness
+                };
+
+                Some(self.colon).filter(|_| dot(height / 3) || dot(height * 2 / 3))
+            }
+            '.' => Some(true).filter(|_| y + thickness >= height),
+            _ => {
+                let lit = segments(c);
+
+                // Work in half pixels, so segments can be centered between pixels
+                let (px, py) = (2 * x as i32 + 1, 2 * y as i32 + 1);
+                let (w, h, t) = (2 * width as i32, 2 * height as i32, 2 * thickness as i32);
+                let half = t / 2;
+                let middle = 2 * ((height.saturating_sub(thickness) / 2) as i32) + half;
+
+                // Centre line of each segment, as (horizontal, across, start, end)
+                let lines = [
+                    (true, half, half, w - half),
+                    (false, w - half, half, middle),
+                    (false, w - half, middle, h - half),
+                    (true, h - half, half, w - half),
+                    (false, half, middle, h - half),
+                    (false, half, half, middle),
+                    (true, middle, half, w - half),
+                ];
+
+                lines
+                    .iter()
+                    .position(|&(horizontal, across, start, end)| {
+                        let (along, offset) = if horizontal { (px, py) } else { (py, px) };
+                        let distance = (offset - across).abs();
+
+                        // Ends are beveled at 45 degrees, with a pixel gap between segments
+                        2 * distance < t + 1
+                            && along - start > distance + 1
+                            && end - along > distance + 1
+                    })
+                    .map(|segment| lit & (1 << segment) != 0)
+            }
+        }
+    }
+
+    /// Color of the pixel at `point`, relative to the top left corner of the text
+    fn color_at(&self, point: UnsignedCoord) -> Option<C> {
+        let mut x = point[0];
+
+        for c in self.text.chars() {
+            let width = self.cell_width(c);
+
+            if x < width {
+                return match self.segment_at(c, x, point[1]) {
+                    Some(true) => Some(self.color),
+                    Some(false) => self.unlit_color.or(self.background),
+                    None => self.background,
+                };
+            }
+
+            x = match x.checked_sub(width + self.spacing) {
+                Some(x) => x,
+                None => return self.background,
+            };
+        }
+
+        None
+    }
+}
+
+impl<C> Dimensions for SevenSegment<'_, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.pos
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    /// Get the bounding box of the digits
+    ///
+    /// It will give `(0, 0)` if the string to render is empty.
+    fn size(&self) -> UnsignedCoord {
+        let mut chars = self.text.chars().peekable();
+        if chars.peek().is_none() {
+            return UnsignedCoord::new(0, 0);
+        }
+
+        let width: u32 = chars.map(|c| self.cell_width(c) + self.spacing).sum();
+
+        UnsignedCoord::new(width - self.spacing, self.digit_size[1])
+    }
+}
+
+impl<'a, C> IntoIterator for SevenSegment<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SevenSegmentIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &SevenSegment<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SevenSegmentIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SevenSegmentIterator {
+            digits: *self,
+            area: FillArea::new(Some((self.top_left(), self.bottom_right()))),
+        }
+    }
+}
+
+/// Pixel iterator for the `SevenSegment` object
+#[derive(Debug, Copy, Clone)]
+pub struct SevenSegmentIterator<'a, C>
+where
+    C: PixelColor,
+{
+    digits: SevenSegment<'a, C>,
+    area: FillArea,
+}
+
+impl<C> Iterator for SevenSegmentIterator<'_, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digits = &self.digits;
+
+        self.area.find_map(|point| {
+            digits
+                .color_at((point - digits.pos).to_unsigned())
+                .map(|color| Pixel(point.to_unsigned(), color))
+        })
+    }
+}
+
+impl<C> Drawable for SevenSegment<'_, C> where C: PixelColor {}
+
+impl<C> Transform for SevenSegment<'_, C>
+where
+    C: PixelColor,
+{
+    /// Translate the digits from their current position to a new position by (x, y) pixels,
+    /// returning a new `SevenSegment`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the digits from their current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    #[test]
+    fn segments_and_colon() {
+        let digits = SevenSegment::new("1:8-", UnsignedCoord::new(4, 7), 1u8)
+            .segment_width(1)
+            .unlit_color(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(digits);
+
+        #[rustfmt::skip]
+        let expected: [[u8; 16]; 7] = [
+            [0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 2, 2, 0],
+            [2, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 2],
+            [2, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 2, 0, 0, 2],
+            [0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
+            [2, 0, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 2, 0, 0, 2],
+            [2, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 2, 0, 0, 2],
+            [0, 2, 2, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 2, 2, 0],
+        ];
+
+        assert_eq!(digits.size(), UnsignedCoord::new(16, 7));
+        for (y, row) in expected.iter().enumerate() {
+            assert_eq!(display.0[y][..16], row[..], "row {}", y);
+        }
+
+        // An unlit colon is drawn like the unlit segments
+        let mut display = Display::default();
+        display.draw(digits.colon(false));
+        assert_eq!((display.0[2][5], display.0[4][5]), (2, 2));
+    }
+
+    #[test]
+    fn background_and_size() {
+        let digits = SevenSegment::new("8.8", UnsignedCoord::new(10, 16), 1u8)
+            .spacing(2)
+            .background(Some(3u8))
+            .translate(Coord::new(-1, 0));
+
+        assert_eq!(digits.top_left(), Coord::new(-1, 0));
+        assert_eq!(digits.size(), UnsignedCoord::new(10 + 2 + 2 + 2 + 10, 16));
+        assert_eq!(
+            SevenSegment::new("", UnsignedCoord::new(10, 16), 1u8).size(),
+            UnsignedCoord::new(0, 0)
+        );
+
+        // Every pixel on screen is drawn once, with unlit segments drawn as background
+        let mut counts = Display::default();
+        for Pixel(p, color) in digits {
+            assert!(color == 1 || color == 3);
+            if p[0] < 24 {
+                counts.0[p[1] as usize][p[0] as usize] += 1;
+            }
+        }
+        assert!(counts.0.iter().flatten().all(|&count| count == 1));
+
+        // Decimal point at the bottom of its cell
+        let mut display = Display::default();
+        display.draw(digits);
+        assert_eq!(display.0[15][11], 1);
+        assert_eq!(display.0[12][11], 3);
+    }
+}
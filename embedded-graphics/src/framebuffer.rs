@@ -0,0 +1,1895 @@
+//! Framebuffers stored in memory owned by the caller
+//!
+//! A [`Framebuffer`](./struct.Framebuffer.html) keeps one color per pixel in a slice, row by row.
+//! Part of one can be drawn to on its own through a [`FramebufferView`](./struct.FramebufferView.html).
+//! A [`DoubleFramebuffer`](./struct.DoubleFramebuffer.html) keeps two: items are drawn to the back
+//! buffer, and the front buffer holds what was last sent to the display. Comparing the two gives
+//! the pixels that changed, so partial update e-paper panels and displays on slow serial buses only
+//! need to be sent the differences. A [`ByteFramebuffer`](./struct.ByteFramebuffer.html) stores
+//! raw color bytes with a row stride, for buffers whose size is only known at runtime. A
+//! [`BinaryFramebuffer`](./struct.BinaryFramebuffer.html) packs monochrome pixels into bits, in the
+//! polarity the display expects.
+//! [`render_in_bands`](./fn.render_in_bands.html) draws a whole display through a framebuffer
+//! that only holds a few rows of it.
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Clip, Dimensions, Drawable, Pixel};
+use crate::image::Image1BPP;
+use crate::pixelcolor::raw::IntoStorage;
+use crate::pixelcolor::{BinaryColor, PixelColor, Polarity};
+use crate::raster::ReadPixel;
+use crate::transform::Transform;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use crate::{run_end, Drawing};
+use core::marker::PhantomData;
+
+/// Pixels stored row by row in a slice
+///
+/// The height is the number of whole rows of `width` pixels that fit in the slice. Pixels drawn
+/// outside the framebuffer are dropped.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egline;
+/// use embedded_graphics::framebuffer::Framebuffer;
+///
+/// let mut pixels = [0u8; 16 * 8];
+/// let mut framebuffer = Framebuffer::new(&mut pixels, 16);
+///
+/// framebuffer.draw(egline!((0, 1), (3, 1), stroke = Some(1u8)));
+///
+/// assert_eq!(framebuffer.size(), UnsignedCoord::new(16, 8));
+/// assert_eq!(framebuffer.row(1).unwrap()[..5], [1, 1, 1, 1, 0]);
+/// ```
+#[derive(Debug)]
+pub struct Framebuffer<'a, C> {
+    pixels: &'a mut [C],
+    width: u32,
+    height: u32,
+}
+
+impl<'a, C> Framebuffer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Use `pixels` as a framebuffer `width` pixels wide
+    pub fn new(pixels: &'a mut [C], width: u32) -> Self {
+        let height = if width == 0 {
+            0
+        } else {
+            (pixels.len() / width as usize) as u32
+        };
+
+        Self {
+            pixels,
+            width,
+            height,
+        }
+    }
+
+    /// Width and height of the framebuffer in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.height)
+    }
+
+    /// Set every pixel to `color`
+    pub fn clear(&mut self, color: C) {
+        self.pixels.fill(color);
+    }
+
+    /// Set every pixel from `top_left` to `bottom_right`, inclusive, to `color`
+    ///
+    /// Each row is filled in one go, which is much faster than drawing a filled rectangle pixel by
+    /// pixel. Parts of the area outside the framebuffer are skipped.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::framebuffer::Framebuffer;
+    ///
+    /// let mut pixels = [0u8; 16 * 8];
+    /// let mut framebuffer = Framebuffer::new(&mut pixels, 16);
+    ///
+    /// framebuffer.fill_solid(Coord::new(-2, 1), Coord::new(2, 6), 1);
+    ///
+    /// assert_eq!(framebuffer.row(1).unwrap()[..4], [1, 1, 1, 0]);
+    /// ```
+    pub fn fill_solid(&mut self, top_left: Coord, bottom_right: Coord, color: C) {
+        if let Some((left, top, right, bottom)) = self.clip(top_left, bottom_right) {
+            self.fill_rows(top, bottom + 1, left, right + 1, color);
+        }
+    }
+
+    /// Set the pixels in the `size` pixel area at `top_left` to the colors from `colors`, row by
+    /// row
+    ///
+    /// Colors for pixels outside the framebuffer are skipped. If `colors` runs out, the rest of the
+    /// area is left unchanged. Each visible part of a row is copied in one go instead of pixel by
+    /// pixel.
+    pub fn fill_contiguous<I>(&mut self, top_left: Coord, size: UnsignedCoord, colors: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        if size[0] == 0 || size[1] == 0 {
+            return;
+        }
+
+        let bottom_right = top_left + size.to_signed() - Coord::new(1, 1);
+        let (left, top, right, bottom) = match self.clip(top_left, bottom_right) {
+            Some(area) => area,
+            None => return,
+        };
+
+        let mut colors = colors.into_iter();
+        let skip = |colors: &mut I::IntoIter, n: i64| {
+            colors.by_ref().take(n as usize).for_each(drop);
+        };
+
+        // Offsets of the visible area from the unclipped area
+        let size = (i64::from(size[0]), i64::from(size[1]));
+        let skip_left = i64::from(left) - i64::from(top_left[0]);
+        let skip_top = i64::from(top) - i64::from(top_left[1]);
+        let visible = i64::from(right - left + 1);
+
+        skip(&mut colors, skip_top * size.0);
+
+        let width = self.width as usize;
+        for y in top..=bottom {
+            skip(&mut colors, skip_left);
+
+            let start = y as usize * width + left as usize;
+            for (pixel, color) in self.pixels[start..start + visible as usize]
+                .iter_mut()
+                .zip(&mut colors)
+            {
+                *pixel = color;
+            }
+
+            skip(&mut colors, size.0 - skip_left - visible);
+        }
+    }
+
+    /// Get the pixels of row `y`, or `None` if it is below the bottom of the framebuffer
+    pub fn row(&self, y: u32) -> Option<&[C]> {
+        self.index(UnsignedCoord::new(0, y))
+            .map(|start| &self.pixels[start..start + self.width as usize])
+    }
+
+    /// Get every pixel, row by row
+    pub fn as_slice(&self) -> &[C] {
+        &self.pixels[..(self.width * self.height) as usize]
+    }
+
+    /// Copy the pixels from `top_left` to `bottom_right`, inclusive, so the top left corner of the
+    /// copy is at `dest`
+    ///
+    /// The source and destination can overlap. Parts of either area that are outside the
+    /// framebuffer are skipped.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egrectangle;
+    /// use embedded_graphics::framebuffer::Framebuffer;
+    ///
+    /// let mut pixels = [0u8; 16 * 8];
+    /// let mut framebuffer = Framebuffer::new(&mut pixels, 16);
+    ///
+    /// framebuffer.draw(egrectangle!((0, 0), (2, 1), fill = Some(1u8)));
+    /// framebuffer.copy_region(Coord::new(0, 0), Coord::new(3, 1), Coord::new(2, 1));
+    ///
+    /// assert_eq!(framebuffer.row(0).unwrap()[..6], [1, 1, 1, 0, 0, 0]);
+    /// assert_eq!(framebuffer.row(1).unwrap()[..6], [1, 1, 1, 1, 1, 0]);
+    /// assert_eq!(framebuffer.row(2).unwrap()[..6], [0, 0, 1, 1, 1, 0]);
+    /// ```
+    pub fn copy_region(&mut self, top_left: Coord, bottom_right: Coord, dest: Coord) {
+        let (width, height) = (i64::from(self.width), i64::from(self.height));
+
+        // Work in i64 so the offsets between the two areas can't overflow
+        let mut left = i64::from(top_left[0].min(bottom_right[0]));
+        let mut top = i64::from(top_left[1].min(bottom_right[1]));
+        let mut right = i64::from(top_left[0].max(bottom_right[0]));
+        let mut bottom = i64::from(top_left[1].max(bottom_right[1]));
+        let (dx, dy) = (i64::from(dest[0]) - left, i64::from(dest[1]) - top);
+
+        // Clip the source to the framebuffer, then clip the destination too by clipping the source
+        // to the framebuffer moved by the opposite offset
+        left = left.max(0).max(-dx);
+        top = top.max(0).max(-dy);
+        right = right.min(width - 1).min(width - 1 - dx);
+        bottom = bottom.min(height - 1).min(height - 1 - dy);
+
+        if left > right || top > bottom {
+            return;
+        }
+
+        let len = (right - left + 1) as usize;
+        let row = |y: i64| {
+            let src = (y * width + left) as usize;
+            let dst = ((y + dy) * width + left + dx) as usize;
+
+            (src, dst)
+        };
+
+        // Copy rows in the opposite direction to the move so no row is overwritten before it is
+        // copied. `copy_within` handles the overlap inside each row.
+        if dy > 0 {
+            for y in (top..=bottom).rev() {
+                let (src, dst) = row(y);
+                self.pixels.copy_within(src..src + len, dst);
+            }
+        } else {
+            for y in top..=bottom {
+                let (src, dst) = row(y);
+                self.pixels.copy_within(src..src + len, dst);
+            }
+        }
+    }
+
+    /// Move the contents of the framebuffer `dx` pixels right and `dy` pixels down, filling the
+    /// uncovered area with `fill_color`
+    ///
+    /// Negative offsets move the contents left or up. This lets a log or a scope trace scroll
+    /// without being drawn again.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::egline;
+    /// use embedded_graphics::framebuffer::Framebuffer;
+    ///
+    /// let mut pixels = [0u8; 16 * 8];
+    /// let mut framebuffer = Framebuffer::new(&mut pixels, 16);
+    ///
+    /// framebuffer.draw(egline!((0, 7), (15, 7), stroke = Some(1u8)));
+    ///
+    /// // Make room for a new line of text at the bottom
+    /// framebuffer.scroll(0, -2, 0);
+    ///
+    /// assert_eq!(framebuffer.row(5).unwrap()[0], 1);
+    /// assert_eq!(framebuffer.row(7).unwrap()[0], 0);
+    /// ```
+    pub fn scroll(&mut self, dx: i32, dy: i32, fill_color: C) {
+        let (width, height) = (self.width as i32, self.height as i32);
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        self.copy_region(
+            Coord::new(0, 0),
+            Coord::new(width - 1, height - 1),
+            Coord::new(dx, dy),
+        );
+
+        let fill = |from: i32, by: i32, size: i32| {
+            let by = by.max(-size).min(size);
+
+            if by >= 0 {
+                (from, from + by)
+            } else {
+                (from + size + by, from + size)
+            }
+        };
+        let (left, right) = fill(0, dx, width);
+        let (top, bottom) = fill(0, dy, height);
+
+        self.fill_rows(0, height, left, right, fill_color);
+        self.fill_rows(top, bottom, 0, width, fill_color);
+    }
+
+    /// Set the pixels in rows `top..bottom` and columns `left..right` to `color`
+    fn fill_rows(&mut self, top: i32, bottom: i32, left: i32, right: i32, color: C) {
+        let width = self.width as usize;
+
+        for y in top..bottom {
+            let start = y as usize * width;
+
+            self.pixels[start + left as usize..start + right as usize].fill(color);
+        }
+    }
+
+    /// Clip the area from `top_left` to `bottom_right`, inclusive, to the framebuffer, returning
+    /// its `(left, top, right, bottom)` edges or `None` if none of it is inside
+    fn clip(&self, top_left: Coord, bottom_right: Coord) -> Option<(i32, i32, i32, i32)> {
+        let (width, height) = (self.width as i32, self.height as i32);
+
+        let left = top_left[0].min(bottom_right[0]).max(0);
+        let top = top_left[1].min(bottom_right[1]).max(0);
+        let right = top_left[0].max(bottom_right[0]).min(width - 1);
+        let bottom = top_left[1].max(bottom_right[1]).min(height - 1);
+
+        if left > right || top > bottom {
+            None
+        } else {
+            Some((left, top, right, bottom))
+        }
+    }
+
+    /// Get the pixels of the framebuffer as an image that can be drawn to another display
+    pub fn as_image(&self) -> FramebufferImage<'_, C> {
+        FramebufferImage {
+            pixels: self.as_slice(),
+            stride: self.width as usize,
+            width: self.width,
+            height: self.height,
+            offset: Coord::new(0, 0),
+        }
+    }
+
+    /// Get a view of the `size` pixels at `top_left` that can be drawn to on its own
+    ///
+    /// The window is clipped to the framebuffer.
+    pub fn window(
+        &mut self,
+        top_left: UnsignedCoord,
+        size: UnsignedCoord,
+    ) -> FramebufferView<'_, C> {
+        let bounds = self.size();
+
+        FramebufferView::new(self.pixels, self.width as usize, bounds, top_left, size)
+    }
+
+    /// Index of `point` in the slice, or `None` if it is outside the framebuffer
+    fn index(&self, point: UnsignedCoord) -> Option<usize> {
+        if point[0] < self.width && point[1] < self.height {
+            Some(point[1] as usize * self.width as usize + point[0] as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C> Drawing<C> for Framebuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for Pixel(coord, color) in item {
+            if let Some(index) = self.index(coord) {
+                self.pixels[index] = color;
+            }
+        }
+    }
+
+    /// Fill each run with a slice fill instead of pixel by pixel
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        for (start, length, color) in runs {
+            if length > 0 {
+                self.fill_solid(start, run_end(start, length), color);
+            }
+        }
+    }
+}
+
+impl<'a, C> ReadPixel<C> for Framebuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.index(point).map(|index| self.pixels[index])
+    }
+}
+
+/// Part of a [`Framebuffer`](./struct.Framebuffer.html) that can be drawn to on its own
+///
+/// Coordinates are relative to the top left corner of the window, and pixels outside the window
+/// are dropped, so a widget can draw into its own part of a shared buffer without touching the
+/// rest of it. Views are created with [`Framebuffer::window`](./struct.Framebuffer.html#method.window).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::framebuffer::Framebuffer;
+///
+/// let mut pixels = [0u8; 16 * 8];
+/// let mut framebuffer = Framebuffer::new(&mut pixels, 16);
+///
+/// let mut status_bar = framebuffer.window(UnsignedCoord::new(4, 6), UnsignedCoord::new(8, 2));
+/// status_bar.draw(egrectangle!((0, 0), (100, 100), fill = Some(1u8)));
+///
+/// assert_eq!(framebuffer.row(6).unwrap()[2..14], [0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0]);
+/// assert_eq!(framebuffer.row(5).unwrap(), [0; 16]);
+/// ```
+#[derive(Debug)]
+pub struct FramebufferView<'a, C> {
+    pixels: &'a mut [C],
+    width: u32,
+    height: u32,
+    stride: usize,
+}
+
+impl<'a, C> FramebufferView<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a view of the `size` pixels at `top_left` in `pixels`, which has rows `stride`
+    /// pixels apart and is `bounds` pixels in size. The window is clipped to the bounds.
+    fn new(
+        pixels: &'a mut [C],
+        stride: usize,
+        bounds: UnsignedCoord,
+        top_left: UnsignedCoord,
+        size: UnsignedCoord,
+    ) -> Self {
+        let left = top_left[0].min(bounds[0]);
+        let top = top_left[1].min(bounds[1]);
+        let width = size[0].min(bounds[0] - left);
+        let height = size[1].min(bounds[1] - top);
+
+        if width == 0 || height == 0 {
+            return Self {
+                pixels: &mut pixels[..0],
+                width: 0,
+                height: 0,
+                stride,
+            };
+        }
+
+        Self {
+            pixels: &mut pixels[top as usize * stride + left as usize..],
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Width and height of the view in pixels, after clipping it to the framebuffer
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.height)
+    }
+
+    /// Set every pixel in the view to `color`
+    pub fn clear(&mut self, color: C) {
+        for y in 0..self.height as usize {
+            let start = y * self.stride;
+
+            for pixel in &mut self.pixels[start..start + self.width as usize] {
+                *pixel = color;
+            }
+        }
+    }
+
+    /// Get a smaller view of the `size` pixels at `top_left` in this view
+    pub fn window(
+        &mut self,
+        top_left: UnsignedCoord,
+        size: UnsignedCoord,
+    ) -> FramebufferView<'_, C> {
+        let bounds = self.size();
+
+        FramebufferView::new(self.pixels, self.stride, bounds, top_left, size)
+    }
+
+    /// Index of `point` in the slice, or `None` if it is outside the view
+    fn index(&self, point: UnsignedCoord) -> Option<usize> {
+        if point[0] < self.width && point[1] < self.height {
+            Some(point[1] as usize * self.stride + point[0] as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C> Drawing<C> for FramebufferView<'a, C>
+where
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for Pixel(coord, color) in item {
+            if let Some(index) = self.index(coord) {
+                self.pixels[index] = color;
+            }
+        }
+    }
+
+    /// Fill each run with a slice fill instead of pixel by pixel
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        for (start, length, color) in runs {
+            let left = i64::from(start[0]).max(0);
+            let right = i64::from(run_end(start, length)[0]).min(i64::from(self.width) - 1);
+
+            if start[1] < 0 || start[1] as u32 >= self.height || left > right {
+                continue;
+            }
+
+            let row = start[1] as usize * self.stride;
+            self.pixels[row + left as usize..=row + right as usize].fill(color);
+        }
+    }
+}
+
+impl<'a, C> ReadPixel<C> for FramebufferView<'a, C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.index(point).map(|index| self.pixels[index])
+    }
+}
+
+/// Pixels of a [`Framebuffer`](./struct.Framebuffer.html) drawn as an image
+///
+/// This lets an off-screen buffer be drawn onto another display like any other image, for example
+/// to cache a sprite or to redraw part of a screen from a copy of it. Images are created with
+/// [`Framebuffer::as_image`](./struct.Framebuffer.html#method.as_image), and
+/// [`sub_image`](#method.sub_image) picks out part of one.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egcircle;
+/// use embedded_graphics::framebuffer::Framebuffer;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let mut pixels = [0u8; 8 * 8];
+/// let mut sprite = Framebuffer::new(&mut pixels, 8);
+/// sprite.draw(egcircle!((3, 3), 3, fill = Some(1u8)));
+///
+/// // Draw the bottom half of the sprite twice
+/// let half = sprite
+///     .as_image()
+///     .sub_image(UnsignedCoord::new(0, 4), UnsignedCoord::new(8, 4));
+/// display.draw(half.translate(Coord::new(2, 2)));
+/// display.draw(half.translate(Coord::new(12, 2)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FramebufferImage<'a, C> {
+    pixels: &'a [C],
+    stride: usize,
+    width: u32,
+    height: u32,
+
+    /// Image offset in pixels from screen origin (0,0)
+    pub offset: Coord,
+}
+
+impl<'a, C> FramebufferImage<'a, C>
+where
+    C: PixelColor,
+{
+    /// Get an image of the `size` pixels at `top_left` in this image, at the same offset
+    ///
+    /// The area is clipped to this image.
+    pub fn sub_image(&self, top_left: UnsignedCoord, size: UnsignedCoord) -> Self {
+        let left = top_left[0].min(self.width);
+        let top = top_left[1].min(self.height);
+        let width = size[0].min(self.width - left);
+        let height = size[1].min(self.height - top);
+
+        if width == 0 || height == 0 {
+            return Self {
+                pixels: &[],
+                width: 0,
+                height: 0,
+                ..*self
+            };
+        }
+
+        Self {
+            pixels: &self.pixels[top as usize * self.stride + left as usize..],
+            width,
+            height,
+            ..*self
+        }
+    }
+}
+
+impl<'a, C> Dimensions for FramebufferImage<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.offset
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.top_left() + self.size().to_signed()
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.height)
+    }
+}
+
+impl<'a, C> IntoIterator for FramebufferImage<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = FramebufferImageIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FramebufferImageIterator {
+            image: self,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl<'a, C> IntoIterator for &FramebufferImage<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = FramebufferImageIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+/// Pixel iterator for the `FramebufferImage` object
+#[derive(Debug, Clone, Copy)]
+pub struct FramebufferImageIterator<'a, C> {
+    image: FramebufferImage<'a, C>,
+    x: u32,
+    y: u32,
+}
+
+impl<'a, C> Iterator for FramebufferImageIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let image = &self.image;
+
+        while self.y < image.height {
+            let (x, y) = (self.x, self.y);
+
+            self.x += 1;
+            if self.x >= image.width {
+                self.x = 0;
+                self.y += 1;
+            }
+
+            let point = image.offset + Coord::new(x as i32, y as i32);
+            if point[0] >= 0 && point[1] >= 0 {
+                let color = image.pixels[y as usize * image.stride + x as usize];
+
+                return Some(Pixel(point.to_unsigned(), color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, C> Drawable for FramebufferImage<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for FramebufferImage<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the image from its current position to a new position by (x, y) pixels,
+    /// returning a new `FramebufferImage`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the image from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+/// Byte order of the colors stored in a [`ByteFramebuffer`](./struct.ByteFramebuffer.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Most significant byte first
+    BigEndian,
+
+    /// Least significant byte first
+    LittleEndian,
+}
+
+/// Error returned when creating a [`ByteFramebuffer`](./struct.ByteFramebuffer.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FramebufferError {
+    /// The stride is shorter than a row of pixels
+    StrideTooSmall,
+
+    /// The buffer is too short to hold every row
+    BufferTooSmall,
+}
+
+/// Pixels stored as raw bytes in a slice, with dimensions chosen at runtime
+///
+/// Each color is stored as its [`IntoStorage`](../pixelcolor/raw/trait.IntoStorage.html) bytes in
+/// the given byte order, so the buffer can be sent straight to a display controller. Rows start
+/// `stride` bytes apart, which can be more than the width of a row when the controller or the
+/// allocator pads rows. This is useful when the size of the panel is only known at runtime, or
+/// when buffers come from a memory pool.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egline;
+/// use embedded_graphics::framebuffer::{ByteFramebuffer, ByteOrder};
+/// use embedded_graphics::pixelcolor::Rgb565;
+///
+/// // 3 pixels of 2 bytes each, padded to 8 bytes per row
+/// let mut bytes = [0u8; 8 * 2];
+/// let mut framebuffer =
+///     ByteFramebuffer::<Rgb565>::new(&mut bytes, 3, 2, 8, ByteOrder::BigEndian).unwrap();
+///
+/// framebuffer.draw(egline!((1, 1), (2, 1), stroke = Some(Rgb565(0xf800))));
+///
+/// assert_eq!(bytes[8..], [0x00, 0x00, 0xf8, 0x00, 0xf8, 0x00, 0x00, 0x00]);
+/// ```
+#[derive(Debug)]
+pub struct ByteFramebuffer<'a, C> {
+    bytes: &'a mut [u8],
+    width: u32,
+    height: u32,
+    stride: usize,
+    byte_order: ByteOrder,
+    color: PhantomData<C>,
+}
+
+impl<'a, C> ByteFramebuffer<'a, C>
+where
+    C: PixelColor + IntoStorage,
+    C::Bytes: AsRef<[u8]> + AsMut<[u8]> + Default,
+{
+    /// Use `bytes` as a framebuffer `width` by `height` pixels, with rows starting `stride` bytes
+    /// apart
+    ///
+    /// The last row doesn't need any padding after it.
+    pub fn new(
+        bytes: &'a mut [u8],
+        width: u32,
+        height: u32,
+        stride: usize,
+        byte_order: ByteOrder,
+    ) -> Result<Self, FramebufferError> {
+        let row = width as usize * Self::bytes_per_pixel();
+        if stride < row {
+            return Err(FramebufferError::StrideTooSmall);
+        }
+
+        let len = match height {
+            0 => 0,
+            height => (height as usize - 1) * stride + row,
+        };
+        if bytes.len() < len {
+            return Err(FramebufferError::BufferTooSmall);
+        }
+
+        Ok(Self {
+            bytes,
+            width,
+            height,
+            stride,
+            byte_order,
+            color: PhantomData,
+        })
+    }
+
+    /// Number of bytes each pixel is stored in
+    pub fn bytes_per_pixel() -> usize {
+        C::Bytes::default().as_ref().len()
+    }
+
+    /// Width and height of the framebuffer in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.height)
+    }
+
+    /// Number of bytes from the start of one row to the start of the next
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the bytes of row `y`, without any padding, or `None` if it is below the bottom of the
+    /// framebuffer
+    pub fn row_bytes(&self, y: u32) -> Option<&[u8]> {
+        self.index(UnsignedCoord::new(0, y))
+            .map(|start| &self.bytes[start..start + self.width as usize * Self::bytes_per_pixel()])
+    }
+
+    /// Set every pixel to `color`, leaving any padding untouched
+    pub fn clear(&mut self, color: C) {
+        let (width, height) = (self.width as i32, self.height as i32);
+
+        self.fill_solid(Coord::new(0, 0), Coord::new(width - 1, height - 1), color);
+    }
+
+    /// Set every pixel from `top_left` to `bottom_right`, inclusive, to `color`
+    ///
+    /// The color is written once at the start of each row and then copied in doubling chunks, so
+    /// filling a large background doesn't work out the bytes of every pixel. Parts of the area
+    /// outside the framebuffer are skipped.
+    pub fn fill_solid(&mut self, top_left: Coord, bottom_right: Coord, color: C) {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        let left = i64::from(top_left[0].min(bottom_right[0])).max(0);
+        let top = i64::from(top_left[1].min(bottom_right[1])).max(0);
+        let right = i64::from(top_left[0].max(bottom_right[0])).min(width - 1);
+        let bottom = i64::from(top_left[1].max(bottom_right[1])).min(height - 1);
+
+        if left > right || top > bottom {
+            return;
+        }
+
+        let bytes = self.color_bytes(color);
+        let bytes = bytes.as_ref();
+        let len = (right - left + 1) as usize * bytes.len();
+
+        for y in top as usize..=bottom as usize {
+            let start = y * self.stride + left as usize * bytes.len();
+            let row = &mut self.bytes[start..start + len];
+
+            row[..bytes.len()].copy_from_slice(bytes);
+
+            let mut filled = bytes.len();
+            while filled < len {
+                let n = filled.min(len - filled);
+                row.copy_within(..n, filled);
+                filled += n;
+            }
+        }
+    }
+
+    /// Index of the first byte of `point`, or `None` if it is outside the framebuffer
+    fn index(&self, point: UnsignedCoord) -> Option<usize> {
+        if point[0] < self.width && point[1] < self.height {
+            Some(point[1] as usize * self.stride + point[0] as usize * Self::bytes_per_pixel())
+        } else {
+            None
+        }
+    }
+
+    fn color_bytes(&self, color: C) -> C::Bytes {
+        match self.byte_order {
+            ByteOrder::BigEndian => color.to_be_bytes(),
+            ByteOrder::LittleEndian => color.to_le_bytes(),
+        }
+    }
+}
+
+impl<'a, C> Drawing<C> for ByteFramebuffer<'a, C>
+where
+    C: PixelColor + IntoStorage,
+    C::Bytes: AsRef<[u8]> + AsMut<[u8]> + Default,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let len = Self::bytes_per_pixel();
+
+        for Pixel(coord, color) in item {
+            if let Some(index) = self.index(coord) {
+                let bytes = self.color_bytes(color);
+                self.bytes[index..index + len].copy_from_slice(bytes.as_ref());
+            }
+        }
+    }
+
+    /// Fill each run with [`fill_solid`](#method.fill_solid), so the bytes of its color are only
+    /// worked out once
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        for (start, length, color) in runs {
+            if length > 0 {
+                self.fill_solid(start, run_end(start, length), color);
+            }
+        }
+    }
+}
+
+impl<'a, C> ReadPixel<C> for ByteFramebuffer<'a, C>
+where
+    C: PixelColor + IntoStorage,
+    C::Bytes: AsRef<[u8]> + AsMut<[u8]> + Default,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        let index = self.index(point)?;
+
+        let mut bytes = C::Bytes::default();
+        let len = bytes.as_ref().len();
+        bytes
+            .as_mut()
+            .copy_from_slice(&self.bytes[index..index + len]);
+
+        Some(match self.byte_order {
+            ByteOrder::BigEndian => C::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => C::from_le_bytes(bytes),
+        })
+    }
+}
+
+/// Monochrome pixels packed 8 to a byte, stored with a given polarity
+///
+/// Pixels are stored row by row, most significant bit first, with each row padded to a whole
+/// byte. This is the layout of [`Image1BPP`](../image/type.Image1BPP.html) data and of most
+/// monochrome display controllers. Each pixel is stored as its
+/// [`to_bit`](../pixelcolor/enum.BinaryColor.html#method.to_bit) for the framebuffer's
+/// [`Polarity`](../pixelcolor/enum.Polarity.html), so the bytes can be sent as-is to a display
+/// where a `0` bit is lit, without inverting every color that's drawn. The height is the number of
+/// whole rows that fit in the slice.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egline;
+/// use embedded_graphics::framebuffer::BinaryFramebuffer;
+/// use embedded_graphics::pixelcolor::{BinaryColor, Polarity};
+///
+/// let mut bytes = [0u8; 2 * 4];
+/// let mut framebuffer = BinaryFramebuffer::new(&mut bytes, 12, Polarity::ActiveLow);
+///
+/// framebuffer.clear(BinaryColor::Off);
+/// framebuffer.draw(egline!((1, 1), (9, 1), stroke = Some(BinaryColor::On)));
+///
+/// assert_eq!(framebuffer.size(), UnsignedCoord::new(12, 4));
+/// assert_eq!(framebuffer.row_bytes(1), Some(&[0b1000_0000, 0b0011_1111][..]));
+/// ```
+#[derive(Debug)]
+pub struct BinaryFramebuffer<'a> {
+    bytes: &'a mut [u8],
+    width: u32,
+    height: u32,
+    polarity: Polarity,
+}
+
+impl<'a> BinaryFramebuffer<'a> {
+    /// Use `bytes` as a framebuffer `width` pixels wide, storing pixels with `polarity`
+    pub fn new(bytes: &'a mut [u8], width: u32, polarity: Polarity) -> Self {
+        let height = bytes
+            .len()
+            .checked_div((width as usize).div_ceil(8))
+            .unwrap_or(0) as u32;
+
+        Self {
+            bytes,
+            width,
+            height,
+            polarity,
+        }
+    }
+
+    /// Width and height of the framebuffer in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        UnsignedCoord::new(self.width, self.height)
+    }
+
+    /// The polarity pixels are stored with
+    pub fn polarity(&self) -> Polarity {
+        self.polarity
+    }
+
+    /// Set every pixel, including the padding bits at the end of each row, to `color`
+    pub fn clear(&mut self, color: BinaryColor) {
+        let byte = if color.to_bit(self.polarity) == 1 {
+            0xff
+        } else {
+            0x00
+        };
+        let len = self.len();
+
+        for b in self.bytes[..len].iter_mut() {
+            *b = byte;
+        }
+    }
+
+    /// Get the bytes of row `y`, or `None` if it is below the bottom of the framebuffer
+    pub fn row_bytes(&self, y: u32) -> Option<&[u8]> {
+        if y < self.height {
+            let start = y as usize * self.stride();
+
+            Some(&self.bytes[start..start + self.stride()])
+        } else {
+            None
+        }
+    }
+
+    /// Get the bytes of every row, ready to be sent to the display
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len()]
+    }
+
+    /// Get the pixels of the framebuffer as an image that can be drawn to another display
+    ///
+    /// The image is decoded with the framebuffer's polarity, so it draws the same colors that
+    /// were drawn to the framebuffer.
+    pub fn as_image(&self) -> Image1BPP<'_, BinaryColor> {
+        Image1BPP::new(self.as_bytes(), self.width, self.height).with_polarity(self.polarity)
+    }
+
+    /// Number of bytes in each row
+    fn stride(&self) -> usize {
+        (self.width as usize).div_ceil(8)
+    }
+
+    /// Number of bytes used by whole rows
+    fn len(&self) -> usize {
+        self.height as usize * self.stride()
+    }
+
+    /// Index of the byte holding `point` and the mask of its bit, or `None` if it is outside the
+    /// framebuffer
+    fn index(&self, point: UnsignedCoord) -> Option<(usize, u8)> {
+        if point[0] < self.width && point[1] < self.height {
+            let index = point[1] as usize * self.stride() + point[0] as usize / 8;
+
+            Some((index, 0x80 >> (point[0] % 8)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Drawing<BinaryColor> for BinaryFramebuffer<'a> {
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<BinaryColor>>,
+    {
+        for Pixel(coord, color) in item {
+            if let Some((index, mask)) = self.index(coord) {
+                if color.to_bit(self.polarity) == 1 {
+                    self.bytes[index] |= mask;
+                } else {
+                    self.bytes[index] &= !mask;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> ReadPixel<BinaryColor> for BinaryFramebuffer<'a> {
+    fn pixel(&self, point: UnsignedCoord) -> Option<BinaryColor> {
+        self.index(point)
+            .map(|(index, mask)| BinaryColor::from_bit(self.bytes[index] & mask, self.polarity))
+    }
+}
+
+/// A back buffer that items are drawn to, and a front buffer holding what the display shows
+///
+/// Drawing only changes the back buffer. Use [`changed_pixels`](#method.changed_pixels) or
+/// [`changed_rows`](#method.changed_rows) to find what needs to be sent to the display, then call
+/// [`present`](#method.present) to copy the back buffer to the front buffer once it has been sent.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::framebuffer::DoubleFramebuffer;
+///
+/// let (mut front, mut back) = ([0u8; 32 * 16], [0u8; 32 * 16]);
+/// let mut framebuffer = DoubleFramebuffer::new(&mut front, &mut back, 32);
+///
+/// framebuffer.draw(egrectangle!((4, 2), (9, 3), fill = Some(1u8)));
+///
+/// for span in framebuffer.changed_rows() {
+///     // Send the pixels from `span.left` to `span.right` of row `span.y` to the display
+/// }
+/// framebuffer.present();
+///
+/// assert_eq!(framebuffer.changed_rows().count(), 0);
+/// ```
+#[derive(Debug)]
+pub struct DoubleFramebuffer<'a, C> {
+    front: Framebuffer<'a, C>,
+    back: Framebuffer<'a, C>,
+}
+
+impl<'a, C> DoubleFramebuffer<'a, C>
+where
+    C: PixelColor + PartialEq,
+{
+    /// Use `front` and `back` as the two buffers of a framebuffer `width` pixels wide
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers are different lengths.
+    pub fn new(front: &'a mut [C], back: &'a mut [C], width: u32) -> Self {
+        assert_eq!(
+            front.len(),
+            back.len(),
+            "Front and back buffers must be the same length"
+        );
+
+        Self {
+            front: Framebuffer::new(front, width),
+            back: Framebuffer::new(back, width),
+        }
+    }
+
+    /// Width and height of the framebuffer in pixels
+    pub fn size(&self) -> UnsignedCoord {
+        self.back.size()
+    }
+
+    /// Get the buffer that holds what the display shows
+    pub fn front(&self) -> &Framebuffer<'a, C> {
+        &self.front
+    }
+
+    /// Get the buffer that items are drawn to
+    pub fn back(&self) -> &Framebuffer<'a, C> {
+        &self.back
+    }
+
+    /// Get a mutable reference to the buffer that items are drawn to
+    pub fn back_mut(&mut self) -> &mut Framebuffer<'a, C> {
+        &mut self.back
+    }
+
+    /// Iterate over the pixels of the back buffer that differ from the front buffer, row by row
+    pub fn changed_pixels(&self) -> ChangedPixels<'_, C> {
+        ChangedPixels {
+            front: self.front.as_slice(),
+            back: self.back.as_slice(),
+            width: self.back.width,
+            index: 0,
+        }
+    }
+
+    /// Iterate over the rows that differ between the back and front buffers
+    ///
+    /// Each [`RowSpan`](./struct.RowSpan.html) runs from the first to the last changed pixel in its
+    /// row, so it can be sent to the display as a single window.
+    pub fn changed_rows(&self) -> ChangedRows<'_, C> {
+        ChangedRows {
+            front: self.front.as_slice(),
+            back: self.back.as_slice(),
+            width: self.back.width,
+            y: 0,
+        }
+    }
+
+    /// Copy the back buffer to the front buffer, after its changes have been sent to the display
+    pub fn present(&mut self) {
+        self.front.pixels.copy_from_slice(self.back.pixels);
+    }
+}
+
+impl<'a, C> Drawing<C> for DoubleFramebuffer<'a, C>
+where
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        self.back.draw(item);
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        self.back.fill_runs(runs);
+    }
+}
+
+impl<'a, C> ReadPixel<C> for DoubleFramebuffer<'a, C>
+where
+    C: PixelColor,
+{
+    /// Color of the pixel at `point` in the back buffer
+    fn pixel(&self, point: UnsignedCoord) -> Option<C> {
+        self.back.pixel(point)
+    }
+}
+
+/// Iterator over the pixels that differ between the buffers of a
+/// [`DoubleFramebuffer`](./struct.DoubleFramebuffer.html)
+///
+/// Each pixel has the color from the back buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct ChangedPixels<'a, C> {
+    front: &'a [C],
+    back: &'a [C],
+    width: u32,
+    index: usize,
+}
+
+impl<'a, C> Iterator for ChangedPixels<'a, C>
+where
+    C: PixelColor + PartialEq,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.back[self.index..]
+            .iter()
+            .zip(&self.front[self.index..])
+            .position(|(back, front)| back != front)?;
+
+        let index = self.index + offset;
+        self.index = index + 1;
+
+        let width = self.width as usize;
+        let point = UnsignedCoord::new((index % width) as u32, (index / width) as u32);
+
+        Some(Pixel(point, self.back[index]))
+    }
+}
+
+/// Changed part of a row of a [`DoubleFramebuffer`](./struct.DoubleFramebuffer.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSpan {
+    /// Row number
+    pub y: u32,
+
+    /// Column of the first changed pixel
+    pub left: u32,
+
+    /// Column of the last changed pixel, inclusive
+    pub right: u32,
+}
+
+/// Iterator over the changed rows of a [`DoubleFramebuffer`](./struct.DoubleFramebuffer.html)
+#[derive(Debug, Clone, Copy)]
+pub struct ChangedRows<'a, C> {
+    front: &'a [C],
+    back: &'a [C],
+    width: u32,
+    y: u32,
+}
+
+impl<'a, C> Iterator for ChangedRows<'a, C>
+where
+    C: PixelColor + PartialEq,
+{
+    type Item = RowSpan;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.width as usize;
+        if width == 0 {
+            return None;
+        }
+
+        let rows = self.back.chunks(width).zip(self.front.chunks(width));
+
+        for (back, front) in rows.skip(self.y as usize) {
+            let y = self.y;
+            self.y += 1;
+
+            let changed = |(_, (back, front)): &(usize, (&C, &C))| back != front;
+            let mut pixels = back.iter().zip(front).enumerate();
+
+            if let Some((left, _)) = pixels.find(changed) {
+                let right = pixels.rev().find(changed).map_or(left, |(right, _)| right);
+
+                return Some(RowSpan {
+                    y,
+                    left: left as u32,
+                    right: right as u32,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Horizontal band of a display being rendered by [`render_in_bands`](./fn.render_in_bands.html)
+///
+/// Items are drawn to a band in display coordinates. Pixels outside the band are dropped, and the
+/// rest are moved into the band's framebuffer.
+#[derive(Debug)]
+pub struct Band<'a, C> {
+    framebuffer: Framebuffer<'a, C>,
+    top: u32,
+}
+
+impl<'a, C> Band<'a, C>
+where
+    C: PixelColor,
+{
+    /// Top left corner of the band on the display
+    pub fn top_left(&self) -> Coord {
+        Coord::new(0, self.top as i32)
+    }
+
+    /// Bottom right corner of the band on the display, inclusive
+    pub fn bottom_right(&self) -> Coord {
+        let size = self.framebuffer.size();
+
+        Coord::new(size[0] as i32 - 1, (self.top + size[1]) as i32 - 1)
+    }
+
+    /// Draw an item, letting it skip the pixels outside the band without working them out
+    ///
+    /// Each band is drawn to separately, so this saves visiting most of the pixels of tall items.
+    pub fn draw_clipped<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+        T::IntoIter: Clip,
+    {
+        let mut pixels = item.into_iter();
+        pixels.clip(self.top_left(), self.bottom_right());
+
+        self.draw(pixels);
+    }
+}
+
+impl<'a, C> Drawing<C> for Band<'a, C>
+where
+    C: PixelColor,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        let top = self.top;
+
+        self.framebuffer
+            .draw(item.into_iter().filter_map(|Pixel(coord, color)| {
+                let y = coord[1].checked_sub(top)?;
+
+                Some(Pixel(UnsignedCoord::new(coord[0], y), color))
+            }));
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        let top = i64::from(self.top);
+
+        self.framebuffer
+            .fill_runs(runs.into_iter().map(|(start, length, color)| {
+                // Runs above the band are moved to row -1 so the framebuffer drops them
+                let y = (i64::from(start[1]) - top).max(-1) as i32;
+
+                (Coord::new(start[0], y), length, color)
+            }));
+    }
+}
+
+/// Render a display in horizontal bands through a small framebuffer
+///
+/// Devices without enough RAM for a framebuffer covering the whole display can still draw a full
+/// color UI by rendering it a band at a time. The display, which is `size` pixels, is split into
+/// bands as tall as fit in `buffer`. For each band from the top down, the band is cleared to
+/// `background`, `render` is called to draw the whole scene to it, and the band is then drawn to
+/// `display` in one go. The scene is drawn in display coordinates and clipped to each band, so it
+/// doesn't have to know about the bands. Use [`Band::draw_clipped`](./struct.Band.html#method.draw_clipped)
+/// to skip working out pixels of items outside the band.
+///
+/// # Panics
+///
+/// Panics if `buffer` is too small to hold one row of the display.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::framebuffer::render_in_bands;
+/// use embedded_graphics::primitives::Circle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Room for 4 rows of a 24 pixel wide display
+/// let mut buffer = [0u8; 24 * 4];
+///
+/// render_in_bands(&mut display, UnsignedCoord::new(24, 16), &mut buffer, 0, |band| {
+///     band.draw_clipped(Circle::new(Coord::new(12, 8), 6).fill(Some(1u8)));
+/// });
+///
+/// assert_eq!(display.0[8][12], 1);
+/// ```
+pub fn render_in_bands<D, C, F>(
+    display: &mut D,
+    size: UnsignedCoord,
+    buffer: &mut [C],
+    background: C,
+    mut render: F,
+) where
+    D: Drawing<C>,
+    C: PixelColor,
+    F: FnMut(&mut Band<'_, C>),
+{
+    let width = size[0] as usize;
+    assert!(
+        width > 0 && buffer.len() >= width,
+        "Band buffer must hold at least one row"
+    );
+
+    let band_height = (buffer.len() / width) as u32;
+    let mut top = 0;
+
+    while top < size[1] {
+        let height = band_height.min(size[1] - top);
+        let mut band = Band {
+            framebuffer: Framebuffer::new(&mut buffer[..width * height as usize], size[0]),
+            top,
+        };
+
+        band.framebuffer.clear(background);
+        render(&mut band);
+
+        display.draw(
+            band.framebuffer
+                .as_image()
+                .translate(Coord::new(0, top as i32)),
+        );
+
+        top += height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::{Display, MockDisplay};
+    use crate::pixelcolor::{Gray8, Rgb666};
+    use crate::primitives::{Circle, Line, Rectangle};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn draws_inside_bounds() {
+        let mut pixels = [0u8; 10 * 4 + 3];
+        let mut framebuffer = Framebuffer::new(&mut pixels, 10);
+        assert_eq!(framebuffer.size(), UnsignedCoord::new(10, 4));
+
+        framebuffer.draw(Rectangle::new(Coord::new(8, 2), Coord::new(12, 6)).fill(Some(1u8)));
+
+        assert_eq!(framebuffer.pixel(UnsignedCoord::new(9, 3)), Some(1));
+        assert_eq!(framebuffer.pixel(UnsignedCoord::new(10, 3)), None);
+        assert_eq!(framebuffer.row(4), None);
+        assert_eq!(
+            framebuffer.as_slice().iter().filter(|&&p| p == 1).count(),
+            4
+        );
+        assert_eq!(pixels[40..], [0, 0, 0]);
+    }
+
+    /// Framebuffer with a distinct value in every pixel
+    fn numbered() -> [u8; 6 * 5] {
+        let mut pixels = [0u8; 6 * 5];
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = i as u8 + 1;
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn overlapping_copies() {
+        for &(dx, dy) in &[(1, 1), (-1, -1), (2, -1), (-2, 1), (0, 2), (3, 0)] {
+            let mut pixels = numbered();
+            let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+            framebuffer.copy_region(
+                Coord::new(1, 1),
+                Coord::new(4, 3),
+                Coord::new(1 + dx, 1 + dy),
+            );
+
+            let original = numbered();
+            for y in 0..5i32 {
+                for x in 0..6i32 {
+                    let (sx, sy) = (x - dx, y - dy);
+                    let expected = if (1..=4).contains(&sx) && (1..=3).contains(&sy) {
+                        original[(sy * 6 + sx) as usize]
+                    } else {
+                        original[(y * 6 + x) as usize]
+                    };
+
+                    assert_eq!(
+                        framebuffer.pixel(UnsignedCoord::new(x as u32, y as u32)),
+                        Some(expected),
+                        "offset ({}, {}) pixel ({}, {})",
+                        dx,
+                        dy,
+                        x,
+                        y
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn copy_clips_source_and_destination() {
+        let mut pixels = numbered();
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        framebuffer.copy_region(Coord::new(-1, -1), Coord::new(1, 1), Coord::new(3, 2));
+        framebuffer.copy_region(Coord::new(0, 0), Coord::new(2, 0), Coord::new(4, 0));
+        framebuffer.copy_region(Coord::new(0, 0), Coord::new(5, 4), Coord::new(10, 10));
+
+        let original = numbered();
+        let mut expected = original;
+        expected[3 * 6 + 4] = original[0];
+        expected[3 * 6 + 5] = original[1];
+        expected[4 * 6 + 4] = original[6];
+        expected[4 * 6 + 5] = original[7];
+        expected[4] = original[0];
+        expected[5] = original[1];
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn scroll_fills_uncovered_area() {
+        let mut pixels = numbered();
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+        framebuffer.scroll(-2, 1, 0);
+
+        let original = numbered();
+        for y in 0..5 {
+            for x in 0..6 {
+                let expected = if x >= 4 || y == 0 {
+                    0
+                } else {
+                    original[(y - 1) * 6 + x + 2]
+                };
+
+                assert_eq!(pixels[y * 6 + x], expected, "pixel ({}, {})", x, y);
+            }
+        }
+
+        let mut pixels = numbered();
+        Framebuffer::new(&mut pixels, 6).scroll(100, -100, 9);
+        assert!(pixels.iter().all(|&p| p == 9));
+    }
+
+    #[test]
+    fn windows_use_stride() {
+        let mut pixels = numbered();
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        {
+            let mut view = framebuffer.window(UnsignedCoord::new(1, 1), UnsignedCoord::new(3, 10));
+            assert_eq!(view.size(), UnsignedCoord::new(3, 4));
+            assert_eq!(view.pixel(UnsignedCoord::new(0, 0)), Some(8));
+            assert_eq!(view.pixel(UnsignedCoord::new(3, 0)), None);
+
+            view.draw(Line::new(Coord::new(-1, 1), Coord::new(5, 1)).stroke(Some(0u8)));
+
+            let mut inner = view.window(UnsignedCoord::new(1, 2), UnsignedCoord::new(5, 5));
+            assert_eq!(inner.size(), UnsignedCoord::new(2, 2));
+            inner.clear(50);
+        }
+
+        let original = numbered();
+        for y in 0..5 {
+            for x in 0..6 {
+                let expected = match (x, y) {
+                    (1..=3, 2) => 0,
+                    (2..=3, 3..=4) => 50,
+                    _ => original[y * 6 + x],
+                };
+
+                assert_eq!(pixels[y * 6 + x], expected, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_windows() {
+        let mut pixels = numbered();
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        let mut view = framebuffer.window(UnsignedCoord::new(7, 2), UnsignedCoord::new(3, 3));
+        assert_eq!(view.size(), UnsignedCoord::new(0, 0));
+        view.clear(0);
+        view.draw(Rectangle::new(Coord::new(0, 0), Coord::new(5, 5)).fill(Some(0u8)));
+
+        assert_eq!(pixels, numbered());
+    }
+
+    #[test]
+    fn draws_as_image() {
+        let mut pixels = numbered();
+        let framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        let mut display = Display::default();
+        display.draw(framebuffer.as_image().translate(Coord::new(10, 3)));
+
+        let original = numbered();
+        for (y, row) in original.chunks(6).enumerate() {
+            assert_eq!(display.0[y + 3][10..16], *row);
+        }
+        assert_eq!(display.0[2], [0; 24]);
+        assert_eq!(display.0[8], [0; 24]);
+    }
+
+    #[test]
+    fn sub_images() {
+        let mut pixels = numbered();
+        let framebuffer = Framebuffer::new(&mut pixels, 6);
+        let image = framebuffer.as_image().translate(Coord::new(-1, 0));
+
+        let sub = image.sub_image(UnsignedCoord::new(2, 1), UnsignedCoord::new(10, 2));
+        assert_eq!(sub.size(), UnsignedCoord::new(4, 2));
+        assert_eq!(sub.top_left(), Coord::new(-1, 0));
+
+        let mut display = Display::default();
+        display.draw(sub);
+        assert_eq!(display.0[0][..4], [10, 11, 12, 0]);
+        assert_eq!(display.0[1][..4], [16, 17, 18, 0]);
+        assert_eq!(display.0[2][..4], [0, 0, 0, 0]);
+
+        let empty = image.sub_image(UnsignedCoord::new(6, 0), UnsignedCoord::new(2, 2));
+        assert_eq!(empty.size(), UnsignedCoord::new(0, 0));
+        assert_eq!(empty.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn fill_solid_matches_rectangle() {
+        for &(tl, br) in &[
+            ((1, 1), (4, 3)),
+            ((-3, 2), (2, 9)),
+            ((5, 4), (5, 4)),
+            ((0, -1), (4, 0)),
+            ((6, 0), (9, 3)),
+        ] {
+            let (tl, br) = (Coord::new(tl.0, tl.1), Coord::new(br.0, br.1));
+
+            let mut pixels = numbered();
+            Framebuffer::new(&mut pixels, 6).fill_solid(tl, br, 0);
+
+            let mut expected = numbered();
+            Framebuffer::new(&mut expected, 6).draw(Rectangle::new(tl, br).fill(Some(0u8)));
+
+            assert_eq!(pixels, expected, "{:?} to {:?}", tl, br);
+
+            let mut bytes = [0u8; 20 * 5];
+            let mut framebuffer =
+                ByteFramebuffer::<Rgb666>::new(&mut bytes, 6, 5, 20, ByteOrder::BigEndian).unwrap();
+            framebuffer.fill_solid(tl, br, Rgb666(0x12345));
+
+            let mut expected = [0u8; 20 * 5];
+            ByteFramebuffer::<Rgb666>::new(&mut expected, 6, 5, 20, ByteOrder::BigEndian)
+                .unwrap()
+                .draw(Rectangle::new(tl, br).fill(Some(Rgb666(0x12345))));
+
+            assert_eq!(bytes, expected, "{:?} to {:?}", tl, br);
+        }
+    }
+
+    #[test]
+    fn fill_contiguous_skips_clipped_colors() {
+        let mut pixels = [0u8; 6 * 5];
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        framebuffer.fill_contiguous(Coord::new(-1, -1), UnsignedCoord::new(3, 3), 1..);
+        framebuffer.fill_contiguous(Coord::new(4, 3), UnsignedCoord::new(3, 3), 20..);
+        framebuffer.fill_contiguous(Coord::new(0, 4), UnsignedCoord::new(3, 1), 30..32);
+
+        assert_eq!(
+            pixels,
+            [
+                5, 6, 0, 0, 0, 0, //
+                8, 9, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 20, 21, //
+                30, 31, 0, 0, 23, 24, //
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_runs() {
+        let mut pixels = [0u8; 6 * 5];
+        let mut framebuffer = Framebuffer::new(&mut pixels, 6);
+
+        framebuffer.fill_runs(
+            [
+                (Coord::new(-2, 0), 4, 1),
+                (Coord::new(4, 1), 10, 2),
+                (Coord::new(1, -1), 3, 3),
+                (Coord::new(2, 4), 0, 4),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        let mut view = framebuffer.window(UnsignedCoord::new(1, 2), UnsignedCoord::new(3, 2));
+        view.fill_runs(
+            [(Coord::new(-1, 0), 10, 5), (Coord::new(2, 1), 1, 6)]
+                .iter()
+                .cloned(),
+        );
+
+        assert_eq!(
+            pixels,
+            [
+                1, 1, 0, 0, 0, 0, //
+                0, 0, 0, 0, 2, 2, //
+                0, 5, 5, 5, 0, 0, //
+                0, 0, 0, 6, 0, 0, //
+                0, 0, 0, 0, 0, 0, //
+            ]
+        );
+    }
+
+    #[test]
+    fn bands_match_drawing() {
+        let circle = Circle::new(Coord::new(12, 8), 7)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+        let runs = [(Coord::new(-2, 4), 10, 3u8), (Coord::new(5, 15), 30, 4)];
+
+        let mut expected = Display::default();
+        expected.draw(circle);
+        expected.fill_runs(runs.iter().cloned());
+
+        let mut display = Display::default();
+        let mut buffer = [0u8; 24 * 5 + 7];
+        let mut bands = 0;
+        render_in_bands(
+            &mut display,
+            UnsignedCoord::new(24, 16),
+            &mut buffer,
+            0,
+            |band| {
+                assert_eq!(band.top_left(), Coord::new(0, bands * 5));
+                bands += 1;
+
+                band.draw_clipped(circle);
+                band.fill_runs(runs.iter().cloned());
+            },
+        );
+
+        assert_eq!(bands, 4);
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn band_buffer_too_small() {
+        let mut display = Display::default();
+
+        render_in_bands(
+            &mut display,
+            UnsignedCoord::new(24, 16),
+            &mut [0u8; 20],
+            0,
+            |_| {},
+        );
+    }
+
+    #[test]
+    fn byte_framebuffer_round_trip() {
+        let mut bytes = [0xaau8; 10 * 3];
+        let mut framebuffer =
+            ByteFramebuffer::<Rgb666>::new(&mut bytes, 3, 3, 10, ByteOrder::LittleEndian).unwrap();
+        assert_eq!(ByteFramebuffer::<Rgb666>::bytes_per_pixel(), 3);
+
+        framebuffer.clear(Rgb666(0));
+        framebuffer
+            .draw(Rectangle::new(Coord::new(2, 1), Coord::new(4, 4)).fill(Some(Rgb666(0x3f001))));
+
+        assert_eq!(
+            framebuffer.pixel(UnsignedCoord::new(2, 2)),
+            Some(Rgb666(0x3f001))
+        );
+        assert_eq!(framebuffer.pixel(UnsignedCoord::new(1, 2)), Some(Rgb666(0)));
+        assert_eq!(framebuffer.pixel(UnsignedCoord::new(3, 2)), None);
+        assert_eq!(framebuffer.row_bytes(1).unwrap()[6..], [0x01, 0xf0, 0x03]);
+        assert_eq!(framebuffer.row_bytes(3), None);
+
+        // Padding is left alone
+        assert_eq!(bytes[9], 0xaa);
+        assert_eq!(bytes[19], 0xaa);
+
+        let new = |len, stride| {
+            let mut bytes = [0u8; 6];
+            ByteFramebuffer::<Gray8>::new(&mut bytes[..len], 3, 2, stride, ByteOrder::BigEndian)
+                .map(|framebuffer| framebuffer.size())
+        };
+        assert_eq!(new(6, 2), Err(FramebufferError::StrideTooSmall));
+        assert_eq!(new(5, 3), Err(FramebufferError::BufferTooSmall));
+        assert_eq!(new(6, 3), Ok(UnsignedCoord::new(3, 2)));
+    }
+
+    #[test]
+    fn binary_framebuffer_polarity() {
+        let circle = Circle::new(Coord::new(8, 6), 5).fill(Some(BinaryColor::On));
+        let mut expected = MockDisplay::default();
+        expected.draw(circle);
+
+        let (mut high, mut low) = ([0u8; 3 * 16], [0u8; 3 * 16]);
+        for (bytes, polarity) in [
+            (&mut high, Polarity::ActiveHigh),
+            (&mut low, Polarity::ActiveLow),
+        ]
+        .iter_mut()
+        {
+            let mut framebuffer = BinaryFramebuffer::new(*bytes, 24, *polarity);
+            framebuffer.clear(BinaryColor::Off);
+            framebuffer.draw(circle);
+
+            assert_eq!(framebuffer.size(), UnsignedCoord::new(24, 16));
+            assert_eq!(
+                framebuffer.pixel(UnsignedCoord::new(8, 6)),
+                Some(BinaryColor::On)
+            );
+            assert_eq!(
+                framebuffer.pixel(UnsignedCoord::new(0, 0)),
+                Some(BinaryColor::Off)
+            );
+            assert_eq!(framebuffer.pixel(UnsignedCoord::new(24, 0)), None);
+
+            let mut display = MockDisplay::default();
+            display.draw(&framebuffer.as_image());
+            assert_eq!(display, expected);
+        }
+
+        assert!(high.iter().zip(low.iter()).all(|(h, l)| *h == !*l));
+    }
+
+    #[test]
+    fn changed_pixels_and_rows() {
+        let (mut front, mut back) = ([0u8; 16 * 8], [0u8; 16 * 8]);
+        let mut framebuffer = DoubleFramebuffer::new(&mut front, &mut back, 16);
+
+        framebuffer.draw(Line::new(Coord::new(2, 1), Coord::new(5, 1)).stroke(Some(1u8)));
+        framebuffer.draw(Rectangle::new(Coord::new(9, 4), Coord::new(9, 4)).fill(Some(2u8)));
+        framebuffer.draw(Rectangle::new(Coord::new(13, 4), Coord::new(13, 4)).fill(Some(3u8)));
+
+        let mut pixels = framebuffer.changed_pixels();
+        assert_eq!(pixels.next(), Some(Pixel(UnsignedCoord::new(2, 1), 1)));
+        assert_eq!(pixels.nth(3), Some(Pixel(UnsignedCoord::new(9, 4), 2)));
+        assert_eq!(pixels.next(), Some(Pixel(UnsignedCoord::new(13, 4), 3)));
+        assert_eq!(pixels.next(), None);
+
+        let mut rows = framebuffer.changed_rows();
+        assert_eq!(
+            rows.next(),
+            Some(RowSpan {
+                y: 1,
+                left: 2,
+                right: 5
+            })
+        );
+        assert_eq!(
+            rows.next(),
+            Some(RowSpan {
+                y: 4,
+                left: 9,
+                right: 13
+            })
+        );
+        assert_eq!(rows.next(), None);
+
+        framebuffer.present();
+        assert_eq!(framebuffer.changed_pixels().count(), 0);
+        assert_eq!(
+            framebuffer.front().as_slice(),
+            framebuffer.back().as_slice()
+        );
+    }
+
+    #[test]
+    fn only_differences_are_reported() {
+        let (mut front, mut back) = ([0u8; 24 * 16], [0u8; 24 * 16]);
+        let mut framebuffer = DoubleFramebuffer::new(&mut front, &mut back, 24);
+
+        let circle = Circle::new(Coord::new(10, 8), 5).fill(Some(1u8));
+        framebuffer.draw(circle);
+        framebuffer.present();
+
+        // Drawing the same thing again changes nothing
+        framebuffer.draw(circle);
+        assert_eq!(framebuffer.changed_rows().count(), 0);
+
+        framebuffer.draw(circle.translate(Coord::new(1, 0)));
+        for Pixel(p, _) in framebuffer.changed_pixels() {
+            assert_ne!(framebuffer.front().pixel(p), framebuffer.back().pixel(p));
+        }
+        assert!(framebuffer
+            .changed_rows()
+            .all(|span| span.left >= 5 && span.right <= 16));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::coord::Coord;
 use crate::drawable::Dimensions;
 use crate::drawable::Drawable;
-use crate::pixelcolor::PixelColor;
+use crate::pixelcolor::{PixelColor, Polarity};
 use crate::transform::Transform;
 use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 use core::marker::PhantomData;
@@ -28,6 +28,12 @@ where
     /// Image offset in pixels from screen origin (0,0)
     pub offset: Coord,
 
+    /// Raw pixel value that isn't drawn
+    pub(crate) color_key: Option<u16>,
+
+    /// How the bits of an `Image1BPP` map to colors
+    pub(crate) polarity: Polarity,
+
     pixel_type: PhantomData<C>,
     image_type: PhantomData<T>,
 }
@@ -44,10 +50,45 @@ where
             height,
             imagedata,
             offset: Coord::new(0, 0),
+            color_key: None,
+            polarity: Polarity::ActiveHigh,
             pixel_type: PhantomData,
             image_type: PhantomData,
         }
     }
+
+    /// Skip every pixel with the raw value `key` in the image data instead of drawing it
+    ///
+    /// This makes one value transparent, so a sprite can be drawn without its background. The
+    /// key is compared with the value of each pixel before it is converted to a color: a bit for
+    /// [`Image1BPP`](./type.Image1BPP.html), a byte for [`Image8BPP`](./type.Image8BPP.html) and a
+    /// little endian `u16` for [`Image16BPP`](./type.Image16BPP.html).
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::Image16BPP;
+    /// use embedded_graphics::pixelcolor::Rgb565;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay([[Rgb565(0x1234); 24]; 16]);
+    ///
+    /// // 2x1 image with a magenta first pixel
+    /// let sprite: Image16BPP<Rgb565> =
+    ///     Image16BPP::new(&[0x1f, 0xf8, 0xe0, 0x07], 2, 1).with_color_key(0xf81f);
+    ///
+    /// display.draw(&sprite);
+    ///
+    /// assert_eq!(display.0[0][..2], [Rgb565(0x1234), Rgb565(0x07e0)]);
+    /// ```
+    pub fn with_color_key(mut self, key: u16) -> Self {
+        self.color_key = Some(key);
+
+        self
+    }
+
+    /// Whether the raw pixel value `value` is drawn
+    pub(crate) fn is_opaque(&self, value: u16) -> bool {
+        self.color_key != Some(value)
+    }
 }
 
 impl<'a, C, T> Dimensions for Image<'a, C, T>
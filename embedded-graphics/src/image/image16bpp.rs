@@ -93,7 +93,7 @@ where
                 self.y += 1;
             }
 
-            if current_pixel[0] >= 0 && current_pixel[1] >= 0 {
+            if current_pixel[0] >= 0 && current_pixel[1] >= 0 && self.im.is_opaque(bit_value) {
                 break Pixel(current_pixel.to_unsigned(), bit_value.into());
             }
         };
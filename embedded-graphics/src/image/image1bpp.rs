@@ -1,7 +1,7 @@
 use super::super::drawable::*;
 use super::image::{Image, ImageIterator, ImageType};
 use crate::coord::{Coord, ToUnsigned};
-use crate::pixelcolor::PixelColor;
+use crate::pixelcolor::{PixelColor, Polarity};
 
 /// # 1 bit per pixel image
 ///
@@ -43,6 +43,41 @@ pub enum ImageType1BPP {}
 
 impl ImageType for ImageType1BPP {}
 
+impl<'a, C> Image1BPP<'a, C>
+where
+    C: PixelColor,
+{
+    /// Decode the image data with the given polarity
+    ///
+    /// Images are [`ActiveHigh`](../pixelcolor/enum.Polarity.html#variant.ActiveHigh) by default,
+    /// so a `1` bit is converted to `C::from(1)`. With `ActiveLow` data, like a buffer read back
+    /// from an e-paper panel, a `0` bit is converted to `C::from(1)` instead. A color key still
+    /// compares against the raw bit.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::image::Image1BPP;
+    /// use embedded_graphics::pixelcolor::{BinaryColor, Polarity};
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let image: Image1BPP<BinaryColor> =
+    ///     Image1BPP::new(&[0b0111_0000], 4, 1).with_polarity(Polarity::ActiveLow);
+    ///
+    /// display.draw(&image);
+    ///
+    /// assert_eq!(
+    ///     display.0[0][..4],
+    ///     [BinaryColor::On, BinaryColor::Off, BinaryColor::Off, BinaryColor::Off]
+    /// );
+    /// ```
+    pub fn with_polarity(mut self, polarity: Polarity) -> Self {
+        self.polarity = polarity;
+
+        self
+    }
+}
+
 impl<'a, C> IntoIterator for &'a Image1BPP<'a, C>
 where
     C: PixelColor,
@@ -103,8 +138,16 @@ where
                 self.y += 1;
             }
 
-            if current_pixel[0] >= 0 && current_pixel[1] >= 0 {
-                break Pixel(current_pixel.to_unsigned(), bit_value.into());
+            if current_pixel[0] >= 0
+                && current_pixel[1] >= 0
+                && self.im.is_opaque(u16::from(bit_value))
+            {
+                let value = match self.im.polarity {
+                    Polarity::ActiveHigh => bit_value,
+                    Polarity::ActiveLow => bit_value ^ 1,
+                };
+
+                break Pixel(current_pixel.to_unsigned(), value.into());
             }
         };
 
@@ -137,4 +180,26 @@ mod tests {
         assert_eq!(image.bottom_right(), Coord::new(104, 204));
         assert_eq!(image.size(), UnsignedCoord::new(4, 4));
     }
+
+    #[test]
+    fn active_low() {
+        let data = [0b1010_0000, 0b0101_0000];
+        let high: Image1BPP<u8> = Image1BPP::new(&data, 4, 2);
+        let low: Image1BPP<u8> = Image1BPP::new(&data, 4, 2).with_polarity(Polarity::ActiveLow);
+
+        assert!(high
+            .into_iter()
+            .zip(low.into_iter())
+            .all(|(Pixel(a, high), Pixel(b, low))| a == b && low == high ^ 1));
+
+        // The color key is compared with the raw bit, not the decoded value
+        let keyed: Image1BPP<u8> = Image1BPP::new(&data, 4, 2)
+            .with_polarity(Polarity::ActiveLow)
+            .with_color_key(1);
+
+        assert!(keyed
+            .into_iter()
+            .map(|Pixel(_, c)| c)
+            .eq([1, 1, 1, 1].iter().cloned()));
+    }
 }
@@ -88,7 +88,10 @@ where
                 self.y += 1;
             }
 
-            if current_pixel[0] >= 0 && current_pixel[1] >= 0 {
+            if current_pixel[0] >= 0
+                && current_pixel[1] >= 0
+                && self.im.is_opaque(u16::from(bit_value))
+            {
                 break Pixel(current_pixel.to_unsigned(), bit_value.into());
             }
         };
@@ -148,4 +151,22 @@ mod tests {
 
         assert_eq!(it.next(), None);
     }
+
+    #[test]
+    fn color_key_skips_pixels() {
+        let image: Image8BPP<u8> = Image8BPP::new(
+            &[0xff, 0x00, 0xbb, 0x00, 0xcc, 0x00, 0xee, 0x00, 0xaa],
+            3,
+            3,
+        )
+        .with_color_key(0x00)
+        .translate(Coord::new(-1, 0));
+        let mut it = image.into_iter();
+
+        assert_eq!(it.next(), Some(Pixel(UnsignedCoord::new(1, 0), 0xbb_)));
+        assert_eq!(it.next(), Some(Pixel(UnsignedCoord::new(0, 1), 0xcc_)));
+        assert_eq!(it.next(), Some(Pixel(UnsignedCoord::new(1, 2), 0xaa_)));
+
+        assert_eq!(it.next(), None);
+    }
 }
@@ -72,6 +72,8 @@
 //! objects rendered by embedded_graphics.
 //! * `bmp` - use the [TinyBMP](https://crates.io/crates/tinybmp) crate for BMP image support.
 //! * `tga` - use the [TinyTGA](https://crates.io/crates/tinytga) crate for TGA image support.
+//! * `async` - add the [`AsyncDrawing`](./async_drawing/trait.AsyncDrawing.html) trait for display
+//!   drivers that draw asynchronously.
 //!
 //! # Examples
 //!
@@ -158,19 +160,29 @@
 #[cfg(feature = "nalgebra_support")]
 extern crate nalgebra;
 
+pub mod adapter;
+#[cfg(feature = "async")]
+pub mod async_drawing;
+pub mod chart;
+pub mod compositor;
 pub mod coord;
 pub mod drawable;
 pub mod fonts;
+pub mod framebuffer;
 pub mod image;
 #[doc(hidden)]
 pub mod mock_display;
 pub mod pixelcolor;
 pub mod prelude;
 pub mod primitives;
+pub mod raster;
+pub mod recording;
 pub mod style;
 pub mod transform;
+mod trig;
 pub mod unsignedcoord;
 
+use crate::coord::Coord;
 use crate::drawable::Dimensions;
 use crate::pixelcolor::PixelColor;
 
@@ -246,6 +258,54 @@ where
     fn draw<T>(&mut self, item: T)
     where
         T: IntoIterator<Item = drawable::Pixel<C>>;
+
+    /// Draw horizontal runs of pixels, each given as its leftmost point, its length in pixels and
+    /// its color
+    ///
+    /// Rasterizers that fill shapes a scanline at a time can pass their spans here instead of one
+    /// pixel at a time. The default implementation draws every pixel of each run with
+    /// [`draw`](#tymethod.draw), skipping pixels with negative coordinates. Drivers that can set a
+    /// window of the display and stream a color into it should override this method to send each run
+    /// as a single window write.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// display.fill_runs([(Coord::new(-2, 1), 5, 1u8), (Coord::new(4, 2), 2, 2u8)].iter().cloned());
+    ///
+    /// assert_eq!(display.0[1][..4], [1, 1, 1, 0]);
+    /// assert_eq!(display.0[2][3..7], [0, 2, 2, 0]);
+    /// ```
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        self.draw(
+            runs.into_iter()
+                .filter(|(start, _, _)| start[1] >= 0)
+                .flat_map(|(start, length, color)| {
+                    let y = start[1] as u32;
+                    let left = i64::from(start[0]).max(0);
+                    let right = (i64::from(run_end(start, length)[0]) + 1).max(left);
+
+                    (left..right).map(move |x| {
+                        drawable::Pixel(unsignedcoord::UnsignedCoord::new(x as u32, y), color)
+                    })
+                }),
+        );
+    }
+}
+
+/// Rightmost point of a run of `length` pixels starting at `start`, saturated to the range of
+/// `Coord`
+///
+/// A run of length 0 ends one pixel to the left of its start.
+pub(crate) fn run_end(start: Coord, length: u32) -> Coord {
+    let right = i64::from(start[0]) + i64::from(length) - 1;
+
+    Coord::new(right.min(i64::from(i32::MAX)) as i32, start[1])
 }
 
 /// Very similar to the [`Drawing`] trait, but accepts drawable objects which have a known size
@@ -1,5 +1,9 @@
+#[cfg(feature = "async")]
+use crate::async_drawing;
 use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::BinaryColor;
 use crate::prelude::*;
+use crate::raster::ReadPixel;
 use crate::{Drawing, SizedDrawing};
 
 /// Mock display for use in tests and some doc examples. Do not use directly!
@@ -24,6 +28,16 @@ impl MockDisplay<u32> {
     }
 }
 
+impl<P> MockDisplay<P>
+where
+    P: PartialEq + Copy,
+{
+    /// Number of pixels set to `color`
+    pub fn count(&self, color: P) -> usize {
+        self.0.iter().flatten().filter(|&&p| p == color).count()
+    }
+}
+
 impl Default for MockDisplay<u8> {
     fn default() -> Self {
         MockDisplay::<u8>::new([[0; 24]; 16])
@@ -42,6 +56,12 @@ impl Default for MockDisplay<u32> {
     }
 }
 
+impl Default for MockDisplay<BinaryColor> {
+    fn default() -> Self {
+        Self([[BinaryColor::Off; 24]; 16])
+    }
+}
+
 impl<P> Drawing<P> for MockDisplay<P>
 where
     P: PixelColor,
@@ -59,6 +79,31 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<P> async_drawing::AsyncDrawing<P> for MockDisplay<P>
+where
+    P: PixelColor,
+{
+    async fn draw<T>(&mut self, item_pixels: T)
+    where
+        T: IntoIterator<Item = Pixel<P>>,
+    {
+        Drawing::draw(self, item_pixels);
+    }
+}
+
+impl<P> ReadPixel<P> for MockDisplay<P>
+where
+    P: PixelColor,
+{
+    fn pixel(&self, point: UnsignedCoord) -> Option<P> {
+        self.0
+            .get(point[1] as usize)
+            .and_then(|row| row.get(point[0] as usize))
+            .copied()
+    }
+}
+
 impl<P> SizedDrawing<P> for MockDisplay<P>
 where
     P: PixelColor,
@@ -0,0 +1,111 @@
+//! ARGB8888 pixel type
+
+use crate::pixelcolor::PixelColor;
+
+/// A pixel type defining the 32 bit ARGB8888 format with an alpha channel
+///
+/// This is the same as [`Rgba8888`](./struct.Rgba8888.html) with the alpha channel stored in the
+/// most significant byte. Pixel values are stored in a single `u32` segmented as follows:
+///
+/// ```text
+/// 31 ... 24  23 ... 16  15 ... 8  7 ... 0
+///  a ...  a   r ...  r   g ... g  b ... b
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Argb8888(pub u32);
+
+impl Argb8888 {
+    /// Create a new color from 8 bit red, green, blue and alpha channels
+    ///
+    /// The alpha channel is the last argument, the same as for
+    /// [`Rgba8888::new`](./struct.Rgba8888.html#method.new).
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(u32::from_be_bytes([a, r, g, b]))
+    }
+
+    /// Get the red component as a `u8`
+    pub const fn r(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Get the green component as a `u8`
+    pub const fn g(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Get the blue component as a `u8`
+    pub const fn b(&self) -> u8 {
+        self.0 as u8
+    }
+
+    /// Get the alpha component as a `u8`
+    pub const fn a(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+}
+
+impl PixelColor for Argb8888 {}
+
+/// Convert from an 8 bit greyscale colour into a fully opaque grey
+impl From<u8> for Argb8888 {
+    fn from(other: u8) -> Self {
+        Self::from((other, other, other))
+    }
+}
+
+/// Create a pixel from a raw ARGB8888 value
+impl From<u32> for Argb8888 {
+    fn from(other: u32) -> Self {
+        Self(other)
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values and convert them to a fully opaque
+/// color
+impl From<(u8, u8, u8)> for Argb8888 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::from((r, g, b, 0xff))
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue, alpha)` color values and convert them to a single
+/// 32 bit color
+///
+/// Note that the tuple has the alpha channel last, the same as for
+/// [`Rgba8888`](./struct.Rgba8888.html).
+impl From<(u8, u8, u8, u8)> for Argb8888 {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+/// Build a `u32` from four big endian `u8`s in `[a, r, g, b]` order
+impl From<[u8; 4]> for Argb8888 {
+    fn from(other: [u8; 4]) -> Self {
+        Self(u32::from_be_bytes(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Argb8888::from((0x12, 0x34, 0x56)), Argb8888(0xff12_3456));
+        assert_eq!(
+            Argb8888::from((0x12, 0x34, 0x56, 0x78)),
+            Argb8888(0x7812_3456)
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Argb8888(0x1234_5678);
+
+        assert_eq!(p.a(), 0x12);
+        assert_eq!(p.r(), 0x34);
+        assert_eq!(p.g(), 0x56);
+        assert_eq!(p.b(), 0x78);
+    }
+}
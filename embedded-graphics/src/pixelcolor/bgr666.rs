@@ -0,0 +1,127 @@
+//! BGR666 pixel type
+
+use crate::pixelcolor::PixelColor;
+
+const BLUE_MASK: u32 = 0b111111_000000_000000;
+const GREEN_MASK: u32 = 0b000000_111111_000000;
+const RED_MASK: u32 = 0b000000_000000_111111;
+
+/// A pixel type defining the 18 bit BGR666 format
+///
+/// This is the same as [`Rgb666`](./struct.Rgb666.html) with the red and blue channels swapped,
+/// as used by controllers wired in BGR mode. Pixel values are stored in the lower 18 bits of a
+/// `u32` segmented as follows:
+///
+/// ```text
+/// 17 16 15 14 13 12 11 10  9  8  7  6  5  4  3  2  1  0
+///  b  b  b  b  b  b  g  g  g  g  g  g  r  r  r  r  r  r
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bgr666(pub u32);
+
+impl Bgr666 {
+    /// Create a new color from 8 bit red, green and blue channels
+    ///
+    /// The arguments are in RGB order, the same as for `From<(u8, u8, u8)>`. The 2 least
+    /// significant bits of each channel are discarded.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        let value =
+        // Blue
+        (((b as u32) << 10) & BLUE_MASK)
+        // Green
+        | (((g as u32) << 4) & GREEN_MASK)
+        // Red
+        | ((r as u32) >> 2 & RED_MASK);
+
+        Self(value)
+    }
+
+    /// Get the red component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn r(&self) -> u8 {
+        ((self.0 & RED_MASK) << 2) as u8
+    }
+
+    /// Get the green component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn g(&self) -> u8 {
+        ((self.0 & GREEN_MASK) >> 4) as u8
+    }
+
+    /// Get the blue component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn b(&self) -> u8 {
+        ((self.0 & BLUE_MASK) >> 10) as u8
+    }
+}
+
+impl PixelColor for Bgr666 {}
+
+/// Convert from an 8 bit greyscale colour into an 18 bit greyscale representation
+///
+/// The 6 most significant bits are taken from the input and assigned to all three colour channels.
+impl From<u8> for Bgr666 {
+    fn from(other: u8) -> Self {
+        Self::from((other, other, other))
+    }
+}
+
+/// Create a pixel from a raw 18 bit value
+///
+/// Any bits above bit 17 are discarded.
+impl From<u32> for Bgr666 {
+    fn from(other: u32) -> Self {
+        Self(other & (BLUE_MASK | GREEN_MASK | RED_MASK))
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values and convert them to a single 18 bit
+/// color
+///
+/// Note that the tuple is in RGB order, not BGR. The 2 least significant bits of each channel are
+/// discarded.
+impl From<(u8, u8, u8)> for Bgr666 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+/// Build an 18 bit value from three big endian `u8`s
+///
+/// Any bits above bit 17 are discarded.
+impl From<[u8; 3]> for Bgr666 {
+    fn from(other: [u8; 3]) -> Self {
+        Self::from(u32::from_be_bytes([0, other[0], other[1], other[2]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Bgr666::from(0u8), Bgr666(0));
+        assert_eq!(Bgr666::from(0xffu8), Bgr666(0x3ffff));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(
+            Bgr666::from((0xff, 0x0f, 0b0101_0101)),
+            Bgr666(0b010101_000011_111111)
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Bgr666(0b110011_010101_100101);
+
+        assert_eq!(p.r(), 0b100101_00);
+        assert_eq!(p.g(), 0b010101_00);
+        assert_eq!(p.b(), 0b110011_00);
+    }
+}
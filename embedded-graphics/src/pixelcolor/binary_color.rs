@@ -0,0 +1,247 @@
+//! Binary (on/off) pixel type
+
+use crate::pixelcolor::conversion::Luma;
+use crate::pixelcolor::PixelColor;
+
+/// A pixel type for monochrome displays where each pixel is either on or off
+///
+/// Colors can be converted into a `BinaryColor` with `From`, which turns on any pixel with a
+/// [luma](./conversion/trait.Luma.html) of at least
+/// [`DEFAULT_THRESHOLD`](#associatedconstant.DEFAULT_THRESHOLD). Use
+/// [`from_color_with_threshold`](#method.from_color_with_threshold) to pick a different cutoff,
+/// e.g. to keep dark UI themes legible on a monochrome OLED.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::{BinaryColor, Rgb565};
+///
+/// let dark_grey = Rgb565::from((40, 40, 40));
+///
+/// assert_eq!(BinaryColor::from(dark_grey), BinaryColor::Off);
+/// assert_eq!(
+///     BinaryColor::from_color_with_threshold(dark_grey, 32),
+///     BinaryColor::On
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinaryColor {
+    /// Pixel is off
+    #[default]
+    Off,
+
+    /// Pixel is on
+    On,
+}
+
+impl BinaryColor {
+    /// The luma level at or above which a converted color is on
+    pub const DEFAULT_THRESHOLD: u8 = 128;
+
+    /// Convert a color into a `BinaryColor`, turning the pixel on if its luma is at least
+    /// `threshold`
+    pub fn from_color_with_threshold<C>(color: C, threshold: u8) -> Self
+    where
+        C: Luma,
+    {
+        if color.luma() >= threshold {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        }
+    }
+
+    /// Get the opposite color
+    pub const fn invert(self) -> Self {
+        match self {
+            BinaryColor::On => BinaryColor::Off,
+            BinaryColor::Off => BinaryColor::On,
+        }
+    }
+
+    /// Return `off` if the pixel is off and `on` if it's on
+    ///
+    /// This is useful for displays where on pixels are dark, or for drawing monochrome assets in
+    /// arbitrary colors.
+    ///
+    /// ```rust
+    /// use embedded_graphics::pixelcolor::{BinaryColor, Gray4};
+    ///
+    /// // E-paper: on pixels are black ink
+    /// assert_eq!(BinaryColor::On.select(Gray4::WHITE, Gray4::BLACK), Gray4::BLACK);
+    /// ```
+    pub fn select<C>(self, off: C, on: C) -> C {
+        match self {
+            BinaryColor::Off => off,
+            BinaryColor::On => on,
+        }
+    }
+
+    /// Create a color from a raw bit, where `bit` is `0` or `1`
+    ///
+    /// Any non-zero value is treated as a `1`.
+    pub const fn from_bit(bit: u8, polarity: Polarity) -> Self {
+        let color = if bit == 0 {
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        };
+
+        polarity.apply(color)
+    }
+
+    /// Get the raw bit that represents this color, as a `0` or `1`
+    pub const fn to_bit(self, polarity: Polarity) -> u8 {
+        match polarity.apply(self) {
+            BinaryColor::Off => 0,
+            BinaryColor::On => 1,
+        }
+    }
+
+    /// Returns `true` if the pixel is on
+    pub const fn is_on(self) -> bool {
+        matches!(self, BinaryColor::On)
+    }
+
+    /// Returns `true` if the pixel is off
+    pub const fn is_off(self) -> bool {
+        matches!(self, BinaryColor::Off)
+    }
+}
+
+/// How a `BinaryColor` maps to the raw bit stored by a display or image
+///
+/// Most monochrome displays light a pixel when its bit is `1`, but some, like many e-paper panels
+/// and inverted LCDs, light it when the bit is `0`. Drivers can store a `Polarity` and convert
+/// colors with [`BinaryColor::to_bit`](./enum.BinaryColor.html#method.to_bit) and
+/// [`BinaryColor::from_bit`](./enum.BinaryColor.html#method.from_bit) instead of calling `invert()`
+/// throughout.
+///
+/// A [`BinaryFramebuffer`](../framebuffer/struct.BinaryFramebuffer.html) stores its bits with a
+/// given polarity, and [`Image1BPP::with_polarity`](../image/struct.Image.html#method.with_polarity)
+/// decodes active low image data:
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::framebuffer::BinaryFramebuffer;
+/// use embedded_graphics::image::Image1BPP;
+/// use embedded_graphics::pixelcolor::{BinaryColor, Polarity};
+///
+/// let image: Image1BPP<BinaryColor> =
+///     Image1BPP::new(&[0b0111_0000], 4, 1).with_polarity(Polarity::ActiveLow);
+///
+/// let mut bytes = [0u8; 1];
+/// let mut framebuffer = BinaryFramebuffer::new(&mut bytes, 8, Polarity::ActiveHigh);
+/// framebuffer.draw(&image);
+///
+/// assert_eq!(bytes, [0b1000_0000]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Polarity {
+    /// A `1` bit is on
+    #[default]
+    ActiveHigh,
+
+    /// A `0` bit is on
+    ActiveLow,
+}
+
+impl Polarity {
+    /// Convert between a color and the color its raw bit represents with this polarity
+    ///
+    /// Returns `color` unchanged for `ActiveHigh` and inverted for `ActiveLow`. Applying the
+    /// same polarity twice gives the original color.
+    pub const fn apply(self, color: BinaryColor) -> BinaryColor {
+        match self {
+            Polarity::ActiveHigh => color,
+            Polarity::ActiveLow => color.invert(),
+        }
+    }
+}
+
+impl PixelColor for BinaryColor {}
+
+/// Convert a `u8` into a `BinaryColor`
+///
+/// `0` is off and any other value is on, matching how 1BPP and 8BPP images treat their data.
+impl From<u8> for BinaryColor {
+    fn from(other: u8) -> Self {
+        if other == 0 {
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        }
+    }
+}
+
+impl From<bool> for BinaryColor {
+    fn from(other: bool) -> Self {
+        if other {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::Rgba8888;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(BinaryColor::from(0u8), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(1u8), BinaryColor::On);
+        assert_eq!(BinaryColor::from(255u8), BinaryColor::On);
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(BinaryColor::Off.select(1u8, 2), 1);
+        assert_eq!(BinaryColor::On.select(1u8, 2), 2);
+    }
+
+    #[test]
+    fn polarity() {
+        assert_eq!(BinaryColor::On.to_bit(Polarity::ActiveHigh), 1);
+        assert_eq!(BinaryColor::On.to_bit(Polarity::ActiveLow), 0);
+        assert_eq!(BinaryColor::Off.to_bit(Polarity::ActiveLow), 1);
+        assert_eq!(
+            BinaryColor::from_bit(0, Polarity::ActiveLow),
+            BinaryColor::On
+        );
+        assert_eq!(
+            BinaryColor::from_bit(2, Polarity::ActiveHigh),
+            BinaryColor::On
+        );
+
+        for c in [BinaryColor::Off, BinaryColor::On].iter() {
+            for p in [Polarity::ActiveHigh, Polarity::ActiveLow].iter() {
+                assert_eq!(BinaryColor::from_bit(c.to_bit(*p), *p), *c);
+            }
+        }
+    }
+
+    #[test]
+    fn invert() {
+        assert_eq!(BinaryColor::On.invert(), BinaryColor::Off);
+        assert_eq!(BinaryColor::Off.invert(), BinaryColor::On);
+    }
+
+    #[test]
+    fn threshold() {
+        let grey = Rgba8888::from((100, 100, 100));
+
+        assert_eq!(
+            BinaryColor::from_color_with_threshold(grey, 100),
+            BinaryColor::On
+        );
+        assert_eq!(
+            BinaryColor::from_color_with_threshold(grey, 101),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BinaryColor::from_color_with_threshold(Rgba8888(0), 0),
+            BinaryColor::On
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Blending between colors
+//!
+//! The [`Blend`](./trait.Blend.html) trait mixes two colors of the same type using integer math,
+//! which is useful for gradients, fades and anti-aliasing.
+
+use crate::pixelcolor::conversion::expand_channel;
+use crate::pixelcolor::conversion::Luma;
+use crate::pixelcolor::{
+    Argb8888, Bgr666, BinaryColor, Gray2, Gray4, Gray8, Hsv, Rgb332, Rgb565, Rgb666, Rgba8888,
+};
+
+/// Linearly interpolate between two colors
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::blend::Blend;
+/// use embedded_graphics::pixelcolor::Rgba8888;
+///
+/// let black = Rgba8888(0x0000_00ff);
+/// let white = Rgba8888(0xffff_ffff);
+///
+/// assert_eq!(black.blend(white, 0), black);
+/// assert_eq!(black.blend(white, 128), Rgba8888(0x8080_80ff));
+/// assert_eq!(black.blend(white, 255), white);
+/// ```
+pub trait Blend: Sized {
+    /// `false` if blending can only ever give `self` or `other`, never a mix of the two
+    ///
+    /// [Anti-aliased](../../primitives/smooth/index.html) primitives are drawn without
+    /// anti-aliasing for types where this is `false`, as thresholded edges look worse than the
+    /// plain aliased ones.
+    const CAN_MIX: bool = true;
+
+    /// Mix `self` with `other`
+    ///
+    /// An `alpha` of `0` returns `self` and an `alpha` of `255` returns `other`. Values in between
+    /// mix the two colors proportionally.
+    fn blend(self, other: Self, alpha: u8) -> Self;
+
+    /// Alias for [`blend`](#tymethod.blend)
+    fn lerp(self, other: Self, alpha: u8) -> Self {
+        self.blend(other, alpha)
+    }
+}
+
+/// Linearly interpolate between two 8 bit channel values
+pub(crate) fn lerp_channel(from: u8, to: u8, alpha: u8) -> u8 {
+    let from = i32::from(from);
+    let to = i32::from(to);
+    let delta = (to - from) * i32::from(alpha);
+
+    // Round half away from zero so that blending is symmetric
+    let delta = if delta < 0 {
+        (delta - 127) / 255
+    } else {
+        (delta + 127) / 255
+    };
+
+    (from + delta) as u8
+}
+
+/// Implement `Blend` for `$t`, where `$t` has `$r`, `$g` and `$b` significant bits in its `r()`,
+/// `g()` and `b()` accessors.
+macro_rules! impl_rgb_blend {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl Blend for $t {
+            fn blend(self, other: Self, alpha: u8) -> Self {
+                Self::from((
+                    lerp_channel(
+                        expand_channel(self.r(), $r),
+                        expand_channel(other.r(), $r),
+                        alpha,
+                    ),
+                    lerp_channel(
+                        expand_channel(self.g(), $g),
+                        expand_channel(other.g(), $g),
+                        alpha,
+                    ),
+                    lerp_channel(
+                        expand_channel(self.b(), $b),
+                        expand_channel(other.b(), $b),
+                        alpha,
+                    ),
+                ))
+            }
+        }
+    };
+}
+
+/// Implement `Blend` for `$t`, which has 8 bit `r()`, `g()`, `b()` and `a()` accessors
+macro_rules! impl_rgba_blend {
+    ($t:ty) => {
+        impl Blend for $t {
+            fn blend(self, other: Self, alpha: u8) -> Self {
+                Self::from((
+                    lerp_channel(self.r(), other.r(), alpha),
+                    lerp_channel(self.g(), other.g(), alpha),
+                    lerp_channel(self.b(), other.b(), alpha),
+                    lerp_channel(self.a(), other.a(), alpha),
+                ))
+            }
+        }
+    };
+}
+
+impl_rgb_blend!(Rgb332, (3, 3, 2));
+impl_rgb_blend!(Rgb565, (5, 6, 5));
+impl_rgb_blend!(Rgb666, (6, 6, 6));
+impl_rgb_blend!(Bgr666, (6, 6, 6));
+impl_rgba_blend!(Rgba8888);
+impl_rgba_blend!(Argb8888);
+
+/// Implement `Blend` for greyscale types by blending their 8 bit luma
+macro_rules! impl_gray_blend {
+    ($($t:ty),*) => {
+        $(
+            impl Blend for $t {
+                fn blend(self, other: Self, alpha: u8) -> Self {
+                    Self::from(lerp_channel(self.luma(), other.luma(), alpha))
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_blend!(Gray2, Gray4, Gray8);
+
+/// Blend two 8 bit greyscale levels
+impl Blend for u8 {
+    fn blend(self, other: Self, alpha: u8) -> Self {
+        lerp_channel(self, other, alpha)
+    }
+}
+
+/// Picks `self` for an `alpha` below `128` and `other` otherwise
+impl Blend for BinaryColor {
+    const CAN_MIX: bool = false;
+
+    fn blend(self, other: Self, alpha: u8) -> Self {
+        if alpha < 128 {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// Blend the hue, saturation and value components
+///
+/// The hue takes the shortest path around the color wheel, so blending from red to magenta doesn't
+/// pass through green.
+impl Blend for Hsv {
+    fn blend(self, other: Self, alpha: u8) -> Self {
+        let diff = other.h.wrapping_sub(self.h) as i8;
+        let hue_delta = (i32::from(diff) * i32::from(alpha)) / 255;
+
+        Hsv::new(
+            (i32::from(self.h) + hue_delta) as u8,
+            lerp_channel(self.s, other.s, alpha),
+            lerp_channel(self.v, other.v, alpha),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerp_channel_endpoints() {
+        assert_eq!(lerp_channel(10, 200, 0), 10);
+        assert_eq!(lerp_channel(10, 200, 255), 200);
+        assert_eq!(lerp_channel(200, 10, 255), 10);
+        assert_eq!(lerp_channel(0, 255, 128), 128);
+        assert_eq!(lerp_channel(255, 0, 128), 127);
+    }
+
+    #[test]
+    fn blend_rgb565() {
+        let black = Rgb565(0);
+        let white = Rgb565(0xffff);
+
+        assert_eq!(black.blend(white, 0), black);
+        assert_eq!(black.blend(white, 255), white);
+        assert_eq!(
+            Rgb565(0b11111_000000_00000).blend(Rgb565(0b00000_000000_11111), 128),
+            Rgb565(0b01111_000000_10000)
+        );
+    }
+
+    #[test]
+    fn blend_same_color_is_noop() {
+        let c = Rgb332(0b101_010_01);
+        assert_eq!(c.blend(c, 77), c);
+
+        let c = Rgb666(0b110011_010101_100101);
+        assert_eq!(c.blend(c, 200), c);
+    }
+
+    #[test]
+    fn blend_alpha_channel() {
+        assert_eq!(
+            Argb8888(0x0000_0000).blend(Argb8888(0xff00_0000), 255),
+            Argb8888(0xff00_0000)
+        );
+        assert_eq!(
+            Rgba8888(0x0000_0000).lerp(Rgba8888(0x0000_00ff), 51),
+            Rgba8888(0x0000_0033)
+        );
+    }
+
+    #[test]
+    fn blend_grey_and_binary() {
+        assert_eq!(0u8.blend(200, 128), 100);
+        assert_eq!(Gray2::BLACK.blend(Gray2::WHITE, 170), Gray2(2));
+        assert_eq!(Gray8(10).blend(Gray8(20), 128), Gray8(15));
+        assert_eq!(
+            BinaryColor::Off.blend(BinaryColor::On, 127),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BinaryColor::Off.blend(BinaryColor::On, 128),
+            BinaryColor::On
+        );
+    }
+
+    #[test]
+    fn blend_hsv_wraps() {
+        let red = Hsv::new(250, 255, 255);
+        let orange = Hsv::new(10, 255, 255);
+
+        assert_eq!(red.blend(orange, 128), Hsv::new(2, 255, 255));
+        assert_eq!(orange.blend(red, 255), red);
+    }
+}
@@ -0,0 +1,812 @@
+//! Conversions between pixel color types
+//!
+//! Channels are rescaled to the full 8 bit range before being truncated to the bit depth of the
+//! target type, so that e.g. full intensity white in one format stays full intensity white in
+//! every other format.
+//!
+//! Converting an alpha carrying type like [`Rgba8888`](../struct.Rgba8888.html) to an opaque type
+//! discards the alpha channel. Converting an opaque type to an alpha carrying type produces a
+//! fully opaque color.
+//!
+//! [`Hsv`](../struct.Hsv.html) colors are converted through 8 bit RGB components, so converting an
+//! HSV color to a low bit depth type and back may not give the same hue.
+//!
+//! Greyscale types are converted to RGB by expanding their level to 8 bits and assigning it to
+//! every channel. RGB types are converted to greyscale using their [luma](./trait.Luma.html).
+//!
+//! Tri-color e-paper types like [`BlackWhiteRed`](../enum.BlackWhiteRed.html) are converted from
+//! other colors by picking the closest ink.
+//!
+//! Colors can be converted to an 8 bit greyscale level with the [`Luma`](./trait.Luma.html) trait.
+//! Converting a color to a [`BinaryColor`](../enum.BinaryColor.html) compares its luma against
+//! [`BinaryColor::DEFAULT_THRESHOLD`](../enum.BinaryColor.html#associatedconstant.DEFAULT_THRESHOLD).
+//! Converting a `BinaryColor` to any other type gives black for off pixels and white for on pixels.
+//! For displays where on pixels are dark, like e-paper panels, call
+//! [`invert`](../enum.BinaryColor.html#method.invert) first or pick the colors explicitly with
+//! [`select`](../enum.BinaryColor.html#method.select).
+//!
+//! # Const conversions
+//!
+//! Trait methods like `From::from` can't be called in `const` items. To define palettes and style
+//! tables as `const` values that live in flash, every color type has a `const fn to_rgb()` that
+//! returns its 8 bit `(red, green, blue)` channels, and a `const fn` constructor that takes 8 bit
+//! channels: `new()` for RGB types and `from_rgb()` for [`Hsv`](../struct.Hsv.html) and
+//! greyscale types. These give the same results as the `From` conversions.
+//!
+//! ```rust
+//! use embedded_graphics::pixelcolor::{Gray4, Rgb332, Rgb565};
+//!
+//! const ACCENT: Rgb565 = Rgb565::new(0xff, 0x80, 0x00);
+//!
+//! const ACCENT_RGB332: Rgb332 = {
+//!     let (r, g, b) = ACCENT.to_rgb();
+//!
+//!     Rgb332::new(r, g, b)
+//! };
+//!
+//! const ACCENT_GRAY: Gray4 = {
+//!     let (r, g, b) = ACCENT.to_rgb();
+//!
+//!     Gray4::from_rgb(r, g, b)
+//! };
+//!
+//! assert_eq!(ACCENT_RGB332, Rgb332::from(ACCENT));
+//! assert_eq!(ACCENT_GRAY, Gray4::from(ACCENT));
+//! ```
+
+use crate::pixelcolor::{
+    Argb8888, Bgr666, BinaryColor, BlackWhiteRed, BlackWhiteYellow, Gray2, Gray4, Gray8, Hsv,
+    Rgb332, Rgb565, Rgb565Swapped, Rgb666, Rgba8888,
+};
+
+/// Expand an `bits` wide color channel stored in the most significant bits of a `u8` to the full
+/// 8 bit range
+pub(crate) const fn expand_channel(value: u8, bits: u8) -> u8 {
+    let max = (1u16 << bits) - 1;
+    let value = (value >> (8 - bits)) as u16;
+
+    ((value * 255 + max / 2) / max) as u8
+}
+
+/// Implement `From<$from> for $to` using the `r()`, `g()` and `b()` accessors of `$from`, which
+/// hold `$r`, `$g` and `$b` significant bits respectively.
+macro_rules! impl_rgb_conversion {
+    ($from:ty => $to:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl From<$from> for $to {
+            fn from(other: $from) -> Self {
+                Self::from((
+                    expand_channel(other.r(), $r),
+                    expand_channel(other.g(), $g),
+                    expand_channel(other.b(), $b),
+                ))
+            }
+        }
+    };
+}
+
+/// Implement `From<Hsv> for $t` and `From<$t> for Hsv`, where `$t` has `$r`, `$g` and `$b`
+/// significant bits in its `r()`, `g()` and `b()` accessors.
+macro_rules! impl_hsv_conversion {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl From<Hsv> for $t {
+            fn from(other: Hsv) -> Self {
+                Self::from(other.to_rgb())
+            }
+        }
+
+        impl From<$t> for Hsv {
+            fn from(other: $t) -> Self {
+                Hsv::from_rgb(
+                    expand_channel(other.r(), $r),
+                    expand_channel(other.g(), $g),
+                    expand_channel(other.b(), $b),
+                )
+            }
+        }
+    };
+}
+
+/// Convert a color to an 8 bit greyscale level, weighted by perceived brightness
+///
+/// A plain average of the red, green and blue channels makes blue heavy colors look too bright.
+/// This trait uses the [ITU-R BT.601](https://en.wikipedia.org/wiki/Rec._601) luma weights instead,
+/// which give a more natural result on greyscale and monochrome displays like e-paper panels.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::conversion::Luma;
+/// use embedded_graphics::pixelcolor::Rgb565;
+///
+/// let blue = Rgb565(0b00000_000000_11111);
+///
+/// assert_eq!(blue.luma(), 29);
+/// assert_eq!(blue.average(), 85);
+/// ```
+pub trait Luma {
+    /// Get the BT.601 weighted greyscale level of this color
+    fn luma(&self) -> u8;
+
+    /// Get the unweighted average of the color channels
+    fn average(&self) -> u8;
+}
+
+/// Implement `Luma` for `$t`, where `$t` has `$r`, `$g` and `$b` significant bits in its `r()`,
+/// `g()` and `b()` accessors.
+macro_rules! impl_luma {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl Luma for $t {
+            fn luma(&self) -> u8 {
+                luma(
+                    expand_channel(self.r(), $r),
+                    expand_channel(self.g(), $g),
+                    expand_channel(self.b(), $b),
+                )
+            }
+
+            fn average(&self) -> u8 {
+                average(
+                    expand_channel(self.r(), $r),
+                    expand_channel(self.g(), $g),
+                    expand_channel(self.b(), $b),
+                )
+            }
+        }
+    };
+}
+
+/// BT.601 weighted sum of 8 bit channels. The weights add up to 256.
+const fn luma(r: u8, g: u8, b: u8) -> u8 {
+    ((r as u32 * 77 + g as u32 * 150 + b as u32 * 29 + 128) >> 8) as u8
+}
+
+/// Rounded average of 8 bit channels
+fn average(r: u8, g: u8, b: u8) -> u8 {
+    ((u16::from(r) + u16::from(g) + u16::from(b) + 1) / 3) as u8
+}
+
+/// Implement `const fn to_rgb()` for `$t`, where `$t` has `$r`, `$g` and `$b` significant bits in
+/// its `r()`, `g()` and `b()` accessors.
+macro_rules! impl_const_to_rgb {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl $t {
+            /// Get the 8 bit `(red, green, blue)` channels of this color
+            ///
+            /// Channels are rescaled to the full 8 bit range, so e.g. white is always
+            /// `(255, 255, 255)`.
+            pub const fn to_rgb(&self) -> (u8, u8, u8) {
+                (
+                    expand_channel(self.r(), $r),
+                    expand_channel(self.g(), $g),
+                    expand_channel(self.b(), $b),
+                )
+            }
+        }
+    };
+}
+
+impl_const_to_rgb!(Rgb332, (3, 3, 2));
+impl_const_to_rgb!(Rgb565, (5, 6, 5));
+impl_const_to_rgb!(Rgb666, (6, 6, 6));
+impl_const_to_rgb!(Bgr666, (6, 6, 6));
+impl_const_to_rgb!(Rgba8888, (8, 8, 8));
+impl_const_to_rgb!(Argb8888, (8, 8, 8));
+
+impl_rgb_conversion!(Rgb332 => Rgb565, (3, 3, 2));
+impl_rgb_conversion!(Rgb332 => Rgb666, (3, 3, 2));
+impl_rgb_conversion!(Rgb332 => Bgr666, (3, 3, 2));
+impl_rgb_conversion!(Rgb565 => Rgb332, (5, 6, 5));
+impl_rgb_conversion!(Rgb565 => Rgb666, (5, 6, 5));
+impl_rgb_conversion!(Rgb565 => Bgr666, (5, 6, 5));
+impl_rgb_conversion!(Rgb666 => Rgb332, (6, 6, 6));
+impl_rgb_conversion!(Rgb666 => Rgb565, (6, 6, 6));
+impl_rgb_conversion!(Rgb666 => Bgr666, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Rgb332, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Rgb565, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Rgb666, (6, 6, 6));
+
+impl_rgb_conversion!(Rgb332 => Rgba8888, (3, 3, 2));
+impl_rgb_conversion!(Rgb565 => Rgba8888, (5, 6, 5));
+impl_rgb_conversion!(Rgb666 => Rgba8888, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Rgba8888, (6, 6, 6));
+impl_rgb_conversion!(Rgb332 => Argb8888, (3, 3, 2));
+impl_rgb_conversion!(Rgb565 => Argb8888, (5, 6, 5));
+impl_rgb_conversion!(Rgb666 => Argb8888, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Argb8888, (6, 6, 6));
+impl_rgb_conversion!(Rgba8888 => Rgb332, (8, 8, 8));
+impl_rgb_conversion!(Rgba8888 => Rgb565, (8, 8, 8));
+impl_rgb_conversion!(Rgba8888 => Rgb666, (8, 8, 8));
+impl_rgb_conversion!(Rgba8888 => Bgr666, (8, 8, 8));
+impl_rgb_conversion!(Argb8888 => Rgb332, (8, 8, 8));
+impl_rgb_conversion!(Argb8888 => Rgb565, (8, 8, 8));
+impl_rgb_conversion!(Argb8888 => Rgb666, (8, 8, 8));
+impl_rgb_conversion!(Argb8888 => Bgr666, (8, 8, 8));
+
+impl_rgb_conversion!(Rgb565Swapped => Rgb332, (5, 6, 5));
+impl_rgb_conversion!(Rgb565Swapped => Rgb666, (5, 6, 5));
+impl_rgb_conversion!(Rgb565Swapped => Bgr666, (5, 6, 5));
+impl_rgb_conversion!(Rgb565Swapped => Rgba8888, (5, 6, 5));
+impl_rgb_conversion!(Rgb565Swapped => Argb8888, (5, 6, 5));
+impl_rgb_conversion!(Rgb332 => Rgb565Swapped, (3, 3, 2));
+impl_rgb_conversion!(Rgb666 => Rgb565Swapped, (6, 6, 6));
+impl_rgb_conversion!(Bgr666 => Rgb565Swapped, (6, 6, 6));
+impl_rgb_conversion!(Rgba8888 => Rgb565Swapped, (8, 8, 8));
+impl_rgb_conversion!(Argb8888 => Rgb565Swapped, (8, 8, 8));
+
+impl_hsv_conversion!(Rgb332, (3, 3, 2));
+impl_hsv_conversion!(Rgb565, (5, 6, 5));
+impl_hsv_conversion!(Rgb565Swapped, (5, 6, 5));
+impl_hsv_conversion!(Rgb666, (6, 6, 6));
+impl_hsv_conversion!(Bgr666, (6, 6, 6));
+impl_hsv_conversion!(Rgba8888, (8, 8, 8));
+impl_hsv_conversion!(Argb8888, (8, 8, 8));
+
+impl_luma!(Rgb332, (3, 3, 2));
+impl_luma!(Rgb565, (5, 6, 5));
+impl_luma!(Rgb565Swapped, (5, 6, 5));
+impl_luma!(Rgb666, (6, 6, 6));
+impl_luma!(Bgr666, (6, 6, 6));
+impl_luma!(Rgba8888, (8, 8, 8));
+impl_luma!(Argb8888, (8, 8, 8));
+
+/// Implement `From<$t> for BinaryColor` using the default luma threshold
+macro_rules! impl_binary_conversion {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BinaryColor {
+                fn from(other: $t) -> Self {
+                    BinaryColor::from_color_with_threshold(other, BinaryColor::DEFAULT_THRESHOLD)
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_conversion!(
+    Rgb332,
+    Rgb565,
+    Rgb565Swapped,
+    Rgb666,
+    Bgr666,
+    Rgba8888,
+    Argb8888,
+    Hsv
+);
+impl_binary_conversion!(Gray2, Gray4, Gray8);
+
+/// Off pixels have a luma of `0` and on pixels a luma of `255`
+impl Luma for BinaryColor {
+    fn luma(&self) -> u8 {
+        match self {
+            BinaryColor::Off => 0,
+            BinaryColor::On => 0xff,
+        }
+    }
+
+    fn average(&self) -> u8 {
+        self.luma()
+    }
+}
+
+/// Implement `From<BinaryColor> for $t`, converting off pixels to black and on pixels to white
+macro_rules! impl_from_binary {
+    ($($t:ty),*) => {
+        $(
+            impl From<BinaryColor> for $t {
+                fn from(other: BinaryColor) -> Self {
+                    let level = other.luma();
+
+                    Self::from((level, level, level))
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `From<BinaryColor> for $t` for greyscale types, converting off pixels to black and on
+/// pixels to white
+macro_rules! impl_gray_from_binary {
+    ($($t:ty),*) => {
+        $(
+            impl From<BinaryColor> for $t {
+                fn from(other: BinaryColor) -> Self {
+                    Self::from(other.luma())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_binary!(
+    Rgb332,
+    Rgb565,
+    Rgb565Swapped,
+    Rgb666,
+    Bgr666,
+    Rgba8888,
+    Argb8888
+);
+impl_gray_from_binary!(Gray2, Gray4, Gray8);
+
+/// Implement `Luma` for the `$bits` bit greyscale type `$gray`, and conversions between it and
+/// each RGB type in `$rgb`
+macro_rules! impl_gray_conversion {
+    ($gray:ident, $bits:expr, [$($rgb:ty),*]) => {
+        impl $gray {
+            /// Convert 8 bit red, green and blue channels to a grey level using their
+            /// [luma](./conversion/trait.Luma.html)
+            pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+                Self::new(luma(r, g, b) >> (8 - $bits))
+            }
+
+            /// Get the 8 bit `(red, green, blue)` channels of this grey level
+            pub const fn to_rgb(&self) -> (u8, u8, u8) {
+                let level = expand_channel(self.level() << (8 - $bits), $bits);
+
+                (level, level, level)
+            }
+        }
+
+        impl Luma for $gray {
+            fn luma(&self) -> u8 {
+                expand_channel(self.level() << (8 - $bits), $bits)
+            }
+
+            fn average(&self) -> u8 {
+                self.luma()
+            }
+        }
+
+        impl From<$gray> for Hsv {
+            fn from(other: $gray) -> Self {
+                Hsv::new(0, 0, other.luma())
+            }
+        }
+
+        impl From<Hsv> for $gray {
+            fn from(other: Hsv) -> Self {
+                Self::from(other.luma())
+            }
+        }
+
+        $(
+            impl From<$gray> for $rgb {
+                fn from(other: $gray) -> Self {
+                    let level = other.luma();
+
+                    Self::from((level, level, level))
+                }
+            }
+
+            impl From<$rgb> for $gray {
+                fn from(other: $rgb) -> Self {
+                    Self::from(other.luma())
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_conversion!(
+    Gray2,
+    2,
+    [
+        Rgb332,
+        Rgb565,
+        Rgb565Swapped,
+        Rgb666,
+        Bgr666,
+        Rgba8888,
+        Argb8888
+    ]
+);
+impl_gray_conversion!(
+    Gray4,
+    4,
+    [
+        Rgb332,
+        Rgb565,
+        Rgb565Swapped,
+        Rgb666,
+        Bgr666,
+        Rgba8888,
+        Argb8888
+    ]
+);
+impl_gray_conversion!(
+    Gray8,
+    8,
+    [
+        Rgb332,
+        Rgb565,
+        Rgb565Swapped,
+        Rgb666,
+        Bgr666,
+        Rgba8888,
+        Argb8888
+    ]
+);
+
+/// Implement conversions between greyscale types of different bit depths
+macro_rules! impl_gray_to_gray {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(other: $from) -> Self {
+                    Self::from(other.luma())
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_to_gray!(
+    Gray2 => Gray4,
+    Gray2 => Gray8,
+    Gray4 => Gray2,
+    Gray4 => Gray8,
+    Gray8 => Gray2,
+    Gray8 => Gray4
+);
+
+impl Luma for Hsv {
+    fn luma(&self) -> u8 {
+        let (r, g, b) = self.to_rgb();
+
+        luma(r, g, b)
+    }
+
+    fn average(&self) -> u8 {
+        let (r, g, b) = self.to_rgb();
+
+        average(r, g, b)
+    }
+}
+
+/// Implement conversions between the tri-color type `$tri` and each type in `$t`, going through
+/// `Rgba8888` to get 8 bit channels
+macro_rules! impl_tri_color_conversion {
+    ($tri:ty, [$($t:ty),*]) => {
+        impl From<Rgba8888> for $tri {
+            fn from(other: Rgba8888) -> Self {
+                Self::nearest(other.r(), other.g(), other.b())
+            }
+        }
+
+        impl From<$tri> for Rgba8888 {
+            fn from(other: $tri) -> Self {
+                Self::from(other.to_rgb())
+            }
+        }
+
+        $(
+            impl From<$t> for $tri {
+                fn from(other: $t) -> Self {
+                    Self::from(Rgba8888::from(other))
+                }
+            }
+
+            impl From<$tri> for $t {
+                fn from(other: $tri) -> Self {
+                    Self::from(Rgba8888::from(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_tri_color_conversion!(
+    BlackWhiteRed,
+    [
+        Rgb332,
+        Rgb565,
+        Rgb565Swapped,
+        Rgb666,
+        Bgr666,
+        Argb8888,
+        Hsv,
+        Gray2,
+        Gray4,
+        Gray8
+    ]
+);
+impl_tri_color_conversion!(
+    BlackWhiteYellow,
+    [
+        Rgb332,
+        Rgb565,
+        Rgb565Swapped,
+        Rgb666,
+        Bgr666,
+        Argb8888,
+        Hsv,
+        Gray2,
+        Gray4,
+        Gray8
+    ]
+);
+
+/// Reorder the channels, keeping the alpha value
+impl From<Rgba8888> for Argb8888 {
+    fn from(other: Rgba8888) -> Self {
+        Self::from((other.r(), other.g(), other.b(), other.a()))
+    }
+}
+
+/// Reorder the channels, keeping the alpha value
+impl From<Argb8888> for Rgba8888 {
+    fn from(other: Argb8888) -> Self {
+        Self::from((other.r(), other.g(), other.b(), other.a()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand() {
+        assert_eq!(expand_channel(0, 5), 0);
+        assert_eq!(expand_channel(0b11111_000, 5), 255);
+        assert_eq!(expand_channel(0b111111_00, 6), 255);
+        assert_eq!(expand_channel(0b10000_000, 5), 132);
+    }
+
+    #[test]
+    fn white_stays_white() {
+        assert_eq!(Rgb666::from(Rgb565(0xffff)), Rgb666(0x3ffff));
+        assert_eq!(Bgr666::from(Rgb565(0xffff)), Bgr666(0x3ffff));
+        assert_eq!(Rgb565::from(Rgb666(0x3ffff)), Rgb565(0xffff));
+        assert_eq!(Rgb565::from(Bgr666(0x3ffff)), Rgb565(0xffff));
+    }
+
+    #[test]
+    fn rgb332_white_and_black() {
+        assert_eq!(Rgb565::from(Rgb332::WHITE), Rgb565(0xffff));
+        assert_eq!(Rgb666::from(Rgb332::WHITE), Rgb666(0x3ffff));
+        assert_eq!(Rgb332::from(Rgb565(0xffff)), Rgb332::WHITE);
+        assert_eq!(Rgb332::from(Bgr666(0x3ffff)), Rgb332::WHITE);
+        assert_eq!(Rgb565::from(Rgb332::BLACK), Rgb565(0));
+    }
+
+    #[test]
+    fn rgb332_to_rgb565() {
+        // Blue channel is 2 bits: 0b01 expands to 85, which is 0b01010 in 5 bits
+        assert_eq!(
+            Rgb565::from(Rgb332(0b000_000_01)),
+            Rgb565(0b00000_000000_01010)
+        );
+        assert_eq!(Rgb565::from(Rgb332::RED), Rgb565(0b11111_000000_00000));
+    }
+
+    #[test]
+    fn opaque_to_alpha_is_opaque() {
+        assert_eq!(Rgba8888::from(Rgb565(0xffff)), Rgba8888(0xffff_ffff));
+        assert_eq!(Rgba8888::from(Rgb332::RED), Rgba8888(0xff00_00ff));
+        assert_eq!(Argb8888::from(Rgb666(0)), Argb8888(0xff00_0000));
+    }
+
+    #[test]
+    fn alpha_to_opaque_drops_alpha() {
+        assert_eq!(Rgb565::from(Rgba8888(0xffff_ff00)), Rgb565(0xffff));
+        assert_eq!(Rgb666::from(Argb8888(0x00ff_ffff)), Rgb666(0x3ffff));
+        assert_eq!(
+            Rgb565::from(Rgba8888(0x1234_5678)),
+            Rgb565::from((0x12, 0x34, 0x56))
+        );
+    }
+
+    #[test]
+    fn rgba8888_argb8888_roundtrip() {
+        let rgba = Rgba8888(0x1234_5678);
+        let argb = Argb8888::from(rgba);
+
+        assert_eq!(argb, Argb8888(0x7812_3456));
+        assert_eq!(Rgba8888::from(argb), rgba);
+    }
+
+    #[test]
+    fn hsv_to_rgb() {
+        assert_eq!(Rgb565::from(Hsv::new(0, 255, 255)), Rgb565(0xf800));
+        assert_eq!(Rgba8888::from(Hsv::new(0, 0, 255)), Rgba8888(0xffff_ffff));
+        assert_eq!(Rgb332::from(Hsv::new(170, 255, 255)), Rgb332::BLUE);
+    }
+
+    #[test]
+    fn rgb_to_hsv() {
+        assert_eq!(Hsv::from(Rgb565(0xffff)), Hsv::new(0, 0, 255));
+        assert_eq!(Hsv::from(Rgb332::GREEN), Hsv::new(85, 255, 255));
+        assert_eq!(Hsv::from(Argb8888(0x0000_00ff)), Hsv::new(170, 255, 255));
+    }
+
+    #[test]
+    fn luma_white_and_black() {
+        assert_eq!(Rgb565(0xffff).luma(), 255);
+        assert_eq!(Rgb332::WHITE.luma(), 255);
+        assert_eq!(Rgba8888(0xffff_ff00).luma(), 255);
+        assert_eq!(Bgr666(0).luma(), 0);
+        assert_eq!(Hsv::new(12, 0, 255).luma(), 255);
+    }
+
+    #[test]
+    fn luma_weights() {
+        assert_eq!(Rgba8888(0xff00_00ff).luma(), 77);
+        assert_eq!(Rgba8888(0x00ff_00ff).luma(), 149);
+        assert_eq!(Rgba8888(0x0000_ffff).luma(), 29);
+    }
+
+    #[test]
+    fn average() {
+        assert_eq!(Rgba8888(0xff00_00ff).average(), 85);
+        assert_eq!(Rgb666(0x3ffff).average(), 255);
+        assert_eq!(Argb8888(0x0010_2030).average(), 0x20);
+    }
+
+    #[test]
+    fn rgb_to_binary() {
+        assert_eq!(BinaryColor::from(Rgb565(0xffff)), BinaryColor::On);
+        assert_eq!(BinaryColor::from(Rgb666(0)), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(Rgba8888(0x8080_80ff)), BinaryColor::On);
+        assert_eq!(BinaryColor::from(Rgba8888(0x7f7f_7fff)), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(Rgb332::BLUE), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(Hsv::new(0, 0, 200)), BinaryColor::On);
+    }
+
+    #[test]
+    fn gray_to_rgb() {
+        assert_eq!(Rgb565::from(Gray2::WHITE), Rgb565(0xffff));
+        assert_eq!(Rgb565::from(Gray4::BLACK), Rgb565(0));
+        assert_eq!(Rgba8888::from(Gray2(1)), Rgba8888(0x5555_55ff));
+        assert_eq!(Argb8888::from(Gray4(0x8)), Argb8888(0xff88_8888));
+        assert_eq!(Rgb666::from(Gray8(0xff)), Rgb666(0x3ffff));
+        assert_eq!(Hsv::from(Gray4::WHITE), Hsv::new(0, 0, 255));
+    }
+
+    #[test]
+    fn rgb_to_gray() {
+        assert_eq!(Gray2::from(Rgb565(0xffff)), Gray2::WHITE);
+        assert_eq!(Gray4::from(Bgr666(0)), Gray4::BLACK);
+        assert_eq!(Gray8::from(Rgba8888(0x0000_ffff)), Gray8(29));
+        assert_eq!(Gray4::from(Hsv::new(0, 0, 0x80)), Gray4(0x8));
+    }
+
+    #[test]
+    fn gray_to_gray() {
+        assert_eq!(Gray4::from(Gray2(1)), Gray4(0x5));
+        assert_eq!(Gray8::from(Gray2(2)), Gray8(0xaa));
+        assert_eq!(Gray2::from(Gray8(0xaa)), Gray2(2));
+        assert_eq!(Gray8::from(Gray4(0xa)), Gray8(0xaa));
+        assert_eq!(Gray2::from(Gray4::WHITE), Gray2::WHITE);
+    }
+
+    #[test]
+    fn gray_roundtrip() {
+        for level in 0..=Gray4::MAX {
+            let gray = Gray4(level);
+
+            assert_eq!(Gray4::from(Rgb565::from(gray)), gray);
+            assert_eq!(Gray4::from(Gray8::from(gray)), gray);
+        }
+    }
+
+    #[test]
+    fn rgb_to_tri_color() {
+        assert_eq!(BlackWhiteRed::from(Rgb565(0xf800)), BlackWhiteRed::Red);
+        assert_eq!(BlackWhiteRed::from(Rgb666(0x3ffff)), BlackWhiteRed::White);
+        assert_eq!(BlackWhiteRed::from(Gray4(0x2)), BlackWhiteRed::Black);
+        assert_eq!(
+            BlackWhiteYellow::from(Rgb332::YELLOW),
+            BlackWhiteYellow::Yellow
+        );
+        assert_eq!(
+            BlackWhiteYellow::from(Hsv::new(40, 255, 255)),
+            BlackWhiteYellow::Yellow
+        );
+    }
+
+    #[test]
+    fn tri_color_to_rgb() {
+        assert_eq!(Rgb565::from(BlackWhiteRed::Red), Rgb565(0xf800));
+        assert_eq!(
+            Rgba8888::from(BlackWhiteYellow::Black),
+            Rgba8888(0x0000_00ff)
+        );
+        assert_eq!(Gray8::from(BlackWhiteRed::White), Gray8::WHITE);
+    }
+
+    #[test]
+    fn rgb565_to_rgb666() {
+        assert_eq!(
+            Rgb666::from(Rgb565(0b11111_000000_00001)),
+            Rgb666(0b111111_000000_000010)
+        );
+    }
+
+    #[test]
+    fn rgb666_to_rgb565() {
+        assert_eq!(
+            Rgb565::from(Rgb666(0b111111_000001_000000)),
+            Rgb565(0b11111_000001_00000)
+        );
+    }
+
+    #[test]
+    fn rgb666_bgr666_roundtrip() {
+        let rgb = Rgb666(0b110011_010101_100101);
+        let bgr = Bgr666::from(rgb);
+
+        assert_eq!(bgr, Bgr666(0b100101_010101_110011));
+        assert_eq!(Rgb666::from(bgr), rgb);
+    }
+
+    #[test]
+    fn const_conversions_match_from() {
+        const ORANGE: Rgb565 = Rgb565::new(0xff, 0x80, 0x10);
+        const ORANGE_RGB: (u8, u8, u8) = ORANGE.to_rgb();
+
+        let (r, g, b) = ORANGE_RGB;
+
+        assert_eq!(ORANGE, Rgb565::from((0xff, 0x80, 0x10)));
+        assert_eq!(Rgb332::new(r, g, b), Rgb332::from(ORANGE));
+        assert_eq!(Rgb666::new(r, g, b), Rgb666::from(ORANGE));
+        assert_eq!(Bgr666::new(r, g, b), Bgr666::from(ORANGE));
+        assert_eq!(Rgba8888::new(r, g, b, 0xff), Rgba8888::from(ORANGE));
+        assert_eq!(Argb8888::new(r, g, b, 0xff), Argb8888::from(ORANGE));
+        assert_eq!(Gray2::from_rgb(r, g, b), Gray2::from(ORANGE));
+        assert_eq!(Gray4::from_rgb(r, g, b), Gray4::from(ORANGE));
+        assert_eq!(Gray8::from_rgb(r, g, b), Gray8::from(ORANGE));
+        assert_eq!(Hsv::from_rgb(r, g, b), Hsv::from(ORANGE));
+        assert_eq!(Gray4(0x5).to_rgb(), (0x55, 0x55, 0x55));
+        assert_eq!(Rgb332::WHITE.to_rgb(), (0xff, 0xff, 0xff));
+    }
+
+    #[test]
+    fn binary_to_gray() {
+        assert_eq!(Gray2::from(BinaryColor::Off), Gray2::BLACK);
+        assert_eq!(Gray2::from(BinaryColor::On), Gray2::WHITE);
+        assert_eq!(Gray4::from(BinaryColor::On), Gray4::WHITE);
+        assert_eq!(Gray8::from(BinaryColor::On.invert()), Gray8::BLACK);
+        assert_eq!(Rgb565::from(BinaryColor::On), Rgb565(0xffff));
+        assert_eq!(Rgba8888::from(BinaryColor::Off), Rgba8888(0x0000_00ff));
+    }
+
+    #[test]
+    fn binary_gray_roundtrip() {
+        for c in [BinaryColor::Off, BinaryColor::On].iter() {
+            assert_eq!(BinaryColor::from(Gray2::from(*c)), *c);
+            assert_eq!(BinaryColor::from(Gray4::from(*c)), *c);
+            assert_eq!(BinaryColor::from(Gray8::from(*c)), *c);
+        }
+    }
+
+    #[test]
+    fn rgb565_swapped() {
+        let red = Rgb565Swapped::from(Rgb565(0xf800));
+
+        assert_eq!(Rgba8888::from(red), Rgba8888(0xff00_00ff));
+        assert_eq!(Rgb565Swapped::from(Rgb332::GREEN), Rgb565Swapped(0xe007));
+        assert_eq!(red.luma(), 77);
+    }
+
+    #[test]
+    fn rgb565_swapped_matches_rgb565() {
+        let color = Rgb565(0b11001_010101_10010);
+        let swapped = Rgb565Swapped::from(color);
+
+        assert_eq!(Hsv::from(swapped), Hsv::from(color));
+        assert_eq!(Gray2::from(swapped), Gray2::from(color));
+        assert_eq!(Gray8::from(swapped), Gray8::from(color));
+        assert_eq!(BinaryColor::from(swapped), BinaryColor::from(color));
+        assert_eq!(BlackWhiteRed::from(swapped), BlackWhiteRed::from(color));
+
+        let hsv = Hsv::new(200, 180, 90);
+        assert_eq!(Rgb565::from(Rgb565Swapped::from(hsv)), Rgb565::from(hsv));
+        assert_eq!(
+            Rgb565Swapped::from(Gray4(0x6)),
+            Rgb565::from(Gray4(0x6)).into()
+        );
+        assert_eq!(Rgb565Swapped::from(BinaryColor::On), Rgb565Swapped(0xffff));
+        assert_eq!(
+            Rgb565Swapped::from(BlackWhiteYellow::Yellow),
+            Rgb565::from(BlackWhiteYellow::Yellow).into()
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Distance between colors
+//!
+//! The [`ColorDistance`](./trait.ColorDistance.html) trait measures how different two colors of the
+//! same type are, and can find the closest match for a color in a list. This is the building block
+//! for palette quantization and dithering.
+
+use crate::pixelcolor::conversion::{expand_channel, Luma};
+use crate::pixelcolor::{
+    Argb8888, Bgr666, BinaryColor, Gray2, Gray4, Gray8, Hsv, Rgb332, Rgb565, Rgb666, Rgba8888,
+};
+
+/// Squared Euclidean distance between two 8 bit RGB colors
+pub(crate) fn distance_squared((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let dr = i32::from(r1) - i32::from(r2);
+    let dg = i32::from(g1) - i32::from(g2);
+    let db = i32::from(b1) - i32::from(b2);
+
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+/// Squared distance between two 8 bit RGB colors, weighted by how sensitive the eye is to each
+/// channel
+///
+/// This uses the "redmean" approximation, which weights green most heavily and adjusts the red and
+/// blue weights depending on how red the colors are.
+pub(crate) fn perceptual_distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let r_mean = (i32::from(r1) + i32::from(r2)) / 2;
+    let dr = i32::from(r1) - i32::from(r2);
+    let dg = i32::from(g1) - i32::from(g2);
+    let db = i32::from(b1) - i32::from(b2);
+
+    ((((512 + r_mean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - r_mean) * db * db) >> 8)) as u32
+}
+
+/// Measure the difference between two colors
+///
+/// Distances are calculated on 8 bit RGB channels, so distances are comparable between different
+/// color types. Alpha channels are ignored.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::distance::ColorDistance;
+/// use embedded_graphics::pixelcolor::Rgb565;
+///
+/// let palette = [Rgb565(0x0000), Rgb565(0xf800), Rgb565(0xffff)];
+///
+/// assert_eq!(Rgb565::from((200, 10, 20)).nearest(&palette), Some(Rgb565(0xf800)));
+/// ```
+pub trait ColorDistance: Copy {
+    /// Get the 8 bit `(red, green, blue)` channels used to calculate distances
+    fn distance_channels(self) -> (u8, u8, u8);
+
+    /// Squared Euclidean distance between this color and `other` in RGB space
+    fn distance_squared(self, other: Self) -> u32 {
+        distance_squared(self.distance_channels(), other.distance_channels())
+    }
+
+    /// Squared distance between this color and `other`, weighted to better match human perception
+    fn perceptual_distance(self, other: Self) -> u32 {
+        perceptual_distance(self.distance_channels(), other.distance_channels())
+    }
+
+    /// Find the color in `colors` with the smallest
+    /// [`distance_squared`](#method.distance_squared) to this color
+    ///
+    /// Returns `None` if `colors` is empty. If multiple colors are equally close, the first one is
+    /// returned.
+    fn nearest(self, colors: &[Self]) -> Option<Self> {
+        nearest_by(self, colors, Self::distance_squared)
+    }
+
+    /// Find the color in `colors` with the smallest
+    /// [`perceptual_distance`](#method.perceptual_distance) to this color
+    fn nearest_perceptual(self, colors: &[Self]) -> Option<Self> {
+        nearest_by(self, colors, Self::perceptual_distance)
+    }
+}
+
+/// Find the color in `colors` closest to `color` using the given distance function
+fn nearest_by<C, F>(color: C, colors: &[C], distance: F) -> Option<C>
+where
+    C: Copy,
+    F: Fn(C, C) -> u32,
+{
+    let mut best: Option<(C, u32)> = None;
+
+    for candidate in colors.iter() {
+        let d = distance(color, *candidate);
+
+        match best {
+            Some((_, best_distance)) if best_distance <= d => {}
+            _ => best = Some((*candidate, d)),
+        }
+    }
+
+    best.map(|(c, _)| c)
+}
+
+/// Implement `ColorDistance` for `$t`, where `$t` has `$r`, `$g` and `$b` significant bits in its
+/// `r()`, `g()` and `b()` accessors.
+macro_rules! impl_rgb_distance {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl ColorDistance for $t {
+            fn distance_channels(self) -> (u8, u8, u8) {
+                (
+                    expand_channel(self.r(), $r),
+                    expand_channel(self.g(), $g),
+                    expand_channel(self.b(), $b),
+                )
+            }
+        }
+    };
+}
+
+/// Implement `ColorDistance` for greyscale types, treating them as an RGB grey
+macro_rules! impl_gray_distance {
+    ($($t:ty),*) => {
+        $(
+            impl ColorDistance for $t {
+                fn distance_channels(self) -> (u8, u8, u8) {
+                    let level = self.luma();
+
+                    (level, level, level)
+                }
+            }
+        )*
+    };
+}
+
+impl_rgb_distance!(Rgb332, (3, 3, 2));
+impl_rgb_distance!(Rgb565, (5, 6, 5));
+impl_rgb_distance!(Rgb666, (6, 6, 6));
+impl_rgb_distance!(Bgr666, (6, 6, 6));
+impl_rgb_distance!(Rgba8888, (8, 8, 8));
+impl_rgb_distance!(Argb8888, (8, 8, 8));
+impl_gray_distance!(Gray2, Gray4, Gray8);
+
+impl ColorDistance for Hsv {
+    fn distance_channels(self) -> (u8, u8, u8) {
+        self.to_rgb()
+    }
+}
+
+impl ColorDistance for BinaryColor {
+    fn distance_channels(self) -> (u8, u8, u8) {
+        match self {
+            BinaryColor::Off => (0, 0, 0),
+            BinaryColor::On => (0xff, 0xff, 0xff),
+        }
+    }
+}
+
+/// Treats the `u8` as an 8 bit grey level
+impl ColorDistance for u8 {
+    fn distance_channels(self) -> (u8, u8, u8) {
+        (self, self, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squared() {
+        assert_eq!(Rgba8888(0).distance_squared(Rgba8888(0)), 0);
+        assert_eq!(
+            Rgba8888(0x0000_0000).distance_squared(Rgba8888(0xffff_ffff)),
+            3 * 255 * 255
+        );
+        assert_eq!(
+            Rgba8888(0x0a00_0000).distance_squared(Rgba8888(0x0003_0400)),
+            100 + 9 + 16
+        );
+        assert_eq!(Gray4(0).distance_squared(Gray4(0xf)), 3 * 255 * 255);
+    }
+
+    #[test]
+    fn alpha_is_ignored() {
+        assert_eq!(
+            Argb8888(0x0012_3456).distance_squared(Argb8888(0xff12_3456)),
+            0
+        );
+    }
+
+    #[test]
+    fn perceptual_weights() {
+        let black = Rgba8888(0x0000_00ff);
+        let white = Rgba8888(0xffff_ffff);
+
+        // Green differences always count the most
+        assert!(
+            black.perceptual_distance(Rgba8888(0x0080_00ff))
+                > black.perceptual_distance(Rgba8888(0x0000_80ff))
+        );
+
+        // Blue differences count more in dark colors, red differences more in red colors
+        assert!(
+            black.perceptual_distance(Rgba8888(0x0000_80ff))
+                > black.perceptual_distance(Rgba8888(0x8000_00ff))
+        );
+        assert!(
+            white.perceptual_distance(Rgba8888(0x80ff_ffff))
+                > white.perceptual_distance(Rgba8888(0xffff_80ff))
+        );
+    }
+
+    #[test]
+    fn nearest() {
+        let colors = [
+            Rgb565(0x0000),
+            Rgb565(0xf800),
+            Rgb565(0x07e0),
+            Rgb565(0xffff),
+        ];
+
+        assert_eq!(Rgb565(0x1000).nearest(&colors), Some(Rgb565(0x0000)));
+        assert_eq!(Rgb565(0x0600).nearest(&colors), Some(Rgb565(0x07e0)));
+        assert_eq!(Rgb565(0xdefb).nearest(&colors), Some(Rgb565(0xffff)));
+        assert_eq!(Rgb565(0).nearest(&[]), None);
+        assert_eq!(100u8.nearest_perceptual(&[0, 128, 255]), Some(128));
+    }
+
+    #[test]
+    fn nearest_prefers_first_on_tie() {
+        assert_eq!(128u8.nearest(&[1, 255]), Some(1));
+        assert_eq!(127u8.nearest(&[254, 0]), Some(254));
+    }
+}
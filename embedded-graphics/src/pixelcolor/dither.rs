@@ -0,0 +1,181 @@
+//! Dithering
+//!
+//! Converting a smooth gradient to a color type with fewer bits per channel produces visible bands
+//! of color. Dithering hides these bands by alternating between the two closest colors the target
+//! type can show, in a pattern that averages out to the original color.
+//!
+//! This module provides the building blocks for ordered dithering with Bayer matrices. To dither
+//! everything drawn to a display, wrap it in a [`Dither`](../../adapter/struct.Dither.html)
+//! adapter.
+
+use crate::pixelcolor::conversion::Luma;
+use crate::pixelcolor::{
+    Bgr666, BinaryColor, Gray2, Gray4, Gray8, Rgb332, Rgb565, Rgb666, Rgba8888,
+};
+
+/// 4x4 Bayer threshold matrix
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// 8x8 Bayer threshold matrix
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// The threshold pattern used for ordered dithering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BayerMatrix {
+    /// A 4x4 pattern with 16 threshold levels
+    #[default]
+    Bayer4x4,
+
+    /// An 8x8 pattern with 64 threshold levels
+    ///
+    /// This gives smoother gradients than `Bayer4x4` at the cost of a more visible pattern.
+    Bayer8x8,
+}
+
+impl BayerMatrix {
+    /// Get the dither threshold for a pixel position, scaled to `0..255`
+    ///
+    /// Thresholds are centered in their bucket, so a 4x4 matrix gives thresholds from `7` to `247`.
+    pub fn threshold(self, x: u32, y: u32) -> u8 {
+        let (value, levels) = match self {
+            BayerMatrix::Bayer4x4 => (BAYER_4X4[(y % 4) as usize][(x % 4) as usize], 16),
+            BayerMatrix::Bayer8x8 => (BAYER_8X8[(y % 8) as usize][(x % 8) as usize], 64),
+        };
+
+        ((2 * u16::from(value) + 1) * 255 / (2 * levels)) as u8
+    }
+}
+
+/// Quantize an 8 bit channel value to `bits` bits, rounding up if the remainder is above
+/// `threshold`
+///
+/// The result is expanded back to the full 8 bit range so that truncating it to `bits` bits gives
+/// the quantized level.
+pub(crate) fn dither_channel(value: u8, bits: u8, threshold: u8) -> u8 {
+    let max = (1u16 << bits) - 1;
+    let scaled = u16::from(value) * max;
+    let mut level = scaled / 255;
+
+    if scaled % 255 > u16::from(threshold) {
+        level += 1;
+    }
+
+    ((level * 255) / max) as u8
+}
+
+/// A color type that can be the output of ordered dithering
+pub trait DitherTarget: Sized {
+    /// Convert an 8 bit color into this type, rounding each channel up or down depending on
+    /// `threshold`
+    ///
+    /// With a `threshold` of `0`, any channel that can't be represented exactly rounds up. With a
+    /// `threshold` of `255`, every channel rounds down.
+    fn dither(color: Rgba8888, threshold: u8) -> Self;
+}
+
+/// Implement `DitherTarget` for `$t` with `$r`, `$g` and `$b` bits per channel
+macro_rules! impl_rgb_dither {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl DitherTarget for $t {
+            fn dither(color: Rgba8888, threshold: u8) -> Self {
+                Self::from((
+                    dither_channel(color.r(), $r, threshold),
+                    dither_channel(color.g(), $g, threshold),
+                    dither_channel(color.b(), $b, threshold),
+                ))
+            }
+        }
+    };
+}
+
+/// Implement `DitherTarget` for the `$bits` bit greyscale type `$t`
+macro_rules! impl_gray_dither {
+    ($t:ty, $bits:expr) => {
+        impl DitherTarget for $t {
+            fn dither(color: Rgba8888, threshold: u8) -> Self {
+                Self::from(dither_channel(color.luma(), $bits, threshold))
+            }
+        }
+    };
+}
+
+impl_rgb_dither!(Rgb332, (3, 3, 2));
+impl_rgb_dither!(Rgb565, (5, 6, 5));
+impl_rgb_dither!(Rgb666, (6, 6, 6));
+impl_rgb_dither!(Bgr666, (6, 6, 6));
+impl_gray_dither!(Gray2, 2);
+impl_gray_dither!(Gray4, 4);
+impl_gray_dither!(Gray8, 8);
+
+impl DitherTarget for BinaryColor {
+    fn dither(color: Rgba8888, threshold: u8) -> Self {
+        Self::from(dither_channel(color.luma(), 1, threshold))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds() {
+        assert_eq!(BayerMatrix::Bayer4x4.threshold(0, 0), 7);
+        assert_eq!(BayerMatrix::Bayer4x4.threshold(0, 3), 247);
+        assert_eq!(BayerMatrix::Bayer4x4.threshold(4, 7), 247);
+        assert_eq!(BayerMatrix::Bayer8x8.threshold(0, 0), 1);
+        assert_eq!(BayerMatrix::Bayer8x8.threshold(0, 7), 253);
+    }
+
+    #[test]
+    fn exact_values_are_not_dithered() {
+        assert_eq!(dither_channel(0, 2, 0), 0);
+        assert_eq!(dither_channel(85, 2, 0), 85);
+        assert_eq!(dither_channel(255, 2, 255), 255);
+        assert_eq!(dither_channel(123, 8, 0), 123);
+    }
+
+    #[test]
+    fn rounds_by_threshold() {
+        // 128 is halfway between levels 0 and 1 of a 1 bit channel
+        assert_eq!(dither_channel(128, 1, 0), 255);
+        assert_eq!(dither_channel(128, 1, 127), 255);
+        assert_eq!(dither_channel(128, 1, 128), 0);
+        assert_eq!(dither_channel(128, 1, 255), 0);
+    }
+
+    #[test]
+    fn average_matches_input() {
+        for value in [32u8, 100, 128, 200].iter() {
+            let on = (0..4)
+                .flat_map(|y| (0..4).map(move |x| (x, y)))
+                .filter(|(x, y)| {
+                    let threshold = BayerMatrix::Bayer4x4.threshold(*x, *y);
+
+                    BinaryColor::dither(Rgba8888::from(*value), threshold) == BinaryColor::On
+                })
+                .count();
+
+            let expected = (usize::from(*value) * 16 + 128) / 255;
+            assert!((on as isize - expected as isize).abs() <= 1, "{}", value);
+        }
+    }
+
+    #[test]
+    fn targets() {
+        let grey = Rgba8888::from((0x40, 0x40, 0x40));
+
+        assert_eq!(Gray2::dither(grey, 0), Gray2(1));
+        assert_eq!(Gray2::dither(grey, 255), Gray2(0));
+        assert_eq!(Rgb565::dither(Rgba8888(0xffff_ffff), 128), Rgb565(0xffff));
+        assert_eq!(Rgb332::dither(grey, 255), Rgb332(0b001_001_00));
+    }
+}
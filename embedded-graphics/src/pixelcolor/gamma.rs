@@ -0,0 +1,203 @@
+//! Gamma correction
+//!
+//! Displays like LED matrices output light linearly with the channel value, but eyes perceive
+//! brightness non-linearly. Colors picked on a desktop monitor therefore look washed out when shown
+//! as-is. The functions in this module convert 8 bit channel values between perceptual (gamma
+//! encoded) and linear light using a gamma of 2.2. They use lookup tables so they're cheap to call
+//! and can be used in `const` contexts.
+//!
+//! To gamma correct everything drawn to a display, wrap it in a
+//! [`GammaCorrected`](../../adapter/struct.GammaCorrected.html) adapter.
+
+use crate::pixelcolor::conversion::expand_channel;
+use crate::pixelcolor::{Argb8888, Bgr666, Gray2, Gray4, Gray8, Rgb332, Rgb565, Rgb666, Rgba8888};
+
+/// Lookup table for `gamma_decode`
+#[rustfmt::skip]
+const DECODE: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2,
+    3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6,
+    6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 11, 11, 11, 12,
+    12, 13, 13, 13, 14, 14, 15, 15, 16, 16, 17, 17, 18, 18, 19, 19,
+    20, 20, 21, 22, 22, 23, 23, 24, 25, 25, 26, 26, 27, 28, 28, 29,
+    30, 30, 31, 32, 33, 33, 34, 35, 35, 36, 37, 38, 39, 39, 40, 41,
+    42, 43, 43, 44, 45, 46, 47, 48, 49, 49, 50, 51, 52, 53, 54, 55,
+    56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71,
+    73, 74, 75, 76, 77, 78, 79, 81, 82, 83, 84, 85, 87, 88, 89, 90,
+    91, 93, 94, 95, 97, 98, 99, 100, 102, 103, 105, 106, 107, 109, 110, 111,
+    113, 114, 116, 117, 119, 120, 121, 123, 124, 126, 127, 129, 130, 132, 133, 135,
+    137, 138, 140, 141, 143, 145, 146, 148, 149, 151, 153, 154, 156, 158, 159, 161,
+    163, 165, 166, 168, 170, 172, 173, 175, 177, 179, 181, 182, 184, 186, 188, 190,
+    192, 194, 196, 197, 199, 201, 203, 205, 207, 209, 211, 213, 215, 217, 219, 221,
+    223, 225, 227, 229, 231, 234, 236, 238, 240, 242, 244, 246, 248, 251, 253, 255,
+];
+
+/// Lookup table for `gamma_encode`
+#[rustfmt::skip]
+const ENCODE: [u8; 256] = [
+    0, 21, 28, 34, 39, 43, 46, 50, 53, 56, 59, 61, 64, 66, 68, 70,
+    72, 74, 76, 78, 80, 82, 84, 85, 87, 89, 90, 92, 93, 95, 96, 98,
+    99, 101, 102, 103, 105, 106, 107, 109, 110, 111, 112, 114, 115, 116, 117, 118,
+    119, 120, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135,
+    136, 137, 138, 139, 140, 141, 142, 143, 144, 144, 145, 146, 147, 148, 149, 150,
+    151, 151, 152, 153, 154, 155, 156, 156, 157, 158, 159, 160, 160, 161, 162, 163,
+    164, 164, 165, 166, 167, 167, 168, 169, 170, 170, 171, 172, 173, 173, 174, 175,
+    175, 176, 177, 178, 178, 179, 180, 180, 181, 182, 182, 183, 184, 184, 185, 186,
+    186, 187, 188, 188, 189, 190, 190, 191, 192, 192, 193, 194, 194, 195, 195, 196,
+    197, 197, 198, 199, 199, 200, 200, 201, 202, 202, 203, 203, 204, 205, 205, 206,
+    206, 207, 207, 208, 209, 209, 210, 210, 211, 212, 212, 213, 213, 214, 214, 215,
+    215, 216, 217, 217, 218, 218, 219, 219, 220, 220, 221, 221, 222, 223, 223, 224,
+    224, 225, 225, 226, 226, 227, 227, 228, 228, 229, 229, 230, 230, 231, 231, 232,
+    232, 233, 233, 234, 234, 235, 235, 236, 236, 237, 237, 238, 238, 239, 239, 240,
+    240, 241, 241, 242, 242, 243, 243, 244, 244, 245, 245, 246, 246, 247, 247, 248,
+    248, 249, 249, 249, 250, 250, 251, 251, 252, 252, 253, 253, 254, 254, 255, 255,
+];
+
+/// Convert a perceptual (gamma encoded) channel value to linear light
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::gamma::gamma_decode;
+///
+/// assert_eq!(gamma_decode(0), 0);
+/// assert_eq!(gamma_decode(128), 56);
+/// assert_eq!(gamma_decode(255), 255);
+/// ```
+pub const fn gamma_decode(value: u8) -> u8 {
+    DECODE[value as usize]
+}
+
+/// Convert a linear light channel value to a perceptual (gamma encoded) value
+///
+/// This is the inverse of [`gamma_decode`](./fn.gamma_decode.html), although the round trip is
+/// lossy for dark values.
+pub const fn gamma_encode(value: u8) -> u8 {
+    ENCODE[value as usize]
+}
+
+/// Apply gamma correction to every color channel of a pixel color
+///
+/// Alpha channels are left untouched.
+pub trait GammaCorrect: Sized {
+    /// Apply a function to each 8 bit color channel
+    fn map_channels<F>(self, f: F) -> Self
+    where
+        F: Fn(u8) -> u8;
+
+    /// Convert a perceptual color to linear light with [`gamma_decode`](./fn.gamma_decode.html)
+    fn gamma_decode(self) -> Self {
+        self.map_channels(gamma_decode)
+    }
+
+    /// Convert a linear light color to a perceptual color with
+    /// [`gamma_encode`](./fn.gamma_encode.html)
+    fn gamma_encode(self) -> Self {
+        self.map_channels(gamma_encode)
+    }
+}
+
+/// Implement `GammaCorrect` for `$t`, where `$t` has `$r`, `$g` and `$b` significant bits in its
+/// `r()`, `g()` and `b()` accessors.
+macro_rules! impl_rgb_gamma {
+    ($t:ty, ($r:expr, $g:expr, $b:expr)) => {
+        impl GammaCorrect for $t {
+            fn map_channels<F>(self, f: F) -> Self
+            where
+                F: Fn(u8) -> u8,
+            {
+                Self::from((
+                    f(expand_channel(self.r(), $r)),
+                    f(expand_channel(self.g(), $g)),
+                    f(expand_channel(self.b(), $b)),
+                ))
+            }
+        }
+    };
+}
+
+/// Implement `GammaCorrect` for `$t`, which has 8 bit `r()`, `g()`, `b()` and `a()` accessors
+macro_rules! impl_rgba_gamma {
+    ($t:ty) => {
+        impl GammaCorrect for $t {
+            fn map_channels<F>(self, f: F) -> Self
+            where
+                F: Fn(u8) -> u8,
+            {
+                Self::from((f(self.r()), f(self.g()), f(self.b()), self.a()))
+            }
+        }
+    };
+}
+
+/// Implement `GammaCorrect` for the `$bits` bit greyscale type `$t`
+macro_rules! impl_gray_gamma {
+    ($t:ty, $bits:expr) => {
+        impl GammaCorrect for $t {
+            fn map_channels<F>(self, f: F) -> Self
+            where
+                F: Fn(u8) -> u8,
+            {
+                Self::from(f(expand_channel(self.level() << (8 - $bits), $bits)))
+            }
+        }
+    };
+}
+
+impl_rgb_gamma!(Rgb332, (3, 3, 2));
+impl_rgb_gamma!(Rgb565, (5, 6, 5));
+impl_rgb_gamma!(Rgb666, (6, 6, 6));
+impl_rgb_gamma!(Bgr666, (6, 6, 6));
+impl_rgba_gamma!(Rgba8888);
+impl_rgba_gamma!(Argb8888);
+impl_gray_gamma!(Gray2, 2);
+impl_gray_gamma!(Gray4, 4);
+impl_gray_gamma!(Gray8, 8);
+
+/// Treats the `u8` as an 8 bit grey level
+impl GammaCorrect for u8 {
+    fn map_channels<F>(self, f: F) -> Self
+    where
+        F: Fn(u8) -> u8,
+    {
+        f(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints() {
+        assert_eq!(gamma_decode(0), 0);
+        assert_eq!(gamma_decode(255), 255);
+        assert_eq!(gamma_encode(0), 0);
+        assert_eq!(gamma_encode(255), 255);
+    }
+
+    #[test]
+    fn monotonic() {
+        for i in 1..=255u8 {
+            assert!(gamma_decode(i) >= gamma_decode(i - 1));
+            assert!(gamma_encode(i) >= gamma_encode(i - 1));
+        }
+    }
+
+    #[test]
+    fn roundtrip_bright_values() {
+        for i in 128..=255u8 {
+            let diff = i16::from(gamma_encode(gamma_decode(i))) - i16::from(i);
+
+            assert!(diff.abs() <= 2, "{}", i);
+        }
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(Rgba8888(0x8080_8080).gamma_decode(), Rgba8888(0x3838_3880));
+        assert_eq!(Rgb565(0xffff).gamma_decode(), Rgb565(0xffff));
+        assert_eq!(Gray8(128).gamma_decode(), Gray8(56));
+        assert_eq!(Gray2(0).gamma_encode(), Gray2(0));
+        assert_eq!(56u8.gamma_encode(), 128);
+    }
+}
@@ -0,0 +1,75 @@
+//! Greyscale pixel types
+
+use crate::pixelcolor::PixelColor;
+
+macro_rules! gray_color {
+    ($type:ident, $bits:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The grey level is stored in the least significant bits of the inner `u8`. `From<u8>`
+        /// takes an 8 bit grey level and keeps its most significant bits, the same as the RGB
+        /// types do.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $type(pub u8);
+
+        impl $type {
+            /// Black
+            pub const BLACK: $type = $type(0);
+
+            /// White
+            pub const WHITE: $type = $type(Self::MAX);
+
+            /// The highest grey level that can be stored in this type
+            pub const MAX: u8 = ((1u16 << $bits) - 1) as u8;
+
+            /// Create a new color from a raw grey level
+            ///
+            /// Any bits above the bit depth of this type are discarded.
+            pub const fn new(level: u8) -> Self {
+                Self(level & Self::MAX)
+            }
+
+            /// Get the raw grey level
+            pub const fn level(&self) -> u8 {
+                self.0 & Self::MAX
+            }
+        }
+
+        impl PixelColor for $type {}
+
+        /// Convert from an 8 bit grey level, discarding the least significant bits
+        impl From<u8> for $type {
+            fn from(other: u8) -> Self {
+                Self((u16::from(other) >> (8 - $bits)) as u8)
+            }
+        }
+    };
+}
+
+gray_color!(
+    Gray2,
+    2,
+    "A 2 bit (4 level) greyscale pixel type, as used by many e-paper panels"
+);
+gray_color!(Gray4, 4, "A 4 bit (16 level) greyscale pixel type");
+gray_color!(Gray8, 8, "An 8 bit (256 level) greyscale pixel type");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Gray2::from(0xffu8), Gray2::WHITE);
+        assert_eq!(Gray2::from(0b1000_0000u8), Gray2(2));
+        assert_eq!(Gray4::from(0xabu8), Gray4(0xa));
+        assert_eq!(Gray8::from(0xabu8), Gray8(0xab));
+    }
+
+    #[test]
+    fn new_masks_level() {
+        assert_eq!(Gray2::new(0xff), Gray2(3));
+        assert_eq!(Gray4::new(0x1f).level(), 0xf);
+        assert_eq!(Gray8::new(0xff), Gray8::WHITE);
+    }
+}
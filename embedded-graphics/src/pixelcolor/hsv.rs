@@ -0,0 +1,179 @@
+//! HSV color type
+
+use crate::pixelcolor::PixelColor;
+
+/// Multiply two `u8`s, treating `255` as `1.0`
+const fn scale(a: u8, b: u8) -> u8 {
+    ((a as u16 * b as u16 + 127) / 255) as u8
+}
+
+/// Get the largest of three values
+const fn max3(a: u8, b: u8, c: u8) -> u8 {
+    let ab = if a > b { a } else { b };
+
+    if ab > c {
+        ab
+    } else {
+        c
+    }
+}
+
+/// Get the smallest of three values
+const fn min3(a: u8, b: u8, c: u8) -> u8 {
+    let ab = if a < b { a } else { b };
+
+    if ab < c {
+        ab
+    } else {
+        c
+    }
+}
+
+/// A color defined by its hue, saturation and value
+///
+/// All three components use the full `u8` range. The hue wraps around the color wheel once over
+/// `0..=255`, so red is at `0`, green at `85` and blue at `170`. This makes hue sweeps as simple as
+/// incrementing `h` with `wrapping_add`.
+///
+/// Conversions to and from the RGB types use integer math only, so they're suitable for use on
+/// devices without an FPU.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::{Hsv, Rgb565};
+///
+/// let red = Rgb565::from(Hsv::new(0, 255, 255));
+///
+/// assert_eq!(red, Rgb565(0xf800));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    /// Hue
+    pub h: u8,
+
+    /// Saturation
+    pub s: u8,
+
+    /// Value
+    pub v: u8,
+}
+
+impl Hsv {
+    /// Create a new color from hue, saturation and value components
+    pub const fn new(h: u8, s: u8, v: u8) -> Self {
+        Self { h, s, v }
+    }
+
+    /// Create an HSV color from 8 bit red, green and blue components
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let max = max3(r, g, b);
+        let min = min3(r, g, b);
+        let delta = (max - min) as i32;
+
+        if delta == 0 {
+            return Self::new(0, 0, max);
+        }
+
+        let s = (delta * 255 / max as i32) as u8;
+
+        let (offset, a, b) = if max == r {
+            (0, g, b)
+        } else if max == g {
+            (85, b, r)
+        } else {
+            (170, r, g)
+        };
+
+        let h = offset + ((a as i32 - b as i32) * 85).div_euclid(2 * delta);
+
+        Self::new(h.rem_euclid(256) as u8, s, max)
+    }
+
+    /// Convert this color into 8 bit `(red, green, blue)` components
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        let Self { h, s, v } = *self;
+
+        if s == 0 {
+            return (v, v, v);
+        }
+
+        // Split the wheel into 6 regions and find how far `h` is into its region
+        let position = h as u16 * 6;
+        let region = position >> 8;
+        let remainder = position as u8;
+
+        let p = scale(v, 255 - s);
+        let q = scale(v, 255 - scale(s, remainder));
+        let t = scale(v, 255 - scale(s, 255 - remainder));
+
+        match region {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        }
+    }
+}
+
+impl PixelColor for Hsv {}
+
+/// Convert from an 8 bit greyscale level into a color with zero saturation
+impl From<u8> for Hsv {
+    fn from(other: u8) -> Self {
+        Self::new(0, 0, other)
+    }
+}
+
+/// Take a tuple of `(hue, saturation, value)` components
+impl From<(u8, u8, u8)> for Hsv {
+    fn from((h, s, v): (u8, u8, u8)) -> Self {
+        Self::new(h, s, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primaries_to_rgb() {
+        assert_eq!(Hsv::new(0, 255, 255).to_rgb(), (255, 0, 0));
+
+        // 256 isn't divisible by 3, so green and blue land slightly before the exact primary
+        assert_eq!(Hsv::new(85, 255, 255).to_rgb(), (1, 255, 0));
+        assert_eq!(Hsv::new(170, 255, 255).to_rgb(), (0, 3, 255));
+    }
+
+    #[test]
+    fn greys_to_rgb() {
+        assert_eq!(Hsv::new(123, 0, 0).to_rgb(), (0, 0, 0));
+        assert_eq!(Hsv::new(123, 0, 200).to_rgb(), (200, 200, 200));
+        assert_eq!(Hsv::from(255u8).to_rgb(), (255, 255, 255));
+    }
+
+    #[test]
+    fn primaries_from_rgb() {
+        assert_eq!(Hsv::from_rgb(255, 0, 0), Hsv::new(0, 255, 255));
+        assert_eq!(Hsv::from_rgb(0, 255, 0), Hsv::new(85, 255, 255));
+        assert_eq!(Hsv::from_rgb(0, 0, 255), Hsv::new(170, 255, 255));
+        assert_eq!(Hsv::from_rgb(255, 0, 1).h, 255);
+    }
+
+    #[test]
+    fn greys_from_rgb() {
+        assert_eq!(Hsv::from_rgb(0, 0, 0), Hsv::new(0, 0, 0));
+        assert_eq!(Hsv::from_rgb(100, 100, 100), Hsv::new(0, 0, 100));
+    }
+
+    #[test]
+    fn roundtrip_is_close() {
+        for h in (0..=255).step_by(5) {
+            let (r, g, b) = Hsv::new(h, 255, 255).to_rgb();
+            let back = Hsv::from_rgb(r, g, b);
+
+            let diff = (i16::from(back.h) - i16::from(h)).rem_euclid(256);
+            assert!(diff <= 2 || diff >= 254, "{} -> {}", h, back.h);
+        }
+    }
+}
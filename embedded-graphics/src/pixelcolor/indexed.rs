@@ -0,0 +1,100 @@
+//! Indexed (palette based) pixel type
+
+use crate::pixelcolor::PixelColor;
+
+/// A pixel color defined by an index into a [`Palette`](./struct.Palette.html)
+///
+/// This is useful for displays with a palette based framebuffer, or to store images and UI
+/// elements in a compact form before mapping them to real colors with a
+/// [`PaletteAdapter`](../adapter/struct.PaletteAdapter.html).
+///
+/// Unlike the originally proposed `Indexed<const N: usize>`, the index isn't typed by bit depth
+/// and is always stored in a `u8`, so palettes are limited to 256 entries. Every
+/// [`PixelColor`](./trait.PixelColor.html) has to be created `From<u8>`, and nothing else in the
+/// crate is generic over a const parameter, so a single byte index keeps `Indexed` usable
+/// everywhere the other color types are. The 1, 2, 4 and 8 bit palettes of common controllers
+/// all fit in it. Lower bit depths are expressed by the length of the
+/// [`Palette`](./struct.Palette.html): the adapter skips indices past its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Indexed(pub u8);
+
+impl PixelColor for Indexed {}
+
+/// Create an indexed color from a raw palette index
+impl From<u8> for Indexed {
+    fn from(other: u8) -> Self {
+        Self(other)
+    }
+}
+
+/// A lookup table mapping [`Indexed`](./struct.Indexed.html) colors to another color type
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::{Indexed, Palette, Rgb565};
+///
+/// const COLORS: [Rgb565; 2] = [Rgb565(0x0000), Rgb565(0xf800)];
+///
+/// let palette = Palette::new(&COLORS);
+///
+/// assert_eq!(palette.get(Indexed(1)), Some(Rgb565(0xf800)));
+/// assert_eq!(palette.get(Indexed(2)), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette<'a, C> {
+    colors: &'a [C],
+}
+
+impl<'a, C> Palette<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new palette from a slice of colors
+    ///
+    /// Only the first 256 colors are reachable by an [`Indexed`](./struct.Indexed.html) color.
+    pub const fn new(colors: &'a [C]) -> Self {
+        Self { colors }
+    }
+
+    /// Look up the color for an index, returning `None` if the index is out of range
+    pub fn get(&self, index: Indexed) -> Option<C> {
+        self.colors.get(usize::from(index.0)).cloned()
+    }
+
+    /// Get the number of colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if the palette contains no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Get the colors in the palette
+    pub fn colors(&self) -> &'a [C] {
+        self.colors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let palette = Palette::new(&[10u8, 20, 30]);
+
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette.get(Indexed(0)), Some(10));
+        assert_eq!(palette.get(Indexed(2)), Some(30));
+        assert_eq!(palette.get(Indexed(3)), None);
+    }
+
+    #[test]
+    fn empty() {
+        let palette: Palette<u8> = Palette::new(&[]);
+
+        assert!(palette.is_empty());
+        assert_eq!(palette.get(Indexed(0)), None);
+    }
+}
@@ -1,8 +1,37 @@
 //! Pixel color
 
+mod argb8888;
+mod bgr666;
+mod binary_color;
+pub mod blend;
+pub mod conversion;
+pub mod distance;
+pub mod dither;
+pub mod gamma;
+mod gray;
+mod hsv;
+mod indexed;
+pub mod raw;
+mod rgb332;
 mod rgb565;
+mod rgb565_swapped;
+mod rgb666;
+mod rgba8888;
+mod tri_color;
+pub mod web_colors;
 
+pub use self::argb8888::Argb8888;
+pub use self::bgr666::Bgr666;
+pub use self::binary_color::{BinaryColor, Polarity};
+pub use self::gray::{Gray2, Gray4, Gray8};
+pub use self::hsv::Hsv;
+pub use self::indexed::{Indexed, Palette};
+pub use self::rgb332::Rgb332;
 pub use self::rgb565::Rgb565;
+pub use self::rgb565_swapped::Rgb565Swapped;
+pub use self::rgb666::Rgb666;
+pub use self::rgba8888::Rgba8888;
+pub use self::tri_color::{BlackWhiteRed, BlackWhiteYellow};
 
 /// Pixel color trait
 ///
@@ -0,0 +1,267 @@
+//! Raw storage and byte order conversions
+//!
+//! Display drivers usually need to send pixel colors over a bus as a sequence of bytes in the byte
+//! order expected by the display controller. The [`IntoStorage`](./trait.IntoStorage.html) trait
+//! converts every color type to and from its raw storage value and its big or little endian bytes,
+//! so drivers don't need to do any bit fiddling themselves.
+//!
+//! ```rust
+//! use embedded_graphics::pixelcolor::raw::IntoStorage;
+//! use embedded_graphics::pixelcolor::{Rgb565, Rgb666};
+//!
+//! assert_eq!(Rgb565(0xf800).to_be_bytes(), [0xf8, 0x00]);
+//! assert_eq!(Rgb565(0xf800).to_le_bytes(), [0x00, 0xf8]);
+//! assert_eq!(Rgb666::from_be_bytes([0x03, 0xff, 0xff]), Rgb666(0x3ffff));
+//! ```
+
+use crate::pixelcolor::{
+    Argb8888, Bgr666, BinaryColor, Gray2, Gray4, Gray8, Indexed, Rgb332, Rgb565, Rgb565Swapped,
+    Rgb666, Rgba8888,
+};
+
+/// Convert a pixel color to and from its raw storage representation
+pub trait IntoStorage: Sized {
+    /// The integer type used to store the raw color value
+    type Storage;
+
+    /// The byte array the raw value is serialized to
+    ///
+    /// This is the smallest number of bytes that can hold every bit of the color, so e.g. 18 bit
+    /// colors are serialized to 3 bytes.
+    type Bytes;
+
+    /// Get the raw storage value of this color
+    fn into_storage(self) -> Self::Storage;
+
+    /// Create a color from a raw storage value
+    ///
+    /// Any bits that aren't used by the color type are discarded.
+    fn from_storage(storage: Self::Storage) -> Self;
+
+    /// Get the raw value as bytes in big endian (most significant byte first) order
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Get the raw value as bytes in little endian (least significant byte first) order
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Create a color from bytes in big endian order
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Create a color from bytes in little endian order
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// Implement `IntoStorage` for a single byte color type, using `$into` and `$from` to convert to
+/// and from the raw `u8`
+macro_rules! impl_u8_storage {
+    ($t:ty, |$s:ident| $into:expr, |$v:ident| $from:expr) => {
+        impl IntoStorage for $t {
+            type Storage = u8;
+            type Bytes = [u8; 1];
+
+            fn into_storage(self) -> u8 {
+                let $s = self;
+                $into
+            }
+
+            fn from_storage(storage: u8) -> Self {
+                let $v = storage;
+                $from
+            }
+
+            fn to_be_bytes(self) -> [u8; 1] {
+                [self.into_storage()]
+            }
+
+            fn to_le_bytes(self) -> [u8; 1] {
+                [self.into_storage()]
+            }
+
+            fn from_be_bytes(bytes: [u8; 1]) -> Self {
+                Self::from_storage(bytes[0])
+            }
+
+            fn from_le_bytes(bytes: [u8; 1]) -> Self {
+                Self::from_storage(bytes[0])
+            }
+        }
+    };
+}
+
+/// Implement `IntoStorage` for a color type wrapping a `$storage` integer that uses the full width
+/// of the integer
+macro_rules! impl_full_storage {
+    ($t:ident, $storage:ty, $n:expr) => {
+        impl IntoStorage for $t {
+            type Storage = $storage;
+            type Bytes = [u8; $n];
+
+            fn into_storage(self) -> $storage {
+                self.0
+            }
+
+            fn from_storage(storage: $storage) -> Self {
+                $t(storage)
+            }
+
+            fn to_be_bytes(self) -> [u8; $n] {
+                self.0.to_be_bytes()
+            }
+
+            fn to_le_bytes(self) -> [u8; $n] {
+                self.0.to_le_bytes()
+            }
+
+            fn from_be_bytes(bytes: [u8; $n]) -> Self {
+                $t(<$storage>::from_be_bytes(bytes))
+            }
+
+            fn from_le_bytes(bytes: [u8; $n]) -> Self {
+                $t(<$storage>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+/// Implement `IntoStorage` for an 18 bit color type stored in a `u32` and serialized to 3 bytes
+macro_rules! impl_18bit_storage {
+    ($t:ident) => {
+        impl IntoStorage for $t {
+            type Storage = u32;
+            type Bytes = [u8; 3];
+
+            fn into_storage(self) -> u32 {
+                self.0 & 0x3ffff
+            }
+
+            fn from_storage(storage: u32) -> Self {
+                $t(storage & 0x3ffff)
+            }
+
+            fn to_be_bytes(self) -> [u8; 3] {
+                let [_, a, b, c] = self.into_storage().to_be_bytes();
+
+                [a, b, c]
+            }
+
+            fn to_le_bytes(self) -> [u8; 3] {
+                let [a, b, c, _] = self.into_storage().to_le_bytes();
+
+                [a, b, c]
+            }
+
+            fn from_be_bytes([a, b, c]: [u8; 3]) -> Self {
+                Self::from_storage(u32::from_be_bytes([0, a, b, c]))
+            }
+
+            fn from_le_bytes([a, b, c]: [u8; 3]) -> Self {
+                Self::from_storage(u32::from_le_bytes([a, b, c, 0]))
+            }
+        }
+    };
+}
+
+/// Implement `IntoStorage` for a plain integer used as a color, which is its own storage value
+macro_rules! impl_int_storage {
+    ($t:ty, $n:expr) => {
+        impl IntoStorage for $t {
+            type Storage = $t;
+            type Bytes = [u8; $n];
+
+            fn into_storage(self) -> $t {
+                self
+            }
+
+            fn from_storage(storage: $t) -> Self {
+                storage
+            }
+
+            fn to_be_bytes(self) -> [u8; $n] {
+                <$t>::to_be_bytes(self)
+            }
+
+            fn to_le_bytes(self) -> [u8; $n] {
+                <$t>::to_le_bytes(self)
+            }
+
+            fn from_be_bytes(bytes: [u8; $n]) -> Self {
+                <$t>::from_be_bytes(bytes)
+            }
+
+            fn from_le_bytes(bytes: [u8; $n]) -> Self {
+                <$t>::from_le_bytes(bytes)
+            }
+        }
+    };
+}
+
+impl_int_storage!(u8, 1);
+impl_int_storage!(u16, 2);
+impl_int_storage!(u32, 4);
+impl_u8_storage!(Rgb332, |c| c.0, |v| Rgb332(v));
+impl_u8_storage!(Gray2, |c| c.level(), |v| Gray2::new(v));
+impl_u8_storage!(Gray4, |c| c.level(), |v| Gray4::new(v));
+impl_u8_storage!(Gray8, |c| c.0, |v| Gray8(v));
+impl_u8_storage!(Indexed, |c| c.0, |v| Indexed(v));
+impl_u8_storage!(BinaryColor, |c| u8::from(c.is_on()), |v| BinaryColor::from(
+    v & 1
+));
+impl_full_storage!(Rgb565, u16, 2);
+impl_full_storage!(Rgb565Swapped, u16, 2);
+impl_full_storage!(Rgba8888, u32, 4);
+impl_full_storage!(Argb8888, u32, 4);
+impl_18bit_storage!(Rgb666);
+impl_18bit_storage!(Bgr666);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_byte() {
+        assert_eq!(Rgb332(0b101_010_01).to_be_bytes(), [0b101_010_01]);
+        assert_eq!(Gray4::from_le_bytes([0xff]), Gray4(0xf));
+        assert_eq!(BinaryColor::On.into_storage(), 1);
+        assert_eq!(BinaryColor::from_storage(2), BinaryColor::Off);
+        assert_eq!(Indexed(7).to_le_bytes(), [7]);
+    }
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(Rgb565(0x1234).to_be_bytes(), [0x12, 0x34]);
+        assert_eq!(Rgb565(0x1234).to_le_bytes(), [0x34, 0x12]);
+        assert_eq!(Rgb565::from_le_bytes([0x34, 0x12]), Rgb565(0x1234));
+        assert_eq!(
+            Argb8888(0x1234_5678).to_le_bytes(),
+            [0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            Rgba8888::from_be_bytes([0x12, 0x34, 0x56, 0x78]),
+            Rgba8888(0x1234_5678)
+        );
+    }
+
+    #[test]
+    fn eighteen_bit() {
+        assert_eq!(Rgb666(0x3abcd).to_be_bytes(), [0x03, 0xab, 0xcd]);
+        assert_eq!(Rgb666(0x3abcd).to_le_bytes(), [0xcd, 0xab, 0x03]);
+        assert_eq!(Bgr666::from_le_bytes([0xcd, 0xab, 0xff]), Bgr666(0x3abcd));
+        assert_eq!(Rgb666::from_storage(0xffff_ffff), Rgb666(0x3ffff));
+    }
+
+    #[test]
+    fn plain_integers() {
+        assert_eq!(0x1234u16.into_storage(), 0x1234);
+        assert_eq!(IntoStorage::to_be_bytes(0x1234u16), [0x12, 0x34]);
+        assert_eq!(<u32 as IntoStorage>::from_le_bytes([1, 0, 0, 0]), 1);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let c = Rgb666(0x2a5a5);
+
+        assert_eq!(Rgb666::from_be_bytes(c.to_be_bytes()), c);
+        assert_eq!(Rgb666::from_le_bytes(c.to_le_bytes()), c);
+        assert_eq!(Rgb666::from_storage(c.into_storage()), c);
+    }
+}
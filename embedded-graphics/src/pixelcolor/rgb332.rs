@@ -0,0 +1,134 @@
+//! RGB332 pixel type
+
+use crate::pixelcolor::PixelColor;
+
+const RED_MASK: u8 = 0b1110_0000;
+const GREEN_MASK: u8 = 0b0001_1100;
+const BLUE_MASK: u8 = 0b0000_0011;
+
+/// A pixel type defining the 8 bit RGB332 format
+///
+/// This format is useful for keeping a full screen framebuffer in memory on devices with very
+/// little RAM. Pixel values are stored in a single `u8` segmented as follows:
+///
+/// ```text
+/// 7  6  5  4  3  2  1  0
+/// r  r  r  g  g  g  b  b
+/// ```
+///
+/// Unlike the other RGB types, `From<u8>` interprets its input as a raw RGB332 value, not a
+/// greyscale level. This allows [`Image8BPP`](../image/type.Image8BPP.html) to load RGB332 image
+/// data directly. Use `Rgb332::from((level, level, level))` to create a grey.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgb332(pub u8);
+
+impl Rgb332 {
+    /// Black
+    pub const BLACK: Rgb332 = Rgb332(0);
+
+    /// White
+    pub const WHITE: Rgb332 = Rgb332(RED_MASK | GREEN_MASK | BLUE_MASK);
+
+    /// Red
+    pub const RED: Rgb332 = Rgb332(RED_MASK);
+
+    /// Green
+    pub const GREEN: Rgb332 = Rgb332(GREEN_MASK);
+
+    /// Blue
+    pub const BLUE: Rgb332 = Rgb332(BLUE_MASK);
+
+    /// Yellow
+    pub const YELLOW: Rgb332 = Rgb332(RED_MASK | GREEN_MASK);
+
+    /// Magenta
+    pub const MAGENTA: Rgb332 = Rgb332(RED_MASK | BLUE_MASK);
+
+    /// Cyan
+    pub const CYAN: Rgb332 = Rgb332(GREEN_MASK | BLUE_MASK);
+
+    /// Create a new color from 8 bit red, green and blue channels
+    ///
+    /// The 5 (or 6 for the blue channel) least significant bits are discarded
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self((r & RED_MASK) | ((g >> 3) & GREEN_MASK) | (b >> 6))
+    }
+
+    /// Get the red component as a `u8`
+    ///
+    /// The least significant 5 bits will always be `0`
+    pub const fn r(&self) -> u8 {
+        self.0 & RED_MASK
+    }
+
+    /// Get the green component as a `u8`
+    ///
+    /// The least significant 5 bits will always be `0`
+    pub const fn g(&self) -> u8 {
+        (self.0 & GREEN_MASK) << 3
+    }
+
+    /// Get the blue component as a `u8`
+    ///
+    /// The least significant 6 bits will always be `0`
+    pub const fn b(&self) -> u8 {
+        (self.0 & BLUE_MASK) << 6
+    }
+}
+
+impl PixelColor for Rgb332 {}
+
+/// Create a pixel from a raw RGB332 value
+impl From<u8> for Rgb332 {
+    fn from(other: u8) -> Self {
+        Self(other)
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values and convert them to a single 8 bit
+/// color
+///
+/// The 5 (or 6 for the blue channel) least significant bits are discarded
+impl From<(u8, u8, u8)> for Rgb332 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Rgb332::from(0xffu8), Rgb332::WHITE);
+        assert_eq!(Rgb332::from(0b101_010_01u8), Rgb332(0b101_010_01));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Rgb332::from((0xff, 0xff, 0xff)), Rgb332::WHITE);
+        assert_eq!(Rgb332::from((0, 0, 0)), Rgb332::BLACK);
+        assert_eq!(
+            Rgb332::from((0b1010_0000, 0b0110_0000, 0b1100_0000)),
+            Rgb332(0b101_011_11)
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Rgb332(0b101_011_10);
+
+        assert_eq!(p.r(), 0b101_00000);
+        assert_eq!(p.g(), 0b011_00000);
+        assert_eq!(p.b(), 0b10_000000);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Rgb332::RED, Rgb332::from((0xff, 0, 0)));
+        assert_eq!(Rgb332::GREEN, Rgb332::from((0, 0xff, 0)));
+        assert_eq!(Rgb332::BLUE, Rgb332::from((0, 0, 0xff)));
+        assert_eq!(Rgb332::CYAN, Rgb332::from((0, 0xff, 0xff)));
+    }
+}
@@ -18,24 +18,39 @@ const BLUE_MASK: u16 = 0b00000_000000_11111;
 pub struct Rgb565(pub u16);
 
 impl Rgb565 {
+    /// Create a new color from 8 bit red, green and blue channels
+    ///
+    /// The 2 or 3 (for the green channel) least significant bits are discarded
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        let value =
+        // Red
+        (((r as u16) << 8) & RED_MASK)
+        // Green
+        | (((g as u16) << 3) & GREEN_MASK)
+        // Blue
+        | ((b as u16) >> 3 & BLUE_MASK);
+
+        Self(value)
+    }
+
     /// Get the red component as a `u8`
     ///
     /// The least significant 3 bits will always be `0`
-    pub fn r(&self) -> u8 {
+    pub const fn r(&self) -> u8 {
         ((self.0 & RED_MASK) >> 8) as u8
     }
 
     /// Get the green component as a `u8`
     ///
     /// The least significant 2 bits will always be `0`
-    pub fn g(&self) -> u8 {
+    pub const fn g(&self) -> u8 {
         ((self.0 & GREEN_MASK) >> 3) as u8
     }
 
     /// Get the blue component as a `u8`
     ///
     /// The least significant 3 bits will always be `0`
-    pub fn b(&self) -> u8 {
+    pub const fn b(&self) -> u8 {
         ((self.0 & BLUE_MASK) << 3) as u8
     }
 }
@@ -65,15 +80,7 @@ impl From<u16> for Rgb565 {
 /// The 2 or 3 (for the green channel) least significant bits are discarded
 impl From<(u8, u8, u8)> for Rgb565 {
     fn from((r, g, b): (u8, u8, u8)) -> Self {
-        let value =
-        // Red
-        (((r as u16) << 8) & RED_MASK)
-        // Green
-        | (((g as u16) << 3) & GREEN_MASK)
-        // Blue
-        | ((b as u16) >> 3 & BLUE_MASK);
-
-        Self(value)
+        Self::new(r, g, b)
     }
 }
 
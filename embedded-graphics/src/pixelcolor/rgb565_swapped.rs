@@ -0,0 +1,129 @@
+//! Byte swapped RGB565 pixel type
+
+use crate::pixelcolor::{PixelColor, Rgb565};
+
+/// An RGB565 pixel stored with its two bytes swapped
+///
+/// Most SPI displays expect RGB565 data in big endian byte order, but microcontrollers are usually
+/// little endian. Storing colors in this type means a framebuffer of `Rgb565Swapped` values can be
+/// sent to the display as-is, instead of swapping the bytes of every pixel in the driver.
+///
+/// The inner `u16` holds the byte swapped value, so `Rgb565Swapped(0x00f8)` is pure red. Use
+/// `From` to convert to and from [`Rgb565`](./struct.Rgb565.html), which handles the swap.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::{Rgb565, Rgb565Swapped};
+///
+/// let red = Rgb565Swapped::from(Rgb565(0xf800));
+///
+/// assert_eq!(red, Rgb565Swapped(0x00f8));
+/// assert_eq!(red.0.to_le_bytes(), [0xf8, 0x00]);
+/// assert_eq!(Rgb565::from(red), Rgb565(0xf800));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgb565Swapped(pub u16);
+
+impl Rgb565Swapped {
+    /// Create a new color from 8 bit red, green and blue channels
+    ///
+    /// The 2 or 3 (for the green channel) least significant bits are discarded
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self(Rgb565::new(r, g, b).0.swap_bytes())
+    }
+
+    /// Get the color as an `Rgb565` in native byte order
+    pub const fn to_rgb565(&self) -> Rgb565 {
+        Rgb565(self.0.swap_bytes())
+    }
+
+    /// Get the red component as a `u8`
+    ///
+    /// The least significant 3 bits will always be `0`
+    pub const fn r(&self) -> u8 {
+        self.to_rgb565().r()
+    }
+
+    /// Get the green component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn g(&self) -> u8 {
+        self.to_rgb565().g()
+    }
+
+    /// Get the blue component as a `u8`
+    ///
+    /// The least significant 3 bits will always be `0`
+    pub const fn b(&self) -> u8 {
+        self.to_rgb565().b()
+    }
+
+    /// Get the 8 bit `(red, green, blue)` channels of this color
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        self.to_rgb565().to_rgb()
+    }
+}
+
+impl PixelColor for Rgb565Swapped {}
+
+/// Convert from an 8 bit greyscale colour, the same as for [`Rgb565`](./struct.Rgb565.html)
+impl From<u8> for Rgb565Swapped {
+    fn from(other: u8) -> Self {
+        Self::from(Rgb565::from(other))
+    }
+}
+
+/// Create a pixel from a raw value that is already byte swapped
+impl From<u16> for Rgb565Swapped {
+    fn from(other: u16) -> Self {
+        Self(other)
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values
+impl From<(u8, u8, u8)> for Rgb565Swapped {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<Rgb565> for Rgb565Swapped {
+    fn from(other: Rgb565) -> Self {
+        Self(other.0.swap_bytes())
+    }
+}
+
+impl From<Rgb565Swapped> for Rgb565 {
+    fn from(other: Rgb565Swapped) -> Self {
+        other.to_rgb565()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swaps_bytes() {
+        assert_eq!(Rgb565Swapped::from(Rgb565(0x1234)), Rgb565Swapped(0x3412));
+        assert_eq!(Rgb565::from(Rgb565Swapped(0x3412)), Rgb565(0x1234));
+        assert_eq!(Rgb565Swapped::new(0xff, 0, 0), Rgb565Swapped(0x00f8));
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Rgb565Swapped::from(Rgb565(0b11001_010101_10010));
+
+        assert_eq!(p.r(), 0b11001_000);
+        assert_eq!(p.g(), 0b010101_00);
+        assert_eq!(p.b(), 0b10010_000);
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Rgb565Swapped::from(0xffu8), Rgb565Swapped(0xffff));
+        assert_eq!(
+            Rgb565Swapped::from(0b1010_1010u8),
+            Rgb565Swapped::from(Rgb565(0b10101_101010_10101))
+        );
+    }
+}
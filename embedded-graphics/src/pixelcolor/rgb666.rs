@@ -0,0 +1,145 @@
+//! RGB666 pixel type
+
+use crate::pixelcolor::PixelColor;
+
+const RED_MASK: u32 = 0b111111_000000_000000;
+const GREEN_MASK: u32 = 0b000000_111111_000000;
+const BLUE_MASK: u32 = 0b000000_000000_111111;
+
+/// A pixel type defining the 18 bit RGB666 format used by many TFT controllers like the ILI9488
+///
+/// Pixel values are stored in the lower 18 bits of a `u32` segmented as follows:
+///
+/// ```text
+/// 17 16 15 14 13 12 11 10  9  8  7  6  5  4  3  2  1  0
+///  r  r  r  r  r  r  g  g  g  g  g  g  b  b  b  b  b  b
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgb666(pub u32);
+
+impl Rgb666 {
+    /// Create a new color from 8 bit red, green and blue channels
+    ///
+    /// The 2 least significant bits of each channel are discarded
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        let value =
+        // Red
+        (((r as u32) << 10) & RED_MASK)
+        // Green
+        | (((g as u32) << 4) & GREEN_MASK)
+        // Blue
+        | ((b as u32) >> 2 & BLUE_MASK);
+
+        Self(value)
+    }
+
+    /// Get the red component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn r(&self) -> u8 {
+        ((self.0 & RED_MASK) >> 10) as u8
+    }
+
+    /// Get the green component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn g(&self) -> u8 {
+        ((self.0 & GREEN_MASK) >> 4) as u8
+    }
+
+    /// Get the blue component as a `u8`
+    ///
+    /// The least significant 2 bits will always be `0`
+    pub const fn b(&self) -> u8 {
+        ((self.0 & BLUE_MASK) << 2) as u8
+    }
+}
+
+impl PixelColor for Rgb666 {}
+
+/// Convert from an 8 bit greyscale colour into an 18 bit greyscale representation
+///
+/// The 6 most significant bits are taken from the input and assigned to all three colour channels.
+impl From<u8> for Rgb666 {
+    fn from(other: u8) -> Self {
+        Self::from((other, other, other))
+    }
+}
+
+/// Create a pixel from a raw 18 bit value
+///
+/// Any bits above bit 17 are discarded.
+impl From<u32> for Rgb666 {
+    fn from(other: u32) -> Self {
+        Self(other & (RED_MASK | GREEN_MASK | BLUE_MASK))
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values and convert them to a single 18 bit
+/// color
+///
+/// The 2 least significant bits of each channel are discarded
+impl From<(u8, u8, u8)> for Rgb666 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+/// Build an 18 bit value from three big endian `u8`s
+///
+/// Any bits above bit 17 are discarded.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::Rgb666;
+///
+/// let pixel = Rgb666::from([0x03, 0xab, 0xcd]);
+///
+/// assert_eq!(pixel, Rgb666(0x3ABCD));
+/// ```
+impl From<[u8; 3]> for Rgb666 {
+    fn from(other: [u8; 3]) -> Self {
+        Self::from(u32::from_be_bytes([0, other[0], other[1], other[2]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Rgb666::from(0u8), Rgb666(0));
+        assert_eq!(Rgb666::from(0xffu8), Rgb666(0x3ffff));
+        assert_eq!(Rgb666::from(0b1010_1010u8), Rgb666(0b101010_101010_101010));
+    }
+
+    #[test]
+    fn from_u32() {
+        assert_eq!(Rgb666::from(0x3ffffu32), Rgb666(0x3ffff));
+        assert_eq!(Rgb666::from(0xffff_ffffu32), Rgb666(0x3ffff));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Rgb666::from((0xff, 0xff, 0xff)), Rgb666(0x3ffff));
+        assert_eq!(
+            Rgb666::from((0xff, 0x0f, 0b0101_0101)),
+            Rgb666(0b111111_000011_010101)
+        );
+    }
+
+    #[test]
+    fn from_be_bytes() {
+        assert_eq!(Rgb666::from([0x03, 0xff, 0xff]), Rgb666(0x3ffff));
+        assert_eq!(Rgb666::from([0xff, 0x00, 0x00]), Rgb666(0x30000));
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Rgb666(0b110011_010101_100101);
+
+        assert_eq!(p.r(), 0b110011_00);
+        assert_eq!(p.g(), 0b010101_00);
+        assert_eq!(p.b(), 0b100101_00);
+    }
+}
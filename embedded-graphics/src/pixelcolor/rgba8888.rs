@@ -0,0 +1,122 @@
+//! RGBA8888 pixel type
+
+use crate::pixelcolor::PixelColor;
+
+/// A pixel type defining the 32 bit RGBA8888 format with an alpha channel
+///
+/// Pixel values are stored in a single `u32` segmented as follows:
+///
+/// ```text
+/// 31 ... 24  23 ... 16  15 ... 8  7 ... 0
+///  r ...  r   g ...  g   b ... b  a ... a
+/// ```
+///
+/// An alpha value of `0` is fully transparent and `255` is fully opaque. Converting to an opaque
+/// color type like [`Rgb565`](./struct.Rgb565.html) discards the alpha channel, and converting from
+/// an opaque color type produces a fully opaque color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba8888(pub u32);
+
+impl Rgba8888 {
+    /// Create a new color from 8 bit red, green, blue and alpha channels
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(u32::from_be_bytes([r, g, b, a]))
+    }
+
+    /// Get the red component as a `u8`
+    pub const fn r(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Get the green component as a `u8`
+    pub const fn g(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Get the blue component as a `u8`
+    pub const fn b(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Get the alpha component as a `u8`
+    pub const fn a(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl PixelColor for Rgba8888 {}
+
+/// Convert from an 8 bit greyscale colour into a fully opaque grey
+impl From<u8> for Rgba8888 {
+    fn from(other: u8) -> Self {
+        Self::from((other, other, other))
+    }
+}
+
+/// Create a pixel from a raw RGBA8888 value
+impl From<u32> for Rgba8888 {
+    fn from(other: u32) -> Self {
+        Self(other)
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue)` color values and convert them to a fully opaque
+/// color
+impl From<(u8, u8, u8)> for Rgba8888 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::from((r, g, b, 0xff))
+    }
+}
+
+/// Take a tuple of 8 bit `(red, green, blue, alpha)` color values and convert them to a single
+/// 32 bit color
+impl From<(u8, u8, u8, u8)> for Rgba8888 {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+/// Build a `u32` from four big endian `u8`s in `[r, g, b, a]` order
+impl From<[u8; 4]> for Rgba8888 {
+    fn from(other: [u8; 4]) -> Self {
+        Self(u32::from_be_bytes(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(Rgba8888::from(0u8), Rgba8888(0x0000_00ff));
+        assert_eq!(Rgba8888::from(0xabu8), Rgba8888(0xabab_abff));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Rgba8888::from((0x12, 0x34, 0x56)), Rgba8888(0x1234_56ff));
+        assert_eq!(
+            Rgba8888::from((0x12, 0x34, 0x56, 0x78)),
+            Rgba8888(0x1234_5678)
+        );
+    }
+
+    #[test]
+    fn from_be_bytes() {
+        assert_eq!(
+            Rgba8888::from([0x12, 0x34, 0x56, 0x78]),
+            Rgba8888(0x1234_5678)
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let p = Rgba8888(0x1234_5678);
+
+        assert_eq!(p.r(), 0x12);
+        assert_eq!(p.g(), 0x34);
+        assert_eq!(p.b(), 0x56);
+        assert_eq!(p.a(), 0x78);
+    }
+}
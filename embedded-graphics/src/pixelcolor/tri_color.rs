@@ -0,0 +1,115 @@
+//! Tri-color e-paper pixel types
+
+use crate::pixelcolor::distance::distance_squared;
+use crate::pixelcolor::PixelColor;
+
+macro_rules! tri_color {
+    ($type:ident, $accent:ident, $accent_rgb:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Converting from an RGB color picks the ink closest to it. Converting from a `u8` treats
+        /// it as a grey level, so only black or white are produced.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $type {
+            /// White (no ink)
+            White,
+
+            /// Black ink
+            Black,
+
+            /// Accent ink
+            $accent,
+        }
+
+        impl $type {
+            /// Every ink this type can display
+            pub const INKS: [$type; 3] = [$type::White, $type::Black, $type::$accent];
+
+            /// Get the approximate 8 bit `(red, green, blue)` color of this ink
+            pub const fn to_rgb(self) -> (u8, u8, u8) {
+                match self {
+                    $type::White => (0xff, 0xff, 0xff),
+                    $type::Black => (0, 0, 0),
+                    $type::$accent => $accent_rgb,
+                }
+            }
+
+            /// Find the ink that is closest to the given 8 bit RGB color
+            pub fn nearest(r: u8, g: u8, b: u8) -> Self {
+                let mut best = $type::White;
+                let mut best_distance = u32::MAX;
+
+                for ink in Self::INKS.iter() {
+                    let d = distance_squared((r, g, b), ink.to_rgb());
+
+                    if d < best_distance {
+                        best = *ink;
+                        best_distance = d;
+                    }
+                }
+
+                best
+            }
+        }
+
+        impl Default for $type {
+            fn default() -> Self {
+                $type::White
+            }
+        }
+
+        impl PixelColor for $type {}
+
+        /// Convert from an 8 bit grey level into black or white
+        impl From<u8> for $type {
+            fn from(other: u8) -> Self {
+                if other >= 128 {
+                    $type::White
+                } else {
+                    $type::Black
+                }
+            }
+        }
+    };
+}
+
+tri_color!(
+    BlackWhiteRed,
+    Red,
+    (0xff, 0, 0),
+    "A pixel type for black, white and red tri-color e-paper displays"
+);
+tri_color!(
+    BlackWhiteYellow,
+    Yellow,
+    (0xff, 0xff, 0),
+    "A pixel type for black, white and yellow tri-color e-paper displays"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_ink() {
+        assert_eq!(BlackWhiteRed::nearest(0, 0, 0), BlackWhiteRed::Black);
+        assert_eq!(BlackWhiteRed::nearest(250, 240, 245), BlackWhiteRed::White);
+        assert_eq!(BlackWhiteRed::nearest(200, 30, 20), BlackWhiteRed::Red);
+        assert_eq!(BlackWhiteRed::nearest(0, 0, 255), BlackWhiteRed::Black);
+
+        assert_eq!(
+            BlackWhiteYellow::nearest(230, 220, 10),
+            BlackWhiteYellow::Yellow
+        );
+        assert_eq!(
+            BlackWhiteYellow::nearest(200, 30, 20),
+            BlackWhiteYellow::Black
+        );
+    }
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(BlackWhiteRed::from(0u8), BlackWhiteRed::Black);
+        assert_eq!(BlackWhiteRed::from(200u8), BlackWhiteRed::White);
+    }
+}
@@ -0,0 +1,242 @@
+//! CSS named colors
+//!
+//! The [`WebColors`](./trait.WebColors.html) trait provides the full set of
+//! [CSS named colors](https://www.w3.org/TR/css-color-4/#named-colors) as constants on every RGB
+//! color type. Colors are truncated to the bit depth of the type they're defined for.
+//!
+//! ```rust
+//! use embedded_graphics::pixelcolor::web_colors::WebColors;
+//! use embedded_graphics::pixelcolor::{Rgb565, Rgba8888};
+//!
+//! assert_eq!(Rgb565::CSS_WHITE, Rgb565(0xffff));
+//! assert_eq!(Rgba8888::CSS_CORNFLOWER_BLUE, Rgba8888(0x6495_edff));
+//! ```
+
+use crate::pixelcolor::{Argb8888, Bgr666, Rgb332, Rgb565, Rgb666, Rgba8888};
+
+/// Call `$callback!` with the list of CSS named colors, prepended by `$args`
+macro_rules! with_css_colors {
+    ($callback:ident, $($args:tt)*) => {
+        $callback! {
+            ($($args)*)
+            CSS_ALICE_BLUE, "aliceblue", 240, 248, 255;
+            CSS_ANTIQUE_WHITE, "antiquewhite", 250, 235, 215;
+            CSS_AQUA, "aqua", 0, 255, 255;
+            CSS_AQUAMARINE, "aquamarine", 127, 255, 212;
+            CSS_AZURE, "azure", 240, 255, 255;
+            CSS_BEIGE, "beige", 245, 245, 220;
+            CSS_BISQUE, "bisque", 255, 228, 196;
+            CSS_BLACK, "black", 0, 0, 0;
+            CSS_BLANCHED_ALMOND, "blanchedalmond", 255, 235, 205;
+            CSS_BLUE, "blue", 0, 0, 255;
+            CSS_BLUE_VIOLET, "blueviolet", 138, 43, 226;
+            CSS_BROWN, "brown", 165, 42, 42;
+            CSS_BURLY_WOOD, "burlywood", 222, 184, 135;
+            CSS_CADET_BLUE, "cadetblue", 95, 158, 160;
+            CSS_CHARTREUSE, "chartreuse", 127, 255, 0;
+            CSS_CHOCOLATE, "chocolate", 210, 105, 30;
+            CSS_CORAL, "coral", 255, 127, 80;
+            CSS_CORNFLOWER_BLUE, "cornflowerblue", 100, 149, 237;
+            CSS_CORNSILK, "cornsilk", 255, 248, 220;
+            CSS_CRIMSON, "crimson", 220, 20, 60;
+            CSS_CYAN, "cyan", 0, 255, 255;
+            CSS_DARK_BLUE, "darkblue", 0, 0, 139;
+            CSS_DARK_CYAN, "darkcyan", 0, 139, 139;
+            CSS_DARK_GOLDENROD, "darkgoldenrod", 184, 134, 11;
+            CSS_DARK_GRAY, "darkgray", 169, 169, 169;
+            CSS_DARK_GREEN, "darkgreen", 0, 100, 0;
+            CSS_DARK_GREY, "darkgrey", 169, 169, 169;
+            CSS_DARK_KHAKI, "darkkhaki", 189, 183, 107;
+            CSS_DARK_MAGENTA, "darkmagenta", 139, 0, 139;
+            CSS_DARK_OLIVE_GREEN, "darkolivegreen", 85, 107, 47;
+            CSS_DARK_ORANGE, "darkorange", 255, 140, 0;
+            CSS_DARK_ORCHID, "darkorchid", 153, 50, 204;
+            CSS_DARK_RED, "darkred", 139, 0, 0;
+            CSS_DARK_SALMON, "darksalmon", 233, 150, 122;
+            CSS_DARK_SEA_GREEN, "darkseagreen", 143, 188, 143;
+            CSS_DARK_SLATE_BLUE, "darkslateblue", 72, 61, 139;
+            CSS_DARK_SLATE_GRAY, "darkslategray", 47, 79, 79;
+            CSS_DARK_SLATE_GREY, "darkslategrey", 47, 79, 79;
+            CSS_DARK_TURQUOISE, "darkturquoise", 0, 206, 209;
+            CSS_DARK_VIOLET, "darkviolet", 148, 0, 211;
+            CSS_DEEP_PINK, "deeppink", 255, 20, 147;
+            CSS_DEEP_SKY_BLUE, "deepskyblue", 0, 191, 255;
+            CSS_DIM_GRAY, "dimgray", 105, 105, 105;
+            CSS_DIM_GREY, "dimgrey", 105, 105, 105;
+            CSS_DODGER_BLUE, "dodgerblue", 30, 144, 255;
+            CSS_FIREBRICK, "firebrick", 178, 34, 34;
+            CSS_FLORAL_WHITE, "floralwhite", 255, 250, 240;
+            CSS_FOREST_GREEN, "forestgreen", 34, 139, 34;
+            CSS_FUCHSIA, "fuchsia", 255, 0, 255;
+            CSS_GAINSBORO, "gainsboro", 220, 220, 220;
+            CSS_GHOST_WHITE, "ghostwhite", 248, 248, 255;
+            CSS_GOLD, "gold", 255, 215, 0;
+            CSS_GOLDENROD, "goldenrod", 218, 165, 32;
+            CSS_GRAY, "gray", 128, 128, 128;
+            CSS_GREEN, "green", 0, 128, 0;
+            CSS_GREEN_YELLOW, "greenyellow", 173, 255, 47;
+            CSS_GREY, "grey", 128, 128, 128;
+            CSS_HONEYDEW, "honeydew", 240, 255, 240;
+            CSS_HOT_PINK, "hotpink", 255, 105, 180;
+            CSS_INDIAN_RED, "indianred", 205, 92, 92;
+            CSS_INDIGO, "indigo", 75, 0, 130;
+            CSS_IVORY, "ivory", 255, 255, 240;
+            CSS_KHAKI, "khaki", 240, 230, 140;
+            CSS_LAVENDER, "lavender", 230, 230, 250;
+            CSS_LAVENDER_BLUSH, "lavenderblush", 255, 240, 245;
+            CSS_LAWN_GREEN, "lawngreen", 124, 252, 0;
+            CSS_LEMON_CHIFFON, "lemonchiffon", 255, 250, 205;
+            CSS_LIGHT_BLUE, "lightblue", 173, 216, 230;
+            CSS_LIGHT_CORAL, "lightcoral", 240, 128, 128;
+            CSS_LIGHT_CYAN, "lightcyan", 224, 255, 255;
+            CSS_LIGHT_GOLDENROD_YELLOW, "lightgoldenrodyellow", 250, 250, 210;
+            CSS_LIGHT_GRAY, "lightgray", 211, 211, 211;
+            CSS_LIGHT_GREEN, "lightgreen", 144, 238, 144;
+            CSS_LIGHT_GREY, "lightgrey", 211, 211, 211;
+            CSS_LIGHT_PINK, "lightpink", 255, 182, 193;
+            CSS_LIGHT_SALMON, "lightsalmon", 255, 160, 122;
+            CSS_LIGHT_SEA_GREEN, "lightseagreen", 32, 178, 170;
+            CSS_LIGHT_SKY_BLUE, "lightskyblue", 135, 206, 250;
+            CSS_LIGHT_SLATE_GRAY, "lightslategray", 119, 136, 153;
+            CSS_LIGHT_SLATE_GREY, "lightslategrey", 119, 136, 153;
+            CSS_LIGHT_STEEL_BLUE, "lightsteelblue", 176, 196, 222;
+            CSS_LIGHT_YELLOW, "lightyellow", 255, 255, 224;
+            CSS_LIME, "lime", 0, 255, 0;
+            CSS_LIME_GREEN, "limegreen", 50, 205, 50;
+            CSS_LINEN, "linen", 250, 240, 230;
+            CSS_MAGENTA, "magenta", 255, 0, 255;
+            CSS_MAROON, "maroon", 128, 0, 0;
+            CSS_MEDIUM_AQUAMARINE, "mediumaquamarine", 102, 205, 170;
+            CSS_MEDIUM_BLUE, "mediumblue", 0, 0, 205;
+            CSS_MEDIUM_ORCHID, "mediumorchid", 186, 85, 211;
+            CSS_MEDIUM_PURPLE, "mediumpurple", 147, 112, 219;
+            CSS_MEDIUM_SEA_GREEN, "mediumseagreen", 60, 179, 113;
+            CSS_MEDIUM_SLATE_BLUE, "mediumslateblue", 123, 104, 238;
+            CSS_MEDIUM_SPRING_GREEN, "mediumspringgreen", 0, 250, 154;
+            CSS_MEDIUM_TURQUOISE, "mediumturquoise", 72, 209, 204;
+            CSS_MEDIUM_VIOLET_RED, "mediumvioletred", 199, 21, 133;
+            CSS_MIDNIGHT_BLUE, "midnightblue", 25, 25, 112;
+            CSS_MINT_CREAM, "mintcream", 245, 255, 250;
+            CSS_MISTY_ROSE, "mistyrose", 255, 228, 225;
+            CSS_MOCCASIN, "moccasin", 255, 228, 181;
+            CSS_NAVAJO_WHITE, "navajowhite", 255, 222, 173;
+            CSS_NAVY, "navy", 0, 0, 128;
+            CSS_OLD_LACE, "oldlace", 253, 245, 230;
+            CSS_OLIVE, "olive", 128, 128, 0;
+            CSS_OLIVE_DRAB, "olivedrab", 107, 142, 35;
+            CSS_ORANGE, "orange", 255, 165, 0;
+            CSS_ORANGE_RED, "orangered", 255, 69, 0;
+            CSS_ORCHID, "orchid", 218, 112, 214;
+            CSS_PALE_GOLDENROD, "palegoldenrod", 238, 232, 170;
+            CSS_PALE_GREEN, "palegreen", 152, 251, 152;
+            CSS_PALE_TURQUOISE, "paleturquoise", 175, 238, 238;
+            CSS_PALE_VIOLET_RED, "palevioletred", 219, 112, 147;
+            CSS_PAPAYA_WHIP, "papayawhip", 255, 239, 213;
+            CSS_PEACH_PUFF, "peachpuff", 255, 218, 185;
+            CSS_PERU, "peru", 205, 133, 63;
+            CSS_PINK, "pink", 255, 192, 203;
+            CSS_PLUM, "plum", 221, 160, 221;
+            CSS_POWDER_BLUE, "powderblue", 176, 224, 230;
+            CSS_PURPLE, "purple", 128, 0, 128;
+            CSS_REBECCA_PURPLE, "rebeccapurple", 102, 51, 153;
+            CSS_RED, "red", 255, 0, 0;
+            CSS_ROSY_BROWN, "rosybrown", 188, 143, 143;
+            CSS_ROYAL_BLUE, "royalblue", 65, 105, 225;
+            CSS_SADDLE_BROWN, "saddlebrown", 139, 69, 19;
+            CSS_SALMON, "salmon", 250, 128, 114;
+            CSS_SANDY_BROWN, "sandybrown", 244, 164, 96;
+            CSS_SEA_GREEN, "seagreen", 46, 139, 87;
+            CSS_SEA_SHELL, "seashell", 255, 245, 238;
+            CSS_SIENNA, "sienna", 160, 82, 45;
+            CSS_SILVER, "silver", 192, 192, 192;
+            CSS_SKY_BLUE, "skyblue", 135, 206, 235;
+            CSS_SLATE_BLUE, "slateblue", 106, 90, 205;
+            CSS_SLATE_GRAY, "slategray", 112, 128, 144;
+            CSS_SLATE_GREY, "slategrey", 112, 128, 144;
+            CSS_SNOW, "snow", 255, 250, 250;
+            CSS_SPRING_GREEN, "springgreen", 0, 255, 127;
+            CSS_STEEL_BLUE, "steelblue", 70, 130, 180;
+            CSS_TAN, "tan", 210, 180, 140;
+            CSS_TEAL, "teal", 0, 128, 128;
+            CSS_THISTLE, "thistle", 216, 191, 216;
+            CSS_TOMATO, "tomato", 255, 99, 71;
+            CSS_TURQUOISE, "turquoise", 64, 224, 208;
+            CSS_VIOLET, "violet", 238, 130, 238;
+            CSS_WHEAT, "wheat", 245, 222, 179;
+            CSS_WHITE, "white", 255, 255, 255;
+            CSS_WHITE_SMOKE, "whitesmoke", 245, 245, 245;
+            CSS_YELLOW, "yellow", 255, 255, 0;
+            CSS_YELLOW_GREEN, "yellowgreen", 154, 205, 50;
+        }
+    };
+}
+
+macro_rules! define_web_colors {
+    (() $($name:ident, $css:expr, $r:expr, $g:expr, $b:expr;)*) => {
+        /// CSS named colors
+        ///
+        /// Each constant is prefixed with `CSS_` so that it doesn't clash with constants defined
+        /// on the color types themselves, like [`Rgb332::GREEN`](../struct.Rgb332.html).
+        pub trait WebColors: Sized {
+            $(
+                #[doc = concat!("CSS color `", $css, "`")]
+                const $name: Self;
+            )*
+        }
+    };
+}
+
+macro_rules! impl_web_colors {
+    (($t:ty, $new:path) $($name:ident, $css:expr, $r:expr, $g:expr, $b:expr;)*) => {
+        impl WebColors for $t {
+            $(
+                const $name: Self = $new($r, $g, $b);
+            )*
+        }
+    };
+}
+
+with_css_colors!(define_web_colors,);
+
+/// Build an opaque RGBA8888 color from 8 bit channels
+const fn rgba8888(r: u8, g: u8, b: u8) -> Rgba8888 {
+    Rgba8888::new(r, g, b, 0xff)
+}
+
+/// Build an opaque ARGB8888 color from 8 bit channels
+const fn argb8888(r: u8, g: u8, b: u8) -> Argb8888 {
+    Argb8888::new(r, g, b, 0xff)
+}
+
+with_css_colors!(impl_web_colors, Rgb332, Rgb332::new);
+with_css_colors!(impl_web_colors, Rgb565, Rgb565::new);
+with_css_colors!(impl_web_colors, Rgb666, Rgb666::new);
+with_css_colors!(impl_web_colors, Bgr666, Bgr666::new);
+with_css_colors!(impl_web_colors, Rgba8888, rgba8888);
+with_css_colors!(impl_web_colors, Argb8888, argb8888);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_tuple_conversion() {
+        let (r, g, b) = (0x64, 0x95, 0xed);
+
+        assert_eq!(Rgb332::CSS_CORNFLOWER_BLUE, Rgb332::from((r, g, b)));
+        assert_eq!(Rgb565::CSS_CORNFLOWER_BLUE, Rgb565::from((r, g, b)));
+        assert_eq!(Rgb666::CSS_CORNFLOWER_BLUE, Rgb666::from((r, g, b)));
+        assert_eq!(Bgr666::CSS_CORNFLOWER_BLUE, Bgr666::from((r, g, b)));
+        assert_eq!(Rgba8888::CSS_CORNFLOWER_BLUE, Rgba8888::from((r, g, b)));
+        assert_eq!(Argb8888::CSS_CORNFLOWER_BLUE, Argb8888::from((r, g, b)));
+    }
+
+    #[test]
+    fn css_values() {
+        assert_eq!(Rgba8888::CSS_BLACK, Rgba8888(0x0000_00ff));
+        assert_eq!(Rgba8888::CSS_GREEN, Rgba8888(0x0080_00ff));
+        assert_eq!(Rgba8888::CSS_LIME, Rgba8888(0x00ff_00ff));
+        assert_eq!(Rgba8888::CSS_REBECCA_PURPLE, Rgba8888(0x6633_99ff));
+        assert_eq!(Rgb565::CSS_RED, Rgb565(0xf800));
+    }
+}
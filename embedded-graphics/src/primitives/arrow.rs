@@ -0,0 +1,555 @@
+//! The arrow primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{EdgeStroke, PointPairs, ShortPath};
+use super::line::{Line, LineIterator};
+use super::smooth::isqrt;
+use super::thick::{stroke_margin, ThickStrokePixels};
+use super::triangle::{Triangle, TriangleIterator};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+
+/// Default length of an arrow head, from its tip to its base, in pixels
+pub const DEFAULT_HEAD_LENGTH: u32 = 5;
+
+/// Default width of the base of an arrow head in pixels
+pub const DEFAULT_HEAD_WIDTH: u32 = 6;
+
+/// Shape of the head of an [`Arrow`](./struct.Arrow.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ArrowHead {
+    /// Solid triangle, filled with the stroke color
+    #[default]
+    Filled,
+
+    /// Two lines meeting at the tip, drawn with the same stroke as the shaft
+    Open,
+}
+
+/// Arrow primitive
+///
+/// A line from `start` to `end` with a head pointing at `end`. The shaft is drawn with the stroke
+/// color, width, dash pattern and cap of the style, while the head is always solid. Open heads
+/// use the style's line join where their two lines meet at the tip.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::arrow::{Arrow, ArrowHead};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Wind from the north west
+/// let wind = Arrow::new(Coord::new(2, 2), Coord::new(12, 12)).stroke(Some(1u8));
+///
+/// // Thick arrow with an open head
+/// let next = Arrow::new(Coord::new(14, 8), Coord::new(22, 8))
+///     .head(ArrowHead::Open)
+///     .head_size(4, 6)
+///     .stroke(Some(1u8))
+///     .stroke_width(2);
+///
+/// display.draw(wind);
+/// display.draw(next);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Arrow<C: PixelColor> {
+    /// Start of the shaft
+    pub start: Coord,
+
+    /// Tip of the head
+    pub end: Coord,
+
+    /// Shape of the head
+    pub head: ArrowHead,
+
+    /// Length of the head from its tip to its base
+    pub head_length: u32,
+
+    /// Width of the base of the head
+    pub head_width: u32,
+
+    /// Arrow style
+    pub style: Style<C>,
+}
+
+impl<C> Arrow<C>
+where
+    C: PixelColor,
+{
+    /// Create a new arrow from `start` pointing at `end` with a filled head of the default size
+    pub fn new(start: Coord, end: Coord) -> Self {
+        Arrow {
+            start,
+            end,
+            head: ArrowHead::default(),
+            head_length: DEFAULT_HEAD_LENGTH,
+            head_width: DEFAULT_HEAD_WIDTH,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the shape of the head
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+
+        self
+    }
+
+    /// Set the length and width of the head in pixels
+    pub fn head_size(mut self, length: u32, width: u32) -> Self {
+        self.head_length = length;
+        self.head_width = width;
+
+        self
+    }
+
+    /// Corners of the head as `[left, tip, right]`, and the center of its base
+    ///
+    /// Heads longer than the arrow are shortened to fit. Returns `None` for arrows of zero length,
+    /// which have no direction to point in.
+    fn head_points(&self) -> Option<([Coord; 3], Coord)> {
+        let dx = i64::from(self.end[0]) - i64::from(self.start[0]);
+        let dy = i64::from(self.end[1]) - i64::from(self.start[1]);
+        let length = isqrt((dx * dx + dy * dy) as u64) as i64;
+
+        if length == 0 {
+            return None;
+        }
+
+        let head_length = i64::from(self.head_length).min(length);
+        let half_width = i64::from(self.head_width);
+
+        // Scale a vector along the arrow to `distance`, rounding to the nearest pixel
+        let scale = |v: i64, distance: i64| {
+            let v = v * distance;
+
+            ((v + v.signum() * length / 2) / length) as i32
+        };
+
+        let base = self.end - Coord::new(scale(dx, head_length), scale(dy, head_length));
+
+        // Perpendicular to the arrow, with the width halved by doubling the divisor
+        let across = |v: i64| {
+            let v = v * half_width;
+
+            ((v + v.signum() * length) / (2 * length)) as i32
+        };
+        let offset = Coord::new(across(-dy), across(dx));
+
+        Some(([base - offset, self.end, base + offset], base))
+    }
+}
+
+impl<C> Primitive for Arrow<C> where C: PixelColor {}
+
+impl<C> Intersects<C> for Arrow<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
+impl<C> Dimensions for Arrow<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        let (corners, _) = self.head_points().unwrap_or(([self.end; 3], self.end));
+
+        corners.iter().fold(self.start, |tl, p| {
+            Coord::new(tl[0].min(p[0]), tl[1].min(p[1]))
+        })
+    }
+
+    fn bottom_right(&self) -> Coord {
+        let (corners, _) = self.head_points().unwrap_or(([self.end; 3], self.end));
+
+        corners.iter().fold(self.start, |br, p| {
+            Coord::new(br[0].max(p[0]), br[1].max(p[1]))
+        })
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<C> WithStyle<C> for Arrow<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Arrow<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C> IntoIterator for Arrow<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ArrowIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Arrow<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = ArrowIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let style = self.style;
+        let head = self.head_points();
+        let head_style = Style {
+            stroke_dash: None,
+            ..style
+        };
+
+        let mut iter = ArrowIterator {
+            shaft: Line::new(self.start, self.end).style(style).into_iter(),
+            filled: None,
+            thin: None,
+            thick: None,
+        };
+
+        if let Some((corners, base)) = head {
+            match self.head {
+                ArrowHead::Filled => {
+                    // Stop the shaft at the base so a thick shaft can't poke through the tip
+                    iter.shaft = Line::new(self.start, base).style(style).into_iter();
+
+                    let [left, tip, right] = corners;
+                    iter.filled = Some(
+                        Triangle::new(left, tip, right)
+                            .style(Style {
+                                fill_color: style.stroke_color,
+                                stroke_width: 1,
+                                stroke_overlap: StrokeOverlap::Exclusive,
+                                ..head_style
+                            })
+                            .into_iter(),
+                    );
+                }
+                ArrowHead::Open => {
+                    let vertices = ShortPath::new(&corners);
+
+                    if style.stroke_width > 1 {
+                        iter.thick = Some(ThickStrokePixels::new(vertices, &head_style));
+                    } else {
+                        iter.thin = Some(EdgeStroke::new(PointPairs::new(vertices), head_style));
+                    }
+                }
+            }
+        }
+
+        iter
+    }
+}
+
+/// Pixel iterator for each pixel in an arrow
+#[derive(Debug, Clone, Copy)]
+pub struct ArrowIterator<C>
+where
+    C: PixelColor,
+{
+    shaft: LineIterator<C>,
+    /// Filled head
+    filled: Option<TriangleIterator<C>>,
+    /// Open head one pixel wide
+    thin: Option<EdgeStroke<C, PointPairs<ShortPath>>>,
+    /// Open head with a wider stroke
+    thick: Option<ThickStrokePixels<C, ShortPath>>,
+}
+
+impl<C> Iterator for ArrowIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.shaft.next() {
+            return Some(pixel);
+        }
+
+        // Pixels where the head meets the shaft have already been drawn by the shaft
+        loop {
+            let pixel = self
+                .filled
+                .as_mut()
+                .and_then(Iterator::next)
+                .or_else(|| self.thin.as_mut().and_then(Iterator::next))
+                .or_else(|| self.thick.as_mut().and_then(Iterator::next))?;
+
+            if !self.shaft.covers(pixel.0.to_signed()) {
+                return Some(pixel);
+            }
+        }
+    }
+}
+
+impl<C> Drawable for Arrow<C> where C: PixelColor {}
+
+impl<C> Transform for Arrow<C>
+where
+    C: PixelColor,
+{
+    /// Translate the arrow from its current position to a new position by (x, y) pixels,
+    /// returning a new `Arrow`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::arrow::Arrow;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let arrow: Arrow<u8> = Arrow::new(Coord::new(5, 10), Coord::new(15, 20));
+    /// let moved = arrow.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Coord::new(15, 20));
+    /// assert_eq!(moved.end, Coord::new(25, 30));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            start: self.start + by,
+            end: self.end + by,
+            ..*self
+        }
+    }
+
+    /// Translate the arrow from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.start += by;
+        self.end += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    #[test]
+    fn head_points() {
+        let arrow: Arrow<u8> = Arrow::new(Coord::new(2, 8), Coord::new(20, 8)).head_size(5, 6);
+
+        let (corners, base) = arrow.head_points().unwrap();
+        assert_eq!(base, Coord::new(15, 8));
+        assert_eq!(
+            corners,
+            [Coord::new(15, 5), Coord::new(20, 8), Coord::new(15, 11)]
+        );
+
+        // Pointing up
+        let arrow: Arrow<u8> = Arrow::new(Coord::new(8, 14), Coord::new(8, 2)).head_size(4, 4);
+        let (corners, _) = arrow.head_points().unwrap();
+        assert_eq!(
+            corners,
+            [Coord::new(6, 6), Coord::new(8, 2), Coord::new(10, 6)]
+        );
+    }
+
+    #[test]
+    fn filled_head() {
+        let mut display = Display::default();
+        display.draw(Arrow::new(Coord::new(2, 8), Coord::new(20, 8)).stroke(Some(1u8)));
+
+        // Shaft, tip and the inside of the head
+        assert_eq!(display.0[8][2], 1);
+        assert_eq!(display.0[8][20], 1);
+        assert_eq!(display.0[7][16], 1);
+        assert_eq!(display.0[5][15], 1);
+        assert_eq!(display.0[7][10], 0);
+    }
+
+    #[test]
+    fn open_head() {
+        let mut display = Display::default();
+        display.draw(
+            Arrow::new(Coord::new(2, 8), Coord::new(20, 8))
+                .head(ArrowHead::Open)
+                .stroke(Some(1u8)),
+        );
+
+        assert_eq!(display.0[8][20], 1);
+        assert_eq!(display.0[5][15], 1);
+        assert_eq!(display.0[11][15], 1);
+
+        // Hollow between the sides of the head and the shaft
+        assert_eq!(display.0[7][16], 0);
+        assert_eq!(display.count(1), 19 + 2 * 5);
+    }
+
+    #[test]
+    fn dashed_shaft_solid_head() {
+        let mut display = Display::default();
+        display.draw(
+            Arrow::new(Coord::new(0, 8), Coord::new(20, 8))
+                .stroke(Some(1u8))
+                .stroke_dash(Some(DashPattern::new(&[2, 2]))),
+        );
+
+        assert_eq!(display.0[8][2], 0);
+        assert_eq!(display.0[5][15], 1);
+        assert_eq!(display.0[6][15], 1);
+    }
+
+    #[test]
+    fn head_and_shaft_pixels_are_drawn_once() {
+        let ends = [
+            (Coord::new(2, 8), Coord::new(20, 8)),
+            (Coord::new(3, 3), Coord::new(19, 14)),
+            (Coord::new(20, 2), Coord::new(6, 12)),
+        ];
+
+        for &(start, end) in ends.iter() {
+            for &head in [ArrowHead::Filled, ArrowHead::Open].iter() {
+                for width in 1..=4 {
+                    for &stroke_dash in [None, Some(DashPattern::DOTTED)].iter() {
+                        for &stroke_overlap in
+                            [StrokeOverlap::Exclusive, StrokeOverlap::OverFill].iter()
+                        {
+                            let arrow = Arrow::new(start, end).head(head).style(Style {
+                                stroke_width: width,
+                                stroke_dash,
+                                stroke_overlap,
+                                ..Style::stroke(1u8)
+                            });
+
+                            let mut counts = [[0u8; 24]; 24];
+                            for Pixel(point, _) in arrow {
+                                let count = &mut counts[point[1] as usize][point[0] as usize];
+                                *count += 1;
+
+                                assert_eq!(*count, 1, "{:?} drawn more than once", point);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn short_and_empty_arrows() {
+        let arrow: Arrow<u8> = Arrow::new(Coord::new(5, 5), Coord::new(7, 5));
+        let (_, base) = arrow.head_points().unwrap();
+        assert_eq!(base, Coord::new(5, 5));
+
+        let empty: Arrow<u8> = Arrow::new(Coord::new(5, 5), Coord::new(5, 5)).stroke(Some(1));
+        assert!(empty.head_points().is_none());
+        assert_eq!(empty.into_iter().count(), 0);
+        assert_eq!(empty.size(), UnsignedCoord::new(0, 0));
+    }
+
+    #[test]
+    fn dimensions() {
+        let arrow: Arrow<u8> = Arrow::new(Coord::new(2, 8), Coord::new(20, 8));
+
+        assert_eq!(arrow.top_left(), Coord::new(2, 5));
+        assert_eq!(arrow.bottom_right(), Coord::new(20, 11));
+    }
+}
@@ -0,0 +1,345 @@
+//! Draw many primitives with one shared style
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled, WithStyle,
+};
+use crate::unsignedcoord::UnsignedCoord;
+use core::fmt;
+use core::iter::Copied;
+use core::slice;
+
+/// A group of primitives drawn with one shared style
+///
+/// Grids, meshes and other drawings made of many similar shapes can be drawn with a single call
+/// to [`draw`](../../trait.Drawing.html#tymethod.draw) instead of styling and drawing each shape
+/// on its own. The batch's style replaces whatever style the primitives already have.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Batch, Line};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Shapes from a slice
+/// let lines = [
+///     Line::new(Coord::new(0, 0), Coord::new(10, 10)),
+///     Line::new(Coord::new(0, 10), Coord::new(10, 0)),
+/// ];
+///
+/// display.draw(Batch::from_slice(&lines).stroke(Some(1u8)));
+///
+/// // Shapes from an iterator
+/// let grid = (0..4).map(|i| Line::new(Coord::new(i * 5, 0), Coord::new(i * 5, 15)));
+///
+/// display.draw(Batch::new(grid).stroke(Some(2u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Batch<I, C: PixelColor> {
+    /// Primitives to draw
+    pub primitives: I,
+
+    /// Style applied to every primitive
+    pub style: Style<C>,
+}
+
+impl<I, C> Batch<I, C>
+where
+    I: Iterator,
+    C: PixelColor,
+{
+    /// Create a new batch from an iterator of primitives
+    pub fn new<P>(primitives: P) -> Self
+    where
+        P: IntoIterator<IntoIter = I, Item = I::Item>,
+    {
+        Batch {
+            primitives: primitives.into_iter(),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a, P, C> Batch<Copied<slice::Iter<'a, P>>, C>
+where
+    P: Copy,
+    C: PixelColor,
+{
+    /// Create a new batch from a slice of primitives
+    pub fn from_slice(primitives: &'a [P]) -> Self {
+        Batch {
+            primitives: primitives.iter().copied(),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<I, C> WithStyle<C> for Batch<I, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<I, C> Styled<C> for Batch<I, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<I, C> Dimensions for Batch<I, C>
+where
+    I: Iterator + Clone,
+    I::Item: Dimensions,
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        let (top_left, bottom_right) = self.bounds();
+
+        (bottom_right - top_left).abs().to_unsigned()
+    }
+}
+
+impl<I, C> Batch<I, C>
+where
+    I: Iterator + Clone,
+    I::Item: Dimensions,
+    C: PixelColor,
+{
+    /// Box around every primitive in the batch, or a zero sized box for an empty batch
+    fn bounds(&self) -> (Coord, Coord) {
+        self.primitives
+            .clone()
+            .map(|primitive| (primitive.top_left(), primitive.bottom_right()))
+            .fold(None, |bounds: Option<(Coord, Coord)>, (tl, br)| {
+                Some(match bounds {
+                    Some((min, max)) => (
+                        Coord::new(min[0].min(tl[0]), min[1].min(tl[1])),
+                        Coord::new(max[0].max(br[0]), max[1].max(br[1])),
+                    ),
+                    None => (tl, br),
+                })
+            })
+            .unwrap_or((Coord::new(0, 0), Coord::new(0, 0)))
+    }
+}
+
+impl<I, C> Drawable for Batch<I, C> where C: PixelColor {}
+
+impl<I, C> IntoIterator for Batch<I, C>
+where
+    I: Iterator,
+    I::Item: WithStyle<C> + IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = BatchIterator<I, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BatchIterator {
+            primitives: self.primitives,
+            style: self.style,
+            current: None,
+        }
+    }
+}
+
+/// Pixel iterator for each primitive in a [`Batch`](./struct.Batch.html) in turn
+pub struct BatchIterator<I, C>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+    C: PixelColor,
+{
+    primitives: I,
+    style: Style<C>,
+    current: Option<<I::Item as IntoIterator>::IntoIter>,
+}
+
+impl<I, C> fmt::Debug for BatchIterator<I, C>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: fmt::Debug,
+    C: PixelColor + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BatchIterator")
+            .field("primitives", &self.primitives)
+            .field("style", &self.style)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+impl<I, C> Iterator for BatchIterator<I, C>
+where
+    I: Iterator,
+    I::Item: WithStyle<C> + IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pixel) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(pixel);
+            }
+
+            let primitive = self.primitives.next()?;
+            self.current = Some(primitive.style(self.style).into_iter());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Line, Rectangle};
+    use crate::Drawing;
+
+    #[test]
+    fn matches_drawing_each_primitive() {
+        let lines = [
+            Line::new(Coord::new(0, 0), Coord::new(20, 15)),
+            Line::new(Coord::new(0, 15), Coord::new(20, 0)),
+            Line::new(Coord::new(10, 0), Coord::new(10, 15)),
+        ];
+
+        let mut expected = Display::default();
+        for line in lines.iter() {
+            expected.draw(line.stroke(Some(1u8)).stroke_width(2));
+        }
+
+        let mut display = Display::default();
+        display.draw(Batch::from_slice(&lines).stroke(Some(1u8)).stroke_width(2));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn style_replaces_primitive_style() {
+        let circles = (0..3).map(|i| Circle::new(Coord::new(4 + i * 8, 8), 3).stroke(Some(9u8)));
+
+        let mut display = Display::default();
+        display.draw(Batch::new(circles).fill(Some(2u8)));
+
+        assert!(display.0.iter().flatten().all(|&p| p != 9));
+        assert_eq!(display.0[8][4], 2);
+        assert_eq!(display.0[8][20], 2);
+    }
+
+    #[test]
+    fn empty_batch() {
+        let rects: [Rectangle<u8>; 0] = [];
+        let batch = Batch::from_slice(&rects).fill(Some(1u8));
+
+        assert_eq!(batch.clone().into_iter().count(), 0);
+        assert_eq!(batch.top_left(), Coord::new(0, 0));
+        assert_eq!(batch.size(), UnsignedCoord::new(0, 0));
+    }
+
+    #[test]
+    fn dimensions() {
+        let rects: [Rectangle<u8>; 2] = [
+            Rectangle::new(Coord::new(5, 2), Coord::new(8, 6)),
+            Rectangle::new(Coord::new(1, 4), Coord::new(3, 10)),
+        ];
+        let batch: Batch<_, u8> = Batch::from_slice(&rects);
+
+        assert_eq!(batch.top_left(), Coord::new(1, 2));
+        assert_eq!(batch.bottom_right(), Coord::new(8, 10));
+        assert_eq!(batch.size(), UnsignedCoord::new(7, 8));
+    }
+}
@@ -0,0 +1,652 @@
+//! Quadratic and cubic Bézier curve primitives
+//!
+//! Curves are drawn by flattening them into a series of straight line segments. The number of
+//! segments is chosen so that no point on the drawn curve strays more than a given `tolerance` from
+//! the true curve. The tolerance is measured in 1/16ths of a pixel and defaults to
+//! [`DEFAULT_TOLERANCE`](constant.DEFAULT_TOLERANCE.html), a quarter of a pixel.
+//!
+//! Only the `stroke` and `stroke_dash` style properties have any effect on curves, which are always
+//! drawn one pixel wide.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{EdgeStroke, PointPairs};
+use super::smooth::isqrt;
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::thick::stroke_margin;
+use crate::primitives::{Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Default flattening tolerance in 1/16ths of a pixel
+pub const DEFAULT_TOLERANCE: u32 = 4;
+
+/// Upper limit on the number of line segments a single curve is flattened into
+const MAX_SEGMENTS: u32 = 256;
+
+/// Quadratic Bézier curve primitive
+///
+/// # Examples
+///
+/// The [macro examples](../../macro.egquadratic_bezier.html) make for more concise code.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::QuadraticBezier;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Curve from (1, 20) to (20, 20), pulled upwards by a control point at (10, 0)
+/// let curve = QuadraticBezier::new(Coord::new(1, 20), Coord::new(10, 0), Coord::new(20, 20))
+///     .stroke(Some(1u8));
+///
+/// display.draw(curve);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct QuadraticBezier<C: PixelColor> {
+    /// Start point
+    pub start: Coord,
+
+    /// Control point
+    pub control: Coord,
+
+    /// End point
+    pub end: Coord,
+
+    /// Maximum distance between the drawn and the true curve in 1/16ths of a pixel
+    pub tolerance: u32,
+
+    /// Curve style
+    pub style: Style<C>,
+}
+
+impl<C> QuadraticBezier<C>
+where
+    C: PixelColor,
+{
+    /// Create a new quadratic Bézier curve
+    pub fn new(start: Coord, control: Coord, end: Coord) -> Self {
+        QuadraticBezier {
+            start,
+            control,
+            end,
+            tolerance: DEFAULT_TOLERANCE,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the flattening tolerance in 1/16ths of a pixel
+    ///
+    /// Smaller values produce smoother curves at the cost of more line segments. A tolerance of
+    /// zero is treated as one.
+    pub fn tolerance(mut self, tolerance: u32) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Iterate over the end points of the line segments this curve is flattened into
+    ///
+    /// The first point is always `start` and the last point is always `end`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::QuadraticBezier;
+    ///
+    /// let curve: QuadraticBezier<u8> =
+    ///     QuadraticBezier::new(Coord::new(0, 0), Coord::new(10, 10), Coord::new(20, 0));
+    ///
+    /// let mut points = curve.points();
+    ///
+    /// assert_eq!(points.next(), Some(Coord::new(0, 0)));
+    /// assert_eq!(points.last(), Some(Coord::new(20, 0)));
+    /// ```
+    pub fn points(&self) -> BezierPoints {
+        BezierPoints::new(
+            [self.start, self.control, self.end, self.end],
+            2,
+            self.tolerance,
+        )
+    }
+}
+
+/// Cubic Bézier curve primitive
+///
+/// # Examples
+///
+/// The [macro examples](../../macro.egcubic_bezier.html) make for more concise code.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::CubicBezier;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // S-shaped curve from (1, 1) to (20, 14)
+/// let curve = CubicBezier::new(
+///     Coord::new(1, 1),
+///     Coord::new(20, 1),
+///     Coord::new(1, 14),
+///     Coord::new(20, 14),
+/// )
+/// .stroke(Some(1u8))
+/// .tolerance(1);
+///
+/// display.draw(curve);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct CubicBezier<C: PixelColor> {
+    /// Start point
+    pub start: Coord,
+
+    /// First control point
+    pub control1: Coord,
+
+    /// Second control point
+    pub control2: Coord,
+
+    /// End point
+    pub end: Coord,
+
+    /// Maximum distance between the drawn and the true curve in 1/16ths of a pixel
+    pub tolerance: u32,
+
+    /// Curve style
+    pub style: Style<C>,
+}
+
+impl<C> CubicBezier<C>
+where
+    C: PixelColor,
+{
+    /// Create a new cubic Bézier curve
+    pub fn new(start: Coord, control1: Coord, control2: Coord, end: Coord) -> Self {
+        CubicBezier {
+            start,
+            control1,
+            control2,
+            end,
+            tolerance: DEFAULT_TOLERANCE,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the flattening tolerance in 1/16ths of a pixel
+    ///
+    /// Smaller values produce smoother curves at the cost of more line segments. A tolerance of
+    /// zero is treated as one.
+    pub fn tolerance(mut self, tolerance: u32) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Iterate over the end points of the line segments this curve is flattened into
+    ///
+    /// The first point is always `start` and the last point is always `end`.
+    pub fn points(&self) -> BezierPoints {
+        BezierPoints::new(
+            [self.start, self.control1, self.control2, self.end],
+            3,
+            self.tolerance,
+        )
+    }
+}
+
+/// Iterator over the points of a flattened Bézier curve
+#[derive(Debug, Copy, Clone)]
+pub struct BezierPoints {
+    points: [Coord; 4],
+    degree: u32,
+    segments: u32,
+    index: u32,
+}
+
+/// Length of the second difference `a - 2b + c`, rounded down
+fn second_difference(a: Coord, b: Coord, c: Coord) -> u64 {
+    let dx = i64::from(a[0]) - 2 * i64::from(b[0]) + i64::from(c[0]);
+    let dy = i64::from(a[1]) - 2 * i64::from(b[1]) + i64::from(c[1]);
+
+    isqrt((dx * dx + dy * dy) as u64)
+}
+
+impl BezierPoints {
+    pub(crate) fn new(points: [Coord; 4], degree: u32, tolerance: u32) -> Self {
+        let tolerance = u64::from(tolerance.max(1));
+
+        // Flattening a curve into `n` segments deviates from it by at most `|B''| / (8 * n^2)`.
+        // The second derivative of a quadratic is `2 * (p0 - 2p1 + p2)`, and that of a cubic is
+        // at most `6 * max(p0 - 2p1 + p2, p1 - 2p2 + p3)`. Tolerance is in 1/16ths of a pixel.
+        let bound = if degree == 2 {
+            4 * second_difference(points[0], points[1], points[2])
+        } else {
+            12 * second_difference(points[0], points[1], points[2])
+                .max(second_difference(points[1], points[2], points[3]))
+        };
+
+        let min_segments_sq = bound.div_ceil(tolerance);
+        let mut segments = isqrt(min_segments_sq);
+        if segments * segments < min_segments_sq {
+            segments += 1;
+        }
+
+        Self {
+            points,
+            degree,
+            segments: segments.clamp(1, u64::from(MAX_SEGMENTS)) as u32,
+            index: 0,
+        }
+    }
+
+    /// Evaluate the curve at `t = index / segments` using its Bernstein form
+    fn point_at(&self, index: u32) -> Coord {
+        let n = i64::from(self.segments);
+        let t = i64::from(index);
+        let u = n - t;
+        let degree = self.degree;
+
+        let (binomials, divisor): (&[i64], i64) = if degree == 2 {
+            (&[1, 2, 1], n * n)
+        } else {
+            (&[1, 3, 3, 1], n * n * n)
+        };
+
+        let (mut x, mut y) = (0i64, 0i64);
+
+        for (k, binomial) in binomials.iter().enumerate() {
+            let weight = binomial * u.pow(degree - k as u32) * t.pow(k as u32);
+
+            x += weight * i64::from(self.points[k][0]);
+            y += weight * i64::from(self.points[k][1]);
+        }
+
+        Coord::new(div_round(x, divisor) as i32, div_round(y, divisor) as i32)
+    }
+}
+
+/// Divide rounding to the nearest integer, with halves rounded away from zero
+pub(crate) fn div_round(n: i64, d: i64) -> i64 {
+    if n >= 0 {
+        (n + d / 2) / d
+    } else {
+        (n - d / 2) / d
+    }
+}
+
+impl Iterator for BezierPoints {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index > self.segments {
+            return None;
+        }
+
+        let point = self.point_at(self.index);
+        self.index += 1;
+
+        Some(point)
+    }
+}
+
+/// Pixel iterator for each pixel along a Bézier curve
+#[derive(Debug, Clone, Copy)]
+pub struct BezierIterator<C>
+where
+    C: PixelColor,
+{
+    stroke: EdgeStroke<C, PointPairs<BezierPoints>>,
+}
+
+impl<C> BezierIterator<C>
+where
+    C: PixelColor,
+{
+    fn new(points: BezierPoints, style: Style<C>) -> Self {
+        Self {
+            stroke: EdgeStroke::new(PointPairs::new(points), style),
+        }
+    }
+}
+
+impl<C> Iterator for BezierIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stroke.next()
+    }
+}
+
+/// Bounding box of a set of points, returned as `(top_left, bottom_right)`
+fn bounds(points: &[Coord]) -> (Coord, Coord) {
+    points
+        .iter()
+        .skip(1)
+        .fold((points[0], points[0]), |(tl, br), p| {
+            (
+                Coord::new(tl[0].min(p[0]), tl[1].min(p[1])),
+                Coord::new(br[0].max(p[0]), br[1].max(p[1])),
+            )
+        })
+}
+
+macro_rules! impl_bezier {
+    ($type:ident, $($point:ident),+) => {
+        impl<C> Primitive for $type<C> where C: PixelColor {}
+
+        impl<C> Intersects<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn intersects(&self, area: &Rectangle<C>) -> bool {
+                let margin = stroke_margin(self.style.stroke_width);
+
+                area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+            }
+        }
+
+        /// The bounding box of a curve is that of its control points, which always contains the
+        /// whole curve.
+        impl<C> Dimensions for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn top_left(&self) -> Coord {
+                bounds(&[$(self.$point),+]).0
+            }
+
+            fn bottom_right(&self) -> Coord {
+                bounds(&[$(self.$point),+]).1
+            }
+
+            fn size(&self) -> UnsignedCoord {
+                let (top_left, bottom_right) = bounds(&[$(self.$point),+]);
+
+                (bottom_right - top_left).to_unsigned()
+            }
+        }
+
+        impl<C> WithStyle<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn style(mut self, style: Style<C>) -> Self {
+                self.style = style;
+
+                self
+            }
+
+            fn stroke(mut self, color: Option<C>) -> Self {
+                self.style.stroke_color = color;
+
+                self
+            }
+
+            fn stroke_width(mut self, width: u8) -> Self {
+                self.style.stroke_width = width;
+
+                self
+            }
+
+            fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+                self.style.stroke_dash = pattern;
+
+                self
+            }
+
+            fn line_join(mut self, join: LineJoin) -> Self {
+                self.style.line_join = join;
+
+                self
+            }
+
+            fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+                self.style.stroke_cap = cap;
+
+                self
+            }
+
+            fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+                self.style.stroke_alignment = alignment;
+
+                self
+            }
+
+            fn stroke_corners(mut self, length: Option<u32>) -> Self {
+                self.style.stroke_corners = length;
+
+                self
+            }
+
+            fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+                self.style.stroke_overlap = overlap;
+
+                self
+            }
+
+            fn draw_mode(mut self, mode: DrawMode) -> Self {
+                self.style.draw_mode = mode;
+
+                self
+            }
+
+            fn fill(mut self, color: Option<C>) -> Self {
+                self.style.fill_color = color;
+
+                self
+            }
+
+            fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+                self.style.fill_gradient = gradient;
+
+                self
+            }
+
+            fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+                self.style.fill_pattern = pattern;
+
+                self
+            }
+        }
+
+        impl<C> Styled<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn current_style(&self) -> &Style<C> {
+                &self.style
+            }
+        }
+
+        impl<C> IntoIterator for $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = BezierIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                (&self).into_iter()
+            }
+        }
+
+        impl<'a, C> IntoIterator for &'a $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = BezierIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                BezierIterator::new(self.points(), self.style)
+            }
+        }
+
+        impl<C> Drawable for $type<C> where C: PixelColor {}
+
+        impl<C> Transform for $type<C>
+        where
+            C: PixelColor,
+        {
+            /// Translate the curve and all of its control points by (x, y) pixels, returning a
+            /// new curve. For a mutating transform, see `translate_mut`.
+            fn translate(&self, by: Coord) -> Self {
+                Self {
+                    $( $point: self.$point + by, )+
+                    ..*self
+                }
+            }
+
+            /// Translate the curve and all of its control points by (x, y) pixels.
+            fn translate_mut(&mut self, by: Coord) -> &mut Self {
+                $( self.$point += by; )+
+
+                self
+            }
+        }
+    };
+}
+
+impl_bezier!(QuadraticBezier, start, control, end);
+impl_bezier!(CubicBezier, start, control1, control2, end);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    #[test]
+    fn straight_curve_is_one_segment() {
+        let curve: QuadraticBezier<u8> =
+            QuadraticBezier::new(Coord::new(0, 0), Coord::new(5, 5), Coord::new(10, 10));
+
+        let mut points = curve.points();
+
+        assert_eq!(points.next(), Some(Coord::new(0, 0)));
+        assert_eq!(points.next(), Some(Coord::new(10, 10)));
+        assert_eq!(points.next(), None);
+    }
+
+    #[test]
+    fn quadratic_midpoint() {
+        let curve: QuadraticBezier<u8> =
+            QuadraticBezier::new(Coord::new(0, 0), Coord::new(8, 16), Coord::new(16, 0));
+
+        // B(0.5) = (p0 + 2 * p1 + p2) / 4
+        assert!(curve.points().any(|p| p == Coord::new(8, 8)));
+        assert_eq!(curve.points().last(), Some(Coord::new(16, 0)));
+    }
+
+    #[test]
+    fn cubic_midpoint() {
+        let curve: CubicBezier<u8> = CubicBezier::new(
+            Coord::new(0, 0),
+            Coord::new(0, 8),
+            Coord::new(8, 8),
+            Coord::new(8, 0),
+        );
+
+        // B(0.5) = (p0 + 3 * p1 + 3 * p2 + p3) / 8
+        assert!(curve.points().any(|p| p == Coord::new(4, 6)));
+        assert_eq!(curve.points().last(), Some(Coord::new(8, 0)));
+    }
+
+    #[test]
+    fn tolerance_controls_segment_count() {
+        let curve: QuadraticBezier<u8> =
+            QuadraticBezier::new(Coord::new(0, 0), Coord::new(50, 100), Coord::new(100, 0));
+
+        let coarse = curve.tolerance(64).points().count();
+        let fine = curve.tolerance(1).points().count();
+
+        assert!(fine > coarse);
+        assert_eq!(curve.tolerance(0).points().count(), fine);
+    }
+
+    #[test]
+    fn negative_coords() {
+        let curve: QuadraticBezier<u8> = QuadraticBezier::new(
+            Coord::new(-10, -10),
+            Coord::new(0, -20),
+            Coord::new(10, -10),
+        );
+
+        assert!(curve.points().all(|p| p[1] <= -10 && p[1] >= -15));
+    }
+
+    #[test]
+    fn draws_end_points_without_repeats() {
+        let curve = CubicBezier::new(
+            Coord::new(1, 1),
+            Coord::new(20, 1),
+            Coord::new(1, 14),
+            Coord::new(20, 14),
+        )
+        .stroke(Some(1u8));
+
+        let mut display = Display::default();
+        display.draw(curve);
+
+        assert_eq!(display.0[1][1], 1);
+        assert_eq!(display.0[14][20], 1);
+
+        let mut last = None;
+        for Pixel(coord, _) in curve {
+            assert_ne!(Some(coord), last);
+            last = Some(coord);
+        }
+    }
+
+    #[test]
+    fn no_stroke_draws_nothing() {
+        let curve: QuadraticBezier<u8> =
+            QuadraticBezier::new(Coord::new(0, 0), Coord::new(8, 16), Coord::new(16, 0));
+
+        assert_eq!(curve.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn dashed_stroke() {
+        let solid = QuadraticBezier::new(Coord::new(0, 0), Coord::new(10, 20), Coord::new(20, 0))
+            .stroke(Some(1u8));
+        let dashed = solid.stroke_dash(Some(DashPattern::new(&[2, 2])));
+
+        let solid_count = solid.into_iter().count();
+        let dashed_count = dashed.into_iter().count();
+
+        assert_eq!(dashed_count, solid_count / 4 * 2 + (solid_count % 4).min(2));
+    }
+
+    #[test]
+    fn dimensions() {
+        let curve: CubicBezier<u8> = CubicBezier::new(
+            Coord::new(5, 10),
+            Coord::new(-5, 20),
+            Coord::new(15, 0),
+            Coord::new(10, 10),
+        );
+
+        assert_eq!(curve.top_left(), Coord::new(-5, 0));
+        assert_eq!(curve.bottom_right(), Coord::new(15, 20));
+        assert_eq!(curve.size(), UnsignedCoord::new(20, 20));
+    }
+
+    #[test]
+    fn translate() {
+        let curve: QuadraticBezier<u8> =
+            QuadraticBezier::new(Coord::new(0, 0), Coord::new(5, 10), Coord::new(10, 0));
+        let moved = curve.translate(Coord::new(3, 4));
+
+        assert_eq!(moved.start, Coord::new(3, 4));
+        assert_eq!(moved.control, Coord::new(8, 14));
+        assert_eq!(moved.end, Coord::new(13, 4));
+    }
+}
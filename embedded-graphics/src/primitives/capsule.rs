@@ -0,0 +1,515 @@
+//! The capsule primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_aligned_border, is_stroke_pixel, styled_pixel, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Capsule primitive
+///
+/// A straight bar with fully rounded ends, also known as a stadium or pill shape. It covers every
+/// pixel within `radius` of the line from `start` to `end`, so each end is a half circle centered on
+/// one of the two points. When both points are the same the capsule is a
+/// [`Circle`](../circle/struct.Circle.html) with the same radius.
+///
+/// The stroke is drawn inside the outline unless the style's
+/// [`stroke_alignment`](../../style/struct.Style.html#structfield.stroke_alignment) says
+/// otherwise. Dash patterns are not supported.
+///
+/// # Examples
+///
+/// ## Toggle switch in the "on" position
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Capsule, Circle};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let track = Capsule::new(Coord::new(6, 6), Coord::new(14, 6), 5)
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+/// let knob = Circle::new(Coord::new(14, 6), 3).fill(Some(3u8));
+///
+/// display.draw(track);
+/// display.draw(knob);
+/// ```
+///
+/// ## Progress bar
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Capsule;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let (left, right) = (3, 20);
+/// let progress = left + (right - left) * 40 / 100;
+///
+/// display.draw(Capsule::new(Coord::new(left, 12), Coord::new(right, 12), 2).stroke(Some(1u8)));
+/// display.draw(Capsule::new(Coord::new(left, 12), Coord::new(progress, 12), 2).fill(Some(1u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Capsule<C: PixelColor> {
+    /// Center of the first rounded end
+    pub start: Coord,
+
+    /// Center of the second rounded end
+    pub end: Coord,
+
+    /// Radius of the rounded ends, and half the thickness of the bar
+    pub radius: u32,
+
+    /// Capsule style
+    pub style: Style<C>,
+}
+
+impl<C> Capsule<C>
+where
+    C: PixelColor,
+{
+    /// Create a new capsule between the centers of its two rounded ends
+    pub fn new(start: Coord, end: Coord, radius: u32) -> Self {
+        Capsule {
+            start,
+            end,
+            radius,
+            style: Style::default(),
+        }
+    }
+
+    fn shape(&self) -> CapsuleShape {
+        CapsuleShape {
+            start: self.start,
+            end: self.end,
+            radius: i64::from(self.radius),
+        }
+    }
+
+    /// Bounding box including the stroke, returned as `(top_left, bottom_right)`
+    fn bounds(&self) -> (Coord, Coord) {
+        let radius = (self.radius + self.style.stroke_outset()) as i32;
+        let radius = Coord::new(radius, radius);
+        let min = Coord::new(
+            self.start[0].min(self.end[0]),
+            self.start[1].min(self.end[1]),
+        );
+        let max = Coord::new(
+            self.start[0].max(self.end[0]),
+            self.start[1].max(self.end[1]),
+        );
+
+        (min - radius, max + radius)
+    }
+}
+
+/// Coverage test for the pixels of a capsule
+#[derive(Debug, Copy, Clone)]
+struct CapsuleShape {
+    start: Coord,
+    end: Coord,
+    radius: i64,
+}
+
+impl CapsuleShape {
+    /// Returns `true` if `point` is no further than the radius from the line between the ends
+    fn contains(&self, point: Coord) -> bool {
+        let (ax, ay) = (i64::from(self.start[0]), i64::from(self.start[1]));
+        let (bx, by) = (i64::from(self.end[0]), i64::from(self.end[1]));
+        let (px, py) = (i64::from(point[0]) - ax, i64::from(point[1]) - ay);
+        let (dx, dy) = (bx - ax, by - ay);
+
+        let length_sq = dx * dx + dy * dy;
+        let along = px * dx + py * dy;
+        let radius_sq = self.radius * self.radius;
+
+        if along <= 0 {
+            px * px + py * py <= radius_sq
+        } else if along >= length_sq {
+            let (qx, qy) = (px - dx, py - dy);
+
+            qx * qx + qy * qy <= radius_sq
+        } else {
+            // Squared distance from the line is cross² / length², compared without dividing
+            let cross = i128::from(px * dy - py * dx);
+
+            cross * cross <= i128::from(radius_sq) * i128::from(length_sq)
+        }
+    }
+}
+
+impl<C> Primitive for Capsule<C> where C: PixelColor {}
+
+impl<C> Intersects<C> for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> ContainsPoint for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.shape().contains(point)
+    }
+}
+
+impl<C> StrokeContainsPoint for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let shape = self.shape();
+
+        is_stroke_pixel(
+            |p| shape.contains(p),
+            &self.style,
+            point,
+            self.style.stroke_outset() as i32,
+        )
+    }
+}
+
+impl<C> Offset for Capsule<C>
+where
+    C: PixelColor,
+{
+    /// The radius changes by `distance`, keeping both end points
+    fn offset(&self, distance: i32) -> Self {
+        Self {
+            radius: (self.radius as i32 + distance).max(0) as u32,
+            ..*self
+        }
+    }
+}
+
+impl<C> Dimensions for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<C> WithStyle<C> for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Capsule<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C> IntoIterator for Capsule<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = CapsuleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Capsule<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = CapsuleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+        let (tl, br) = self.bounds();
+
+        CapsuleIterator {
+            shape: self.shape(),
+            area: FillArea::new(Some((tl, br + Coord::new(1, 1))).filter(|_| visible)),
+            style: self.style,
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the capsule fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct CapsuleIterator<C>
+where
+    C: PixelColor,
+{
+    shape: CapsuleShape,
+    area: FillArea,
+    style: Style<C>,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> Iterator for CapsuleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = self.shape;
+        let style = self.style;
+        let width = i32::from(style.stroke_width);
+        let outset = style.stroke_outset() as i32;
+
+        let (pixel, over) = self.area.find_map(|p| {
+            let stroke = style.stroke_color.filter(|_| {
+                width > 0 && is_aligned_border(|p| shape.contains(p), p, width, outset)
+            });
+
+            styled_pixel(&style, p, stroke, shape.contains(p))
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for CapsuleIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+impl<C> Drawable for Capsule<C> where C: PixelColor {}
+
+impl<C> Transform for Capsule<C>
+where
+    C: PixelColor,
+{
+    /// Translate both ends of the capsule by (x, y) pixels, returning a new `Capsule`. For a
+    /// mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Capsule;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let pill: Capsule<u8> = Capsule::new(Coord::new(5, 5), Coord::new(15, 5), 4);
+    /// let moved = pill.translate(Coord::new(2, 3));
+    ///
+    /// assert_eq!(moved.start, Coord::new(7, 8));
+    /// assert_eq!(moved.end, Coord::new(17, 8));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            start: self.start + by,
+            end: self.end + by,
+            ..*self
+        }
+    }
+
+    /// Translate both ends of the capsule by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.start += by;
+        self.end += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Rectangle};
+    use crate::Drawing;
+
+    #[test]
+    fn zero_length_is_a_circle() {
+        let mut capsule = Display::default();
+        capsule.draw(Capsule::new(Coord::new(8, 8), Coord::new(8, 8), 5).fill(Some(1u8)));
+
+        let mut circle = Display::default();
+        circle.draw(Circle::new(Coord::new(8, 8), 5).fill(Some(1u8)));
+
+        assert_eq!(capsule, circle);
+    }
+
+    #[test]
+    fn horizontal_capsule_is_rectangle_with_half_circles() {
+        let mut capsule = Display::default();
+        capsule.draw(Capsule::new(Coord::new(5, 7), Coord::new(17, 7), 4).fill(Some(1u8)));
+
+        let mut expected = Display::default();
+        expected.draw(Circle::new(Coord::new(5, 7), 4).fill(Some(1u8)));
+        expected.draw(Circle::new(Coord::new(17, 7), 4).fill(Some(1u8)));
+        expected.draw(Rectangle::new(Coord::new(5, 3), Coord::new(17, 11)).fill(Some(1u8)));
+
+        assert_eq!(capsule, expected);
+    }
+
+    #[test]
+    fn diagonal_contains() {
+        let capsule: Capsule<u8> = Capsule::new(Coord::new(2, 2), Coord::new(12, 12), 2);
+
+        assert!(capsule.contains(Coord::new(7, 7)));
+        assert!(capsule.contains(Coord::new(8, 6)));
+        assert!(!capsule.contains(Coord::new(9, 5)));
+        assert!(capsule.contains(Coord::new(0, 2)));
+        assert!(!capsule.contains(Coord::new(0, 0)));
+        assert!(capsule.contains(Coord::new(14, 12)));
+        assert!(!capsule.contains(Coord::new(15, 12)));
+    }
+
+    #[test]
+    fn stroke_inside_fill() {
+        let capsule = Capsule::new(Coord::new(6, 8), Coord::new(16, 8), 5)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(capsule);
+
+        let mut filled = Display::default();
+        filled.draw(capsule.stroke(None));
+
+        assert_eq!(display.count(1) + display.count(2), filled.count(2));
+        assert_eq!(display.0[3][10], 1);
+        assert_eq!(display.0[4][10], 2);
+        assert_eq!(display.0[8][1], 1);
+        assert_eq!(display.0[8][2], 2);
+    }
+
+    #[test]
+    fn dimensions() {
+        let capsule: Capsule<u8> = Capsule::new(Coord::new(15, 4), Coord::new(6, 10), 3);
+
+        assert_eq!(capsule.top_left(), Coord::new(3, 1));
+        assert_eq!(capsule.bottom_right(), Coord::new(18, 13));
+        assert_eq!(capsule.size(), UnsignedCoord::new(15, 12));
+
+        let outside = capsule
+            .stroke(Some(1))
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside);
+        assert_eq!(outside.top_left(), Coord::new(1, -1));
+    }
+
+    #[test]
+    fn offset_changes_radius() {
+        let capsule: Capsule<u8> = Capsule::new(Coord::new(5, 5), Coord::new(10, 5), 3);
+
+        assert_eq!(capsule.offset(2).radius, 5);
+        assert_eq!(capsule.offset(-5).radius, 0);
+    }
+}
@@ -2,11 +2,17 @@
 
 use super::super::drawable::*;
 use super::super::transform::*;
-use crate::coord::{Coord, ToUnsigned};
+use crate::coord::Coord;
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
-use crate::style::Style;
+use crate::primitives::edges::styled_pixel;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
 use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
 use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 
 /// Circle primitive
@@ -66,16 +72,87 @@ where
             style: Style::default(),
         }
     }
+
+    /// Distance from the center to the outside of the stroke
+    fn extent(&self) -> i32 {
+        (self.radius + self.style.stroke_outset()) as i32
+    }
 }
 
 impl<C> Primitive for Circle<C> where C: PixelColor {}
 
+impl<C> Intersects<C> for Circle<C>
+where
+    C: PixelColor,
+{
+    /// Tests the distance from the center to the closest pixel in `area`
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let (tl, br) = (area.top_left, area.bottom_right);
+        let closest = |center: i32, a: i32, b: i32| center.max(a.min(b)).min(a.max(b));
+
+        let dx = i64::from(closest(self.center[0], tl[0], br[0])) - i64::from(self.center[0]);
+        let dy = i64::from(closest(self.center[1], tl[1], br[1])) - i64::from(self.center[1]);
+        let extent = i64::from(self.extent());
+
+        // Outside edge of the stroke's widest ring, see `CircleIterator`
+        dx * dx + dy * dy < extent * extent + extent
+    }
+}
+
+impl<C> ContainsPoint for Circle<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        let dx = i64::from(point[0]) - i64::from(self.center[0]);
+        let dy = i64::from(point[1]) - i64::from(self.center[1]);
+        let radius = i64::from(self.radius);
+
+        dx * dx + dy * dy <= radius * radius
+    }
+}
+
+impl<C> StrokeContainsPoint for Circle<C>
+where
+    C: PixelColor,
+{
+    fn stroke_contains(&self, point: Coord) -> bool {
+        if self.style.stroke_color.is_none() || self.style.stroke_width == 0 {
+            return false;
+        }
+
+        let dx = i64::from(point[0]) - i64::from(self.center[0]);
+        let dy = i64::from(point[1]) - i64::from(self.center[1]);
+        let len = dx * dx + dy * dy;
+
+        // Same rings as `CircleIterator`
+        let outer_radius = i64::from(self.extent());
+        let radius = outer_radius - i64::from(self.style.stroke_width) + 1;
+        let fill_radius = i64::from(self.radius);
+        let inner_border_sq = (radius * radius - radius).min(fill_radius * fill_radius);
+
+        len > inner_border_sq && len < outer_radius * outer_radius + radius
+    }
+}
+
+impl<C> Offset for Circle<C>
+where
+    C: PixelColor,
+{
+    fn offset(&self, distance: i32) -> Self {
+        Self {
+            radius: (self.radius as i32 + distance).max(0) as u32,
+            ..*self
+        }
+    }
+}
+
 impl<C> Dimensions for Circle<C>
 where
     C: PixelColor,
 {
     fn top_left(&self) -> Coord {
-        let radius_coord = Coord::new(self.radius as i32, self.radius as i32);
+        let radius_coord = Coord::new(self.extent(), self.extent());
 
         self.center - radius_coord
     }
@@ -85,7 +162,9 @@ where
     }
 
     fn size(&self) -> UnsignedCoord {
-        UnsignedCoord::new(self.radius * 2, self.radius * 2)
+        let diameter = self.extent() as u32 * 2;
+
+        UnsignedCoord::new(diameter, diameter)
     }
 }
 
@@ -111,11 +190,74 @@ where
         self
     }
 
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
     fn fill(mut self, color: Option<C>) -> Self {
         self.style.fill_color = color;
 
         self
     }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Circle<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
 }
 
 impl<C> IntoIterator for Circle<C>
@@ -142,8 +284,12 @@ where
             center: self.center,
             radius: self.radius,
             style: self.style,
-            x: -(self.radius as i32),
-            y: -(self.radius as i32),
+            x: -self.extent(),
+            y: -self.extent(),
+            left: -self.extent(),
+            right: self.extent(),
+            bottom: self.extent(),
+            pending: None,
         }
     }
 }
@@ -156,6 +302,60 @@ pub struct CircleIterator<C: PixelColor> {
     style: Style<C>,
     x: i32,
     y: i32,
+
+    /// Range of offsets from the center still to visit, narrowed by clipping
+    left: i32,
+    right: i32,
+    bottom: i32,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+/// Approximate `atan(n / d) / (π / 4)` for `n <= d`, scaled to `0..=256`
+fn octant_angle(n: u32, d: u32) -> u32 {
+    let t = (u64::from(n) * 256 / u64::from(d)) as u32;
+
+    // atan(z) ≈ π/4 * z + 0.273 * z * (1 - z)
+    t + ((89 * t * (256 - t)) >> 16)
+}
+
+/// Approximate distance around the circumference of a circle of `radius` to the point `(x, y)`,
+/// measured clockwise from the rightmost point
+fn arc_position(x: i32, y: i32, radius: u32) -> u32 {
+    let (ax, ay) = (x.unsigned_abs(), y.unsigned_abs());
+
+    if ax == 0 && ay == 0 {
+        return 0;
+    }
+
+    // Angle within the quadrant, where a quarter turn is 512
+    let quadrant_angle = if ax >= ay {
+        octant_angle(ay, ax)
+    } else {
+        512 - octant_angle(ax, ay)
+    };
+
+    // Full turn is 2048, clockwise on screen since Y points down
+    let angle = match (x >= 0, y >= 0) {
+        (true, true) => quadrant_angle,
+        (false, true) => 1024 - quadrant_angle,
+        (false, false) => 1024 + quadrant_angle,
+        (true, false) => (2048 - quadrant_angle) % 2048,
+    };
+
+    // 2π * 128 ≈ 804
+    ((u64::from(angle) * u64::from(radius) * 804) >> 18) as u32
+}
+
+impl<C> CircleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Distance from the center to the outside of the stroke
+    fn extent(&self) -> i32 {
+        (self.radius + self.style.stroke_outset()) as i32
+    }
 }
 
 impl<C> Iterator for CircleIterator<C>
@@ -166,65 +366,87 @@ where
 
     // https://stackoverflow.com/questions/1201200/fast-algorithm-for-drawing-filled-circles
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
         // If border or stroke colour is `None`, treat entire object as transparent and exit early
-        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
+        if self.style.stroke_color.is_none() && !self.style.has_fill() {
             return None;
         }
 
         let cx = self.center[0];
         let cy = self.center[1];
 
-        let radius = self.radius as i32 - self.style.stroke_width as i32 + 1;
-        let outer_radius = self.radius as i32;
+        let outer_radius = self.extent();
+        let radius = outer_radius - self.style.stroke_width as i32 + 1;
 
         let radius_sq = radius * radius;
         let outer_radius_sq = outer_radius * outer_radius;
+        let fill_radius_sq = self.radius as i32 * self.radius as i32;
+
+        // An outside stroke starts where the fill ends, leaving no gap between them
+        let inner_border_sq = (radius_sq - radius).min(fill_radius_sq);
 
         let item = loop {
+            if self.y > self.bottom || self.left > self.right {
+                break None;
+            }
+
             let tx = self.x;
             let ty = self.y;
             let len = tx * tx + ty * ty;
 
-            let is_border = len > radius_sq - radius && len < outer_radius_sq + radius;
+            let is_border = len > inner_border_sq
+                && len < outer_radius_sq + radius
+                && self
+                    .style
+                    .stroke_dash
+                    .is_none_or(|p| p.is_dash(arc_position(tx, ty, self.radius)));
 
             // TODO: Should this be a <= or a <?
-            let is_fill = len <= outer_radius_sq;
-
-            let item = if is_border && self.style.stroke_color.is_some() {
-                Some((
-                    cx + tx,
-                    cy + ty,
-                    self.style.stroke_color.expect("Border color not defined"),
-                ))
-            } else if is_fill && self.style.fill_color.is_some() {
-                Some((
-                    cx + tx,
-                    cy + ty,
-                    self.style.fill_color.expect("Fill color not defined"),
-                ))
+            let is_fill = len <= fill_radius_sq;
+
+            let point = Coord::new(cx + tx, cy + ty);
+            let stroke = self.style.stroke_color.filter(|_| is_border);
+
+            let item = if point[0] >= 0 && point[1] >= 0 {
+                styled_pixel(&self.style, point, stroke, is_fill)
             } else {
                 None
             };
 
             self.x += 1;
 
-            if self.x > self.radius as i32 {
-                self.x = -(self.radius as i32);
+            if self.x > self.right {
+                self.x = self.left;
                 self.y += 1;
             }
 
-            if self.y > self.radius as i32 {
-                break None;
-            }
-
-            if let Some(i) = item {
-                if i.0 >= 0 && i.1 >= 0 {
-                    break item;
-                }
+            if item.is_some() {
+                break item;
             }
         };
 
-        item.map(|(x, y, c)| Pixel(Coord::new(x, y).to_unsigned(), c))
+        let (pixel, over) = item?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for CircleIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        let radius = self.extent();
+
+        self.left = top_left[0].saturating_sub(self.center[0]).max(-radius);
+        self.right = bottom_right[0].saturating_sub(self.center[0]).min(radius);
+        self.bottom = bottom_right[1].saturating_sub(self.center[1]).min(radius);
+        self.x = self.left;
+        self.y = top_left[1].saturating_sub(self.center[1]).max(-radius);
     }
 }
 
@@ -281,6 +503,8 @@ where
 mod tests {
     use super::*;
     use crate::drawable::Dimensions;
+    use crate::mock_display::Display;
+    use crate::Drawing;
 
     #[test]
     fn negative_dimensions() {
@@ -333,4 +557,183 @@ mod tests {
 
         assert!(circ.next().is_some());
     }
+
+    #[test]
+    fn arc_positions() {
+        assert_eq!(arc_position(10, 0, 10), 0);
+        assert_eq!(arc_position(0, 10, 10), 15);
+        assert_eq!(arc_position(-10, 0, 10), 31);
+        assert_eq!(arc_position(0, -10, 10), 47);
+
+        // Roughly 45 degrees
+        assert_eq!(arc_position(7, 7, 10), 7);
+    }
+
+    #[test]
+    fn dashed_stroke() {
+        let solid = Circle::new(Coord::new(12, 12), 10).stroke(Some(1u8));
+        let dashed = solid.stroke_dash(Some(DashPattern::new(&[4, 4])));
+
+        let solid_count = solid.into_iter().count();
+        let dashed_count = dashed.into_iter().count();
+
+        assert!(dashed_count > solid_count * 2 / 5 && dashed_count < solid_count * 3 / 5);
+    }
+
+    #[test]
+    fn gradient_fill_under_stroke() {
+        let circ = Circle::new(Coord::new(8, 8), 4)
+            .stroke(Some(1u8))
+            .fill_gradient(Some(Gradient::radial(Coord::new(8, 8), 4, 10, 50)));
+
+        let color_at = |x, y| {
+            circ.into_iter()
+                .find(|p| p.0 == UnsignedCoord::new(x, y))
+                .map(|p| p.1)
+        };
+
+        assert_eq!(color_at(8, 8), Some(10));
+        assert_eq!(color_at(10, 8), Some(30));
+        assert_eq!(color_at(12, 8), Some(1));
+    }
+
+    #[test]
+    fn offset() {
+        let circle: Circle<u8> = Circle::new(Coord::new(10, 10), 5);
+
+        assert_eq!(circle.offset(3).radius, 8);
+        assert_eq!(circle.offset(-3).radius, 2);
+        assert_eq!(circle.offset(-8).radius, 0);
+        assert_eq!(circle.offset(3).center, circle.center);
+    }
+
+    #[test]
+    fn outside_stroke() {
+        let fill = Circle::new(Coord::new(12, 12), 6).fill(Some(1u8));
+        let stroked = fill
+            .stroke(Some(2u8))
+            .stroke_width(3)
+            .stroke_alignment(StrokeAlignment::Outside);
+
+        // The fill is untouched and the stroke wraps around it
+        let fill_pixels = stroked.into_iter().filter(|p| p.1 == 1).count();
+        assert_eq!(fill_pixels, fill.into_iter().count());
+
+        assert_eq!(stroked.top_left(), Coord::new(3, 3));
+        assert_eq!(stroked.size(), UnsignedCoord::new(18, 18));
+
+        // Every row is one solid run, so there's no gap between the fill and the stroke
+        for y in 3..=21 {
+            let xs = stroked.into_iter().filter(|p| p.0[1] == y).map(|p| p.0[0]);
+            let (min, max, count) = xs.fold((u32::MAX, 0, 0), |(min, max, count), x| {
+                (min.min(x), max.max(x), count + 1)
+            });
+
+            assert_eq!(max - min + 1, count, "gap in row {}", y);
+        }
+    }
+
+    #[test]
+    fn center_stroke() {
+        let circle = Circle::new(Coord::new(12, 12), 6)
+            .stroke(Some(1u8))
+            .stroke_width(4)
+            .stroke_alignment(StrokeAlignment::Center);
+
+        assert_eq!(circle.top_left(), Coord::new(4, 4));
+        assert_eq!(circle.bottom_right(), Coord::new(20, 20));
+
+        let on_row = |x: u32| circle.into_iter().any(|p| p.0 == UnsignedCoord::new(x, 12));
+
+        assert!(on_row(4));
+        assert!(on_row(7));
+        assert!(!on_row(8));
+    }
+
+    #[test]
+    fn stroke_contains_matches_drawn_stroke() {
+        for &alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .iter()
+        {
+            let circle = Circle::new(Coord::new(10, 8), 5)
+                .stroke(Some(1u8))
+                .stroke_width(3)
+                .stroke_alignment(alignment)
+                .fill(Some(2u8));
+
+            let mut display = Display::default();
+            display.draw(circle);
+
+            for (y, row) in display.0.iter().enumerate() {
+                for (x, &pixel) in row.iter().enumerate() {
+                    let point = Coord::new(x as i32, y as i32);
+
+                    assert_eq!(circle.stroke_contains(point), pixel == 1, "{:?}", point);
+                    assert_eq!(circle.contains_styled(point), pixel != 0, "{:?}", point);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_stroke_contains_nothing() {
+        let circle = Circle::new(Coord::new(10, 8), 5).fill(Some(2u8));
+
+        assert!(circle.contains(Coord::new(10, 3)));
+        assert!(!circle.stroke_contains(Coord::new(10, 3)));
+    }
+
+    #[test]
+    fn intersects_matches_drawn_pixels() {
+        let circle = Circle::new(Coord::new(10, 8), 5)
+            .stroke(Some(1u8))
+            .stroke_alignment(StrokeAlignment::Outside)
+            .fill(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(circle);
+
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let point = Coord::new(x as i32, y as i32);
+                let area = Rectangle::new(point, point);
+
+                assert_eq!(circle.intersects(&area), pixel != 0, "{:?}", point);
+            }
+        }
+
+        // Corners of the bounding box are outside the circle
+        let corner = Rectangle::new(Coord::new(0, 0), Coord::new(5, 3));
+        assert!(!circle.intersects(&corner));
+        assert!(circle.intersects(&Rectangle::new(Coord::new(0, 0), Coord::new(23, 15))));
+    }
+
+    #[test]
+    fn each_pixel_drawn_once() {
+        let circle = Circle::new(Coord::new(10, 7), 6)
+            .stroke(Some(1u8))
+            .stroke_width(2)
+            .stroke_dash(Some(DashPattern::new(&[2, 2])))
+            .fill(Some(2u8));
+
+        let mut times_drawn = Display::default();
+        for Pixel(p, _) in circle {
+            times_drawn.0[p[1] as usize][p[0] as usize] += 1;
+        }
+        assert_eq!(times_drawn.0.iter().flatten().max(), Some(&1));
+
+        // The fill is drawn under the inside of the stroke too, which doesn't change the image
+        let over_fill = circle.stroke_overlap(StrokeOverlap::OverFill);
+        assert!(over_fill.into_iter().count() > circle.into_iter().count());
+
+        let mut exclusive_display = Display::default();
+        exclusive_display.draw(circle);
+        let mut over_fill_display = Display::default();
+        over_fill_display.draw(over_fill);
+        assert_eq!(exclusive_display, over_fill_display);
+    }
 }
@@ -0,0 +1,620 @@
+//! Boolean operations on primitives
+//!
+//! Any two primitives that enclose an area can be combined into a new shape with
+//! [`union`](./trait.Combine.html#method.union),
+//! [`intersection`](./trait.Combine.html#method.intersection) or
+//! [`difference`](./trait.Combine.html#method.difference). The result can be combined again, and
+//! is drawn in a single pass with its own style.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_inner_border, is_stroke_pixel, styled_pixel, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// How the two shapes of a [`Combined`](./struct.Combined.html) shape are joined
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// Points inside either shape
+    Union,
+
+    /// Points inside both shapes
+    Intersection,
+
+    /// Points inside the first shape but not the second
+    Difference,
+}
+
+/// Combine primitives that enclose an area into new shapes
+///
+/// This is implemented for every primitive that implements
+/// [`ContainsPoint`](../trait.ContainsPoint.html), including combined shapes themselves.
+pub trait Combine<C>: ContainsPoint + Dimensions + Sized
+where
+    C: PixelColor,
+{
+    /// Shape covering the points inside either `self` or `other`
+    fn union<B>(self, other: B) -> Combined<C, Self, B>
+    where
+        B: Combine<C>,
+    {
+        Combined::new(Operation::Union, self, other)
+    }
+
+    /// Shape covering the points inside both `self` and `other`
+    fn intersection<B>(self, other: B) -> Combined<C, Self, B>
+    where
+        B: Combine<C>,
+    {
+        Combined::new(Operation::Intersection, self, other)
+    }
+
+    /// Shape covering the points inside `self` that are not inside `other`
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::{Circle, Combine, Rectangle};
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// // A square with a round hole in the middle
+    /// let washer = Rectangle::new(Coord::new(2, 2), Coord::new(14, 14))
+    ///     .difference(Circle::new(Coord::new(8, 8), 3))
+    ///     .fill(Some(1u8));
+    ///
+    /// display.draw(washer);
+    /// ```
+    fn difference<B>(self, other: B) -> Combined<C, Self, B>
+    where
+        B: Combine<C>,
+    {
+        Combined::new(Operation::Difference, self, other)
+    }
+}
+
+impl<C, T> Combine<C> for T
+where
+    C: PixelColor,
+    T: ContainsPoint + Dimensions + WithStyle<C>,
+{
+}
+
+/// Shape made by combining two other shapes
+///
+/// The styles of the two shapes are ignored, a combined shape is drawn with its own style. The
+/// stroke is drawn inside the combined outline, including around any holes cut by a difference.
+/// Dash patterns are not supported.
+#[derive(Debug, Copy, Clone)]
+pub struct Combined<C, A, B>
+where
+    C: PixelColor,
+{
+    /// How the shapes are combined
+    pub operation: Operation,
+
+    /// First shape
+    pub a: A,
+
+    /// Second shape
+    pub b: B,
+
+    /// Combined shape style
+    pub style: Style<C>,
+}
+
+impl<C, A, B> Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions,
+    B: ContainsPoint + Dimensions,
+{
+    /// Create a new shape by combining `a` and `b`
+    pub fn new(operation: Operation, a: A, b: B) -> Self {
+        Combined {
+            operation,
+            a,
+            b,
+            style: Style::default(),
+        }
+    }
+
+    /// Iterator over the on screen points inside the combined shape, one row at a time
+    pub fn points(&self) -> CombinedPoints<'_, C, A, B> {
+        CombinedPoints {
+            shape: self,
+            area: self.search_area(true),
+        }
+    }
+
+    /// Bounding box of the combined shape, returned as `(top_left, bottom_right)`, or `None` if
+    /// the shapes can't overlap
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        let (a_tl, a_br) = (self.a.top_left(), self.a.bottom_right());
+        let (b_tl, b_br) = (self.b.top_left(), self.b.bottom_right());
+
+        match self.operation {
+            Operation::Union => Some((
+                Coord::new(a_tl[0].min(b_tl[0]), a_tl[1].min(b_tl[1])),
+                Coord::new(a_br[0].max(b_br[0]), a_br[1].max(b_br[1])),
+            )),
+            Operation::Intersection => {
+                let tl = Coord::new(a_tl[0].max(b_tl[0]), a_tl[1].max(b_tl[1]));
+                let br = Coord::new(a_br[0].min(b_br[0]), a_br[1].min(b_br[1]));
+
+                Some((tl, br)).filter(|_| tl[0] <= br[0] && tl[1] <= br[1])
+            }
+            Operation::Difference => Some((a_tl, a_br)),
+        }
+    }
+
+    /// Area to search for pixels
+    fn search_area(&self, visible: bool) -> FillArea {
+        FillArea::new(
+            self.bounds()
+                .filter(|_| visible)
+                .map(|(tl, br)| (tl, br + Coord::new(1, 1))),
+        )
+    }
+}
+
+impl<C, A, B> ContainsPoint for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint,
+    B: ContainsPoint,
+{
+    fn contains(&self, point: Coord) -> bool {
+        match self.operation {
+            Operation::Union => self.a.contains(point) || self.b.contains(point),
+            Operation::Intersection => self.a.contains(point) && self.b.contains(point),
+            Operation::Difference => self.a.contains(point) && !self.b.contains(point),
+        }
+    }
+}
+
+impl<C, A, B> StrokeContainsPoint for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint,
+    B: ContainsPoint,
+{
+    /// The stroke is always drawn inside the outline, whatever the stroke alignment
+    fn stroke_contains(&self, point: Coord) -> bool {
+        is_stroke_pixel(|p| self.contains(p), &self.style, point, 0)
+    }
+}
+
+impl<C, A, B> Offset for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: Offset,
+    B: Offset,
+{
+    /// For a difference, the second shape shrinks as the first one grows, so holes get smaller
+    fn offset(&self, distance: i32) -> Self {
+        let b_distance = match self.operation {
+            Operation::Difference => -distance,
+            _ => distance,
+        };
+
+        Self {
+            operation: self.operation,
+            a: self.a.offset(distance),
+            b: self.b.offset(b_distance),
+            style: self.style,
+        }
+    }
+}
+
+impl<C, A, B> Primitive for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions,
+    B: ContainsPoint + Dimensions,
+{
+}
+
+impl<C, A, B> Intersects<C> for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions,
+    B: ContainsPoint + Dimensions,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C, A, B> Dimensions for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions,
+    B: ContainsPoint + Dimensions,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().map_or(self.a.top_left(), |(tl, _)| tl)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().map_or(self.a.top_left(), |(_, br)| br)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<C, A, B> WithStyle<C> for Combined<C, A, B>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C, A, B> Styled<C> for Combined<C, A, B>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C, A, B> IntoIterator for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions,
+    B: ContainsPoint + Dimensions,
+{
+    type Item = Pixel<C>;
+    type IntoIter = CombinedIterator<C, A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+
+        CombinedIterator {
+            area: self.search_area(visible),
+            shape: self,
+            pending: None,
+        }
+    }
+}
+
+impl<C, A, B> IntoIterator for &Combined<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint + Dimensions + Clone,
+    B: ContainsPoint + Dimensions + Clone,
+{
+    type Item = Pixel<C>;
+    type IntoIter = CombinedIterator<C, A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone().into_iter()
+    }
+}
+
+/// Pixel iterator for each pixel in a combined shape's fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct CombinedIterator<C, A, B>
+where
+    C: PixelColor,
+{
+    shape: Combined<C, A, B>,
+    area: FillArea,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C, A, B> Iterator for CombinedIterator<C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint,
+    B: ContainsPoint,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = &self.shape;
+        let style = shape.style;
+        let width = i32::from(style.stroke_width);
+
+        let (pixel, over) = self.area.find_map(|p| {
+            if !shape.contains(p) {
+                return None;
+            }
+
+            let stroke = style
+                .stroke_color
+                .filter(|_| width > 0 && is_inner_border(|p| shape.contains(p), p, width));
+
+            styled_pixel(&style, p, stroke, true)
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C, A, B> Clip for CombinedIterator<C, A, B>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+/// Iterator over the points inside a combined shape
+///
+/// Created by [`Combined::points`](./struct.Combined.html#method.points).
+#[derive(Debug, Clone)]
+pub struct CombinedPoints<'a, C, A, B>
+where
+    C: PixelColor,
+{
+    shape: &'a Combined<C, A, B>,
+    area: FillArea,
+}
+
+impl<C, A, B> Iterator for CombinedPoints<'_, C, A, B>
+where
+    C: PixelColor,
+    A: ContainsPoint,
+    B: ContainsPoint,
+{
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let shape = self.shape;
+
+        self.area.find(|&p| shape.contains(p))
+    }
+}
+
+impl<C, A, B> Drawable for Combined<C, A, B> where C: PixelColor {}
+
+impl<C, A, B> Transform for Combined<C, A, B>
+where
+    C: PixelColor,
+    A: Transform,
+    B: Transform,
+{
+    /// Translate both shapes by (x, y) pixels, returning a new combined shape. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            operation: self.operation,
+            a: self.a.translate(by),
+            b: self.b.translate(by),
+            style: self.style,
+        }
+    }
+
+    /// Translate both shapes by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.a.translate_mut(by);
+        self.b.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Rectangle, Triangle};
+    use crate::Drawing;
+
+    fn rect(x1: i32, y1: i32, x2: i32, y2: i32) -> Rectangle<u8> {
+        Rectangle::new(Coord::new(x1, y1), Coord::new(x2, y2))
+    }
+
+    #[test]
+    fn rectangle_with_hole() {
+        let square = rect(2, 2, 14, 14);
+        let hole = Circle::new(Coord::new(8, 8), 3);
+
+        let mut display = Display::default();
+        display.draw(square.difference(hole).fill(Some(1u8)));
+
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let p = Coord::new(x as i32, y as i32);
+                let expected = square.contains(p) && !hole.contains(p);
+
+                assert_eq!(pixel == 1, expected, "pixel ({}, {})", x, y);
+            }
+        }
+
+        assert_eq!(display.0[8][8], 0);
+        assert_eq!(display.0[2][2], 1);
+    }
+
+    #[test]
+    fn intersection_of_rectangles() {
+        let mut combined = Display::default();
+        combined.draw(
+            rect(2, 2, 10, 10)
+                .intersection(rect(6, 4, 16, 8))
+                .fill(Some(1u8)),
+        );
+
+        let mut expected = Display::default();
+        expected.draw(rect(6, 4, 10, 8).fill(Some(1u8)));
+
+        assert_eq!(combined, expected);
+    }
+
+    #[test]
+    fn union_covers_both() {
+        let shape = rect(0, 0, 3, 3).union(Circle::new(Coord::new(10, 10), 2));
+
+        assert!(shape.contains(Coord::new(1, 1)));
+        assert!(shape.contains(Coord::new(10, 12)));
+        assert!(!shape.contains(Coord::new(6, 6)));
+        assert_eq!(shape.top_left(), Coord::new(0, 0));
+        assert_eq!(shape.bottom_right(), Coord::new(12, 12));
+        assert_eq!(shape.points().count(), 16 + 13);
+    }
+
+    #[test]
+    fn nested_operations() {
+        let shape = rect(0, 0, 10, 10)
+            .difference(rect(3, 3, 7, 7))
+            .union(Triangle::new(
+                Coord::new(4, 4),
+                Coord::new(6, 4),
+                Coord::new(5, 6),
+            ));
+
+        assert!(shape.contains(Coord::new(1, 1)));
+        assert!(!shape.contains(Coord::new(3, 6)));
+        assert!(shape.contains(Coord::new(5, 5)));
+    }
+
+    #[test]
+    fn stroke_follows_hole() {
+        let mut display = Display::default();
+        display.draw(
+            rect(0, 0, 10, 10)
+                .difference(rect(4, 4, 6, 6))
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        // Outer edge, edge around the hole and the hole itself
+        assert_eq!(display.0[0][5], 1);
+        assert_eq!(display.0[3][5], 1);
+        assert_eq!(display.0[5][5], 0);
+
+        // Between the two edges
+        assert_eq!(display.0[2][5], 2);
+    }
+
+    #[test]
+    fn disjoint_intersection() {
+        let shape = rect(0, 0, 3, 3)
+            .intersection(rect(5, 5, 8, 8))
+            .fill(Some(1u8));
+
+        assert_eq!(shape.size(), UnsignedCoord::new(0, 0));
+        assert_eq!(shape.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn translate() {
+        let shape = rect(0, 0, 3, 3)
+            .union(rect(2, 2, 5, 5))
+            .translate(Coord::new(4, 1));
+
+        assert_eq!(shape.top_left(), Coord::new(4, 1));
+        assert_eq!(shape.bottom_right(), Coord::new(9, 6));
+    }
+
+    #[test]
+    fn offset() {
+        let shape = rect(0, 0, 10, 10)
+            .difference(Circle::new(Coord::new(5, 5), 3))
+            .offset(1);
+
+        // The outside grows and the hole shrinks
+        assert_eq!(shape.a.top_left, Coord::new(-1, -1));
+        assert_eq!(shape.b.radius, 2);
+
+        let union = rect(0, 0, 4, 4).union(rect(6, 6, 8, 8)).offset(-1);
+        assert_eq!(union.b.top_left, Coord::new(7, 7));
+    }
+}
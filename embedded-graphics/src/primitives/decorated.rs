@@ -0,0 +1,367 @@
+//! Drop shadows and halos drawn around primitives
+//!
+//! Any primitive that implements [`StrokeContainsPoint`](../trait.StrokeContainsPoint.html) can be
+//! given a [`shadow`](./trait.Decorate.html#method.shadow) or a
+//! [`halo`](./trait.Decorate.html#method.halo) in a second color. The decoration is drawn in the
+//! same pass as the shape, and only where the shape itself doesn't draw, so every pixel is still
+//! drawn once.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::FillArea;
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::StrokeContainsPoint;
+use crate::style::Styled;
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Decoration drawn around a [`Decorated`](./struct.Decorated.html) shape
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Decoration<C>
+where
+    C: PixelColor,
+{
+    /// Copy of the shape moved by `offset` pixels, drawn behind it
+    Shadow {
+        /// Distance to move the shadow, usually down and to the right
+        offset: Coord,
+
+        /// Shadow color
+        color: C,
+    },
+
+    /// Outline `width` pixels wide around the outside of the shape
+    Halo {
+        /// Width of the outline in pixels
+        width: u32,
+
+        /// Outline color
+        color: C,
+    },
+}
+
+/// Add a shadow or halo to primitives that enclose an area
+///
+/// This is implemented for every primitive that implements
+/// [`StrokeContainsPoint`](../trait.StrokeContainsPoint.html), including combined shapes.
+pub trait Decorate<C>: StrokeContainsPoint + Dimensions + Styled<C> + Sized
+where
+    C: PixelColor,
+{
+    /// Draw a copy of the shape moved by `offset` pixels behind it in `color`
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::{Decorate, Rectangle};
+    /// # use embedded_graphics::mock_display::Display;
+    /// # let mut display = Display::default();
+    ///
+    /// let card = Rectangle::new(Coord::new(2, 2), Coord::new(14, 10))
+    ///     .stroke(Some(1u8))
+    ///     .fill(Some(2u8))
+    ///     .shadow(Coord::new(2, 2), 3u8);
+    ///
+    /// display.draw(card);
+    /// assert_eq!(display.0[12][16], 3);
+    /// ```
+    fn shadow(self, offset: Coord, color: C) -> Decorated<C, Self> {
+        Decorated::new(self, Decoration::Shadow { offset, color })
+    }
+
+    /// Draw an outline `width` pixels wide around the outside of the shape in `color`
+    ///
+    /// The outline keeps the same distance from the shape all the way round, so it has rounded
+    /// corners even around a rectangle.
+    fn halo(self, width: u32, color: C) -> Decorated<C, Self> {
+        Decorated::new(self, Decoration::Halo { width, color })
+    }
+}
+
+impl<C, T> Decorate<C> for T
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C>,
+{
+}
+
+/// Shape drawn with a shadow or halo around it
+///
+/// The shape is drawn with its own style. The decoration fills the pixels around it that the shape
+/// doesn't cover with [`contains_styled`](../trait.StrokeContainsPoint.html#method.contains_styled),
+/// so a shape without a fill still hides the decoration inside its outline.
+#[derive(Debug, Copy, Clone)]
+pub struct Decorated<C, T>
+where
+    C: PixelColor,
+{
+    /// Decorated shape
+    pub shape: T,
+
+    /// Decoration drawn around the shape
+    pub decoration: Decoration<C>,
+}
+
+impl<C, T> Decorated<C, T>
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C>,
+{
+    /// Decorate `shape`
+    pub fn new(shape: T, decoration: Decoration<C>) -> Self {
+        Decorated { shape, decoration }
+    }
+
+    /// Returns the decoration color if the pixel at `point` is covered by the decoration
+    fn decoration_at(&self, point: Coord) -> Option<C> {
+        let shape = &self.shape;
+
+        if shape.contains_styled(point) {
+            return None;
+        }
+
+        match self.decoration {
+            Decoration::Shadow { offset, color } => {
+                Some(color).filter(|_| shape.contains_styled(point - offset))
+            }
+            Decoration::Halo { width, color } => {
+                let width = width as i32;
+
+                Some(color).filter(|_| {
+                    (-width..=width).any(|dy| {
+                        (-width..=width).any(|dx| {
+                            dx * dx + dy * dy <= width * width
+                                && shape.contains_styled(point + Coord::new(dx, dy))
+                        })
+                    })
+                })
+            }
+        }
+    }
+
+    /// Bounding box of the shape and its decoration, returned as `(top_left, bottom_right)`
+    fn bounds(&self) -> (Coord, Coord) {
+        let margin = i32::from(self.shape.current_style().stroke_width);
+        let margin = Coord::new(margin, margin);
+        let (tl, br) = (
+            self.shape.top_left() - margin,
+            self.shape.bottom_right() + margin,
+        );
+
+        match self.decoration {
+            Decoration::Shadow { offset, .. } => (
+                Coord::new(tl[0].min(tl[0] + offset[0]), tl[1].min(tl[1] + offset[1])),
+                Coord::new(br[0].max(br[0] + offset[0]), br[1].max(br[1] + offset[1])),
+            ),
+            Decoration::Halo { width, .. } => {
+                let width = Coord::new(width as i32, width as i32);
+
+                (tl - width, br + width)
+            }
+        }
+    }
+}
+
+impl<C, T> Dimensions for Decorated<C, T>
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C>,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        let (tl, br) = self.bounds();
+
+        (br - tl).to_unsigned()
+    }
+}
+
+impl<C, T> IntoIterator for Decorated<C, T>
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C> + IntoIterator<Item = Pixel<C>> + Clone,
+{
+    type Item = Pixel<C>;
+    type IntoIter = DecoratedIterator<C, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (tl, br) = self.bounds();
+
+        DecoratedIterator {
+            area: FillArea::new(Some((tl, br + Coord::new(1, 1)))),
+            pixels: self.shape.clone().into_iter(),
+            decorated: self,
+        }
+    }
+}
+
+impl<C, T> IntoIterator for &Decorated<C, T>
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C> + IntoIterator<Item = Pixel<C>> + Clone,
+{
+    type Item = Pixel<C>;
+    type IntoIter = DecoratedIterator<C, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone().into_iter()
+    }
+}
+
+/// Pixel iterator for a decorated shape
+///
+/// The decoration is returned first, followed by the pixels of the shape itself.
+#[derive(Debug, Clone)]
+pub struct DecoratedIterator<C, T>
+where
+    C: PixelColor,
+    T: IntoIterator<Item = Pixel<C>>,
+{
+    decorated: Decorated<C, T>,
+    area: FillArea,
+    pixels: T::IntoIter,
+}
+
+impl<C, T> Iterator for DecoratedIterator<C, T>
+where
+    C: PixelColor,
+    T: StrokeContainsPoint + Dimensions + Styled<C> + IntoIterator<Item = Pixel<C>>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let decorated = &self.decorated;
+
+        self.area
+            .find_map(|p| {
+                decorated
+                    .decoration_at(p)
+                    .map(|color| Pixel(p.to_unsigned(), color))
+            })
+            .or_else(|| self.pixels.next())
+    }
+}
+
+impl<C, T> Drawable for Decorated<C, T> where C: PixelColor {}
+
+impl<C, T> Transform for Decorated<C, T>
+where
+    C: PixelColor,
+    T: Transform,
+{
+    /// Translate the shape and its decoration by (x, y) pixels, returning a new decorated shape.
+    /// For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            shape: self.shape.translate(by),
+            decoration: self.decoration,
+        }
+    }
+
+    /// Translate the shape and its decoration by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.shape.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Combine, ContainsPoint, Offset, Rectangle};
+    use crate::style::WithStyle;
+    use crate::Drawing;
+
+    #[test]
+    fn shadow_behind_rectangle() {
+        let rect = Rectangle::new(Coord::new(2, 2), Coord::new(10, 8))
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(rect.shadow(Coord::new(3, 2), 3u8));
+
+        let mut expected = Display::default();
+        expected.draw(Rectangle::new(Coord::new(5, 4), Coord::new(13, 10)).fill(Some(3u8)));
+        expected.draw(rect);
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn halo_around_circle() {
+        let circle = Circle::new(Coord::new(11, 8), 4).fill(Some(1u8));
+        let right = (0..24)
+            .rev()
+            .find(|&x| circle.contains(Coord::new(x, 8)))
+            .unwrap() as usize;
+
+        let mut display = Display::default();
+        display.draw(circle.halo(2, 2u8));
+
+        assert_eq!(display.0[8][right], 1);
+        assert_eq!(display.0[8][right + 1], 2);
+        assert_eq!(display.0[8][right + 2], 2);
+        assert_eq!(display.0[8][right + 3], 0);
+
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let p = Coord::new(x as i32, y as i32);
+
+                assert_eq!(pixel == 1, circle.contains(p), "pixel ({}, {})", x, y);
+                if pixel == 2 {
+                    assert!(circle.offset(2).contains(p), "pixel ({}, {})", x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn each_pixel_drawn_once() {
+        let shape = Rectangle::new(Coord::new(3, 3), Coord::new(12, 9))
+            .union(Circle::new(Coord::new(12, 6), 4))
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .halo(1, 3u8);
+
+        let mut counts = Display::default();
+        for Pixel(p, _) in shape {
+            counts.0[p[1] as usize][p[0] as usize] += 1;
+        }
+
+        assert!(counts.0.iter().flatten().all(|&count| count <= 1));
+        assert_eq!(counts.0[6][17], 1);
+        assert_eq!(counts.0[6][18], 0);
+    }
+
+    #[test]
+    fn bounds_include_decoration() {
+        let rect = Rectangle::<u8>::new(Coord::new(2, 2), Coord::new(10, 8));
+
+        // Bounds leave room for the default 1 pixel stroke
+        let shadow = rect.shadow(Coord::new(3, -2), 1);
+        assert_eq!(shadow.top_left(), Coord::new(1, -1));
+        assert_eq!(shadow.bottom_right(), Coord::new(14, 9));
+
+        let halo = rect.halo(2, 1);
+        assert_eq!(halo.top_left(), Coord::new(-1, -1));
+        assert_eq!(halo.size(), UnsignedCoord::new(14, 12));
+    }
+
+    #[test]
+    fn translate() {
+        let shape = Circle::<u8>::new(Coord::new(5, 5), 2).halo(1, 1);
+
+        assert_eq!(
+            shape.translate(Coord::new(3, 4)).top_left(),
+            Coord::new(4, 5)
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Shared helpers for primitives made of straight edges, such as paths and polygons, and for
+//! shapes drawn by testing each pixel in their bounding box
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::primitives::line::{is_on_line, ThickLineIter};
+use crate::style::{StrokeCap, StrokeOverlap, Style};
+
+/// Winding number of a set of closed edges around the center of the pixel at `(x, y)`
+pub(crate) fn winding_number<I>(edges: I, x: i32, y: i32) -> i32
+where
+    I: Iterator<Item = (Coord, Coord)>,
+{
+    // Work in doubled coordinates so pixel centers land on odd integers and edge end points on even
+    // ones, which means a sample can never lie exactly on an end point.
+    let sx = 2 * i64::from(x) + 1;
+    let sy = 2 * i64::from(y) + 1;
+
+    edges.fold(0, |winding, (start, end)| {
+        let (ax, ay) = (2 * i64::from(start[0]), 2 * i64::from(start[1]));
+        let (bx, by) = (2 * i64::from(end[0]), 2 * i64::from(end[1]));
+
+        if (ay < sy) == (by < sy) {
+            return winding;
+        }
+
+        // Which side of the edge the sample lies on
+        let side = (sx - ax) * (by - ay) - (sy - ay) * (bx - ax);
+
+        if by > ay && side > 0 {
+            winding + 1
+        } else if by < ay && side < 0 {
+            winding - 1
+        } else {
+            winding
+        }
+    })
+}
+
+/// Bounding box of a set of edges, returned as `(top_left, bottom_right)`
+pub(crate) fn edge_bounds<I>(edges: I) -> Option<(Coord, Coord)>
+where
+    I: Iterator<Item = (Coord, Coord)>,
+{
+    edges.fold(None, |bounds, (start, end)| {
+        let (tl, br) = bounds.unwrap_or((start, start));
+
+        Some((
+            Coord::new(
+                tl[0].min(start[0]).min(end[0]),
+                tl[1].min(start[1]).min(end[1]),
+            ),
+            Coord::new(
+                br[0].max(start[0]).max(end[0]),
+                br[1].max(start[1]).max(end[1]),
+            ),
+        ))
+    })
+}
+
+/// Iterator over the on screen pixels whose centers lie within a bounding box
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct FillArea {
+    top_left: Coord,
+    bottom_right: Coord,
+    x: i32,
+    y: i32,
+}
+
+impl FillArea {
+    pub(crate) fn new(bounds: Option<(Coord, Coord)>) -> Self {
+        let (top_left, bottom_right) = bounds.unwrap_or((Coord::new(0, 0), Coord::new(0, 0)));
+        let top_left = Coord::new(top_left[0].max(0), top_left[1].max(0));
+
+        Self {
+            top_left,
+            bottom_right,
+            x: top_left[0],
+            y: top_left[1],
+        }
+    }
+
+    /// Shrink the area to the part inside `top_left` to `bottom_right`, inclusive, and restart it
+    pub(crate) fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.top_left = Coord::new(
+            self.top_left[0].max(top_left[0]),
+            self.top_left[1].max(top_left[1]),
+        );
+        self.bottom_right = Coord::new(
+            self.bottom_right[0].min(bottom_right[0].saturating_add(1)),
+            self.bottom_right[1].min(bottom_right[1].saturating_add(1)),
+        );
+        self.x = self.top_left[0];
+        self.y = self.top_left[1];
+    }
+}
+
+impl Iterator for FillArea {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.bottom_right[1] || self.x >= self.bottom_right[0] {
+            return None;
+        }
+
+        let point = Coord::new(self.x, self.y);
+
+        self.x += 1;
+        if self.x >= self.bottom_right[0] {
+            self.x = self.top_left[0];
+            self.y += 1;
+        }
+
+        Some(point)
+    }
+}
+
+/// Returns `true` if a pixel covered by a shape is within `width` pixels of the shape's outline
+///
+/// Shapes drawn with a coverage test use this to draw their stroke on the inside of their outline.
+pub(crate) fn is_inner_border<F>(contains: F, point: Coord, width: i32) -> bool
+where
+    F: Fn(Coord) -> bool,
+{
+    (1..=width).any(|d| {
+        [(d, 0), (-d, 0), (0, d), (0, -d)]
+            .iter()
+            .any(|&(dx, dy)| !contains(point + Coord::new(dx, dy)))
+    })
+}
+
+/// Returns `true` if a pixel is part of a stroke `width` pixels wide along a shape's outline, with
+/// `outset` of those pixels outside the shape and the rest inside
+///
+/// With an `outset` of zero this is the same as [`is_inner_border`].
+pub(crate) fn is_aligned_border<F>(contains: F, point: Coord, width: i32, outset: i32) -> bool
+where
+    F: Fn(Coord) -> bool,
+{
+    if contains(point) {
+        return is_inner_border(contains, point, width - outset);
+    }
+
+    (1..=outset).any(|d| {
+        [(d, 0), (-d, 0), (0, d), (0, -d)]
+            .iter()
+            .any(|&(dx, dy)| contains(point + Coord::new(dx, dy)))
+    })
+}
+
+/// Returns `true` if a shape drawn with [`is_aligned_border`] and `style` covers `point` with its
+/// stroke
+pub(crate) fn is_stroke_pixel<C, F>(
+    contains: F,
+    style: &Style<C>,
+    point: Coord,
+    outset: i32,
+) -> bool
+where
+    C: PixelColor,
+    F: Fn(Coord) -> bool,
+{
+    let width = i32::from(style.stroke_width);
+
+    style.stroke_color.is_some() && width > 0 && is_aligned_border(contains, point, width, outset)
+}
+
+/// Pixel to draw at `point` for a shape drawn with a coverage test, followed by a stroke pixel to
+/// draw on top of it
+///
+/// `stroke` is the stroke color if the point is part of the shape's stroke. The second pixel is
+/// only returned for styles that draw the stroke over the fill, at points that are part of both.
+pub(crate) fn styled_pixel<C>(
+    style: &Style<C>,
+    point: Coord,
+    stroke: Option<C>,
+    is_fill: bool,
+) -> Option<(Pixel<C>, Option<Pixel<C>>)>
+where
+    C: PixelColor,
+{
+    let over_fill = style.stroke_overlap == StrokeOverlap::OverFill;
+    let fill = if is_fill && (stroke.is_none() || over_fill) {
+        style.fill_color_at(point)
+    } else {
+        None
+    };
+    let point = point.to_unsigned();
+
+    match (fill, stroke) {
+        (Some(fill), Some(stroke)) => Some((Pixel(point, fill), Some(Pixel(point, stroke)))),
+        (_, Some(color)) | (Some(color), None) => Some((Pixel(point, color), None)),
+        (None, None) => None,
+    }
+}
+
+/// Iterator over a short list of up to three points, for shapes built from a few line segments
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ShortPath {
+    points: [Coord; 3],
+    len: usize,
+    index: usize,
+}
+
+impl ShortPath {
+    /// Path through `points`, of which only the first three are used
+    pub(crate) fn new(points: &[Coord]) -> Self {
+        let mut path = Self {
+            points: [Coord::new(0, 0); 3],
+            len: points.len().min(3),
+            index: 0,
+        };
+
+        path.points[..path.len].copy_from_slice(&points[..path.len]);
+
+        path
+    }
+}
+
+impl Iterator for ShortPath {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let point = self.points[self.index];
+        self.index += 1;
+
+        Some(point)
+    }
+}
+
+/// Iterator over consecutive pairs of points, turning a list of points into connected edges
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PointPairs<I> {
+    points: I,
+    previous: Option<Coord>,
+}
+
+impl<I> PointPairs<I>
+where
+    I: Iterator<Item = Coord>,
+{
+    pub(crate) fn new(mut points: I) -> Self {
+        let previous = points.next();
+
+        Self { points, previous }
+    }
+}
+
+impl<I> Iterator for PointPairs<I>
+where
+    I: Iterator<Item = Coord>,
+{
+    type Item = (Coord, Coord);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.previous?;
+        let end = self.points.next()?;
+
+        self.previous = Some(end);
+
+        Some((start, end))
+    }
+}
+
+/// Pixel iterator that strokes a series of edges with one pixel wide lines
+///
+/// Every pixel is returned at most once, even where edges meet or cross, and dash patterns run
+/// continuously across all edges.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct EdgeStroke<C, I>
+where
+    C: PixelColor,
+{
+    style: Style<C>,
+    /// Every edge of the stroke, used to find pixels already covered by an earlier edge
+    all_edges: I,
+    edges: I,
+    segment: Option<ThickLineIter>,
+    /// Number of edges before the current one
+    index: usize,
+    /// Number of pixels from the start of the stroke, used for dash patterns
+    position: u32,
+}
+
+impl<C, I> EdgeStroke<C, I>
+where
+    C: PixelColor,
+    I: Iterator<Item = (Coord, Coord)> + Clone,
+{
+    pub(crate) fn new(edges: I, style: Style<C>) -> Self {
+        Self {
+            style,
+            all_edges: edges.clone(),
+            edges,
+            segment: None,
+            index: 0,
+            position: 0,
+        }
+    }
+
+    /// Returns `true` if the stroke passes through `point`, whether or not it falls in a gap of
+    /// the dash pattern
+    pub(crate) fn covers(&self, point: Coord) -> bool {
+        self.style.stroke_color.is_some()
+            && self
+                .all_edges
+                .clone()
+                .any(|(start, end)| is_on_line(start, end, point))
+    }
+
+    /// Next pixel of the stroke, or of the fill where `fill` returns a color for a point in a gap
+    /// of the dash pattern
+    pub(crate) fn next_with_gaps<F>(&mut self, fill: F) -> Option<Pixel<C>>
+    where
+        F: Fn(Coord) -> Option<C>,
+    {
+        let stroke_color = self.style.stroke_color?;
+
+        loop {
+            if let Some(point) = self.segment.as_mut().and_then(Iterator::next) {
+                let index = self.index - 1;
+
+                if self
+                    .all_edges
+                    .clone()
+                    .take(index)
+                    .any(|(start, end)| is_on_line(start, end, point))
+                {
+                    continue;
+                }
+
+                let position = self.position;
+                self.position += 1;
+
+                let color = if self.style.stroke_dash.is_none_or(|p| p.is_dash(position)) {
+                    Some(stroke_color)
+                } else {
+                    fill(point)
+                };
+
+                if let Some(color) = color {
+                    return Some(Pixel(point.to_unsigned(), color));
+                }
+
+                continue;
+            }
+
+            let (start, end) = self.edges.next()?;
+
+            self.index += 1;
+            self.segment = Some(ThickLineIter::new(start, end, 1, StrokeCap::Butt));
+        }
+    }
+}
+
+impl<C, I> Iterator for EdgeStroke<C, I>
+where
+    C: PixelColor,
+    I: Iterator<Item = (Coord, Coord)> + Clone,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_gaps(|_| None)
+    }
+}
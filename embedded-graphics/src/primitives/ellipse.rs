@@ -0,0 +1,840 @@
+//! Elliptical arc and sector primitives
+//!
+//! Both shapes take the same angles as a [`RingSector`](../ring_sector/struct.RingSector.html): in
+//! degrees, clockwise, with an angle of zero pointing straight up from the center. Angles are
+//! measured on the circle the ellipse is stretched from, so sectors with equal sweeps cover equal
+//! areas of the ellipse, which keeps the slices of a squashed pie chart in proportion.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_aligned_border, is_stroke_pixel, styled_pixel, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::trig::{cos, sin, Sweep, DEGREE, ONE};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Elliptical arc primitive
+///
+/// The outline of an ellipse between two angles. The stroke is drawn inside the ellipse, so wider
+/// strokes grow towards the center, unless the style's
+/// [`stroke_alignment`](../../style/struct.Style.html#structfield.stroke_alignment) moves it
+/// outwards. Dash patterns and stroke caps are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::EllipseArc;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Gauge scale seen at an angle
+/// let scale = EllipseArc::new(Coord::new(12, 10), 10, 5, -90, 180)
+///     .stroke(Some(1u8))
+///     .stroke_width(2);
+///
+/// display.draw(scale);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct EllipseArc<C: PixelColor> {
+    /// Center point of the ellipse
+    pub center: Coord,
+
+    /// Horizontal radius
+    pub radius_x: u32,
+
+    /// Vertical radius
+    pub radius_y: u32,
+
+    /// Clockwise angle of the start of the arc in degrees
+    pub start_angle: i32,
+
+    /// Clockwise angle covered by the arc in degrees
+    pub sweep_angle: i32,
+
+    /// Arc style
+    pub style: Style<C>,
+}
+
+/// Elliptical sector primitive
+///
+/// A slice of a filled ellipse between two angles. The stroke is drawn along the curved edge and
+/// both straight sides, inside the outline unless the style's
+/// [`stroke_alignment`](../../style/struct.Style.html#structfield.stroke_alignment) says
+/// otherwise. Dash patterns are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::EllipseSector;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let center = Coord::new(12, 8);
+///
+/// // A pie chart on a wide display, split 25% / 75%
+/// display.draw(EllipseSector::new(center, 11, 6, 0, 90).fill(Some(1u8)));
+/// display.draw(EllipseSector::new(center, 11, 6, 90, 270).fill(Some(2u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct EllipseSector<C: PixelColor> {
+    /// Center point of the ellipse
+    pub center: Coord,
+
+    /// Horizontal radius
+    pub radius_x: u32,
+
+    /// Vertical radius
+    pub radius_y: u32,
+
+    /// Clockwise angle of the start of the sector in degrees
+    pub start_angle: i32,
+
+    /// Clockwise angle covered by the sector in degrees
+    pub sweep_angle: i32,
+
+    /// Sector style
+    pub style: Style<C>,
+}
+
+/// Start angle within a single turn and sweep angle limited to a full turn, so neither overflows
+/// when converted to angle units
+fn normalize_angles(start_angle: i32, sweep_angle: i32) -> (i32, i32) {
+    (start_angle.rem_euclid(360), sweep_angle.clamp(-360, 360))
+}
+
+/// Coverage tests for the pixels of an elliptical arc or sector
+#[derive(Debug, Copy, Clone)]
+struct EllipseShape {
+    center: Coord,
+    radius_x: i64,
+    radius_y: i64,
+    sweep: Sweep,
+}
+
+impl EllipseShape {
+    fn new(
+        center: Coord,
+        radius_x: u32,
+        radius_y: u32,
+        start_angle: i32,
+        sweep_angle: i32,
+    ) -> Self {
+        let (start_angle, sweep_angle) = normalize_angles(start_angle, sweep_angle);
+
+        Self {
+            center,
+            radius_x: i64::from(radius_x),
+            radius_y: i64::from(radius_y),
+            sweep: Sweep::new(start_angle * DEGREE, sweep_angle * DEGREE),
+        }
+    }
+
+    /// Returns `true` if `(dx, dy)` from the center lies inside an ellipse with the given radii
+    ///
+    /// The radii are extended by half a pixel so that a radius of `r` covers `r` pixels on either
+    /// side of the center.
+    fn in_ellipse(dx: i64, dy: i64, radius_x: i64, radius_y: i64) -> bool {
+        // Points outside the bounding box are skipped before anything is squared
+        if dx.abs() > radius_x || dy.abs() > radius_y {
+            return false;
+        }
+
+        let (dx, dy) = (i128::from(dx), i128::from(dy));
+        let a = i128::from(2 * radius_x + 1);
+        let b = i128::from(2 * radius_y + 1);
+
+        4 * dx * dx * b * b + 4 * dy * dy * a * a < a * a * b * b
+    }
+
+    fn offset(&self, point: Coord) -> (i64, i64) {
+        (
+            i64::from(point[0]) - i64::from(self.center[0]),
+            i64::from(point[1]) - i64::from(self.center[1]),
+        )
+    }
+
+    /// Returns `true` if the direction of `(dx, dy)` is within the sweep
+    fn in_sweep(&self, dx: i64, dy: i64) -> bool {
+        // Squash the ellipse back into a circle, where the angles are measured
+        self.sweep.contains(dx * self.radius_y, dy * self.radius_x)
+    }
+
+    /// Returns `true` if the sector covers the pixel at `point`
+    fn in_sector(&self, point: Coord) -> bool {
+        let (dx, dy) = self.offset(point);
+
+        Self::in_ellipse(dx, dy, self.radius_x, self.radius_y) && self.in_sweep(dx, dy)
+    }
+
+    /// Returns `true` if an arc `width` pixels wide covers the pixel at `point`
+    fn in_arc(&self, point: Coord, width: i64) -> bool {
+        let (dx, dy) = self.offset(point);
+        let inner_x = self.radius_x - width;
+        let inner_y = self.radius_y - width;
+
+        let in_inner = inner_x >= 0 && inner_y >= 0 && Self::in_ellipse(dx, dy, inner_x, inner_y);
+
+        !in_inner && Self::in_ellipse(dx, dy, self.radius_x, self.radius_y) && self.in_sweep(dx, dy)
+    }
+
+    /// Point at `angle` degrees on an ellipse with the given radii around the same center
+    fn point_at(&self, radius_x: i64, radius_y: i64, angle: i32) -> Coord {
+        let scale = |radius: i64, v: i32| {
+            let v = radius * i64::from(v);
+
+            // Round to nearest, away from zero
+            ((v + v.signum() * i64::from(ONE / 2)) / i64::from(ONE)) as i32
+        };
+        let angle = angle * DEGREE;
+
+        self.center + Coord::new(scale(radius_x, sin(angle)), -scale(radius_y, cos(angle)))
+    }
+
+    /// Bounding box of the part of the ellipse within the sweep that lies outside a smaller
+    /// ellipse `inset` pixels in from the outline
+    fn bounds(&self, start_angle: i32, sweep_angle: i32, inset: i64) -> Option<(Coord, Coord)> {
+        let (start_angle, sweep_angle) = normalize_angles(start_angle, sweep_angle);
+        if sweep_angle == 0 {
+            return None;
+        }
+
+        let start = start_angle.min(start_angle + sweep_angle);
+        let end = start + sweep_angle.abs();
+
+        let inner_x = (self.radius_x - inset).max(0);
+        let inner_y = (self.radius_y - inset).max(0);
+
+        let mut tl = self.point_at(self.radius_x, self.radius_y, start);
+        let mut br = tl;
+        let mut include = |p: Coord| {
+            tl = Coord::new(tl[0].min(p[0]), tl[1].min(p[1]));
+            br = Coord::new(br[0].max(p[0]), br[1].max(p[1]));
+        };
+
+        include(self.point_at(self.radius_x, self.radius_y, end));
+        include(self.point_at(inner_x, inner_y, start));
+        include(self.point_at(inner_x, inner_y, end));
+
+        // The outline reaches furthest at any of the four compass points it passes through
+        for &(dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)].iter() {
+            if self.sweep.contains(i64::from(dx), i64::from(dy)) {
+                let radius = Coord::new(self.radius_x as i32, self.radius_y as i32);
+
+                include(self.center + Coord::new(dx * radius[0], dy * radius[1]));
+            }
+        }
+
+        Some((tl, br))
+    }
+}
+
+macro_rules! impl_ellipse {
+    ($type:ident, $iterator:ident, $name:expr) => {
+        impl<C> $type<C>
+        where
+            C: PixelColor,
+        {
+            #[doc = "Create a new "]
+            #[doc = $name]
+            pub fn new(
+                center: Coord,
+                radius_x: u32,
+                radius_y: u32,
+                start_angle: i32,
+                sweep_angle: i32,
+            ) -> Self {
+                $type {
+                    center,
+                    radius_x,
+                    radius_y,
+                    start_angle,
+                    sweep_angle,
+                    style: Style::default(),
+                }
+            }
+
+            fn shape(&self) -> EllipseShape {
+                EllipseShape::new(
+                    self.center,
+                    self.radius_x,
+                    self.radius_y,
+                    self.start_angle,
+                    self.sweep_angle,
+                )
+            }
+        }
+
+        impl<C> Primitive for $type<C> where C: PixelColor {}
+
+        impl<C> Intersects<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn intersects(&self, area: &Rectangle<C>) -> bool {
+                area.overlaps(self.top_left(), self.bottom_right())
+            }
+        }
+
+        impl<C> Dimensions for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn top_left(&self) -> Coord {
+                self.bounds().map_or(self.center, |(tl, _)| tl)
+            }
+
+            fn bottom_right(&self) -> Coord {
+                self.bounds().map_or(self.center, |(_, br)| br)
+            }
+
+            fn size(&self) -> UnsignedCoord {
+                (self.bottom_right() - self.top_left()).to_unsigned()
+            }
+        }
+
+        impl<C> WithStyle<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn style(mut self, style: Style<C>) -> Self {
+                self.style = style;
+
+                self
+            }
+
+            fn stroke(mut self, color: Option<C>) -> Self {
+                self.style.stroke_color = color;
+
+                self
+            }
+
+            fn stroke_width(mut self, width: u8) -> Self {
+                self.style.stroke_width = width;
+
+                self
+            }
+
+            fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+                self.style.stroke_dash = pattern;
+
+                self
+            }
+
+            fn line_join(mut self, join: LineJoin) -> Self {
+                self.style.line_join = join;
+
+                self
+            }
+
+            fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+                self.style.stroke_cap = cap;
+
+                self
+            }
+
+            fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+                self.style.stroke_alignment = alignment;
+
+                self
+            }
+
+            fn stroke_corners(mut self, length: Option<u32>) -> Self {
+                self.style.stroke_corners = length;
+
+                self
+            }
+
+            fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+                self.style.stroke_overlap = overlap;
+
+                self
+            }
+
+            fn draw_mode(mut self, mode: DrawMode) -> Self {
+                self.style.draw_mode = mode;
+
+                self
+            }
+
+            fn fill(mut self, color: Option<C>) -> Self {
+                self.style.fill_color = color;
+
+                self
+            }
+
+            fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+                self.style.fill_gradient = gradient;
+
+                self
+            }
+
+            fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+                self.style.fill_pattern = pattern;
+
+                self
+            }
+        }
+
+        impl<C> Styled<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn current_style(&self) -> &Style<C> {
+                &self.style
+            }
+        }
+
+        impl<C> IntoIterator for $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = $iterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                (&self).into_iter()
+            }
+        }
+
+        impl<C> Drawable for $type<C> where C: PixelColor {}
+
+        impl<C> Transform for $type<C>
+        where
+            C: PixelColor,
+        {
+            /// Translate the center from its current position to a new position by (x, y)
+            /// pixels, returning a new object. For a mutating transform, see `translate_mut`.
+            fn translate(&self, by: Coord) -> Self {
+                Self {
+                    center: self.center + by,
+                    ..*self
+                }
+            }
+
+            /// Translate the center from its current position to a new position by (x, y)
+            /// pixels.
+            fn translate_mut(&mut self, by: Coord) -> &mut Self {
+                self.center += by;
+
+                self
+            }
+        }
+    };
+}
+
+impl_ellipse!(EllipseArc, EllipseArcIterator, "elliptical arc");
+impl_ellipse!(EllipseSector, EllipseSectorIterator, "elliptical sector");
+
+impl<C> EllipseArc<C>
+where
+    C: PixelColor,
+{
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        // The inside edge of a wide arc can stick out past the ends of the outside edge
+        self.stroke_shape().bounds(
+            self.start_angle,
+            self.sweep_angle,
+            i64::from(self.style.stroke_width),
+        )
+    }
+
+    /// Shape of the outside edge of the stroke, which is moved out from the ellipse for strokes
+    /// that aren't aligned to the inside
+    fn stroke_shape(&self) -> EllipseShape {
+        let outset = i64::from(self.style.stroke_outset());
+        let mut shape = self.shape();
+
+        shape.radius_x += outset;
+        shape.radius_y += outset;
+
+        shape
+    }
+}
+
+impl<C> EllipseSector<C>
+where
+    C: PixelColor,
+{
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        let radius = self.radius_x.max(self.radius_y);
+        let outset = self.style.stroke_outset() as i32;
+
+        self.shape()
+            .bounds(self.start_angle, self.sweep_angle, i64::from(radius))
+            .map(|(tl, br)| {
+                (
+                    tl - Coord::new(outset, outset),
+                    br + Coord::new(outset, outset),
+                )
+            })
+    }
+}
+
+impl<C> ContainsPoint for EllipseSector<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.shape().in_sector(point)
+    }
+}
+
+impl<C> StrokeContainsPoint for EllipseSector<C>
+where
+    C: PixelColor,
+{
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let shape = self.shape();
+
+        is_stroke_pixel(
+            |p| shape.in_sector(p),
+            &self.style,
+            point,
+            self.style.stroke_outset() as i32,
+        )
+    }
+}
+
+impl<C> Offset for EllipseSector<C>
+where
+    C: PixelColor,
+{
+    /// Only the curved edge moves, the straight sides keep their angles
+    fn offset(&self, distance: i32) -> Self {
+        Self {
+            radius_x: (self.radius_x as i32 + distance).max(0) as u32,
+            radius_y: (self.radius_y as i32 + distance).max(0) as u32,
+            ..*self
+        }
+    }
+}
+
+/// Area to search for pixels, from a bounding box with an inclusive bottom right corner
+fn search_area(bounds: Option<(Coord, Coord)>, visible: bool) -> FillArea {
+    FillArea::new(
+        bounds
+            .filter(|_| visible)
+            .map(|(tl, br)| (tl, br + Coord::new(1, 1))),
+    )
+}
+
+impl<C> IntoIterator for &EllipseArc<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = EllipseArcIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.stroke_color.is_some() && self.style.stroke_width > 0;
+
+        EllipseArcIterator {
+            shape: self.stroke_shape(),
+            area: search_area(self.bounds(), visible),
+            style: self.style,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in an elliptical arc
+#[derive(Debug, Clone, Copy)]
+pub struct EllipseArcIterator<C>
+where
+    C: PixelColor,
+{
+    shape: EllipseShape,
+    area: FillArea,
+    style: Style<C>,
+}
+
+impl<C> Iterator for EllipseArcIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.style.stroke_color?;
+        let shape = self.shape;
+        let width = i64::from(self.style.stroke_width);
+
+        self.area
+            .find(|p| shape.in_arc(*p, width))
+            .map(|p| Pixel(p.to_unsigned(), color))
+    }
+}
+
+impl<C> Clip for EllipseArcIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+impl<C> IntoIterator for &EllipseSector<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = EllipseSectorIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+
+        EllipseSectorIterator {
+            shape: self.shape(),
+            area: search_area(self.bounds(), visible),
+            style: self.style,
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in an elliptical sector's fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct EllipseSectorIterator<C>
+where
+    C: PixelColor,
+{
+    shape: EllipseShape,
+    area: FillArea,
+    style: Style<C>,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> Iterator for EllipseSectorIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = self.shape;
+        let style = self.style;
+        let width = i32::from(style.stroke_width);
+        let outset = style.stroke_outset() as i32;
+
+        let (pixel, over) = self.area.find_map(|p| {
+            let stroke = style.stroke_color.filter(|_| {
+                width > 0 && is_aligned_border(|p| shape.in_sector(p), p, width, outset)
+            });
+
+            styled_pixel(&style, p, stroke, shape.in_sector(p))
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for EllipseSectorIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::RingSector;
+    use crate::Drawing;
+
+    #[test]
+    fn circular_sector_matches_ring_sector() {
+        let ellipse = EllipseSector::new(Coord::new(10, 8), 6, 6, 30, 200).fill(Some(1u8));
+        let ring = RingSector::new(Coord::new(10, 8), 6, 0, 30, 200).fill(Some(1u8));
+
+        assert!(ellipse.into_iter().eq(ring.into_iter()));
+    }
+
+    #[test]
+    fn full_ellipse() {
+        let mut display = Display::default();
+        display.draw(EllipseSector::new(Coord::new(11, 7), 10, 4, 0, 360).fill(Some(1u8)));
+
+        // Reaches exactly the radius along both axes
+        assert_eq!(display.0[7][1], 1);
+        assert_eq!(display.0[7][21], 1);
+        assert_eq!(display.0[7][0], 0);
+        assert_eq!(display.0[7][22], 0);
+        assert_eq!(display.0[3][11], 1);
+        assert_eq!(display.0[11][11], 1);
+        assert_eq!(display.0[2][11], 0);
+        assert_eq!(display.0[12][11], 0);
+
+        // Corners of the bounding box are outside
+        assert_eq!(display.0[3][1], 0);
+    }
+
+    #[test]
+    fn sweeps_are_proportional() {
+        let center = Coord::new(11, 7);
+        let first = EllipseSector::new(center, 10, 4, 0, 45).fill(Some(1u8));
+        let second = EllipseSector::new(center, 10, 4, 45, 45).fill(Some(1u8));
+
+        // Both halves of the quarter cover about the same area, even though the ellipse is much
+        // wider than it is tall
+        let first = first.into_iter().count() as i32;
+        let second = second.into_iter().count() as i32;
+
+        assert!((first - second).abs() <= 2, "{} {}", first, second);
+    }
+
+    #[test]
+    fn arc_is_outline_only() {
+        let mut display = Display::default();
+        display.draw(EllipseArc::new(Coord::new(11, 7), 10, 4, 0, 360).stroke(Some(1u8)));
+
+        assert_eq!(display.0[7][1], 1);
+        assert_eq!(display.0[3][11], 1);
+        assert_eq!(display.0[7][11], 0);
+        assert_eq!(display.0[7][3], 0);
+
+        let mut wide = Display::default();
+        wide.draw(
+            EllipseArc::new(Coord::new(11, 7), 10, 4, 0, 360)
+                .stroke(Some(1u8))
+                .stroke_width(3),
+        );
+
+        assert_eq!(wide.0[7][3], 1);
+        assert_eq!(wide.0[7][11], 0);
+        assert!(wide.count(1) > display.count(1));
+    }
+
+    #[test]
+    fn arc_sweep() {
+        let mut display = Display::default();
+        display.draw(EllipseArc::new(Coord::new(11, 7), 10, 4, 0, 90).stroke(Some(1u8)));
+
+        // Top right quarter only
+        assert_eq!(display.0[7][21], 1);
+        assert_eq!(display.0[3][11], 1);
+        assert_eq!(display.0[7][1], 0);
+        assert_eq!(display.0[11][11], 0);
+    }
+
+    #[test]
+    fn sector_stroke() {
+        let mut display = Display::default();
+        display.draw(
+            EllipseSector::new(Coord::new(11, 7), 10, 6, 90, 180)
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        // Straight edge along the middle and curved edge at the bottom
+        assert_eq!(display.0[7][15], 1);
+        assert_eq!(display.0[13][11], 1);
+        assert_eq!(display.0[10][11], 2);
+
+        // Nothing above the center
+        assert_eq!(display.0[6][11], 0);
+    }
+
+    #[test]
+    fn far_points() {
+        let sector = EllipseSector::<u8>::new(Coord::new(0, 0), 40000, 40000, 0, 360);
+
+        assert!(sector.contains(Coord::new(30000, -100)));
+        assert!(!sector.contains(Coord::new(40001, 0)));
+        assert!(!sector.contains(Coord::new(i32::MAX, i32::MIN)));
+        assert!(!sector
+            .stroke(Some(1))
+            .stroke_contains(Coord::new(i32::MIN, i32::MAX)));
+    }
+
+    #[test]
+    fn extreme_angles() {
+        let center = Coord::new(10, 8);
+
+        let mut display = Display::default();
+        display.draw(EllipseArc::new(center, 7, 5, 90 + 360 * 5_000_000, 90).stroke(Some(1u8)));
+        let mut reference = Display::default();
+        reference.draw(EllipseArc::new(center, 7, 5, 90, 90).stroke(Some(1u8)));
+        assert_eq!(display, reference);
+
+        for &sweep in [i32::MIN, i32::MAX].iter() {
+            let sector = EllipseSector::new(center, 7, 5, 9_000_000, sweep).fill(Some(1u8));
+            let mut display = Display::default();
+            display.draw(sector);
+            let mut reference = Display::default();
+            reference.draw(EllipseSector::new(center, 7, 5, 0, 360).fill(Some(1u8)));
+
+            assert_eq!(display, reference);
+            assert_eq!(sector.size(), UnsignedCoord::new(14, 10));
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        let arc: EllipseArc<u8> = EllipseArc::new(Coord::new(20, 10), 8, 4, 0, 90);
+        assert_eq!(arc.top_left(), Coord::new(20, 6));
+        assert_eq!(arc.bottom_right(), Coord::new(28, 10));
+
+        let sector: EllipseSector<u8> = EllipseSector::new(Coord::new(20, 10), 8, 4, 180, 90);
+        assert_eq!(sector.top_left(), Coord::new(12, 10));
+        assert_eq!(sector.bottom_right(), Coord::new(20, 14));
+        assert_eq!(sector.size(), UnsignedCoord::new(8, 4));
+
+        let empty: EllipseSector<u8> = EllipseSector::new(Coord::new(20, 10), 8, 4, 0, 0);
+        assert_eq!(empty.into_iter().count(), 0);
+        assert_eq!(empty.top_left(), Coord::new(20, 10));
+    }
+
+    #[test]
+    fn outside_arc_stroke_grows_outwards() {
+        let center = Coord::new(12, 8);
+        let arc = EllipseArc::new(center, 8, 4, -90, 180)
+            .stroke(Some(1u8))
+            .stroke_width(2);
+
+        let outside = arc.stroke_alignment(StrokeAlignment::Outside);
+        let larger = EllipseArc::new(center, 10, 6, -90, 180)
+            .stroke(Some(1u8))
+            .stroke_width(2);
+
+        assert!(outside.into_iter().eq(larger.into_iter()));
+        assert_eq!(outside.top_left(), larger.top_left());
+        assert_eq!(outside.bottom_right(), larger.bottom_right());
+    }
+
+    #[test]
+    fn center_sector_stroke() {
+        let sector = EllipseSector::new(Coord::new(12, 8), 8, 5, 0, 360).fill(Some(1u8));
+        let stroked = sector
+            .stroke(Some(2u8))
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Center);
+
+        let mut display = Display::default();
+        display.draw(stroked);
+
+        // One pixel of the stroke sits outside the ellipse and one inside
+        assert_eq!(&display.0[8][2..6], &[0, 2, 2, 1]);
+        assert_eq!(stroked.top_left(), Coord::new(3, 2));
+    }
+}
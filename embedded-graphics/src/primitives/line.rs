@@ -4,13 +4,22 @@ use super::super::drawable::*;
 use super::super::transform::*;
 use crate::coord::{Coord, ToUnsigned};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
-use crate::style::Style;
+use crate::primitives::thick::{stroke_margin, ThickStrokePixels, ThickStrokePoints};
+use crate::primitives::{Intersects, Primitive, Rectangle};
 use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
 use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 
 /// Line primitive
 ///
+/// Lines with a stroke width greater than one are drawn centered on the line between the start
+/// and end points, with ends shaped by the style's
+/// [`stroke_cap`](../../style/struct.Style.html#structfield.stroke_cap). Dash patterns are only
+/// applied to lines one pixel wide.
+///
 /// # Examples
 ///
 /// The [macro examples](../../macro.egline.html) make for more concise code.
@@ -20,6 +29,7 @@ use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 /// ```rust
 /// use embedded_graphics::prelude::*;
 /// use embedded_graphics::primitives::Line;
+/// use embedded_graphics::style::StrokeCap;
 /// # use embedded_graphics::mock_display::Display;
 /// # let mut display = Display::default();
 ///
@@ -34,9 +44,16 @@ use crate::unsignedcoord::{ToSigned, UnsignedCoord};
 /// let l3 = Line::new(Coord::new(50, 20), Coord::new(60, 35))
 ///     .translate(Coord::new(65, 35));
 ///
+/// // Thick line with rounded ends
+/// let l4 = Line::new(Coord::new(5, 5), Coord::new(15, 10))
+///     .stroke(Some(5u8))
+///     .stroke_width(3)
+///     .stroke_cap(StrokeCap::Round);
+///
 /// display.draw(l1);
 /// display.draw(l2);
 /// display.draw(l3);
+/// display.draw(l4);
 /// ```
 #[derive(Debug, Copy, Clone)]
 pub struct Line<C: PixelColor> {
@@ -52,6 +69,26 @@ pub struct Line<C: PixelColor> {
 
 impl<C> Primitive for Line<C> where C: PixelColor {}
 
+impl<C> Intersects<C> for Line<C>
+where
+    C: PixelColor,
+{
+    /// Uses the end points directly, widened to make room for a thick stroke
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+        let top_left = Coord::new(
+            self.start[0].min(self.end[0]),
+            self.start[1].min(self.end[1]),
+        );
+        let bottom_right = Coord::new(
+            self.start[0].max(self.end[0]),
+            self.start[1].max(self.end[1]),
+        );
+
+        area.overlaps(top_left - margin, bottom_right + margin)
+    }
+}
+
 impl<C> Dimensions for Line<C>
 where
     C: PixelColor,
@@ -108,11 +145,74 @@ where
         self
     }
 
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
     fn fill(mut self, color: Option<C>) -> Self {
         self.style.fill_color = color;
 
         self
     }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Line<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
 }
 
 impl<C> IntoIterator for Line<C>
@@ -132,30 +232,20 @@ impl<'a, C: PixelColor> IntoIterator for &'a Line<C> {
     type IntoIter = LineIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut delta = self.end - self.start;
-        if delta[0] < 0 {
-            delta = Coord::new(-delta[0], delta[1]);
-        }
-        if delta[1] > 0 {
-            delta = Coord::new(delta[0], -delta[1]);
-        }
-
-        let direction = match (self.start[0] >= self.end[0], self.start[1] >= self.end[1]) {
-            (false, false) => Coord::new(1, 1),
-            (false, true) => Coord::new(1, -1),
-            (true, false) => Coord::new(-1, 1),
-            (true, true) => Coord::new(-1, -1),
-        };
-
         LineIterator {
             style: self.style,
-
             start: self.start,
             end: self.end,
-            delta,
-            direction,
-            err: delta[0] + delta[1],
-            stop: self.start == self.end, // if line length is zero, draw nothing
+            points: Bresenham::new(self.start, self.end),
+            position: 0,
+            thick: if self.style.stroke_width > 1 {
+                Some(ThickStrokePixels::new(
+                    LineVertices::new(self.start, self.end),
+                    &self.style,
+                ))
+            } else {
+                None
+            },
         }
     }
 }
@@ -167,7 +257,53 @@ where
     C: PixelColor,
 {
     style: Style<C>,
+    start: Coord,
+    end: Coord,
+    points: Bresenham,
+    /// Number of pixels from the start of the line, used for dash patterns
+    position: u32,
+    /// Rasterizer used instead of Bresenham's algorithm for lines wider than one pixel
+    thick: Option<ThickStrokePixels<C, LineVertices>>,
+}
+
+/// Iterator over the start and end points of a line
+#[derive(Debug, Clone, Copy)]
+struct LineVertices {
+    start: Coord,
+    end: Coord,
+    index: u8,
+}
+
+impl LineVertices {
+    fn new(start: Coord, end: Coord) -> Self {
+        Self {
+            start,
+            end,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for LineVertices {
+    type Item = Coord;
 
+    fn next(&mut self) -> Option<Self::Item> {
+        let vertex = match self.index {
+            0 => self.start,
+            1 => self.end,
+            _ => return None,
+        };
+        self.index += 1;
+
+        Some(vertex)
+    }
+}
+
+/// Every point of a one pixel wide line, on screen or not
+///
+/// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
+#[derive(Debug, Clone, Copy)]
+struct Bresenham {
     start: Coord,
     end: Coord,
     delta: Coord,
@@ -177,7 +313,105 @@ where
     stop: bool,
 }
 
-// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
+impl Bresenham {
+    fn new(start: Coord, end: Coord) -> Self {
+        let mut delta = end - start;
+        if delta[0] < 0 {
+            delta = Coord::new(-delta[0], delta[1]);
+        }
+        if delta[1] > 0 {
+            delta = Coord::new(delta[0], -delta[1]);
+        }
+
+        let direction = match (start[0] >= end[0], start[1] >= end[1]) {
+            (false, false) => Coord::new(1, 1),
+            (false, true) => Coord::new(1, -1),
+            (true, false) => Coord::new(-1, 1),
+            (true, true) => Coord::new(-1, -1),
+        };
+
+        Self {
+            start,
+            end,
+            delta,
+            direction,
+            err: delta[0] + delta[1],
+            stop: start == end, // if line length is zero, draw nothing
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stop {
+            return None;
+        }
+
+        let p_coord = self.start;
+
+        if self.start == self.end {
+            self.stop = true;
+        }
+        let err_double = 2 * self.err;
+        if err_double > self.delta[1] {
+            self.err += self.delta[1];
+            self.start += Coord::new(self.direction[0], 0);
+        }
+        if err_double < self.delta[0] {
+            self.err += self.delta[0];
+            self.start += Coord::new(0, self.direction[1]);
+        }
+
+        Some(p_coord)
+    }
+}
+
+/// Returns `true` if a one pixel wide line from `start` to `end` sets the pixel at `point`
+///
+/// Gives the same answer as searching the line's [`Bresenham`] points, without walking the line.
+pub(crate) fn is_on_line(start: Coord, end: Coord, point: Coord) -> bool {
+    if start == end {
+        return false;
+    }
+
+    let (x0, y0) = (i64::from(start[0]), i64::from(start[1]));
+    let (x1, y1) = (i64::from(end[0]), i64::from(end[1]));
+
+    // Mirror the line into the octants going right and down from the start point
+    let dx = (x1 - x0).abs();
+    let dy = (y1 - y0).abs();
+    let k = (i64::from(point[0]) - x0) * if x1 >= x0 { 1 } else { -1 };
+    let j = (i64::from(point[1]) - y0) * if y1 >= y0 { 1 } else { -1 };
+
+    if k < 0 || k > dx || j < 0 || j > dy {
+        return false;
+    }
+
+    // Step along the major axis, rounding halfway points the same way the error term does
+    if dx >= dy {
+        j == (2 * dy * k + dx - 1).div_euclid(2 * dx)
+    } else {
+        k == (2 * dx * j + dy - 1).div_euclid(2 * dy)
+    }
+}
+
+impl<C: PixelColor> LineIterator<C> {
+    /// Returns `true` if the line passes through `point`, whether or not it falls in a gap of the
+    /// dash pattern
+    pub(crate) fn covers(&self, point: Coord) -> bool {
+        if self.style.stroke_color.is_none() {
+            return false;
+        }
+
+        match &self.thick {
+            Some(thick) => thick.covers(point),
+            None => is_on_line(self.start, self.end, point),
+        }
+    }
+}
+
 impl<C: PixelColor> Iterator for LineIterator<C> {
     type Item = Pixel<C>;
 
@@ -185,21 +419,20 @@ impl<C: PixelColor> Iterator for LineIterator<C> {
         // return none if stroke color is none
         self.style.stroke_color?;
 
-        while !self.stop {
-            let p_coord = self.start;
+        if let Some(thick) = &mut self.thick {
+            return thick.next();
+        }
 
-            if self.start == self.end {
-                self.stop = true;
-            }
-            let err_double = 2 * self.err;
-            if err_double > self.delta[1] {
-                self.err += self.delta[1];
-                self.start += Coord::new(self.direction[0], 0);
-            }
-            if err_double < self.delta[0] {
-                self.err += self.delta[0];
-                self.start += Coord::new(0, self.direction[1]);
+        for p_coord in &mut self.points {
+            let position = self.position;
+            self.position += 1;
+
+            if let Some(pattern) = self.style.stroke_dash {
+                if !pattern.is_dash(position) {
+                    continue;
+                }
             }
+
             if p_coord[0] >= 0 && p_coord[1] >= 0 {
                 return Some(Pixel(
                     p_coord.to_unsigned(),
@@ -211,6 +444,92 @@ impl<C: PixelColor> Iterator for LineIterator<C> {
     }
 }
 
+/// Iterator over the points covered by a line of any thickness, without a color
+///
+/// Lines one pixel wide use Bresenham's algorithm and wider lines use the same rasterizer as thick
+/// [`Line`](./struct.Line.html)s, so the points are exactly the pixels drawing the line would set.
+/// Dash patterns are ignored and only points with non-negative coordinates are returned.
+///
+/// Driver and widget authors can use this to build their own effects on top of lines, such as a
+/// color that changes along the line. Create one with [`ThickLineIter::new`](#method.new), or from
+/// an existing line with [`BresenhamExt::points`](./trait.BresenhamExt.html#tymethod.points).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::line::ThickLineIter;
+/// use embedded_graphics::style::StrokeCap;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let points = ThickLineIter::new(Coord::new(2, 8), Coord::new(20, 8), 3, StrokeCap::Butt);
+///
+/// // Brighter towards the end of the line
+/// display.draw(points.map(|p| Pixel(p.to_unsigned(), (p[0] / 3) as u8)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ThickLineIter {
+    thin: Option<Bresenham>,
+    thick: Option<ThickStrokePoints<LineVertices>>,
+}
+
+impl ThickLineIter {
+    /// Iterate over the points of a line `width` pixels wide, with ends shaped by `cap`
+    ///
+    /// The cap is only used for lines wider than one pixel.
+    pub fn new(start: Coord, end: Coord, width: u8, cap: StrokeCap) -> Self {
+        if width > 1 {
+            let vertices = LineVertices::new(start, end);
+
+            Self {
+                thin: None,
+                thick: Some(ThickStrokePoints::new(
+                    vertices,
+                    width,
+                    LineJoin::default(),
+                    cap,
+                )),
+            }
+        } else {
+            Self {
+                thin: Some(Bresenham::new(start, end)),
+                thick: None,
+            }
+        }
+    }
+}
+
+impl Iterator for ThickLineIter {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(thick) = &mut self.thick {
+            return thick.next();
+        }
+
+        self.thin.as_mut()?.find(|p| p[0] >= 0 && p[1] >= 0)
+    }
+}
+
+/// Access to the points of a [`Line`](./struct.Line.html) without drawing it
+pub trait BresenhamExt {
+    /// Iterate over the points the line covers with its current stroke width and cap
+    fn points(&self) -> ThickLineIter;
+}
+
+impl<C> BresenhamExt for Line<C>
+where
+    C: PixelColor,
+{
+    fn points(&self) -> ThickLineIter {
+        ThickLineIter::new(
+            self.start,
+            self.end,
+            self.style.stroke_width,
+            self.style.stroke_cap,
+        )
+    }
+}
+
 impl<C> Drawable for Line<C> where C: PixelColor {}
 
 impl<C> Transform for Line<C>
@@ -285,6 +604,15 @@ mod tests {
         assert!(expected_iter.next().is_none())
     }
 
+    #[test]
+    fn intersects_thick_stroke() {
+        let area = Rectangle::new(Coord::new(0, 0), Coord::new(9, 9));
+        let line = Line::new(Coord::new(20, 3), Coord::new(13, 3)).stroke(Some(1u8));
+
+        assert!(!line.intersects(&area));
+        assert!(line.stroke_width(5).intersects(&area));
+    }
+
     #[test]
     fn bounding_box() {
         let start = Coord::new(10, 10);
@@ -389,4 +717,118 @@ mod tests {
         let expected = [(0, 0), (1, 1), (2, 2)];
         test_expected_line(start, end, &expected);
     }
+
+    #[test]
+    fn dashed() {
+        let line = Line::new(Coord::new(0, 0), Coord::new(7, 0))
+            .stroke(Some(1u8))
+            .stroke_dash(Some(DashPattern::new(&[2, 1])));
+
+        assert!(line
+            .into_iter()
+            .map(|Pixel(coord, _)| coord[0])
+            .eq([0, 1, 3, 4, 6, 7].iter().cloned()));
+    }
+
+    #[test]
+    fn dash_dot() {
+        let line = Line::new(Coord::new(0, 0), Coord::new(14, 0))
+            .stroke(Some(1u8))
+            .stroke_dash(Some(DashPattern::DASH_DOT));
+
+        assert!(line.into_iter().map(|Pixel(coord, _)| coord[0]).eq([
+            0, 1, 2, 3, 4, 5, 8, 11, 12, 13, 14
+        ]
+        .iter()
+        .cloned()));
+    }
+
+    #[test]
+    fn dash_continues_offscreen() {
+        let line = Line::new(Coord::new(-1, 0), Coord::new(3, 0))
+            .stroke(Some(1u8))
+            .stroke_dash(Some(DashPattern::DOTTED));
+
+        assert!(line
+            .into_iter()
+            .map(|Pixel(coord, _)| coord[0])
+            .eq([1, 3].iter().cloned()));
+    }
+
+    #[test]
+    fn thick_caps() {
+        let line = Line::new(Coord::new(2, 5), Coord::new(10, 5))
+            .stroke(Some(1u8))
+            .stroke_width(5);
+
+        let butt = line.into_iter().count();
+        let square = line.stroke_cap(StrokeCap::Square).into_iter().count();
+        let round = line.stroke_cap(StrokeCap::Round).into_iter().count();
+
+        assert_eq!(butt, 9 * 5);
+        assert_eq!(square, 13 * 5);
+        assert_eq!(round, 9 * 5 + 2 * (5 + 3));
+    }
+
+    #[test]
+    fn thick_butt_ends_are_perpendicular() {
+        let line = Line::new(Coord::new(2, 2), Coord::new(12, 12))
+            .stroke(Some(1u8))
+            .stroke_width(3);
+
+        assert!(line
+            .into_iter()
+            .all(|Pixel(coord, _)| { (4..=24).contains(&(coord[0] + coord[1])) }));
+    }
+
+    #[test]
+    fn points_match_drawn_pixels() {
+        for &width in [1u8, 2, 5].iter() {
+            let line = Line::new(Coord::new(-3, 2), Coord::new(20, 13))
+                .stroke(Some(1u8))
+                .stroke_width(width)
+                .stroke_cap(StrokeCap::Round);
+
+            let pixels = line.into_iter().map(|Pixel(p, _)| p.to_signed());
+
+            assert!(line.points().eq(pixels));
+        }
+    }
+
+    #[test]
+    fn points_ignore_dashes() {
+        let line = Line::new(Coord::new(0, 0), Coord::new(9, 0))
+            .stroke(Some(1u8))
+            .stroke_dash(Some(DashPattern::new(&[2, 2])));
+
+        assert_eq!(line.into_iter().count(), 6);
+        assert_eq!(line.points().count(), 10);
+    }
+
+    #[test]
+    fn zero_length_has_no_points() {
+        let points = ThickLineIter::new(Coord::new(4, 4), Coord::new(4, 4), 1, StrokeCap::Butt);
+
+        assert_eq!(points.count(), 0);
+    }
+
+    #[test]
+    fn is_on_line_matches_bresenham() {
+        let start = Coord::new(0, 0);
+
+        for x in -7..=7 {
+            for y in -7..=7 {
+                let end = Coord::new(x, y);
+
+                for px in -8..=8 {
+                    for py in -8..=8 {
+                        let point = Coord::new(px, py);
+                        let walked = Bresenham::new(start, end).any(|p| p == point);
+
+                        assert_eq!(is_on_line(start, end, point), walked, "{:?}", (end, point));
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,584 @@
+//! Small marker shapes for checkboxes and plots
+//!
+//! Each marker is drawn with lines that fill a bounding box, using the stroke color, width, dash
+//! pattern, line join and cap of its style. The lines are inset by half the stroke width, so wide
+//! strokes stay close to the bounding box.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{EdgeStroke, PointPairs, ShortPath};
+use super::thick::{stroke_margin, ThickStrokePixels};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+
+/// Plus shaped marker
+///
+/// A horizontal and a vertical line crossing in the middle of the bounding box.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::marker::CrossHair;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// display.draw(CrossHair::new(Coord::new(2, 2), Coord::new(8, 8)).stroke(Some(1u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct CrossHair<C: PixelColor> {
+    /// Top left corner of the bounding box
+    pub top_left: Coord,
+
+    /// Bottom right corner of the bounding box
+    pub bottom_right: Coord,
+
+    /// Marker style
+    pub style: Style<C>,
+}
+
+/// Check mark
+///
+/// A short stroke down from the middle of the left edge, followed by a long stroke up to the top
+/// right corner.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::marker::Check;
+/// use embedded_graphics::primitives::Rectangle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // A ticked checkbox
+/// display.draw(Rectangle::new(Coord::new(0, 0), Coord::new(10, 10)).stroke(Some(1u8)));
+/// display.draw(Check::new(Coord::new(2, 2), Coord::new(8, 8)).stroke(Some(1u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Check<C: PixelColor> {
+    /// Top left corner of the bounding box
+    pub top_left: Coord,
+
+    /// Bottom right corner of the bounding box
+    pub bottom_right: Coord,
+
+    /// Marker style
+    pub style: Style<C>,
+}
+
+/// Cross shaped marker
+///
+/// Two diagonal lines joining opposite corners of the bounding box.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::marker::XMark;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// display.draw(XMark::new(Coord::new(2, 2), Coord::new(8, 8)).stroke(Some(1u8)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct XMark<C: PixelColor> {
+    /// Top left corner of the bounding box
+    pub top_left: Coord,
+
+    /// Bottom right corner of the bounding box
+    pub bottom_right: Coord,
+
+    /// Marker style
+    pub style: Style<C>,
+}
+
+impl<C> CrossHair<C>
+where
+    C: PixelColor,
+{
+    fn paths(&self) -> [ShortPath; 2] {
+        let (tl, br) = inset_box(self.top_left, self.bottom_right, &self.style);
+        let center = Coord::new(tl[0] + (br[0] - tl[0]) / 2, tl[1] + (br[1] - tl[1]) / 2);
+
+        [
+            ShortPath::new(&[Coord::new(tl[0], center[1]), Coord::new(br[0], center[1])]),
+            ShortPath::new(&[Coord::new(center[0], tl[1]), Coord::new(center[0], br[1])]),
+        ]
+    }
+}
+
+impl<C> Check<C>
+where
+    C: PixelColor,
+{
+    fn paths(&self) -> [ShortPath; 2] {
+        let (tl, br) = inset_box(self.top_left, self.bottom_right, &self.style);
+        let size = br - tl;
+
+        [
+            ShortPath::new(&[
+                Coord::new(tl[0], tl[1] + size[1] / 2),
+                Coord::new(tl[0] + size[0] / 3, br[1]),
+                Coord::new(br[0], tl[1]),
+            ]),
+            ShortPath::new(&[]),
+        ]
+    }
+}
+
+impl<C> XMark<C>
+where
+    C: PixelColor,
+{
+    fn paths(&self) -> [ShortPath; 2] {
+        let (tl, br) = inset_box(self.top_left, self.bottom_right, &self.style);
+
+        [
+            ShortPath::new(&[tl, br]),
+            ShortPath::new(&[Coord::new(br[0], tl[1]), Coord::new(tl[0], br[1])]),
+        ]
+    }
+}
+
+/// Bounding box moved inwards by half the stroke width, without letting it turn inside out
+fn inset_box<C>(top_left: Coord, bottom_right: Coord, style: &Style<C>) -> (Coord, Coord)
+where
+    C: PixelColor,
+{
+    let inset = i32::from(style.stroke_width / 2);
+    let inset = |min: i32, max: i32| {
+        let inset = inset.min((max - min) / 2);
+
+        (min + inset, max - inset)
+    };
+
+    let (left, right) = inset(top_left[0], bottom_right[0]);
+    let (top, bottom) = inset(top_left[1], bottom_right[1]);
+
+    (Coord::new(left, top), Coord::new(right, bottom))
+}
+
+macro_rules! impl_marker {
+    ($type:ident, $name:expr) => {
+        impl<C> $type<C>
+        where
+            C: PixelColor,
+        {
+            #[doc = "Create a new "]
+            #[doc = $name]
+            #[doc = " filling the box from `top_left` to `bottom_right`"]
+            pub fn new(top_left: Coord, bottom_right: Coord) -> Self {
+                $type {
+                    top_left,
+                    bottom_right,
+                    style: Style::default(),
+                }
+            }
+        }
+
+        impl<C> Primitive for $type<C> where C: PixelColor {}
+
+        impl<C> Intersects<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn intersects(&self, area: &Rectangle<C>) -> bool {
+                let margin = stroke_margin(self.style.stroke_width);
+
+                area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+            }
+        }
+
+        impl<C> Dimensions for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn top_left(&self) -> Coord {
+                self.top_left
+            }
+
+            fn bottom_right(&self) -> Coord {
+                self.bottom_right
+            }
+
+            fn size(&self) -> UnsignedCoord {
+                (self.bottom_right - self.top_left).abs().to_unsigned()
+            }
+        }
+
+        impl<C> WithStyle<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn style(mut self, style: Style<C>) -> Self {
+                self.style = style;
+
+                self
+            }
+
+            fn stroke(mut self, color: Option<C>) -> Self {
+                self.style.stroke_color = color;
+
+                self
+            }
+
+            fn stroke_width(mut self, width: u8) -> Self {
+                self.style.stroke_width = width;
+
+                self
+            }
+
+            fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+                self.style.stroke_dash = pattern;
+
+                self
+            }
+
+            fn line_join(mut self, join: LineJoin) -> Self {
+                self.style.line_join = join;
+
+                self
+            }
+
+            fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+                self.style.stroke_cap = cap;
+
+                self
+            }
+
+            fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+                self.style.stroke_alignment = alignment;
+
+                self
+            }
+
+            fn stroke_corners(mut self, length: Option<u32>) -> Self {
+                self.style.stroke_corners = length;
+
+                self
+            }
+
+            fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+                self.style.stroke_overlap = overlap;
+
+                self
+            }
+
+            fn draw_mode(mut self, mode: DrawMode) -> Self {
+                self.style.draw_mode = mode;
+
+                self
+            }
+
+            fn fill(mut self, color: Option<C>) -> Self {
+                self.style.fill_color = color;
+
+                self
+            }
+
+            fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+                self.style.fill_gradient = gradient;
+
+                self
+            }
+
+            fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+                self.style.fill_pattern = pattern;
+
+                self
+            }
+        }
+
+        impl<C> Styled<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn current_style(&self) -> &Style<C> {
+                &self.style
+            }
+        }
+
+        impl<C> IntoIterator for $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = MarkerIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                (&self).into_iter()
+            }
+        }
+
+        impl<C> IntoIterator for &$type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = MarkerIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                let [first, second] = self.paths();
+
+                MarkerIterator {
+                    strokes: [
+                        MarkerStroke::new(first, &self.style),
+                        MarkerStroke::new(second, &self.style),
+                    ],
+                    index: 0,
+                }
+            }
+        }
+
+        impl<C> Drawable for $type<C> where C: PixelColor {}
+
+        impl<C> Transform for $type<C>
+        where
+            C: PixelColor,
+        {
+            /// Translate the marker from its current position to a new position by (x, y)
+            /// pixels, returning a new marker. For a mutating transform, see `translate_mut`.
+            fn translate(&self, by: Coord) -> Self {
+                Self {
+                    top_left: self.top_left + by,
+                    bottom_right: self.bottom_right + by,
+                    ..*self
+                }
+            }
+
+            /// Translate the marker from its current position to a new position by (x, y)
+            /// pixels.
+            fn translate_mut(&mut self, by: Coord) -> &mut Self {
+                self.top_left += by;
+                self.bottom_right += by;
+
+                self
+            }
+        }
+    };
+}
+
+impl_marker!(CrossHair, "cross hair");
+impl_marker!(Check, "check mark");
+impl_marker!(XMark, "cross");
+
+/// Pixels of one of the lines making up a marker
+#[derive(Debug, Clone, Copy)]
+struct MarkerStroke<C>
+where
+    C: PixelColor,
+{
+    /// Bresenham stroke for lines one pixel wide
+    thin: Option<EdgeStroke<C, PointPairs<ShortPath>>>,
+    /// Rasterizer for wider lines
+    thick: Option<ThickStrokePixels<C, ShortPath>>,
+}
+
+impl<C> MarkerStroke<C>
+where
+    C: PixelColor,
+{
+    fn new(path: ShortPath, style: &Style<C>) -> Self {
+        let width = style.stroke_width;
+
+        Self {
+            thin: if width == 1 {
+                Some(EdgeStroke::new(PointPairs::new(path), *style))
+            } else {
+                None
+            },
+            thick: if width > 1 {
+                Some(ThickStrokePixels::new(path, style))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl<C> MarkerStroke<C>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the line passes through `point`, whether or not it was returned yet
+    fn covers(&self, point: Coord) -> bool {
+        if let Some(thin) = &self.thin {
+            thin.covers(point)
+        } else if let Some(thick) = &self.thick {
+            thick.covers(point)
+        } else {
+            false
+        }
+    }
+}
+
+impl<C> Iterator for MarkerStroke<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(thin) = &mut self.thin {
+            thin.next()
+        } else if let Some(thick) = &mut self.thick {
+            thick.next()
+        } else {
+            None
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in a marker
+#[derive(Debug, Clone, Copy)]
+pub struct MarkerIterator<C>
+where
+    C: PixelColor,
+{
+    strokes: [MarkerStroke<C>; 2],
+    index: usize,
+}
+
+impl<C> Iterator for MarkerIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let [first, second] = &mut self.strokes;
+
+        if self.index == 0 {
+            if let Some(pixel) = first.next() {
+                return Some(pixel);
+            }
+
+            self.index = 1;
+        }
+
+        // Pixels where the lines cross have already been drawn by the first line
+        second
+            .by_ref()
+            .find(|Pixel(point, _)| !first.covers(point.to_signed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::Line;
+    use crate::Drawing;
+
+    fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> Line<u8> {
+        Line::new(Coord::new(x1, y1), Coord::new(x2, y2)).stroke(Some(1))
+    }
+
+    #[test]
+    fn cross_hair() {
+        let mut display = Display::default();
+        display.draw(CrossHair::new(Coord::new(2, 3), Coord::new(8, 9)).stroke(Some(1u8)));
+
+        let mut expected = Display::default();
+        expected.draw(line(2, 6, 8, 6));
+        expected.draw(line(5, 3, 5, 9));
+
+        assert_eq!(display, expected);
+        assert_eq!(display.count(1), 13);
+    }
+
+    #[test]
+    fn x_mark() {
+        let mut display = Display::default();
+        display.draw(XMark::new(Coord::new(2, 2), Coord::new(8, 8)).stroke(Some(1u8)));
+
+        let mut expected = Display::default();
+        expected.draw(line(2, 2, 8, 8));
+        expected.draw(line(8, 2, 2, 8));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn check() {
+        let mut display = Display::default();
+        display.draw(Check::new(Coord::new(0, 0), Coord::new(9, 6)).stroke(Some(1u8)));
+
+        let mut expected = Display::default();
+        expected.draw(line(0, 3, 3, 6));
+        expected.draw(line(3, 6, 9, 0));
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn thick_strokes_are_inset() {
+        let marker = XMark::new(Coord::new(2, 2), Coord::new(12, 12))
+            .stroke(Some(1u8))
+            .stroke_width(3);
+
+        let mut display = Display::default();
+        display.draw(marker);
+
+        // Corners are pulled in by one pixel so the stroke doesn't spill out
+        assert_eq!(display.0[3][3], 1);
+        assert_eq!(display.0[1][1], 0);
+        assert!(display.count(1) > 2 * 11);
+    }
+
+    /// Panics if any point of `pixels` is returned more than once
+    fn assert_drawn_once(pixels: MarkerIterator<u8>) {
+        let mut counts = [[0u8; 24]; 24];
+
+        for Pixel(point, _) in pixels {
+            let count = &mut counts[point[1] as usize][point[0] as usize];
+            *count += 1;
+
+            assert_eq!(*count, 1, "{:?} drawn more than once", point);
+        }
+    }
+
+    #[test]
+    fn crossing_pixels_are_drawn_once() {
+        let (tl, br) = (Coord::new(2, 3), Coord::new(15, 13));
+
+        for width in 1..=4 {
+            for &stroke_dash in [None, Some(DashPattern::DOTTED)].iter() {
+                for &line_join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round].iter() {
+                    for &stroke_cap in [StrokeCap::Butt, StrokeCap::Round, StrokeCap::Square].iter()
+                    {
+                        let style = Style {
+                            stroke_width: width,
+                            stroke_dash,
+                            line_join,
+                            stroke_cap,
+                            ..Style::stroke(1u8)
+                        };
+
+                        assert_drawn_once(CrossHair::new(tl, br).style(style).into_iter());
+                        assert_drawn_once(Check::new(tl, br).style(style).into_iter());
+                        assert_drawn_once(XMark::new(tl, br).style(style).into_iter());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_stroke() {
+        let marker: Check<u8> = Check::new(Coord::new(0, 0), Coord::new(8, 8));
+
+        assert_eq!(marker.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn transform() {
+        let marker: CrossHair<u8> =
+            CrossHair::new(Coord::new(0, 0), Coord::new(4, 4)).translate(Coord::new(3, 2));
+
+        assert_eq!(marker.top_left(), Coord::new(3, 2));
+        assert_eq!(marker.size(), UnsignedCoord::new(4, 4));
+    }
+}
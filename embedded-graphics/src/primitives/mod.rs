@@ -1,18 +1,160 @@
 //! Graphics primitives
 
+use crate::coord::Coord;
 use crate::drawable::Dimensions;
+use crate::pixelcolor::PixelColor;
 
+pub mod arrow;
+pub mod batch;
+pub mod bezier;
+pub mod capsule;
 pub mod circle;
+pub mod combine;
+pub mod decorated;
+pub(crate) mod edges;
+pub mod ellipse;
 pub mod line;
+pub mod marker;
+pub mod path;
+pub mod polygon;
+pub mod polyline;
 pub mod rectangle;
+pub mod regular_polygon;
+pub mod ring_sector;
+pub mod rounded_rectangle;
+pub mod scanline;
+pub mod smooth;
+pub mod spline;
+pub mod superellipse;
+pub(crate) mod thick;
 pub mod triangle;
 
 /// Primitive trait
 pub trait Primitive: Dimensions {}
 
+/// Hit test for primitives that enclose an area
+///
+/// Only the filled interior is tested, the stroke style is ignored. Pixels are tested the same way
+/// the shape decides which pixels to fill when it is drawn.
+pub trait ContainsPoint {
+    /// Returns `true` if the pixel at `point` is inside the shape
+    fn contains(&self, point: Coord) -> bool;
+}
+
+/// Hit test for styled primitives that takes their stroke into account
+///
+/// [`ContainsPoint`](./trait.ContainsPoint.html) only tests the shape's outline, so it misses the
+/// part of a stroke that sits outside the shape and can't tell stroke pixels from fill pixels. This
+/// tests the pixels the stroke covers when the shape is drawn with its current style, including its
+/// width and [`stroke_alignment`](../style/struct.Style.html#structfield.stroke_alignment). Dash
+/// patterns are ignored, so gaps between dashes still count as part of the stroke.
+///
+/// This is useful for hit testing touch input on outlined buttons:
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Circle, ContainsPoint, StrokeContainsPoint};
+/// use embedded_graphics::style::StrokeAlignment;
+///
+/// let button = Circle::new(Coord::new(12, 8), 4)
+///     .stroke(Some(1u8))
+///     .stroke_width(3)
+///     .stroke_alignment(StrokeAlignment::Outside);
+///
+/// // Just outside the circle, but on its stroke
+/// let touch = Coord::new(12, 14);
+///
+/// assert!(!button.contains(touch));
+/// assert!(button.stroke_contains(touch));
+/// assert!(button.contains_styled(touch));
+/// ```
+pub trait StrokeContainsPoint: ContainsPoint {
+    /// Returns `true` if the pixel at `point` is covered by the shape's stroke
+    ///
+    /// Always `false` if the shape has no stroke color or a stroke width of zero.
+    fn stroke_contains(&self, point: Coord) -> bool;
+
+    /// Returns `true` if the pixel at `point` is inside the outer edge of the stroke
+    ///
+    /// The interior of the shape counts whether or not it is filled, so an outlined button can be
+    /// pressed anywhere inside its outline.
+    fn contains_styled(&self, point: Coord) -> bool {
+        self.contains(point) || self.stroke_contains(point)
+    }
+}
+
+/// Grow or shrink a primitive that encloses an area
+///
+/// A positive distance moves the outline of the shape outwards by that many pixels, which is
+/// useful for drawing focus rings around other shapes. A negative distance moves it inwards to
+/// inset content. Shapes shrunk past their center collapse to a point instead of turning inside
+/// out.
+///
+/// [`Polygon`](./polygon/struct.Polygon.html) and [`Path`](./path/struct.Path.html) borrow their
+/// points, so they can't be offset.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Circle, Offset};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let button = Circle::new(Coord::new(12, 8), 4).fill(Some(1u8));
+/// let focus_ring = button.offset(2).fill(None).stroke(Some(2u8));
+///
+/// display.draw(button);
+/// display.draw(focus_ring);
+/// ```
+pub trait Offset {
+    /// Copy of the shape with its outline moved outwards by `distance` pixels
+    fn offset(&self, distance: i32) -> Self;
+}
+
+/// Cheap overlap test between a primitive and a rectangular area of the display
+///
+/// Scene graphs and dirty rectangle trackers can use this to skip shapes that can't touch a damaged
+/// region before drawing them. The test is conservative: it never returns `false` for a shape that
+/// would draw a pixel inside the area, but can return `true` for one that only comes close. Most
+/// shapes are tested using their bounding box, widened to make room for thick strokes where the
+/// bounding box doesn't already include them. Circles and rectangles are tested exactly.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Circle, Intersects, Rectangle};
+///
+/// let damaged = Rectangle::new(Coord::new(0, 0), Coord::new(9, 9));
+///
+/// let near: Circle<u8> = Circle::new(Coord::new(12, 12), 5);
+/// let far: Circle<u8> = Circle::new(Coord::new(40, 40), 5);
+///
+/// assert!(near.intersects(&damaged));
+/// assert!(!far.intersects(&damaged));
+/// ```
+pub trait Intersects<C: PixelColor> {
+    /// Returns `false` if drawing the shape can't change any pixel inside `area`
+    fn intersects(&self, area: &Rectangle<C>) -> bool;
+}
+
+pub use self::arrow::Arrow;
+pub use self::batch::Batch;
+pub use self::bezier::{CubicBezier, QuadraticBezier};
+pub use self::capsule::Capsule;
 pub use self::circle::Circle;
+pub use self::combine::{Combine, Combined, Operation};
+pub use self::decorated::{Decorate, Decorated, Decoration};
+pub use self::ellipse::{EllipseArc, EllipseSector};
 pub use self::line::Line;
+pub use self::marker::{Check, CrossHair, XMark};
+pub use self::path::Path;
+pub use self::polygon::Polygon;
+pub use self::polyline::Polyline;
 pub use self::rectangle::Rectangle;
+pub use self::regular_polygon::{RegularPolygon, Star};
+pub use self::ring_sector::RingSector;
+pub use self::rounded_rectangle::RoundedRectangle;
+pub use self::scanline::{Scanline, Scanlines};
+pub use self::spline::Spline;
+pub use self::superellipse::Superellipse;
 pub use self::triangle::Triangle;
 
 /// Create a [`Circle`](./primitives/circle/struct.Circle.html) with optional styling using a
@@ -50,7 +192,8 @@ macro_rules! egcircle {
 /// Create a [`Line`](./primitives/line/struct.Line.html) with optional styling using a
 /// convenient macro.
 ///
-/// Note that only the `stroke` property has any effect on lines currently.
+/// Note that only the `stroke`, `stroke_width`, `stroke_dash` and `stroke_cap` properties have any
+/// effect on lines.
 ///
 /// ```rust
 /// use embedded_graphics::{egline, style::Style, primitives::Line};
@@ -150,6 +293,52 @@ macro_rules! egtriangle {
     }};
 }
 
+/// Create a [`QuadraticBezier`](./primitives/bezier/struct.QuadraticBezier.html) curve with
+/// optional styling using a convenient macro.
+///
+/// ```rust
+/// use embedded_graphics::{egquadratic_bezier, style::Style, primitives::QuadraticBezier};
+///
+/// let curve: QuadraticBezier<u8> = egquadratic_bezier!((10, 20), (30, 0), (50, 20));
+/// let stroke_curve: QuadraticBezier<u8> =
+///     egquadratic_bezier!((10, 20), (30, 0), (50, 20), stroke = Some(5u8), tolerance = 1);
+/// ```
+///
+/// Style properties like `stroke` map to the method calls on the
+/// [`WithStyle`](style/trait.WithStyle.html) trait. `tolerance` sets the flattening tolerance.
+#[macro_export]
+macro_rules! egquadratic_bezier {
+    (($x1:expr, $y1:expr), ($x2:expr, $y2:expr), ($x3:expr, $y3:expr) $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::QuadraticBezier::new($crate::coord::Coord::new($x1, $y1), $crate::coord::Coord::new($x2, $y2), $crate::coord::Coord::new($x3, $y3))
+            $( .$style_key($style_value) )*
+    }};
+}
+
+/// Create a [`CubicBezier`](./primitives/bezier/struct.CubicBezier.html) curve with optional
+/// styling using a convenient macro.
+///
+/// ```rust
+/// use embedded_graphics::{egcubic_bezier, style::Style, primitives::CubicBezier};
+///
+/// let curve: CubicBezier<u8> = egcubic_bezier!((10, 20), (30, 0), (50, 40), (70, 20));
+/// let stroke_curve: CubicBezier<u8> =
+///     egcubic_bezier!((10, 20), (30, 0), (50, 40), (70, 20), stroke = Some(5u8));
+/// ```
+///
+/// Style properties like `stroke` map to the method calls on the
+/// [`WithStyle`](style/trait.WithStyle.html) trait. `tolerance` sets the flattening tolerance.
+#[macro_export]
+macro_rules! egcubic_bezier {
+    (($x1:expr, $y1:expr), ($x2:expr, $y2:expr), ($x3:expr, $y3:expr), ($x4:expr, $y4:expr) $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::CubicBezier::new($crate::coord::Coord::new($x1, $y1), $crate::coord::Coord::new($x2, $y2), $crate::coord::Coord::new($x3, $y3), $crate::coord::Coord::new($x4, $y4))
+            $( .$style_key($style_value) )*
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,4 +378,31 @@ mod tests {
         );
         let _t: Triangle<u8> = egtriangle!((10, 20), (30, 40), (50, 60), style = Style::default());
     }
+
+    #[test]
+    fn quadratic_bezier() {
+        let _b: QuadraticBezier<u8> = egquadratic_bezier!((10, 20), (30, 40), (50, 60));
+        let _b: QuadraticBezier<u8> = egquadratic_bezier!(
+            (10, 20),
+            (30, 40),
+            (50, 60),
+            stroke = Some(1u8),
+            tolerance = 2
+        );
+        let _b: QuadraticBezier<u8> =
+            egquadratic_bezier!((10, 20), (30, 40), (50, 60), style = Style::default());
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        let _b: CubicBezier<u8> = egcubic_bezier!((10, 20), (30, 40), (50, 60), (70, 80));
+        let _b: CubicBezier<u8> = egcubic_bezier!(
+            (10, 20),
+            (30, 40),
+            (50, 60),
+            (70, 80),
+            stroke = Some(1u8),
+            tolerance = 2
+        );
+    }
 }
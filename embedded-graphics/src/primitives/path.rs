@@ -0,0 +1,802 @@
+//! The path primitive
+//!
+//! A path is a list of [`PathCommand`](enum.PathCommand.html)s describing one or more subpaths made
+//! of straight lines and Bézier curves. Paths can be stroked, filled, or both, which makes them
+//! handy for drawing icons and other complex shapes.
+//!
+//! As this crate doesn't allocate, the commands are stored in a slice owned by the caller. The
+//! slice can either be filled at runtime using a [`PathBuilder`](struct.PathBuilder.html), or
+//! declared up front as an array for shapes that never change.
+//!
+//! The stroke of a path is always one pixel wide. When
+//! filling, each subpath is implicitly closed and a pixel is filled if its center lies inside the
+//! path according to the path's [`FillRule`](enum.FillRule.html).
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::bezier::{BezierPoints, DEFAULT_TOLERANCE};
+use super::edges::{edge_bounds, winding_number, EdgeStroke, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::thick::stroke_margin;
+use crate::primitives::{ContainsPoint, Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// A single drawing command in a path
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PathCommand {
+    /// Start a new subpath at the given point
+    MoveTo(Coord),
+
+    /// Draw a straight line to the given point
+    LineTo(Coord),
+
+    /// Draw a quadratic Bézier curve through a control point to an end point
+    QuadTo(Coord, Coord),
+
+    /// Draw a cubic Bézier curve through two control points to an end point
+    CurveTo(Coord, Coord, Coord),
+
+    /// Draw a straight line back to the start of the current subpath
+    Close,
+}
+
+/// Rule used to decide which parts of a self intersecting or nested path are inside it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// A point is inside the path if the path winds around it a non-zero number of times
+    #[default]
+    NonZero,
+
+    /// A point is inside the path if a ray from it crosses the path an odd number of times
+    EvenOdd,
+}
+
+impl FillRule {
+    pub(crate) fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Builder that records path commands into a caller provided buffer
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::path::{PathBuilder, PathCommand};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let mut buffer = [PathCommand::Close; 8];
+///
+/// // A rounded speech bubble
+/// let bubble = PathBuilder::new(&mut buffer)
+///     .move_to(Coord::new(4, 1))
+///     .line_to(Coord::new(16, 1))
+///     .quad_to(Coord::new(19, 1), Coord::new(19, 4))
+///     .line_to(Coord::new(19, 8))
+///     .quad_to(Coord::new(19, 11), Coord::new(16, 11))
+///     .line_to(Coord::new(6, 11))
+///     .line_to(Coord::new(2, 14))
+///     .close()
+///     .build()
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+///
+/// display.draw(bubble);
+/// ```
+#[derive(Debug)]
+pub struct PathBuilder<'a> {
+    commands: &'a mut [PathCommand],
+    len: usize,
+}
+
+impl<'a> PathBuilder<'a> {
+    /// Create a new builder that writes commands into `buffer`
+    pub fn new(buffer: &'a mut [PathCommand]) -> Self {
+        Self {
+            commands: buffer,
+            len: 0,
+        }
+    }
+
+    /// Append a command to the path
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is full.
+    pub fn command(mut self, command: PathCommand) -> Self {
+        assert!(self.len < self.commands.len(), "Path buffer is full");
+
+        self.commands[self.len] = command;
+        self.len += 1;
+
+        self
+    }
+
+    /// Start a new subpath at `point`
+    pub fn move_to(self, point: Coord) -> Self {
+        self.command(PathCommand::MoveTo(point))
+    }
+
+    /// Add a straight line from the current point to `point`
+    pub fn line_to(self, point: Coord) -> Self {
+        self.command(PathCommand::LineTo(point))
+    }
+
+    /// Add a quadratic Bézier curve from the current point to `end`
+    pub fn quad_to(self, control: Coord, end: Coord) -> Self {
+        self.command(PathCommand::QuadTo(control, end))
+    }
+
+    /// Add a cubic Bézier curve from the current point to `end`
+    pub fn curve_to(self, control1: Coord, control2: Coord, end: Coord) -> Self {
+        self.command(PathCommand::CurveTo(control1, control2, end))
+    }
+
+    /// Close the current subpath with a straight line back to its start
+    pub fn close(self) -> Self {
+        self.command(PathCommand::Close)
+    }
+
+    /// Finish building and return a path with the default style
+    pub fn build<C>(self) -> Path<'a, C>
+    where
+        C: PixelColor,
+    {
+        let commands: &'a [PathCommand] = self.commands;
+
+        Path::new(&commands[..self.len])
+    }
+}
+
+/// Path primitive
+///
+/// # Examples
+///
+/// ## Draw a filled arrow from a static list of commands
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::path::{Path, PathCommand};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let arrow = [
+///     PathCommand::MoveTo(Coord::new(2, 6)),
+///     PathCommand::LineTo(Coord::new(10, 6)),
+///     PathCommand::LineTo(Coord::new(10, 2)),
+///     PathCommand::LineTo(Coord::new(18, 8)),
+///     PathCommand::LineTo(Coord::new(10, 14)),
+///     PathCommand::LineTo(Coord::new(10, 10)),
+///     PathCommand::LineTo(Coord::new(2, 10)),
+///     PathCommand::Close,
+/// ];
+///
+/// let path = Path::new(&arrow).fill(Some(1u8));
+///
+/// display.draw(path);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Path<'a, C: PixelColor> {
+    /// Commands making up the path
+    pub commands: &'a [PathCommand],
+
+    /// Offset added to every point in the path
+    pub offset: Coord,
+
+    /// Rule used to decide which pixels are filled
+    pub fill_rule: FillRule,
+
+    /// Maximum distance between drawn and true curves in 1/16ths of a pixel
+    pub tolerance: u32,
+
+    /// Path style
+    pub style: Style<C>,
+}
+
+impl<'a, C> Path<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new path from a list of commands
+    pub fn new(commands: &'a [PathCommand]) -> Self {
+        Path {
+            commands,
+            offset: Coord::new(0, 0),
+            fill_rule: FillRule::default(),
+            tolerance: DEFAULT_TOLERANCE,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the rule used to decide which pixels are filled
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+
+        self
+    }
+
+    /// Set the flattening tolerance for curves in 1/16ths of a pixel
+    pub fn tolerance(mut self, tolerance: u32) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Iterate over the straight line segments the path is flattened into
+    fn segments(&self, close_subpaths: bool) -> Segments<'a> {
+        Segments {
+            commands: self.commands,
+            index: 0,
+            offset: self.offset,
+            tolerance: self.tolerance,
+            close_subpaths,
+            current: self.offset,
+            subpath_start: self.offset,
+            curve: None,
+        }
+    }
+
+    /// Bounding box of the flattened path, returned as `(top_left, bottom_right)`
+    fn bounds(&self) -> Option<(Coord, Coord)> {
+        edge_bounds(self.segments(true))
+    }
+
+    /// Fill color of the pixel at `point`, or `None` if it lies outside the path
+    fn fill_at(&self, point: Coord) -> Option<C> {
+        let winding = winding_number(self.segments(true), point[0], point[1]);
+
+        if self.fill_rule.is_inside(winding) {
+            self.style.fill_color_at(point)
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the straight line segments of a flattened path
+#[derive(Debug, Copy, Clone)]
+struct Segments<'a> {
+    commands: &'a [PathCommand],
+    index: usize,
+    offset: Coord,
+    tolerance: u32,
+    /// Whether open subpaths get a closing segment, as required for filling
+    close_subpaths: bool,
+    current: Coord,
+    subpath_start: Coord,
+    curve: Option<BezierPoints>,
+}
+
+impl<'a> Segments<'a> {
+    fn segment_to(&mut self, point: Coord) -> Option<(Coord, Coord)> {
+        let start = self.current;
+        self.current = point;
+
+        Some((start, point))
+    }
+
+    fn needs_closing(&self) -> bool {
+        self.close_subpaths && self.current != self.subpath_start
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = (Coord, Coord);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.curve.as_mut().and_then(Iterator::next) {
+                return self.segment_to(point);
+            }
+            self.curve = None;
+
+            let command = match self.commands.get(self.index) {
+                Some(command) => *command,
+                None if self.needs_closing() => return self.segment_to(self.subpath_start),
+                None => return None,
+            };
+
+            match command {
+                PathCommand::MoveTo(_) if self.needs_closing() => {
+                    // Close the previous subpath first, then come back to this command
+                    return self.segment_to(self.subpath_start);
+                }
+                PathCommand::MoveTo(point) => {
+                    self.current = point + self.offset;
+                    self.subpath_start = self.current;
+                }
+                PathCommand::LineTo(point) => {
+                    self.index += 1;
+
+                    return self.segment_to(point + self.offset);
+                }
+                PathCommand::QuadTo(control, end) => {
+                    let (control, end) = (control + self.offset, end + self.offset);
+                    let mut curve =
+                        BezierPoints::new([self.current, control, end, end], 2, self.tolerance);
+
+                    // Skip the start point, which is the current point
+                    curve.next();
+                    self.curve = Some(curve);
+                }
+                PathCommand::CurveTo(control1, control2, end) => {
+                    let points = [
+                        self.current,
+                        control1 + self.offset,
+                        control2 + self.offset,
+                        end + self.offset,
+                    ];
+                    let mut curve = BezierPoints::new(points, 3, self.tolerance);
+
+                    curve.next();
+                    self.curve = Some(curve);
+                }
+                PathCommand::Close => {
+                    self.index += 1;
+
+                    if self.current != self.subpath_start {
+                        return self.segment_to(self.subpath_start);
+                    }
+
+                    continue;
+                }
+            }
+
+            self.index += 1;
+        }
+    }
+}
+
+impl<'a, C> Primitive for Path<'a, C> where C: PixelColor {}
+
+impl<'a, C> Intersects<C> for Path<'a, C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
+impl<'a, C> ContainsPoint for Path<'a, C>
+where
+    C: PixelColor,
+{
+    /// Open subpaths are closed with a straight line, the same way they are when filled
+    fn contains(&self, point: Coord) -> bool {
+        self.fill_rule
+            .is_inside(winding_number(self.segments(true), point[0], point[1]))
+    }
+}
+
+impl<'a, C> Dimensions for Path<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().map_or(self.offset, |(tl, _)| tl)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().map_or(self.offset, |(_, br)| br)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<'a, C> WithStyle<C> for Path<'a, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<'a, C> Styled<C> for Path<'a, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for Path<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PathIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Path<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PathIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let fill_bounds = self.bounds().filter(|_| self.style.has_fill());
+
+        PathIterator {
+            path: *self,
+            fill: FillArea::new(fill_bounds),
+            stroke: EdgeStroke::new(self.segments(false), self.style),
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the path fill and stroke
+///
+/// The fill is drawn first, followed by the stroke. How the two share the pixels under the stroke
+/// depends on the style's [`StrokeOverlap`](../../style/enum.StrokeOverlap.html).
+#[derive(Debug, Clone, Copy)]
+pub struct PathIterator<'a, C>
+where
+    C: PixelColor,
+{
+    path: Path<'a, C>,
+    fill: FillArea,
+    stroke: EdgeStroke<C, Segments<'a>>,
+}
+
+impl<'a, C> PathIterator<'a, C>
+where
+    C: PixelColor,
+{
+    fn next_fill(&mut self) -> Option<Pixel<C>> {
+        let path = self.path;
+        let exclusive = path.style.stroke_overlap == StrokeOverlap::Exclusive;
+
+        for p in &mut self.fill {
+            if exclusive && self.stroke.covers(p) {
+                continue;
+            }
+
+            if let Some(color) = path.fill_at(p) {
+                return Some(Pixel(p.to_unsigned(), color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, C> Iterator for PathIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let path = self.path;
+        let exclusive = path.style.stroke_overlap == StrokeOverlap::Exclusive;
+
+        self.next_fill().or_else(|| {
+            self.stroke
+                .next_with_gaps(|p| path.fill_at(p).filter(|_| exclusive))
+        })
+    }
+}
+
+impl<'a, C> Clip for PathIterator<'a, C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.fill.clip(top_left, bottom_right);
+    }
+}
+
+impl<'a, C> Drawable for Path<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for Path<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the path by (x, y) pixels, returning a new `Path`. For a mutating transform, see
+    /// `translate_mut`.
+    ///
+    /// The commands themselves are borrowed and left untouched; the translation is stored in the
+    /// path's `offset` instead.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::path::{Path, PathCommand};
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let commands = [PathCommand::MoveTo(Coord::new(5, 10)), PathCommand::LineTo(Coord::new(15, 20))];
+    /// let path: Path<u8> = Path::new(&commands);
+    /// let moved = path.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left(), Coord::new(15, 20));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the path by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    fn square() -> [PathCommand; 5] {
+        [
+            PathCommand::MoveTo(Coord::new(1, 1)),
+            PathCommand::LineTo(Coord::new(5, 1)),
+            PathCommand::LineTo(Coord::new(5, 5)),
+            PathCommand::LineTo(Coord::new(1, 5)),
+            PathCommand::Close,
+        ]
+    }
+
+    #[test]
+    fn builder() {
+        let mut buffer = [PathCommand::Close; 4];
+        let path: Path<u8> = PathBuilder::new(&mut buffer)
+            .move_to(Coord::new(1, 2))
+            .line_to(Coord::new(3, 4))
+            .curve_to(Coord::new(5, 6), Coord::new(7, 8), Coord::new(9, 10))
+            .build();
+
+        assert_eq!(
+            path.commands,
+            &[
+                PathCommand::MoveTo(Coord::new(1, 2)),
+                PathCommand::LineTo(Coord::new(3, 4)),
+                PathCommand::CurveTo(Coord::new(5, 6), Coord::new(7, 8), Coord::new(9, 10)),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Path buffer is full")]
+    fn builder_overflow() {
+        let mut buffer = [PathCommand::Close; 1];
+
+        PathBuilder::new(&mut buffer)
+            .move_to(Coord::new(1, 2))
+            .line_to(Coord::new(3, 4));
+    }
+
+    #[test]
+    fn stroke_square() {
+        let mut display = Display::default();
+        display.draw(Path::new(&square()).stroke(Some(1u8)));
+
+        assert_eq!(display.count(1), 16);
+        assert_eq!(display.0[3][3], 0);
+    }
+
+    #[test]
+    fn fill_square() {
+        let mut display = Display::default();
+        display.draw(Path::new(&square()).fill(Some(2u8)));
+
+        assert_eq!(display.count(2), 16);
+        assert_eq!(display.0[1][1], 2);
+        assert_eq!(display.0[4][4], 2);
+        assert_eq!(display.0[5][5], 0);
+    }
+
+    #[test]
+    fn stroke_drawn_over_fill() {
+        let mut display = Display::default();
+        display.draw(Path::new(&square()).stroke(Some(1u8)).fill(Some(2u8)));
+
+        assert_eq!(display.count(1), 16);
+        assert_eq!(display.count(2), 9);
+    }
+
+    #[test]
+    fn fill_closes_subpaths() {
+        let open = [
+            PathCommand::MoveTo(Coord::new(1, 1)),
+            PathCommand::LineTo(Coord::new(5, 1)),
+            PathCommand::LineTo(Coord::new(5, 5)),
+            PathCommand::LineTo(Coord::new(1, 5)),
+        ];
+
+        let mut display = Display::default();
+        display.draw(Path::new(&open).fill(Some(2u8)));
+
+        assert_eq!(display.count(2), 16);
+    }
+
+    #[test]
+    fn fill_rules() {
+        // Two nested squares wound in the same direction
+        let mut buffer = [PathCommand::Close; 10];
+        let path: Path<u8> = PathBuilder::new(&mut buffer)
+            .move_to(Coord::new(0, 0))
+            .line_to(Coord::new(8, 0))
+            .line_to(Coord::new(8, 8))
+            .line_to(Coord::new(0, 8))
+            .close()
+            .move_to(Coord::new(2, 2))
+            .line_to(Coord::new(6, 2))
+            .line_to(Coord::new(6, 6))
+            .line_to(Coord::new(2, 6))
+            .close()
+            .build();
+
+        let mut non_zero = Display::default();
+        non_zero.draw(path.fill(Some(1u8)));
+
+        let mut even_odd = Display::default();
+        even_odd.draw(path.fill(Some(1u8)).fill_rule(FillRule::EvenOdd));
+
+        assert_eq!(non_zero.0[3][3], 1);
+        assert_eq!(even_odd.0[3][3], 0);
+        assert_eq!(even_odd.0[1][1], 1);
+        assert_eq!(non_zero.count(1), 64);
+        assert_eq!(even_odd.count(1), 48);
+    }
+
+    #[test]
+    fn curves() {
+        let mut buffer = [PathCommand::Close; 3];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Coord::new(1, 10))
+            .quad_to(Coord::new(10, -8), Coord::new(19, 10))
+            .close()
+            .build()
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(path);
+
+        assert_eq!(display.0[10][1], 1);
+        assert_eq!(display.0[10][19], 1);
+        assert_eq!(display.0[1][10], 1);
+        assert_eq!(display.0[5][10], 2);
+    }
+
+    #[test]
+    fn dimensions() {
+        let square = square();
+        let path: Path<u8> = Path::new(&square);
+
+        assert_eq!(path.top_left(), Coord::new(1, 1));
+        assert_eq!(path.bottom_right(), Coord::new(5, 5));
+        assert_eq!(path.size(), UnsignedCoord::new(4, 4));
+
+        let empty: Path<u8> = Path::new(&[]);
+
+        assert_eq!(empty.size(), UnsignedCoord::new(0, 0));
+    }
+
+    #[test]
+    fn translate() {
+        let square = square();
+        let path = Path::new(&square)
+            .fill(Some(2u8))
+            .translate(Coord::new(10, 2));
+
+        let mut display = Display::default();
+        display.draw(path);
+
+        assert_eq!(path.top_left(), Coord::new(11, 3));
+        assert_eq!(display.0[3][11], 2);
+        assert_eq!(display.count(2), 16);
+    }
+
+    #[test]
+    fn offscreen() {
+        let square = square();
+        let path = Path::new(&square)
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .translate(Coord::new(-3, -3));
+
+        let mut display = Display::default();
+        display.draw(path);
+
+        assert_eq!(display.count(2), 4);
+    }
+}
@@ -0,0 +1,571 @@
+//! The polygon primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{edge_bounds, winding_number, EdgeStroke, FillArea};
+use super::path::FillRule;
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::thick::stroke_margin;
+use crate::primitives::{ContainsPoint, Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Polygon primitive
+///
+/// A closed shape with any number of vertices, which may be concave. The last vertex is always
+/// joined back to the first one. A pixel is filled if its center lies inside the polygon according
+/// to the polygon's [`FillRule`](../path/enum.FillRule.html), which only makes a difference for
+/// self intersecting polygons.
+///
+/// # Examples
+///
+/// ## Draw a filled, concave arrow head
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Polygon;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let vertices = [
+///     Coord::new(2, 2),
+///     Coord::new(20, 8),
+///     Coord::new(2, 14),
+///     Coord::new(7, 8),
+/// ];
+///
+/// let arrow = Polygon::new(&vertices)
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+///
+/// display.draw(arrow);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Polygon<'a, C: PixelColor> {
+    /// Vertices of the polygon
+    pub vertices: &'a [Coord],
+
+    /// Offset added to every vertex
+    pub offset: Coord,
+
+    /// Rule used to decide which pixels are filled
+    pub fill_rule: FillRule,
+
+    /// Polygon style
+    pub style: Style<C>,
+}
+
+impl<'a, C> Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new polygon from a list of vertices
+    pub fn new(vertices: &'a [Coord]) -> Self {
+        Polygon {
+            vertices,
+            offset: Coord::new(0, 0),
+            fill_rule: FillRule::default(),
+            style: Style::default(),
+        }
+    }
+
+    /// Set the rule used to decide which pixels are filled
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+
+        self
+    }
+
+    fn edges(&self) -> PolygonEdges<'a> {
+        PolygonEdges {
+            vertices: self.vertices,
+            offset: self.offset,
+            index: 0,
+        }
+    }
+
+    /// Fill color of the pixel at `point`, or `None` if it lies outside the polygon
+    fn fill_at(&self, point: Coord) -> Option<C> {
+        if self.contains(point) {
+            self.style.fill_color_at(point)
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the edges of a polygon, including the closing edge
+#[derive(Debug, Copy, Clone)]
+struct PolygonEdges<'a> {
+    vertices: &'a [Coord],
+    offset: Coord,
+    index: usize,
+}
+
+impl Iterator for PolygonEdges<'_> {
+    type Item = (Coord, Coord);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.vertices.len();
+
+        if len < 2 || self.index >= len {
+            return None;
+        }
+
+        let start = self.vertices[self.index];
+        let end = self.vertices[(self.index + 1) % len];
+        self.index += 1;
+
+        Some((start + self.offset, end + self.offset))
+    }
+}
+
+impl<'a, C> Primitive for Polygon<'a, C> where C: PixelColor {}
+
+impl<'a, C> Intersects<C> for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
+impl<'a, C> ContainsPoint for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.fill_rule
+            .is_inside(winding_number(self.edges(), point[0], point[1]))
+    }
+}
+
+impl<'a, C> Dimensions for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        edge_bounds(self.edges()).map_or(self.offset, |(tl, _)| tl)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        edge_bounds(self.edges()).map_or(self.offset, |(_, br)| br)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<'a, C> WithStyle<C> for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<'a, C> Styled<C> for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolygonIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolygonIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let fill_bounds = edge_bounds(self.edges()).filter(|_| self.style.has_fill());
+
+        PolygonIterator {
+            polygon: *self,
+            fill: FillArea::new(fill_bounds),
+            stroke: EdgeStroke::new(self.edges(), self.style),
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the polygon fill and border
+///
+/// The fill is drawn first, followed by the border. How the two share the pixels under the border
+/// depends on the style's [`StrokeOverlap`](../../style/enum.StrokeOverlap.html).
+#[derive(Debug, Clone, Copy)]
+pub struct PolygonIterator<'a, C>
+where
+    C: PixelColor,
+{
+    polygon: Polygon<'a, C>,
+    fill: FillArea,
+    stroke: EdgeStroke<C, PolygonEdges<'a>>,
+}
+
+impl<'a, C> Iterator for PolygonIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let polygon = self.polygon;
+        let exclusive = polygon.style.stroke_overlap == StrokeOverlap::Exclusive;
+
+        for p in &mut self.fill {
+            if exclusive && self.stroke.covers(p) {
+                continue;
+            }
+
+            if let Some(color) = polygon.fill_at(p) {
+                return Some(Pixel(p.to_unsigned(), color));
+            }
+        }
+
+        self.stroke
+            .next_with_gaps(|p| polygon.fill_at(p).filter(|_| exclusive))
+    }
+}
+
+impl<'a, C> Clip for PolygonIterator<'a, C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.fill.clip(top_left, bottom_right);
+    }
+}
+
+impl<'a, C> Drawable for Polygon<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for Polygon<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the polygon by (x, y) pixels, returning a new `Polygon`. For a mutating
+    /// transform, see `translate_mut`.
+    ///
+    /// The vertices are borrowed and left untouched; the translation is stored in the polygon's
+    /// `offset` instead.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let vertices = [Coord::new(5, 10), Coord::new(15, 20), Coord::new(5, 20)];
+    /// let polygon: Polygon<u8> = Polygon::new(&vertices);
+    /// let moved = polygon.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left(), Coord::new(15, 20));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polygon by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::style::Hatch;
+    use crate::Drawing;
+
+    /// An L shaped, concave hexagon
+    fn l_shape() -> [Coord; 6] {
+        [
+            Coord::new(1, 1),
+            Coord::new(4, 1),
+            Coord::new(4, 6),
+            Coord::new(8, 6),
+            Coord::new(8, 9),
+            Coord::new(1, 9),
+        ]
+    }
+
+    #[test]
+    fn concave_fill() {
+        let vertices = l_shape();
+        let mut display = Display::default();
+        display.draw(Polygon::new(&vertices).fill(Some(1u8)));
+
+        // 3x8 vertical bar plus a 4x3 foot
+        assert_eq!(display.count(1), 3 * 8 + 4 * 3);
+        assert_eq!(display.0[2][2], 1);
+        assert_eq!(display.0[7][6], 1);
+        assert_eq!(display.0[2][6], 0);
+    }
+
+    #[test]
+    fn stroke() {
+        let vertices = l_shape();
+        let mut display = Display::default();
+        display.draw(Polygon::new(&vertices).stroke(Some(1u8)));
+
+        // Perimeter of the L shape
+        assert_eq!(display.count(1), 3 + 5 + 4 + 3 + 7 + 8);
+        assert_eq!(display.0[3][2], 0);
+    }
+
+    #[test]
+    fn stroke_drawn_over_fill() {
+        let vertices = l_shape();
+        let mut display = Display::default();
+        display.draw(Polygon::new(&vertices).stroke(Some(1u8)).fill(Some(2u8)));
+
+        assert_eq!(display.count(1), 30);
+        assert_eq!(display.0[2][2], 2);
+    }
+
+    #[test]
+    fn stroke_passes_through_vertices() {
+        let vertices = [Coord::new(2, 2), Coord::new(12, 4), Coord::new(5, 14)];
+
+        let mut display = Display::default();
+        display.draw(Polygon::new(&vertices).stroke(Some(1u8)));
+
+        for v in vertices.iter() {
+            assert_eq!(display.0[v[1] as usize][v[0] as usize], 1);
+        }
+    }
+
+    #[test]
+    fn fill_rules() {
+        // Pentagram, whose center is wound around twice
+        let vertices = [
+            Coord::new(10, 0),
+            Coord::new(16, 19),
+            Coord::new(0, 7),
+            Coord::new(20, 7),
+            Coord::new(4, 19),
+        ];
+
+        let mut non_zero = Display::default();
+        non_zero.draw(Polygon::new(&vertices).fill(Some(1u8)));
+
+        let mut even_odd = Display::default();
+        even_odd.draw(
+            Polygon::new(&vertices)
+                .fill(Some(1u8))
+                .fill_rule(FillRule::EvenOdd),
+        );
+
+        assert_eq!(non_zero.0[10][10], 1);
+        assert_eq!(even_odd.0[10][10], 0);
+        assert_eq!(even_odd.0[3][10], 1);
+    }
+
+    #[test]
+    fn degenerate() {
+        let vertices = [Coord::new(5, 5)];
+
+        let polygon: Polygon<u8> = Polygon::new(&vertices).stroke(Some(1)).fill(Some(2));
+
+        assert_eq!(polygon.into_iter().count(), 0);
+        assert_eq!(Polygon::<u8>::new(&[]).into_iter().count(), 0);
+    }
+
+    #[test]
+    fn dimensions() {
+        let vertices = l_shape();
+        let polygon: Polygon<u8> = Polygon::new(&vertices);
+
+        assert_eq!(polygon.top_left(), Coord::new(1, 1));
+        assert_eq!(polygon.bottom_right(), Coord::new(8, 9));
+        assert_eq!(polygon.size(), UnsignedCoord::new(7, 8));
+    }
+
+    #[test]
+    fn translate() {
+        let vertices = l_shape();
+        let polygon = Polygon::new(&vertices)
+            .fill(Some(1u8))
+            .translate(Coord::new(-2, 3));
+
+        let mut display = Display::default();
+        display.draw(polygon);
+
+        assert_eq!(polygon.top_left(), Coord::new(-1, 4));
+        assert_eq!(display.0[5][0], 1);
+        assert_eq!(display.0[4][0], 1);
+        assert_eq!(display.0[3][0], 0);
+    }
+
+    #[test]
+    fn gradient_fill() {
+        let vertices = l_shape();
+        let mut display = Display::default();
+        display.draw(Polygon::new(&vertices).fill_gradient(Some(Gradient::linear(
+            Coord::new(0, 1),
+            Coord::new(0, 9),
+            8,
+            16,
+        ))));
+
+        assert_eq!(display.0[1][2], 8);
+        assert_eq!(display.0[5][2], 12);
+        assert_eq!(display.0[8][6], 15);
+        assert_eq!(display.0[2][6], 0);
+    }
+
+    #[test]
+    fn pattern_fill_drawn_before_stroke() {
+        let vertices = l_shape();
+        let polygon = Polygon::new(&vertices)
+            .stroke(Some(1u8))
+            .stroke_overlap(StrokeOverlap::OverFill)
+            .fill_pattern(Some(FillPattern::hatch(Hatch::Checkerboard, 2)));
+
+        // Transparent pixels in the pattern don't interleave stroke pixels with the fill
+        let fill = polygon.into_iter().take_while(|p| p.1 == 2).count();
+        assert_eq!(
+            polygon.into_iter().skip(fill).filter(|p| p.1 == 2).count(),
+            0
+        );
+        assert_eq!(fill, 18);
+    }
+
+    #[test]
+    fn each_pixel_drawn_once() {
+        // Self intersecting, so the stroke crosses itself and the closing edge meets the first one
+        let vertices = [
+            Coord::new(2, 2),
+            Coord::new(20, 12),
+            Coord::new(20, 2),
+            Coord::new(2, 12),
+        ];
+        let polygon = Polygon::new(&vertices)
+            .stroke(Some(1u8))
+            .stroke_dash(Some(DashPattern::new(&[3, 2])))
+            .fill(Some(2u8));
+
+        let mut times_drawn = Display::default();
+        for Pixel(p, _) in polygon {
+            times_drawn.0[p[1] as usize][p[0] as usize] += 1;
+        }
+        assert_eq!(times_drawn.0.iter().flatten().max(), Some(&1));
+
+        // Drawing the fill under the stroke gives the same image, with the stroke pixels drawn
+        // twice
+        let over_fill = polygon.stroke_overlap(StrokeOverlap::OverFill);
+        let mut times_drawn = Display::default();
+        for Pixel(p, _) in over_fill {
+            times_drawn.0[p[1] as usize][p[0] as usize] += 1;
+        }
+        assert_eq!(times_drawn.0.iter().flatten().max(), Some(&2));
+
+        let mut exclusive_display = Display::default();
+        exclusive_display.draw(polygon);
+        let mut over_fill_display = Display::default();
+        over_fill_display.draw(over_fill);
+        assert_eq!(exclusive_display, over_fill_display);
+    }
+}
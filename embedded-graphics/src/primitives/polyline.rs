@@ -0,0 +1,688 @@
+//! The polyline primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{edge_bounds, EdgeStroke, PointPairs};
+use super::thick::{stroke_margin, ThickStrokePixels};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+use core::slice;
+
+/// Polyline primitive
+///
+/// A series of connected straight lines through a list of vertices. Unlike a
+/// [`Polygon`](../polygon/struct.Polygon.html), the last vertex isn't joined back to the first.
+///
+/// Polylines can be stroked with any width. The corners of wide strokes are shaped by the style's
+/// [`line_join`](../../style/struct.Style.html#structfield.line_join), and the two open ends by its
+/// [`stroke_cap`](../../style/struct.Style.html#structfield.stroke_cap). Dash patterns are only
+/// applied to strokes one pixel wide.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Polyline;
+/// use embedded_graphics::style::{LineJoin, StrokeCap};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let vertices = [
+///     Coord::new(2, 12),
+///     Coord::new(8, 4),
+///     Coord::new(14, 10),
+///     Coord::new(20, 3),
+/// ];
+///
+/// let chart = Polyline::new(&vertices)
+///     .stroke(Some(1u8))
+///     .stroke_width(3)
+///     .line_join(LineJoin::Round)
+///     .stroke_cap(StrokeCap::Round);
+///
+/// display.draw(chart);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Polyline<'a, C: PixelColor> {
+    /// Vertices of the polyline
+    pub vertices: &'a [Coord],
+
+    /// Offset added to every vertex
+    pub offset: Coord,
+
+    /// Polyline style
+    pub style: Style<C>,
+}
+
+impl<'a, C> Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new polyline through a list of vertices
+    pub fn new(vertices: &'a [Coord]) -> Self {
+        Polyline {
+            vertices,
+            offset: Coord::new(0, 0),
+            style: Style::default(),
+        }
+    }
+
+    fn points(&self) -> Vertices<'a> {
+        Vertices {
+            vertices: self.vertices.iter(),
+            offset: self.offset,
+        }
+    }
+}
+
+/// Iterator over the vertices of a polyline with its offset applied
+#[derive(Debug, Clone)]
+struct Vertices<'a> {
+    vertices: slice::Iter<'a, Coord>,
+    offset: Coord,
+}
+
+impl Iterator for Vertices<'_> {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.vertices.next().map(|v| *v + self.offset)
+    }
+}
+
+impl<'a, C> Primitive for Polyline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Intersects<C> for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
+impl<'a, C> Dimensions for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        edge_bounds(PointPairs::new(self.points())).map_or(self.offset, |(tl, _)| tl)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        edge_bounds(PointPairs::new(self.points())).map_or(self.offset, |(_, br)| br)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<'a, C> WithStyle<C> for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<'a, C> Styled<C> for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolylineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolylineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let width = self.style.stroke_width;
+
+        PolylineIterator {
+            thin: if width == 1 {
+                Some(EdgeStroke::new(PointPairs::new(self.points()), self.style))
+            } else {
+                None
+            },
+            thick: if width > 1 {
+                Some(ThickStrokePixels::new(self.points(), &self.style))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the polyline
+#[derive(Debug, Clone)]
+pub struct PolylineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    /// Bresenham stroke for polylines one pixel wide
+    thin: Option<EdgeStroke<C, PointPairs<Vertices<'a>>>>,
+    /// Rasterizer for wider polylines
+    thick: Option<ThickStrokePixels<C, Vertices<'a>>>,
+}
+
+impl<'a, C> Iterator for PolylineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(thin) = &mut self.thin {
+            thin.next()
+        } else if let Some(thick) = &mut self.thick {
+            thick.next()
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C> Drawable for Polyline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for Polyline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the polyline by (x, y) pixels, returning a new `Polyline`. For a mutating
+    /// transform, see `translate_mut`.
+    ///
+    /// The vertices are borrowed and left untouched; the translation is stored in the polyline's
+    /// `offset` instead.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polyline;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let vertices = [Coord::new(5, 10), Coord::new(15, 20), Coord::new(25, 10)];
+    /// let polyline: Polyline<u8> = Polyline::new(&vertices);
+    /// let moved = polyline.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left(), Coord::new(15, 20));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polyline by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+/// Remove vertices that barely change the shape of a polyline, in place
+///
+/// Plotting hundreds of samples on a small display wastes time drawing segments shorter than a
+/// pixel. This uses a variant of the
+/// [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm)
+/// that needs no extra memory: every removed vertex lies within `epsilon` pixels of the segment
+/// between the two kept vertices on either side of it. The first and last vertices are always kept.
+///
+/// The kept vertices are moved to the front of `points`, in their original order, and returned as
+/// a slice ready to pass to [`Polyline::new`](./struct.Polyline.html#method.new). The rest of
+/// `points` is left in an unspecified order. Simplifying takes `O(n²)` time in the worst case,
+/// but close to `O(n)` for smooth data.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::polyline::simplify;
+/// use embedded_graphics::primitives::Polyline;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // A flat but noisy signal, sampled every pixel
+/// let mut samples = [Coord::new(0, 0); 24];
+/// for (x, sample) in samples.iter_mut().enumerate() {
+///     let x = x as i32;
+///     *sample = Coord::new(x, 8 + x % 2);
+/// }
+///
+/// let vertices = simplify(&mut samples, 1);
+/// assert_eq!(vertices.len(), 2);
+///
+/// display.draw(Polyline::new(vertices).stroke(Some(1u8)));
+/// ```
+pub fn simplify(points: &mut [Coord], epsilon: u32) -> &[Coord] {
+    let len = points.len();
+
+    if len < 3 {
+        return points;
+    }
+
+    let epsilon = i64::from(epsilon);
+    let mut anchor = 0;
+    let mut kept = 1;
+
+    while anchor < len - 1 {
+        let mut end = len - 1;
+
+        // Move the end back to the furthest vertex until every vertex in between is close enough
+        while let Some(furthest) = furthest_outside(points, anchor, end, epsilon) {
+            end = furthest;
+        }
+
+        points[kept] = points[end];
+        kept += 1;
+        anchor = end;
+    }
+
+    &points[..kept]
+}
+
+/// Index of the vertex between `start` and `end` furthest from the segment joining them, if it's
+/// more than `epsilon` pixels away
+fn furthest_outside(points: &[Coord], start: usize, end: usize, epsilon: i64) -> Option<usize> {
+    let segment = Segment::new(points[start], points[end]);
+    let threshold = i128::from(epsilon * epsilon) * segment.scale;
+
+    points[start + 1..end]
+        .iter()
+        .map(|&p| segment.distance_sq(p))
+        .enumerate()
+        .filter(|&(_, distance)| distance > threshold)
+        .max_by_key(|&(_, distance)| distance)
+        .map(|(index, _)| start + 1 + index)
+}
+
+/// Distance test between points and a line segment
+struct Segment {
+    start: Coord,
+    delta: (i64, i64),
+    length_sq: i64,
+    /// Factor all squared distances are multiplied by, so they stay integers
+    scale: i128,
+}
+
+impl Segment {
+    fn new(start: Coord, end: Coord) -> Self {
+        let delta = (
+            i64::from(end[0]) - i64::from(start[0]),
+            i64::from(end[1]) - i64::from(start[1]),
+        );
+        let length_sq = delta.0 * delta.0 + delta.1 * delta.1;
+
+        Self {
+            start,
+            delta,
+            length_sq,
+            scale: i128::from(length_sq.max(1)),
+        }
+    }
+
+    /// Squared distance from `point` to the closest point on the segment, times `scale`
+    fn distance_sq(&self, point: Coord) -> i128 {
+        let (dx, dy) = self.delta;
+        let px = i64::from(point[0]) - i64::from(self.start[0]);
+        let py = i64::from(point[1]) - i64::from(self.start[1]);
+        let along = px * dx + py * dy;
+
+        if along > 0 && along < self.length_sq {
+            let cross = i128::from(px * dy - py * dx);
+
+            return cross * cross;
+        }
+
+        let (qx, qy) = if along <= 0 {
+            (px, py)
+        } else {
+            (px - dx, py - dy)
+        };
+
+        i128::from(qx * qx + qy * qy) * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    /// A right angle turning from rightwards to downwards at (10, 4)
+    fn corner() -> [Coord; 3] {
+        [Coord::new(2, 4), Coord::new(10, 4), Coord::new(10, 12)]
+    }
+
+    fn draw(style: Style<u8>) -> Display {
+        let vertices = corner();
+        let mut display = Display::default();
+        display.draw(Polyline::new(&vertices).style(style));
+
+        display
+    }
+
+    #[test]
+    fn thin_stroke() {
+        let display = draw(Style::stroke(1));
+
+        assert_eq!(display.count(1), 9 + 8);
+        assert_eq!(display.0[4][10], 1);
+    }
+
+    #[test]
+    fn zero_width() {
+        let vertices = corner();
+        let polyline = Polyline::new(&vertices).stroke(Some(1u8)).stroke_width(0);
+
+        assert_eq!(polyline.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn thick_butt() {
+        let display = draw(Style {
+            stroke_width: 3,
+            ..Style::stroke(1)
+        });
+
+        // Horizontal arm spans rows 3 to 5 and stops at the start point
+        assert_eq!(display.0[3][2], 1);
+        assert_eq!(display.0[5][2], 1);
+        assert_eq!(display.0[4][1], 0);
+        assert_eq!(display.0[2][5], 0);
+
+        // Vertical arm spans columns 9 to 11 and stops at the end point
+        assert_eq!(display.0[12][9], 1);
+        assert_eq!(display.0[12][11], 1);
+        assert_eq!(display.0[13][10], 0);
+    }
+
+    #[test]
+    fn joins() {
+        let miter = draw(Style {
+            stroke_width: 5,
+            line_join: LineJoin::Miter,
+            ..Style::stroke(1)
+        });
+        let bevel = draw(Style {
+            stroke_width: 5,
+            line_join: LineJoin::Bevel,
+            ..Style::stroke(1)
+        });
+        let round = draw(Style {
+            stroke_width: 5,
+            line_join: LineJoin::Round,
+            ..Style::stroke(1)
+        });
+
+        // The outermost corner is only filled by a miter
+        assert_eq!(miter.0[2][12], 1);
+        assert_eq!(bevel.0[2][12], 0);
+        assert_eq!(round.0[2][12], 0);
+
+        // A round join bulges further out than a bevel
+        assert_eq!(round.0[3][12], 1);
+        assert_eq!(bevel.0[3][12], 0);
+
+        // No gaps where the arms meet
+        for display in [&miter, &bevel, &round].iter() {
+            assert_eq!(display.0[3][11], 1);
+            assert_eq!(display.0[2][10], 1);
+            assert_eq!(display.0[4][12], 1);
+        }
+    }
+
+    #[test]
+    fn sharp_miter_falls_back_to_bevel() {
+        let vertices = [Coord::new(2, 2), Coord::new(20, 4), Coord::new(2, 6)];
+        let style = Style {
+            stroke_width: 3,
+            ..Style::stroke(1u8)
+        };
+
+        let mut miter = Display::default();
+        miter.draw(Polyline::new(&vertices).style(style));
+
+        let mut bevel = Display::default();
+        bevel.draw(
+            Polyline::new(&vertices)
+                .style(style)
+                .line_join(LineJoin::Bevel),
+        );
+
+        assert_eq!(miter, bevel);
+    }
+
+    #[test]
+    fn caps() {
+        let square = draw(Style {
+            stroke_width: 3,
+            stroke_cap: StrokeCap::Square,
+            ..Style::stroke(1)
+        });
+        let round = draw(Style {
+            stroke_width: 5,
+            stroke_cap: StrokeCap::Round,
+            ..Style::stroke(1)
+        });
+
+        // Square caps extend by half the width, one pixel here
+        assert_eq!(square.0[3][1], 1);
+        assert_eq!(square.0[5][1], 1);
+        assert_eq!(square.0[4][0], 0);
+        assert_eq!(square.0[13][9], 1);
+
+        // Round caps extend by a half circle, two pixels here
+        assert_eq!(round.0[4][0], 1);
+        assert_eq!(round.0[2][0], 0);
+    }
+
+    #[test]
+    fn even_width() {
+        let display = draw(Style {
+            stroke_width: 2,
+            ..Style::stroke(1)
+        });
+
+        assert_eq!(display.0[3][5], 1);
+        assert_eq!(display.0[4][5], 1);
+        assert_eq!(display.0[5][5], 0);
+    }
+
+    #[test]
+    fn dimensions() {
+        let vertices = corner();
+        let polyline: Polyline<u8> = Polyline::new(&vertices);
+
+        assert_eq!(polyline.top_left(), Coord::new(2, 4));
+        assert_eq!(polyline.bottom_right(), Coord::new(10, 12));
+        assert_eq!(polyline.size(), UnsignedCoord::new(8, 8));
+    }
+
+    #[test]
+    fn simplify_straight_line() {
+        let mut points = [Coord::new(0, 0); 10];
+        for (i, point) in points.iter_mut().enumerate() {
+            *point = Coord::new(i as i32 * 2, i as i32);
+        }
+
+        assert_eq!(
+            simplify(&mut points, 0),
+            &[Coord::new(0, 0), Coord::new(18, 9)]
+        );
+    }
+
+    #[test]
+    fn simplify_keeps_corners() {
+        let mut points = [
+            Coord::new(0, 0),
+            Coord::new(5, 1),
+            Coord::new(10, 0),
+            Coord::new(10, 5),
+            Coord::new(11, 10),
+            Coord::new(10, 15),
+        ];
+
+        assert_eq!(
+            simplify(&mut points, 1),
+            &[Coord::new(0, 0), Coord::new(10, 0), Coord::new(10, 15)]
+        );
+    }
+
+    #[test]
+    fn simplify_within_epsilon() {
+        let original = [
+            Coord::new(0, 5),
+            Coord::new(3, 3),
+            Coord::new(6, 8),
+            Coord::new(9, 4),
+            Coord::new(12, 6),
+            Coord::new(15, 2),
+            Coord::new(18, 7),
+            Coord::new(21, 5),
+        ];
+
+        for epsilon in 0..6 {
+            let mut points = original;
+            let kept = simplify(&mut points, epsilon);
+
+            assert_eq!(kept.first(), original.first());
+            assert_eq!(kept.last(), original.last());
+
+            // Every removed point is close to the kept segment around it
+            for pair in kept.windows(2) {
+                let start = original.iter().position(|p| *p == pair[0]).unwrap();
+                let end = original.iter().position(|p| *p == pair[1]).unwrap();
+                let segment = Segment::new(pair[0], pair[1]);
+
+                for &p in original[start + 1..end].iter() {
+                    let limit = i128::from(epsilon * epsilon) * segment.scale;
+                    assert!(segment.distance_sq(p) <= limit);
+                }
+            }
+        }
+
+        let mut points = original;
+        assert_eq!(simplify(&mut points, 0).len(), original.len());
+    }
+
+    #[test]
+    fn simplify_short_and_closed() {
+        let mut empty: [Coord; 0] = [];
+        assert!(simplify(&mut empty, 1).is_empty());
+
+        let mut pair = [Coord::new(1, 1), Coord::new(1, 1)];
+        assert_eq!(simplify(&mut pair, 1).len(), 2);
+
+        // The segment from the first to the last vertex of a closed loop is a single point
+        let mut square = [
+            Coord::new(0, 0),
+            Coord::new(8, 0),
+            Coord::new(8, 8),
+            Coord::new(0, 8),
+            Coord::new(0, 0),
+        ];
+        assert_eq!(simplify(&mut square, 1).len(), 5);
+    }
+}
@@ -2,11 +2,15 @@
 
 use super::super::drawable::*;
 use super::super::transform::*;
+use super::edges::{is_stroke_pixel, styled_pixel};
 use crate::coord::{Coord, ToUnsigned};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
-use crate::style::Style;
+use crate::primitives::{ContainsPoint, Intersects, Offset, Primitive, StrokeContainsPoint};
 use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
 use crate::unsignedcoord::UnsignedCoord;
 
 /// Rectangle primitive
@@ -40,6 +44,25 @@ use crate::unsignedcoord::UnsignedCoord;
 /// display.draw(r2);
 /// display.draw(r3);
 /// ```
+///
+/// ## Draw a viewfinder with corner brackets
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Rectangle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Only the 4 pixels of stroke closest to each corner are drawn
+/// let viewfinder = Rectangle::new(Coord::new(2, 2), Coord::new(21, 13))
+///     .stroke(Some(1u8))
+///     .stroke_width(2)
+///     .stroke_corners(Some(4));
+///
+/// display.draw(viewfinder);
+/// # assert_eq!(display.0[2][5], 1);
+/// # assert_eq!(display.0[2][6], 0);
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Rectangle<C: PixelColor> {
     /// Top left point of the rect
@@ -52,8 +75,119 @@ pub struct Rectangle<C: PixelColor> {
     pub style: Style<C>,
 }
 
+impl<C> Rectangle<C>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the box from `top_left` to `bottom_right`, inclusive, shares a pixel with
+    /// this rectangle
+    pub(crate) fn overlaps(&self, top_left: Coord, bottom_right: Coord) -> bool {
+        let (tl, br) = (self.top_left, self.bottom_right);
+        let overlap = |a: i32, b: i32, c: i32, d: i32| a.min(b) <= c.max(d) && c.min(d) <= a.max(b);
+
+        overlap(tl[0], br[0], top_left[0], bottom_right[0])
+            && overlap(tl[1], br[1], top_left[1], bottom_right[1])
+    }
+}
+
+/// Returns `true` if `point` is within `length` pixels of a corner both horizontally and
+/// vertically, or if there's no `length`
+fn in_corner_marks(
+    top_left: Coord,
+    bottom_right: Coord,
+    length: Option<u32>,
+    point: Coord,
+) -> bool {
+    let length = match length {
+        Some(length) => i64::from(length),
+        None => return true,
+    };
+    let near = |value: i32, a: i32, b: i32| {
+        let (value, a, b) = (i64::from(value), i64::from(a), i64::from(b));
+
+        (value - a.min(b)).min(a.max(b) - value) < length
+    };
+
+    near(point[0], top_left[0], bottom_right[0]) && near(point[1], top_left[1], bottom_right[1])
+}
+
 impl<C> Primitive for Rectangle<C> where C: PixelColor {}
 
+impl<C> Intersects<C> for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> ContainsPoint for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        let (tl, br) = (self.top_left, self.bottom_right);
+
+        point[0] >= tl[0].min(br[0])
+            && point[0] <= tl[0].max(br[0])
+            && point[1] >= tl[1].min(br[1])
+            && point[1] <= tl[1].max(br[1])
+    }
+}
+
+impl<C> StrokeContainsPoint for Rectangle<C>
+where
+    C: PixelColor,
+{
+    /// The stroke is always drawn inside the outline, whatever the stroke alignment. Pixels left
+    /// out between corner marks aren't part of the stroke.
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let (tl, br, corners) = (self.top_left, self.bottom_right, self.style.stroke_corners);
+
+        is_stroke_pixel(|p| self.contains(p), &self.style, point, 0)
+            && in_corner_marks(tl, br, corners, point)
+    }
+}
+
+impl<C> Offset for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn offset(&self, distance: i32) -> Self {
+        let (top_left, bottom_right) = offset_corners(self.top_left, self.bottom_right, distance);
+
+        Self {
+            top_left,
+            bottom_right,
+            ..*self
+        }
+    }
+}
+
+/// Move the corners of a box apart by `distance` on each side, or together for negative
+/// distances, meeting in the middle if they would cross
+pub(crate) fn offset_corners(
+    top_left: Coord,
+    bottom_right: Coord,
+    distance: i32,
+) -> (Coord, Coord) {
+    let offset = |min: i32, max: i32| {
+        if max - min + 2 * distance >= 0 {
+            (min - distance, max + distance)
+        } else {
+            let middle = min + (max - min) / 2;
+
+            (middle, middle)
+        }
+    };
+
+    let (left, right) = offset(top_left[0], bottom_right[0]);
+    let (top, bottom) = offset(top_left[1], bottom_right[1]);
+
+    (Coord::new(left, top), Coord::new(right, bottom))
+}
+
 impl<C> Dimensions for Rectangle<C>
 where
     C: PixelColor,
@@ -107,11 +241,74 @@ where
         self
     }
 
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
     fn fill(mut self, color: Option<C>) -> Self {
         self.style.fill_color = color;
 
         self
     }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Rectangle<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
 }
 
 impl<C> IntoIterator for Rectangle<C>
@@ -140,6 +337,10 @@ where
             style: self.style,
             x: self.top_left[0],
             y: self.top_left[1],
+            left: self.top_left[0],
+            right: self.bottom_right[0],
+            bottom: self.bottom_right[1],
+            pending: None,
         }
     }
 }
@@ -155,6 +356,47 @@ where
     style: Style<C>,
     x: i32,
     y: i32,
+
+    /// Columns and last row still to visit, narrowed by clipping
+    left: i32,
+    right: i32,
+    bottom: i32,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> RectangleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Get the distance around the border, clockwise from the top left corner, of the current
+    /// pixel or `None` if it isn't part of the border
+    fn border_position(&self) -> Option<u32> {
+        let border_width = self.style.stroke_width as i32;
+        let tl = self.top_left;
+        let br = self.bottom_right;
+        let width = br[0] - tl[0];
+        let height = br[1] - tl[1];
+
+        let position = if self.y >= tl[1] && self.y < tl[1] + border_width {
+            // Top border
+            self.x - tl[0]
+        } else if self.x <= br[0] && self.x > br[0] - border_width {
+            // Right border
+            width + self.y - tl[1]
+        } else if self.y <= br[1] && self.y > br[1] - border_width {
+            // Bottom border
+            width + height + br[0] - self.x
+        } else if self.x >= tl[0] && self.x < tl[0] + border_width {
+            // Left border
+            2 * width + height + br[1] - self.y
+        } else {
+            return None;
+        };
+
+        Some(position.max(0) as u32)
+    }
 }
 
 impl<C> Iterator for RectangleIterator<C>
@@ -164,11 +406,15 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
         // If entire object is off the top left of the screen or has no border or fill colour,
         // don't render anything
         if (self.top_left[0] < 0 || self.top_left[1] < 0)
             && (self.bottom_right[0] < 0 || self.bottom_right[1] < 0)
-            || (self.style.stroke_color.is_none() && self.style.fill_color.is_none())
+            || (self.style.stroke_color.is_none() && !self.style.has_fill())
         {
             return None;
         }
@@ -177,44 +423,35 @@ where
             let mut out = None;
 
             // Finished, i.e. we're below the rect
-            if self.y > self.bottom_right[1] {
+            if self.y > self.bottom || self.left > self.right {
                 break None;
             }
 
-            let border_width = self.style.stroke_width as i32;
-            let tl = self.top_left;
-            let br = self.bottom_right;
-
             if self.x >= 0 && self.y >= 0 {
-                // Border
-                if (
-                    // Top border
-                    (self.y >= tl[1] && self.y < tl[1] + border_width)
-                // Bottom border
-                || (self.y <= br[1] && self.y > br[1] - border_width)
-                // Left border
-                || (self.x >= tl[0] && self.x < tl[0] + border_width)
-                // Right border
-                || (self.x <= br[0] && self.x > br[0] - border_width)
-                ) && self.style.stroke_color.is_some()
-                {
-                    out = Some((
-                        self.x,
-                        self.y,
-                        self.style.stroke_color.expect("Expected stroke"),
-                    ));
-                }
-                // Fill
-                else if let Some(fill) = self.style.fill_color {
-                    out = Some((self.x, self.y, fill));
-                }
+                let point = Coord::new(self.x, self.y);
+                let is_border = self.border_position().is_some_and(|position| {
+                    self.style.stroke_dash.is_none_or(|p| p.is_dash(position))
+                }) && in_corner_marks(
+                    self.top_left,
+                    self.bottom_right,
+                    self.style.stroke_corners,
+                    point,
+                );
+
+                // Border, then fill
+                out = styled_pixel(
+                    &self.style,
+                    point,
+                    self.style.stroke_color.filter(|_| is_border),
+                    true,
+                );
             }
 
             self.x += 1;
 
             // Reached end of row? Jump down one line
-            if self.x > self.bottom_right[0] {
-                self.x = self.top_left[0];
+            if self.x > self.right {
+                self.x = self.left;
                 self.y += 1;
             }
 
@@ -223,7 +460,23 @@ where
             }
         };
 
-        pixel.map(|(x, y, c)| Pixel(Coord::new(x, y).to_unsigned(), c))
+        let (pixel, over) = pixel?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for RectangleIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.left = self.top_left[0].max(top_left[0]);
+        self.right = self.bottom_right[0].min(bottom_right[0]);
+        self.bottom = self.bottom_right[1].min(bottom_right[1]);
+        self.x = self.left;
+        self.y = self.top_left[1].max(top_left[1]);
     }
 }
 
@@ -283,7 +536,64 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::Display;
+    use crate::style::Hatch;
     use crate::unsignedcoord::UnsignedCoord;
+    use crate::Drawing;
+
+    #[test]
+    fn corner_marks() {
+        let rect = Rectangle::new(Coord::new(2, 1), Coord::new(12, 9))
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .stroke_corners(Some(3));
+
+        let mut display = Display::default();
+        display.draw(rect);
+
+        // Three pixels along each side from all four corners, sharing the corner pixels
+        assert_eq!(display.count(1), 4 * 5);
+        assert_eq!(display.0[1][4], 1);
+        assert_eq!(display.0[7][12], 1);
+
+        // The gaps are filled
+        assert_eq!(display.0[1][5], 2);
+        assert_eq!(display.0[6][12], 2);
+
+        for (y, row) in display.0.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                let point = Coord::new(x as i32, y as i32);
+
+                assert_eq!(rect.stroke_contains(point), pixel == 1);
+            }
+        }
+    }
+
+    #[test]
+    fn intersects() {
+        let area = Rectangle::new(Coord::new(10, 10), Coord::new(5, 5));
+        let rect = |tl, br| Rectangle::<u8>::new(tl, br).stroke(Some(1));
+
+        assert!(rect(Coord::new(0, 0), Coord::new(5, 5)).intersects(&area));
+        assert!(rect(Coord::new(12, 7), Coord::new(10, 20)).intersects(&area));
+        assert!(!rect(Coord::new(0, 0), Coord::new(4, 20)).intersects(&area));
+        assert!(!rect(Coord::new(6, 11), Coord::new(9, 20)).intersects(&area));
+    }
+
+    #[test]
+    fn stroke_ignores_alignment() {
+        let rect = Rectangle::new(Coord::new(2, 2), Coord::new(12, 10))
+            .stroke(Some(1u8))
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside);
+
+        assert!(rect.stroke_contains(Coord::new(2, 5)));
+        assert!(rect.stroke_contains(Coord::new(11, 5)));
+        assert!(!rect.stroke_contains(Coord::new(4, 5)));
+        assert!(!rect.stroke_contains(Coord::new(1, 5)));
+        assert!(rect.contains_styled(Coord::new(6, 6)));
+        assert!(!rect.contains_styled(Coord::new(13, 6)));
+    }
 
     #[test]
     fn dimensions() {
@@ -340,4 +650,86 @@ mod tests {
         assert_eq!(rect.next(), Some(Pixel(UnsignedCoord::new(1, 2), 1.into())));
         assert_eq!(rect.next(), Some(Pixel(UnsignedCoord::new(2, 2), 1.into())));
     }
+
+    #[test]
+    fn dashed_border_shows_fill_in_gaps() {
+        let mut rect = Rectangle::new(Coord::new(0, 0), Coord::new(4, 2))
+            .stroke(Some(1u8))
+            .fill(Some(2u8))
+            .stroke_dash(Some(DashPattern::DOTTED))
+            .into_iter();
+
+        // Top edge, left to right
+        for (x, c) in [(0, 1), (1, 2), (2, 1), (3, 2), (4, 1)].iter() {
+            assert_eq!(rect.next(), Some(Pixel(UnsignedCoord::new(*x, 0), *c)));
+        }
+
+        // The left and right edges are at positions 11 and 5, so both fall in gaps
+        for x in 0..=4 {
+            assert_eq!(rect.next(), Some(Pixel(UnsignedCoord::new(x, 1), 2)));
+        }
+
+        // Bottom edge, at positions 10 down to 6 from left to right
+        for (x, c) in [(0, 1), (1, 2), (2, 1), (3, 2), (4, 1)].iter() {
+            assert_eq!(rect.next(), Some(Pixel(UnsignedCoord::new(*x, 2), *c)));
+        }
+    }
+
+    #[test]
+    fn gradient_fill() {
+        let rect = Rectangle::new(Coord::new(0, 0), Coord::new(4, 2))
+            .fill(Some(9u8))
+            .fill_gradient(Some(Gradient::linear(
+                Coord::new(0, 0),
+                Coord::new(4, 0),
+                0,
+                200,
+            )));
+
+        let row: [u8; 5] = [0, 1, 2, 3, 4].map(|x| {
+            rect.into_iter()
+                .find(|p| p.0 == UnsignedCoord::new(x, 1))
+                .unwrap()
+                .1
+        });
+
+        assert_eq!(row, [0, 50, 100, 150, 200]);
+        assert_eq!(rect.into_iter().count(), 15);
+    }
+
+    #[test]
+    fn pattern_fill_leaves_gaps() {
+        let rect = Rectangle::new(Coord::new(0, 0), Coord::new(4, 4))
+            .fill_pattern(Some(FillPattern::hatch(Hatch::Horizontal, 2u8)));
+
+        // Only the top and bottom rows are on a hatch line
+        assert_eq!(rect.into_iter().count(), 10);
+        assert!(rect.into_iter().all(|p| p.0[1] == 0 || p.0[1] == 4));
+
+        // The background fills the gaps
+        let rect = rect.fill_pattern(Some(
+            FillPattern::hatch(Hatch::Horizontal, 2u8).background(Some(3)),
+        ));
+
+        assert_eq!(rect.into_iter().count(), 25);
+        assert_eq!(rect.into_iter().filter(|p| p.1 == 3).count(), 15);
+    }
+
+    #[test]
+    fn offset() {
+        let rect: Rectangle<u8> = Rectangle::new(Coord::new(5, 10), Coord::new(15, 14));
+
+        let grown = rect.offset(2);
+        assert_eq!(grown.top_left, Coord::new(3, 8));
+        assert_eq!(grown.bottom_right, Coord::new(17, 16));
+
+        let shrunk = rect.offset(-2);
+        assert_eq!(shrunk.top_left, Coord::new(7, 12));
+        assert_eq!(shrunk.bottom_right, Coord::new(13, 12));
+
+        // Too thin to shrink any further vertically
+        let collapsed = rect.offset(-3);
+        assert_eq!(collapsed.top_left, Coord::new(8, 12));
+        assert_eq!(collapsed.bottom_right, Coord::new(12, 12));
+    }
 }
@@ -0,0 +1,614 @@
+//! Regular polygon and star primitives
+//!
+//! Both shapes are generated from a center point, a radius and a number of sides or points, which
+//! is useful for gauges, compass roses and icons. Rotations are given in degrees, clockwise, and a
+//! rotation of zero places the first vertex straight above the center.
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{edge_bounds, winding_number, EdgeStroke, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::thick::stroke_margin;
+use crate::primitives::{ContainsPoint, Intersects, Offset, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::trig::{cos, polar, DEGREE, ONE};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Regular polygon primitive
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::RegularPolygon;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Hexagon with a flat top and bottom
+/// let hexagon = RegularPolygon::new(Coord::new(12, 8), 7, 6)
+///     .rotation(30)
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+///
+/// display.draw(hexagon);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct RegularPolygon<C: PixelColor> {
+    /// Center point of the polygon
+    pub center: Coord,
+
+    /// Distance from the center to each vertex
+    pub radius: u32,
+
+    /// Number of sides. Polygons with fewer than three sides are not drawn.
+    pub sides: u32,
+
+    /// Clockwise rotation in degrees
+    pub rotation: i32,
+
+    /// Polygon style
+    pub style: Style<C>,
+}
+
+impl<C> RegularPolygon<C>
+where
+    C: PixelColor,
+{
+    /// Create a new regular polygon with its first vertex straight above the center
+    pub fn new(center: Coord, radius: u32, sides: u32) -> Self {
+        RegularPolygon {
+            center,
+            radius,
+            sides,
+            rotation: 0,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the clockwise rotation in degrees
+    pub fn rotation(mut self, rotation: i32) -> Self {
+        self.rotation = rotation;
+
+        self
+    }
+
+    fn edges(&self) -> RegularEdges {
+        RegularEdges::new(
+            self.center,
+            self.radius,
+            self.radius,
+            self.sides,
+            self.rotation,
+        )
+    }
+}
+
+impl<C> Offset for RegularPolygon<C>
+where
+    C: PixelColor,
+{
+    fn offset(&self, distance: i32) -> Self {
+        // Moving each side by `distance` moves the vertices by `distance / cos(180° / sides)`
+        let cos = if self.sides >= 3 {
+            i64::from(cos(180 * DEGREE / self.sides as i32))
+        } else {
+            i64::from(ONE)
+        };
+        let scaled = i64::from(distance) * i64::from(ONE);
+        let delta = (scaled + scaled.signum() * cos / 2) / cos;
+
+        Self {
+            radius: (i64::from(self.radius) + delta).max(0) as u32,
+            ..*self
+        }
+    }
+}
+
+/// Star primitive
+///
+/// A star alternates between vertices on an outer and an inner radius.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Star;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Five pointed star
+/// let star = Star::new(Coord::new(12, 8), 7, 3, 5).fill(Some(1u8));
+///
+/// display.draw(star);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Star<C: PixelColor> {
+    /// Center point of the star
+    pub center: Coord,
+
+    /// Distance from the center to the tip of each point
+    pub outer_radius: u32,
+
+    /// Distance from the center to the inner vertices between points
+    pub inner_radius: u32,
+
+    /// Number of points. Stars with fewer than two points are not drawn.
+    pub points: u32,
+
+    /// Clockwise rotation in degrees
+    pub rotation: i32,
+
+    /// Star style
+    pub style: Style<C>,
+}
+
+impl<C> Star<C>
+where
+    C: PixelColor,
+{
+    /// Create a new star with its first point straight above the center
+    pub fn new(center: Coord, outer_radius: u32, inner_radius: u32, points: u32) -> Self {
+        Star {
+            center,
+            outer_radius,
+            inner_radius,
+            points,
+            rotation: 0,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the clockwise rotation in degrees
+    pub fn rotation(mut self, rotation: i32) -> Self {
+        self.rotation = rotation;
+
+        self
+    }
+
+    fn edges(&self) -> RegularEdges {
+        RegularEdges::new(
+            self.center,
+            self.outer_radius,
+            self.inner_radius,
+            self.points * 2,
+            self.rotation,
+        )
+    }
+}
+
+/// Iterator over the edges of a polygon whose vertices are evenly spaced around a center point
+///
+/// Even vertices lie on the outer radius and odd vertices on the inner radius.
+#[derive(Debug, Copy, Clone)]
+struct RegularEdges {
+    center: Coord,
+    outer_radius: u32,
+    inner_radius: u32,
+    vertices: u32,
+    rotation: i32,
+    index: u32,
+}
+
+impl RegularEdges {
+    fn new(
+        center: Coord,
+        outer_radius: u32,
+        inner_radius: u32,
+        vertices: u32,
+        rotation: i32,
+    ) -> Self {
+        Self {
+            center,
+            outer_radius,
+            inner_radius,
+            // Anything with fewer than three vertices has no area and isn't drawn
+            vertices: if vertices < 3 { 0 } else { vertices },
+            rotation,
+            index: 0,
+        }
+    }
+
+    fn vertex(&self, index: u32) -> Coord {
+        let index = index % self.vertices;
+        let radius = if index.is_multiple_of(2) {
+            self.outer_radius
+        } else {
+            self.inner_radius
+        };
+        let step = i64::from(index) * i64::from(360 * DEGREE) / i64::from(self.vertices);
+
+        polar(
+            self.center,
+            radius,
+            self.rotation.rem_euclid(360) * DEGREE + step as i32,
+        )
+    }
+}
+
+impl Iterator for RegularEdges {
+    type Item = (Coord, Coord);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.vertices {
+            return None;
+        }
+
+        let edge = (self.vertex(self.index), self.vertex(self.index + 1));
+        self.index += 1;
+
+        Some(edge)
+    }
+}
+
+/// Pixel iterator for each pixel in a regular polygon or star
+///
+/// The fill is drawn first, followed by the border. How the two share the pixels under the border
+/// depends on the style's [`StrokeOverlap`](../../style/enum.StrokeOverlap.html).
+#[derive(Debug, Clone, Copy)]
+pub struct RegularPolygonIterator<C>
+where
+    C: PixelColor,
+{
+    edges: RegularEdges,
+    style: Style<C>,
+    fill: FillArea,
+    stroke: EdgeStroke<C, RegularEdges>,
+}
+
+impl<C> RegularPolygonIterator<C>
+where
+    C: PixelColor,
+{
+    fn new(edges: RegularEdges, style: Style<C>) -> Self {
+        Self {
+            edges,
+            style,
+            fill: FillArea::new(edge_bounds(edges).filter(|_| style.has_fill())),
+            stroke: EdgeStroke::new(edges, style),
+        }
+    }
+}
+
+impl<C> Iterator for RegularPolygonIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edges = self.edges;
+        let style = self.style;
+        let exclusive = style.stroke_overlap == StrokeOverlap::Exclusive;
+        let fill_at = |p: Coord| {
+            if winding_number(edges, p[0], p[1]) != 0 {
+                style.fill_color_at(p)
+            } else {
+                None
+            }
+        };
+
+        for p in &mut self.fill {
+            if exclusive && self.stroke.covers(p) {
+                continue;
+            }
+
+            if let Some(color) = fill_at(p) {
+                return Some(Pixel(p.to_unsigned(), color));
+            }
+        }
+
+        self.stroke
+            .next_with_gaps(|p| fill_at(p).filter(|_| exclusive))
+    }
+}
+
+impl<C> Clip for RegularPolygonIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.fill.clip(top_left, bottom_right);
+    }
+}
+
+macro_rules! impl_regular_polygon {
+    ($type:ident) => {
+        impl<C> Primitive for $type<C> where C: PixelColor {}
+
+        impl<C> Intersects<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn intersects(&self, area: &Rectangle<C>) -> bool {
+                let margin = stroke_margin(self.style.stroke_width);
+
+                area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+            }
+        }
+
+        impl<C> ContainsPoint for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn contains(&self, point: Coord) -> bool {
+                winding_number(self.edges(), point[0], point[1]) != 0
+            }
+        }
+
+        impl<C> Dimensions for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn top_left(&self) -> Coord {
+                edge_bounds(self.edges()).map_or(self.center, |(tl, _)| tl)
+            }
+
+            fn bottom_right(&self) -> Coord {
+                edge_bounds(self.edges()).map_or(self.center, |(_, br)| br)
+            }
+
+            fn size(&self) -> UnsignedCoord {
+                (self.bottom_right() - self.top_left()).to_unsigned()
+            }
+        }
+
+        impl<C> WithStyle<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn style(mut self, style: Style<C>) -> Self {
+                self.style = style;
+
+                self
+            }
+
+            fn stroke(mut self, color: Option<C>) -> Self {
+                self.style.stroke_color = color;
+
+                self
+            }
+
+            fn stroke_width(mut self, width: u8) -> Self {
+                self.style.stroke_width = width;
+
+                self
+            }
+
+            fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+                self.style.stroke_dash = pattern;
+
+                self
+            }
+
+            fn line_join(mut self, join: LineJoin) -> Self {
+                self.style.line_join = join;
+
+                self
+            }
+
+            fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+                self.style.stroke_cap = cap;
+
+                self
+            }
+
+            fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+                self.style.stroke_alignment = alignment;
+
+                self
+            }
+
+            fn stroke_corners(mut self, length: Option<u32>) -> Self {
+                self.style.stroke_corners = length;
+
+                self
+            }
+
+            fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+                self.style.stroke_overlap = overlap;
+
+                self
+            }
+
+            fn draw_mode(mut self, mode: DrawMode) -> Self {
+                self.style.draw_mode = mode;
+
+                self
+            }
+
+            fn fill(mut self, color: Option<C>) -> Self {
+                self.style.fill_color = color;
+
+                self
+            }
+
+            fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+                self.style.fill_gradient = gradient;
+
+                self
+            }
+
+            fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+                self.style.fill_pattern = pattern;
+
+                self
+            }
+        }
+
+        impl<C> Styled<C> for $type<C>
+        where
+            C: PixelColor,
+        {
+            fn current_style(&self) -> &Style<C> {
+                &self.style
+            }
+        }
+
+        impl<C> IntoIterator for $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = RegularPolygonIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                (&self).into_iter()
+            }
+        }
+
+        impl<'a, C> IntoIterator for &'a $type<C>
+        where
+            C: PixelColor,
+        {
+            type Item = Pixel<C>;
+            type IntoIter = RegularPolygonIterator<C>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                RegularPolygonIterator::new(self.edges(), self.style)
+            }
+        }
+
+        impl<C> Drawable for $type<C> where C: PixelColor {}
+
+        impl<C> Transform for $type<C>
+        where
+            C: PixelColor,
+        {
+            /// Translate the center from its current position to a new position by (x, y)
+            /// pixels, returning a new object. For a mutating transform, see `translate_mut`.
+            fn translate(&self, by: Coord) -> Self {
+                Self {
+                    center: self.center + by,
+                    ..*self
+                }
+            }
+
+            /// Translate the center from its current position to a new position by (x, y)
+            /// pixels.
+            fn translate_mut(&mut self, by: Coord) -> &mut Self {
+                self.center += by;
+
+                self
+            }
+        }
+    };
+}
+
+impl_regular_polygon!(RegularPolygon);
+impl_regular_polygon!(Star);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    #[test]
+    fn square_vertices() {
+        let square: RegularPolygon<u8> = RegularPolygon::new(Coord::new(10, 10), 5, 4);
+        let mut edges = square.edges();
+
+        assert_eq!(edges.next(), Some((Coord::new(10, 5), Coord::new(15, 10))));
+        assert_eq!(edges.next(), Some((Coord::new(15, 10), Coord::new(10, 15))));
+        assert_eq!(edges.next(), Some((Coord::new(10, 15), Coord::new(5, 10))));
+        assert_eq!(edges.next(), Some((Coord::new(5, 10), Coord::new(10, 5))));
+        assert_eq!(edges.next(), None);
+    }
+
+    #[test]
+    fn rotated_square_is_axis_aligned() {
+        let square: RegularPolygon<u8> = RegularPolygon::new(Coord::new(10, 8), 7, 4).rotation(45);
+
+        assert_eq!(square.top_left(), Coord::new(5, 3));
+        assert_eq!(square.bottom_right(), Coord::new(15, 13));
+
+        let mut display = Display::default();
+        display.draw(square.fill(Some(1u8)));
+
+        assert_eq!(display.count(1), 10 * 10);
+    }
+
+    #[test]
+    fn extreme_rotation() {
+        let square: RegularPolygon<u8> = RegularPolygon::new(Coord::new(10, 8), 7, 4);
+
+        assert!(square
+            .rotation(45 + 360 * 5_000_000)
+            .edges()
+            .eq(square.rotation(45).edges()));
+        assert!(square
+            .rotation(i32::MIN)
+            .edges()
+            .eq(square.rotation(i32::MIN.rem_euclid(360)).edges()));
+    }
+
+    #[test]
+    fn star_alternates_radii() {
+        let star: Star<u8> = Star::new(Coord::new(10, 10), 8, 4, 4);
+        let mut edges = star.edges();
+
+        assert_eq!(edges.next(), Some((Coord::new(10, 2), Coord::new(13, 7))));
+        assert_eq!(edges.next(), Some((Coord::new(13, 7), Coord::new(18, 10))));
+        assert_eq!(edges.count(), 6);
+    }
+
+    #[test]
+    fn star_fill() {
+        let mut display = Display::default();
+        display.draw(Star::new(Coord::new(10, 8), 7, 3, 5).fill(Some(1u8)));
+
+        // Tip of the top point and the center are filled, the gap between points is not
+        assert_eq!(display.0[2][10], 1);
+        assert_eq!(display.0[8][10], 1);
+        assert_eq!(display.0[3][6], 0);
+    }
+
+    #[test]
+    fn stroke_drawn_over_fill() {
+        let mut display = Display::default();
+        display.draw(
+            RegularPolygon::new(Coord::new(10, 8), 6, 6)
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        assert_eq!(display.0[2][10], 1);
+        assert_eq!(display.0[8][10], 2);
+    }
+
+    #[test]
+    fn degenerate() {
+        let line: RegularPolygon<u8> = RegularPolygon::new(Coord::new(10, 10), 5, 2)
+            .stroke(Some(1))
+            .fill(Some(2));
+        let star: Star<u8> = Star::new(Coord::new(10, 10), 5, 2, 1).stroke(Some(1));
+
+        assert_eq!(line.into_iter().count(), 0);
+        assert_eq!(star.into_iter().count(), 0);
+        assert_eq!(line.size(), UnsignedCoord::new(0, 0));
+    }
+
+    #[test]
+    fn translate() {
+        let hexagon: RegularPolygon<u8> = RegularPolygon::new(Coord::new(10, 10), 5, 6);
+        let moved = hexagon.translate(Coord::new(5, -5));
+
+        assert_eq!(moved.center, Coord::new(15, 5));
+        assert_eq!(moved.top_left(), hexagon.top_left() + Coord::new(5, -5));
+    }
+
+    #[test]
+    fn offset() {
+        let square: RegularPolygon<u8> = RegularPolygon::new(Coord::new(12, 8), 10, 4);
+
+        // Sides move by 2, so the corners move by 2√2
+        assert_eq!(square.offset(2).radius, 13);
+        assert_eq!(square.offset(-2).radius, 7);
+        assert_eq!(square.offset(-20).radius, 0);
+    }
+}
@@ -0,0 +1,646 @@
+//! The ring sector primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_aligned_border, is_stroke_pixel, styled_pixel, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::trig::{polar, Sweep, DEGREE};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Ring sector primitive
+///
+/// The part of a ring between an inner and an outer radius that lies within a range of angles, also
+/// known as an annular sector. Angles are given in degrees, clockwise, and an angle of zero points
+/// straight up from the center. A negative sweep angle goes counter clockwise from the start
+/// angle, and a sweep of 360 degrees or more draws a complete ring.
+///
+/// Both radii are inclusive, so a ring sector with an inner radius of `r + 1` fits exactly around
+/// one with an outer radius of `r`. An inner radius of zero draws a pie slice.
+///
+/// The stroke is drawn along both arcs and both straight sides, inside the shape's outline unless
+/// the style's [`stroke_alignment`](../../style/struct.Style.html#structfield.stroke_alignment)
+/// says otherwise. Dash patterns are not supported.
+///
+/// # Examples
+///
+/// ## Draw a gauge showing 60%
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::RingSector;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let center = Coord::new(12, 8);
+///
+/// let track = RingSector::new(center, 7, 5, -135, 270).stroke(Some(1u8));
+/// let value = RingSector::new(center, 7, 5, -135, 270 * 60 / 100).fill(Some(2u8));
+///
+/// display.draw(track);
+/// display.draw(value);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct RingSector<C: PixelColor> {
+    /// Center point of the ring
+    pub center: Coord,
+
+    /// Distance from the center to the outside of the ring
+    pub outer_radius: u32,
+
+    /// Distance from the center to the inside of the ring
+    pub inner_radius: u32,
+
+    /// Clockwise angle of the start of the sector in degrees
+    pub start_angle: i32,
+
+    /// Clockwise angle covered by the sector in degrees
+    pub sweep_angle: i32,
+
+    /// Ring sector style
+    pub style: Style<C>,
+}
+
+impl<C> RingSector<C>
+where
+    C: PixelColor,
+{
+    /// Create a new ring sector
+    pub fn new(
+        center: Coord,
+        outer_radius: u32,
+        inner_radius: u32,
+        start_angle: i32,
+        sweep_angle: i32,
+    ) -> Self {
+        RingSector {
+            center,
+            outer_radius,
+            inner_radius,
+            start_angle,
+            sweep_angle,
+            style: Style::default(),
+        }
+    }
+
+    /// Start angle within a single turn and sweep angle limited to a full turn, so neither
+    /// overflows when converted to angle units
+    fn angles(&self) -> (i32, i32) {
+        (
+            self.start_angle.rem_euclid(360),
+            self.sweep_angle.clamp(-360, 360),
+        )
+    }
+
+    fn shape(&self) -> RingShape {
+        let (start_angle, sweep_angle) = self.angles();
+
+        RingShape {
+            center: self.center,
+            outer_radius: i64::from(self.outer_radius),
+            inner_radius: i64::from(self.inner_radius),
+            sweep: Sweep::new(start_angle * DEGREE, sweep_angle * DEGREE),
+        }
+    }
+
+    /// Bounding box of the ring sector, returned as `(top_left, bottom_right)`
+    fn bounds(&self) -> (Coord, Coord) {
+        let shape = self.shape();
+        let (start_angle, sweep_angle) = self.angles();
+        let start = start_angle.min(start_angle + sweep_angle);
+        let sweep = sweep_angle.abs();
+
+        let mut tl = self.center;
+        let mut br = self.center;
+        let mut include = |p: Coord| {
+            tl = Coord::new(tl[0].min(p[0]), tl[1].min(p[1]));
+            br = Coord::new(br[0].max(p[0]), br[1].max(p[1]));
+        };
+
+        if sweep > 0 {
+            for &angle in [start, start + sweep].iter() {
+                include(polar(self.center, self.outer_radius, angle * DEGREE));
+                include(polar(self.center, self.inner_radius, angle * DEGREE));
+            }
+        }
+
+        // The outer arc reaches furthest at any of the four compass points it passes through
+        for &(dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)].iter() {
+            if sweep > 0 && shape.sweep.contains(i64::from(dx), i64::from(dy)) {
+                let r = self.outer_radius as i32;
+                include(self.center + Coord::new(dx * r, dy * r));
+            }
+        }
+
+        let outset = self.style.stroke_outset() as i32;
+
+        (
+            tl - Coord::new(outset, outset),
+            br + Coord::new(outset, outset),
+        )
+    }
+}
+
+/// Coverage test for the pixels of a ring sector
+#[derive(Debug, Copy, Clone)]
+struct RingShape {
+    center: Coord,
+    outer_radius: i64,
+    inner_radius: i64,
+    sweep: Sweep,
+}
+
+impl RingShape {
+    /// Returns `true` if the ring sector covers the pixel at `point`
+    fn contains(&self, point: Coord) -> bool {
+        let dx = i64::from(point[0]) - i64::from(self.center[0]);
+        let dy = i64::from(point[1]) - i64::from(self.center[1]);
+        // Squared distances of far away points don't fit in an i64
+        let distance_sq = 4 * (i128::from(dx) * i128::from(dx) + i128::from(dy) * i128::from(dy));
+
+        // Compare against the radii plus and minus half a pixel, with everything doubled to
+        // stay in integers
+        let outer = i128::from(2 * self.outer_radius + 1);
+        let inner = i128::from(2 * self.inner_radius - 1);
+
+        distance_sq < outer * outer
+            && (self.inner_radius == 0 || distance_sq >= inner * inner)
+            && self.sweep.contains(dx, dy)
+    }
+}
+
+impl<C> Primitive for RingSector<C> where C: PixelColor {}
+
+impl<C> Intersects<C> for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> ContainsPoint for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.shape().contains(point)
+    }
+}
+
+impl<C> StrokeContainsPoint for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let shape = self.shape();
+
+        is_stroke_pixel(
+            |p| shape.contains(p),
+            &self.style,
+            point,
+            self.style.stroke_outset() as i32,
+        )
+    }
+}
+
+impl<C> Offset for RingSector<C>
+where
+    C: PixelColor,
+{
+    /// Only the arcs move, the straight sides keep their angles
+    fn offset(&self, distance: i32) -> Self {
+        let outer = self.outer_radius as i32 + distance;
+        let inner = self.inner_radius as i32 - distance;
+
+        let (outer_radius, inner_radius) = if outer >= inner {
+            (outer.max(0) as u32, inner.max(0) as u32)
+        } else {
+            let middle = (self.outer_radius + self.inner_radius) / 2;
+
+            (middle, middle)
+        };
+
+        Self {
+            outer_radius,
+            inner_radius,
+            ..*self
+        }
+    }
+}
+
+impl<C> Dimensions for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<C> WithStyle<C> for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for RingSector<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C> IntoIterator for RingSector<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RingSectorIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &RingSector<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RingSectorIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+        let (tl, br) = self.bounds();
+
+        RingSectorIterator {
+            shape: self.shape(),
+            area: FillArea::new(Some((tl, br + Coord::new(1, 1))).filter(|_| visible)),
+            style: self.style,
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the ring sector fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct RingSectorIterator<C>
+where
+    C: PixelColor,
+{
+    shape: RingShape,
+    area: FillArea,
+    style: Style<C>,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> Iterator for RingSectorIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = self.shape;
+        let style = self.style;
+        let width = i32::from(style.stroke_width);
+        let outset = style.stroke_outset() as i32;
+
+        let (pixel, over) = self.area.find_map(|p| {
+            let stroke = style.stroke_color.filter(|_| {
+                width > 0 && is_aligned_border(|p| shape.contains(p), p, width, outset)
+            });
+
+            styled_pixel(&style, p, stroke, shape.contains(p))
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for RingSectorIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+impl<C> Drawable for RingSector<C> where C: PixelColor {}
+
+impl<C> Transform for RingSector<C>
+where
+    C: PixelColor,
+{
+    /// Translate the ring sector's center by (x, y) pixels, returning a new `RingSector`. For a
+    /// mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RingSector;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let sector: RingSector<u8> = RingSector::new(Coord::new(10, 10), 8, 4, 0, 360);
+    /// let moved = sector.translate(Coord::new(5, 5));
+    ///
+    /// assert_eq!(moved.center, Coord::new(15, 15));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the ring sector's center by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::Drawing;
+
+    #[test]
+    fn full_ring_has_a_hole() {
+        let mut display = Display::default();
+        display.draw(RingSector::new(Coord::new(10, 8), 6, 3, 0, 360).fill(Some(1u8)));
+
+        assert_eq!(display.0[8][10], 0);
+        assert_eq!(display.0[8][11], 0);
+        assert_eq!(display.0[8][13], 1);
+        assert_eq!(display.0[8][16], 1);
+        assert_eq!(display.0[8][17], 0);
+        assert_eq!(display.0[2][10], 1);
+    }
+
+    #[test]
+    fn quarters() {
+        let center = Coord::new(10, 8);
+        let mut display = Display::default();
+        display.draw(RingSector::new(center, 6, 2, 0, 90).fill(Some(1u8)));
+        display.draw(RingSector::new(center, 6, 2, 90, 90).fill(Some(2u8)));
+
+        // Top right and bottom right quarters
+        assert_eq!(display.0[4][14], 1);
+        assert_eq!(display.0[12][14], 2);
+        assert_eq!(display.0[4][6], 0);
+        assert_eq!(display.0[12][6], 0);
+
+        // The shared edge belongs to the sector drawn last
+        assert_eq!(display.0[8][14], 2);
+    }
+
+    #[test]
+    fn adjacent_rings_dont_overlap() {
+        let center = Coord::new(10, 8);
+        let inner = RingSector::new(center, 3, 0, 0, 360).fill(Some(1u8));
+        let outer = RingSector::new(center, 6, 4, 0, 360).fill(Some(1u8));
+        let whole = RingSector::new(center, 6, 0, 0, 360).fill(Some(1u8));
+
+        assert_eq!(
+            inner.into_iter().count() + outer.into_iter().count(),
+            whole.into_iter().count()
+        );
+    }
+
+    #[test]
+    fn far_points() {
+        let sector = RingSector::<u8>::new(Coord::new(10, 8), 6, 3, 0, 360);
+
+        assert!(sector.contains(Coord::new(15, 8)));
+        assert!(!sector.contains(Coord::new(i32::MAX, i32::MIN)));
+        assert!(!sector.contains(Coord::new(i32::MIN, i32::MAX)));
+    }
+
+    #[test]
+    fn extreme_angles() {
+        let center = Coord::new(10, 8);
+
+        let mut display = Display::default();
+        display.draw(RingSector::new(center, 6, 3, 90 + 360 * 5_000_000, 90).fill(Some(1u8)));
+        let mut reference = Display::default();
+        reference.draw(RingSector::new(center, 6, 3, 90, 90).fill(Some(1u8)));
+        assert_eq!(display, reference);
+
+        for &sweep in [i32::MIN, i32::MAX].iter() {
+            let full = RingSector::new(center, 6, 3, 9_000_000, sweep).fill(Some(1u8));
+            let mut display = Display::default();
+            display.draw(full);
+            let mut reference = Display::default();
+            reference.draw(RingSector::new(center, 6, 3, 0, 360).fill(Some(1u8)));
+
+            assert_eq!(display, reference);
+            assert_eq!(full.size(), UnsignedCoord::new(12, 12));
+        }
+    }
+
+    #[test]
+    fn negative_sweep() {
+        let clockwise = RingSector::new(Coord::new(10, 8), 6, 2, 30, 100).fill(Some(1u8));
+        let counter = RingSector::new(Coord::new(10, 8), 6, 2, 130, -100).fill(Some(1u8));
+
+        assert!(clockwise.into_iter().eq(counter.into_iter()));
+    }
+
+    #[test]
+    fn stroke_outlines_the_sector() {
+        let mut display = Display::default();
+        display.draw(
+            RingSector::new(Coord::new(4, 8), 10, 4, 0, 180)
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        // Outer and inner arcs
+        assert_eq!(display.0[8][14], 1);
+        assert_eq!(display.0[8][8], 1);
+        assert_eq!(display.0[8][11], 2);
+
+        // Straight side along the top
+        assert_eq!(display.0[2][4], 1);
+        assert_eq!(display.0[3][4], 1);
+        assert_eq!(display.0[3][5], 2);
+
+        assert!(display.count(1) > 0);
+        assert!(display.count(2) > display.count(1));
+    }
+
+    #[test]
+    fn empty() {
+        let sector = RingSector::new(Coord::new(10, 8), 6, 2, 0, 0).fill(Some(1u8));
+        assert_eq!(sector.into_iter().count(), 0);
+
+        let unstyled: RingSector<u8> = RingSector::new(Coord::new(10, 8), 6, 2, 0, 90);
+        assert_eq!(unstyled.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn dimensions() {
+        let full: RingSector<u8> = RingSector::new(Coord::new(10, 10), 5, 2, 0, 360);
+        assert_eq!(full.top_left(), Coord::new(5, 5));
+        assert_eq!(full.bottom_right(), Coord::new(15, 15));
+
+        let quarter: RingSector<u8> = RingSector::new(Coord::new(10, 10), 5, 2, 0, 90);
+        assert_eq!(quarter.top_left(), Coord::new(10, 5));
+        assert_eq!(quarter.bottom_right(), Coord::new(15, 10));
+        assert_eq!(quarter.size(), UnsignedCoord::new(5, 5));
+    }
+
+    #[test]
+    fn offset() {
+        let sector: RingSector<u8> = RingSector::new(Coord::new(10, 10), 8, 4, 0, 90);
+
+        let grown = sector.offset(1);
+        assert_eq!((grown.outer_radius, grown.inner_radius), (9, 3));
+
+        let shrunk = sector.offset(-1);
+        assert_eq!((shrunk.outer_radius, shrunk.inner_radius), (7, 5));
+
+        let collapsed = sector.offset(-3);
+        assert_eq!((collapsed.outer_radius, collapsed.inner_radius), (6, 6));
+
+        assert_eq!(sector.offset(5).inner_radius, 0);
+    }
+
+    #[test]
+    fn outside_stroke() {
+        let sector = RingSector::new(Coord::new(12, 8), 5, 2, 0, 90).fill(Some(1u8));
+        let stroked = sector
+            .stroke(Some(2u8))
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside);
+
+        let mut filled = Display::default();
+        filled.draw(sector);
+
+        let mut display = Display::default();
+        display.draw(stroked);
+
+        // The fill is untouched and the stroke only covers pixels outside the shape
+        assert_eq!(display.count(1), filled.count(1));
+        assert!(display.count(2) > 0);
+        assert!(display
+            .0
+            .iter()
+            .flatten()
+            .zip(filled.0.iter().flatten())
+            .all(|(&drawn, &fill)| fill == 0 || drawn == fill));
+
+        assert_eq!(stroked.top_left(), sector.top_left() - Coord::new(2, 2));
+        assert_eq!(
+            stroked.bottom_right(),
+            sector.bottom_right() + Coord::new(2, 2)
+        );
+    }
+}
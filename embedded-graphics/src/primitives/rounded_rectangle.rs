@@ -0,0 +1,730 @@
+//! The rounded rectangle primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_inner_border, is_stroke_pixel, styled_pixel, FillArea};
+use super::rectangle::offset_corners;
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Radius of each corner of a [`RoundedRectangle`](./struct.RoundedRectangle.html)
+///
+/// A radius of zero leaves the corner square.
+///
+/// ```rust
+/// use embedded_graphics::primitives::rounded_rectangle::CornerRadii;
+///
+/// // Tab shape, rounded on top only
+/// let tab = CornerRadii::builder().top(4).build();
+///
+/// assert_eq!(tab.top_left, 4);
+/// assert_eq!(tab.top_right, 4);
+/// assert_eq!(tab.bottom_right, 0);
+/// assert_eq!(tab.bottom_left, 0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct CornerRadii {
+    /// Radius of the top left corner
+    pub top_left: u32,
+
+    /// Radius of the top right corner
+    pub top_right: u32,
+
+    /// Radius of the bottom right corner
+    pub bottom_right: u32,
+
+    /// Radius of the bottom left corner
+    pub bottom_left: u32,
+}
+
+impl CornerRadii {
+    /// Create radii with the same value for all four corners
+    pub const fn new(radius: u32) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+
+    /// Start building radii with every corner square
+    pub fn builder() -> CornerRadiiBuilder {
+        CornerRadiiBuilder {
+            radii: CornerRadii::default(),
+        }
+    }
+
+    /// Shrink the radii proportionally until they fit in a rectangle of the given size
+    ///
+    /// Radii fit if the two corners on each side don't overlap, so rounding never changes the
+    /// overall size of the rectangle.
+    pub fn confine(self, size: UnsignedCoord) -> Self {
+        let sides = [
+            (self.top_left + self.top_right, size[0]),
+            (self.bottom_left + self.bottom_right, size[0]),
+            (self.top_left + self.bottom_left, size[1]),
+            (self.top_right + self.bottom_right, size[1]),
+        ];
+
+        // Largest ratio of the radii on one side to the length of that side, as a fraction
+        let (radii, length) = sides.iter().fold((0, 1), |(radii, length), &(r, l)| {
+            if u64::from(r) * u64::from(length) > u64::from(radii) * u64::from(l) {
+                (r, l)
+            } else {
+                (radii, length)
+            }
+        });
+
+        if radii <= length {
+            return self;
+        }
+
+        let scale = |r: u32| (u64::from(r) * u64::from(length) / u64::from(radii)) as u32;
+
+        Self {
+            top_left: scale(self.top_left),
+            top_right: scale(self.top_right),
+            bottom_right: scale(self.bottom_right),
+            bottom_left: scale(self.bottom_left),
+        }
+    }
+}
+
+/// Builder for [`CornerRadii`](./struct.CornerRadii.html)
+///
+/// Later calls override earlier ones for the corners they share, so `.all(8).bottom(0)` rounds the
+/// top corners only.
+#[derive(Debug, Copy, Clone)]
+pub struct CornerRadiiBuilder {
+    radii: CornerRadii,
+}
+
+impl CornerRadiiBuilder {
+    /// Set the radius of all four corners
+    pub fn all(self, radius: u32) -> Self {
+        Self {
+            radii: CornerRadii::new(radius),
+        }
+    }
+
+    /// Set the radius of the top left and top right corners
+    pub fn top(self, radius: u32) -> Self {
+        self.top_left(radius).top_right(radius)
+    }
+
+    /// Set the radius of the bottom left and bottom right corners
+    pub fn bottom(self, radius: u32) -> Self {
+        self.bottom_left(radius).bottom_right(radius)
+    }
+
+    /// Set the radius of the top left and bottom left corners
+    pub fn left(self, radius: u32) -> Self {
+        self.top_left(radius).bottom_left(radius)
+    }
+
+    /// Set the radius of the top right and bottom right corners
+    pub fn right(self, radius: u32) -> Self {
+        self.top_right(radius).bottom_right(radius)
+    }
+
+    /// Set the radius of the top left corner
+    pub fn top_left(mut self, radius: u32) -> Self {
+        self.radii.top_left = radius;
+
+        self
+    }
+
+    /// Set the radius of the top right corner
+    pub fn top_right(mut self, radius: u32) -> Self {
+        self.radii.top_right = radius;
+
+        self
+    }
+
+    /// Set the radius of the bottom right corner
+    pub fn bottom_right(mut self, radius: u32) -> Self {
+        self.radii.bottom_right = radius;
+
+        self
+    }
+
+    /// Set the radius of the bottom left corner
+    pub fn bottom_left(mut self, radius: u32) -> Self {
+        self.radii.bottom_left = radius;
+
+        self
+    }
+
+    /// Finish building the radii
+    pub fn build(self) -> CornerRadii {
+        self.radii
+    }
+}
+
+/// Rounded rectangle primitive
+///
+/// A rectangle whose corners are rounded off with quarter circles. Each corner can have a different
+/// radius, including zero for a square corner. Radii that are too large for the rectangle are
+/// scaled down until the corners on each side no longer overlap.
+///
+/// The stroke is drawn inside the outline. Dash patterns are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::rounded_rectangle::CornerRadii;
+/// use embedded_graphics::primitives::RoundedRectangle;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // Button with evenly rounded corners
+/// let button = RoundedRectangle::new(Coord::new(2, 2), Coord::new(21, 8), CornerRadii::new(3))
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+///
+/// // Tab, rounded on top only
+/// let tab = RoundedRectangle::new(
+///     Coord::new(2, 10),
+///     Coord::new(10, 15),
+///     CornerRadii::builder().top(3).build(),
+/// )
+/// .fill(Some(1u8));
+///
+/// display.draw(button);
+/// display.draw(tab);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedRectangle<C: PixelColor> {
+    /// Top left point of the rect
+    pub top_left: Coord,
+
+    /// Bottom right point of the rect
+    pub bottom_right: Coord,
+
+    /// Radius of each corner
+    pub corners: CornerRadii,
+
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C> RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// Create a new rounded rectangle from the top left point to the bottom right point
+    pub fn new(top_left: Coord, bottom_right: Coord, corners: CornerRadii) -> Self {
+        RoundedRectangle {
+            top_left,
+            bottom_right,
+            corners,
+            style: Style::default(),
+        }
+    }
+
+    /// Create a new rounded rectangle with the same radius on every corner
+    pub fn with_equal_corners(top_left: Coord, bottom_right: Coord, radius: u32) -> Self {
+        Self::new(top_left, bottom_right, CornerRadii::new(radius))
+    }
+
+    fn shape(&self) -> RoundedShape {
+        // A rectangle from `(0, 0)` to `(2, 2)` is three pixels across
+        let size =
+            (self.bottom_right - self.top_left).abs().to_unsigned() + UnsignedCoord::new(1, 1);
+
+        RoundedShape {
+            top_left: self.top_left,
+            bottom_right: self.bottom_right,
+            corners: self.corners.confine(size),
+        }
+    }
+}
+
+/// Coverage test for the pixels of a rounded rectangle
+#[derive(Debug, Copy, Clone)]
+struct RoundedShape {
+    top_left: Coord,
+    bottom_right: Coord,
+    corners: CornerRadii,
+}
+
+impl RoundedShape {
+    /// Returns `true` if the rounded rectangle covers the pixel at `point`
+    fn contains(&self, point: Coord) -> bool {
+        let (tl, br) = (self.top_left, self.bottom_right);
+
+        if point[0] < tl[0] || point[1] < tl[1] || point[0] > br[0] || point[1] > br[1] {
+            return false;
+        }
+
+        let in_corner = |radius: u32, dx: i32, dy: i32| {
+            let radius = radius as i32;
+
+            // Offset from the corner's center, which is only non zero inside the corner's square
+            let dx = i64::from((radius - dx).max(0));
+            let dy = i64::from((radius - dy).max(0));
+            let diameter = 2 * i64::from(radius) + 1;
+
+            4 * (dx * dx + dy * dy) < diameter * diameter
+        };
+
+        let left = point[0] - tl[0];
+        let right = br[0] - point[0];
+        let top = point[1] - tl[1];
+        let bottom = br[1] - point[1];
+
+        in_corner(self.corners.top_left, left, top)
+            && in_corner(self.corners.top_right, right, top)
+            && in_corner(self.corners.bottom_right, right, bottom)
+            && in_corner(self.corners.bottom_left, left, bottom)
+    }
+}
+
+impl<C> Primitive for RoundedRectangle<C> where C: PixelColor {}
+
+impl<C> Intersects<C> for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> ContainsPoint for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.shape().contains(point)
+    }
+}
+
+impl<C> StrokeContainsPoint for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// The stroke is always drawn inside the outline, whatever the stroke alignment
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let shape = self.shape();
+
+        is_stroke_pixel(|p| shape.contains(p), &self.style, point, 0)
+    }
+}
+
+impl<C> Offset for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// The corner radii grow and shrink along with the sides, so square corners stay square
+    fn offset(&self, distance: i32) -> Self {
+        let (top_left, bottom_right) = offset_corners(self.top_left, self.bottom_right, distance);
+        let radius = |r: u32| {
+            if r == 0 {
+                0
+            } else {
+                (r as i32 + distance).max(0) as u32
+            }
+        };
+        let corners = self.corners;
+
+        Self {
+            top_left,
+            bottom_right,
+            corners: CornerRadii {
+                top_left: radius(corners.top_left),
+                top_right: radius(corners.top_right),
+                bottom_right: radius(corners.bottom_right),
+                bottom_left: radius(corners.bottom_left),
+            },
+            ..*self
+        }
+    }
+}
+
+impl<C> Dimensions for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.top_left
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bottom_right
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right - self.top_left).abs().to_unsigned()
+    }
+}
+
+impl<C> WithStyle<C> for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C> IntoIterator for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RoundedRectangleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RoundedRectangleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+        let shape = self.shape();
+
+        RoundedRectangleIterator {
+            shape,
+            area: FillArea::new(
+                Some((shape.top_left, shape.bottom_right + Coord::new(1, 1))).filter(|_| visible),
+            ),
+            style: self.style,
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the rounded rectangle fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    shape: RoundedShape,
+    area: FillArea,
+    style: Style<C>,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> Iterator for RoundedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = self.shape;
+        let style = self.style;
+        let width = i32::from(style.stroke_width);
+
+        let (pixel, over) = self.area.find_map(|p| {
+            if !shape.contains(p) {
+                return None;
+            }
+
+            let stroke = style
+                .stroke_color
+                .filter(|_| width > 0 && is_inner_border(|p| shape.contains(p), p, width));
+
+            styled_pixel(&style, p, stroke, true)
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for RoundedRectangleIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+impl<C> Drawable for RoundedRectangle<C> where C: PixelColor {}
+
+impl<C> Transform for RoundedRectangle<C>
+where
+    C: PixelColor,
+{
+    /// Translate the rounded rectangle from its current position to a new position by (x, y)
+    /// pixels, returning a new `RoundedRectangle`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RoundedRectangle;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let rect: RoundedRectangle<u8> =
+    ///     RoundedRectangle::with_equal_corners(Coord::new(5, 10), Coord::new(15, 20), 3);
+    /// let moved = rect.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Coord::new(15, 20));
+    /// assert_eq!(moved.bottom_right, Coord::new(25, 30));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            bottom_right: self.bottom_right + by,
+            ..*self
+        }
+    }
+
+    /// Translate the rounded rectangle from its current position to a new position by (x, y)
+    /// pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.top_left += by;
+        self.bottom_right += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::Rectangle;
+    use crate::Drawing;
+
+    #[test]
+    fn square_corners_match_rectangle() {
+        let mut rounded = Display::default();
+        rounded.draw(
+            RoundedRectangle::with_equal_corners(Coord::new(2, 3), Coord::new(12, 9), 0)
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        let mut square = Display::default();
+        square.draw(
+            Rectangle::new(Coord::new(2, 3), Coord::new(12, 9))
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        assert_eq!(rounded, square);
+    }
+
+    #[test]
+    fn rounded_corners() {
+        let mut display = Display::default();
+        display.draw(
+            RoundedRectangle::with_equal_corners(Coord::new(0, 0), Coord::new(10, 10), 3)
+                .fill(Some(1u8)),
+        );
+
+        // Corner pixels are cut off, edge midpoints aren't
+        for &(x, y) in [(0, 0), (10, 0), (10, 10), (0, 10), (1, 0), (0, 1)].iter() {
+            assert_eq!(display.0[y][x], 0, "({}, {})", x, y);
+        }
+        for &(x, y) in [(5, 0), (10, 5), (5, 10), (0, 5), (1, 1), (3, 0)].iter() {
+            assert_eq!(display.0[y][x], 1, "({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn independent_corners() {
+        let corners = CornerRadii::builder().top(4).bottom_right(2).build();
+
+        let mut display = Display::default();
+        display.draw(
+            RoundedRectangle::new(Coord::new(0, 0), Coord::new(12, 12), corners).fill(Some(1u8)),
+        );
+
+        assert_eq!(display.0[0][0], 0);
+        assert_eq!(display.0[0][12], 0);
+        assert_eq!(display.0[12][12], 0);
+        assert_eq!(display.0[11][12], 1);
+        assert_eq!(display.0[12][0], 1);
+    }
+
+    #[test]
+    fn builder() {
+        let radii = CornerRadii::builder().all(8).bottom(0).left(2).build();
+
+        assert_eq!(
+            radii,
+            CornerRadii {
+                top_left: 2,
+                top_right: 8,
+                bottom_right: 0,
+                bottom_left: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn confine() {
+        let size = UnsignedCoord::new(10, 20);
+
+        assert_eq!(CornerRadii::new(5).confine(size), CornerRadii::new(5));
+        assert_eq!(CornerRadii::new(8).confine(size), CornerRadii::new(5));
+
+        let tab = CornerRadii::builder().top(10).build();
+        assert_eq!(tab.confine(size), CornerRadii::builder().top(5).build());
+
+        // Only the longer side limits a single large corner
+        let one = CornerRadii::builder().top_left(15).build();
+        assert_eq!(
+            one.confine(size),
+            CornerRadii::builder().top_left(10).build()
+        );
+    }
+
+    #[test]
+    fn stroke_follows_corners() {
+        let mut display = Display::default();
+        display.draw(
+            RoundedRectangle::with_equal_corners(Coord::new(0, 0), Coord::new(10, 10), 4)
+                .stroke(Some(1u8))
+                .fill(Some(2u8)),
+        );
+
+        assert_eq!(display.0[0][5], 1);
+        assert_eq!(display.0[1][5], 2);
+        assert_eq!(display.0[0][0], 0);
+        assert_eq!(display.0[0][1], 0);
+        assert_eq!(display.0[1][1], 1);
+        assert_eq!(display.0[2][2], 2);
+        assert!(display.count(1) < 40);
+    }
+
+    #[test]
+    fn dimensions() {
+        let rect: RoundedRectangle<u8> =
+            RoundedRectangle::with_equal_corners(Coord::new(5, 10), Coord::new(15, 20), 3);
+
+        assert_eq!(rect.top_left(), Coord::new(5, 10));
+        assert_eq!(rect.bottom_right(), Coord::new(15, 20));
+        assert_eq!(rect.size(), UnsignedCoord::new(10, 10));
+    }
+
+    #[test]
+    fn offset() {
+        let corners = CornerRadii::builder().top(4).bottom(0).build();
+        let rect: RoundedRectangle<u8> =
+            RoundedRectangle::new(Coord::new(4, 4), Coord::new(16, 12), corners);
+
+        let grown = rect.offset(2);
+        assert_eq!(grown.top_left, Coord::new(2, 2));
+        assert_eq!(grown.bottom_right, Coord::new(18, 14));
+        assert_eq!(
+            grown.corners,
+            CornerRadii::builder().top(6).bottom(0).build()
+        );
+
+        let shrunk = rect.offset(-5);
+        assert_eq!(shrunk.corners, CornerRadii::new(0));
+    }
+}
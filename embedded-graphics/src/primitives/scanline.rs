@@ -0,0 +1,197 @@
+//! Horizontal runs of pixels covered by a filled shape
+
+use super::ContainsPoint;
+use crate::coord::Coord;
+use crate::drawable::Dimensions;
+use core::ops::Range;
+
+/// A run of pixels in a single row, from `x.start` up to but not including `x.end`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanline {
+    /// Row of the run
+    pub y: i32,
+
+    /// Columns covered by the run
+    pub x: Range<i32>,
+}
+
+/// Split the filled interior of a shape into horizontal runs
+///
+/// Drivers that can write a contiguous row of pixels in one go, for example with a DMA transfer or
+/// a "fill window" command, can use this instead of drawing a filled shape pixel by pixel. Runs are
+/// returned top to bottom and left to right, cover the pixels for which
+/// [`contains`](../trait.ContainsPoint.html#tymethod.contains) returns `true`, and leave out pixels
+/// left of or above the display. Only the fill area is returned, so this is most useful for shapes
+/// with a solid `fill_color`.
+///
+/// For most shapes these are exactly the pixels the fill would draw. A filled
+/// [`Triangle`](../triangle/struct.Triangle.html) is drawn along its edges and can cover a few more
+/// pixels on its sloped sides.
+///
+/// This is implemented for every primitive that implements
+/// [`ContainsPoint`](../trait.ContainsPoint.html).
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::{Circle, Scanlines};
+///
+/// let circle: Circle<u8> = Circle::new(Coord::new(10, 10), 4);
+///
+/// for run in circle.scanlines() {
+///     // Write `run.x.len()` pixels starting at `(run.x.start, run.y)` in one transfer
+/// #   assert!(!run.x.is_empty());
+/// }
+/// ```
+pub trait Scanlines: ContainsPoint + Dimensions + Sized {
+    /// Iterate over the horizontal runs covered by the shape's fill
+    fn scanlines(&self) -> ScanlineIterator<'_, Self> {
+        ScanlineIterator::new(self)
+    }
+}
+
+impl<T> Scanlines for T where T: ContainsPoint + Dimensions {}
+
+/// Iterator over the runs of a filled shape, returned by
+/// [`Scanlines::scanlines`](./trait.Scanlines.html#method.scanlines)
+#[derive(Debug)]
+pub struct ScanlineIterator<'a, T> {
+    shape: &'a T,
+    left: i32,
+    right: i32,
+    bottom: i32,
+    x: i32,
+    y: i32,
+}
+
+impl<'a, T> ScanlineIterator<'a, T>
+where
+    T: Dimensions,
+{
+    fn new(shape: &'a T) -> Self {
+        let (tl, br) = (shape.top_left(), shape.bottom_right());
+        let left = tl[0].min(br[0]).max(0);
+        let top = tl[1].min(br[1]).max(0);
+
+        Self {
+            shape,
+            left,
+            right: tl[0].max(br[0]),
+            bottom: tl[1].max(br[1]),
+            x: left,
+            y: top,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ScanlineIterator<'a, T>
+where
+    T: ContainsPoint,
+{
+    type Item = Scanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.y <= self.bottom {
+            let y = self.y;
+            let shape = self.shape;
+            let inside = |x: i32| shape.contains(Coord::new(x, y));
+
+            while self.x <= self.right && !inside(self.x) {
+                self.x += 1;
+            }
+
+            if self.x > self.right {
+                self.x = self.left;
+                self.y += 1;
+                continue;
+            }
+
+            let start = self.x;
+
+            while self.x <= self.right && inside(self.x) {
+                self.x += 1;
+            }
+
+            return Some(Scanline {
+                y,
+                x: start..self.x,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawable::Pixel;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Polygon, Rectangle};
+    use crate::style::WithStyle;
+    use crate::unsignedcoord::UnsignedCoord;
+    use crate::Drawing;
+
+    fn draw_runs<T: Scanlines>(shape: &T) -> Display {
+        let mut display = Display::default();
+
+        for Scanline { y, x } in shape.scanlines() {
+            display.draw(x.map(|x| Pixel(UnsignedCoord::new(x as u32, y as u32), 1u8)));
+        }
+
+        display
+    }
+
+    #[test]
+    fn rectangle_runs() {
+        let rect: Rectangle<u8> = Rectangle::new(Coord::new(2, 3), Coord::new(6, 5));
+        let mut runs = rect.scanlines();
+
+        assert_eq!(runs.next(), Some(Scanline { y: 3, x: 2..7 }));
+        assert_eq!(runs.next(), Some(Scanline { y: 4, x: 2..7 }));
+        assert_eq!(runs.next(), Some(Scanline { y: 5, x: 2..7 }));
+        assert_eq!(runs.next(), None);
+    }
+
+    #[test]
+    fn matches_filled_shapes() {
+        let circle = Circle::new(Coord::new(10, 8), 6).fill(Some(1u8));
+        let vertices = [Coord::new(1, 1), Coord::new(22, 4), Coord::new(8, 14)];
+        let polygon = Polygon::new(&vertices).fill(Some(1u8));
+
+        let mut expected = Display::default();
+        expected.draw(circle);
+        assert_eq!(draw_runs(&circle), expected);
+
+        let mut expected = Display::default();
+        expected.draw(polygon);
+        assert_eq!(draw_runs(&polygon), expected);
+    }
+
+    #[test]
+    fn concave_rows_have_several_runs() {
+        let vertices = [
+            Coord::new(1, 1),
+            Coord::new(20, 1),
+            Coord::new(20, 10),
+            Coord::new(14, 10),
+            Coord::new(14, 5),
+            Coord::new(7, 5),
+            Coord::new(7, 10),
+            Coord::new(1, 10),
+        ];
+        let polygon: Polygon<u8> = Polygon::new(&vertices);
+
+        assert_eq!(polygon.scanlines().filter(|run| run.y == 8).count(), 2);
+        assert_eq!(polygon.scanlines().filter(|run| run.y == 2).count(), 1);
+    }
+
+    #[test]
+    fn clips_negative_coordinates() {
+        let rect: Rectangle<u8> = Rectangle::new(Coord::new(-3, -2), Coord::new(2, 1));
+        let mut runs = rect.scanlines();
+
+        assert_eq!(runs.next(), Some(Scanline { y: 0, x: 0..3 }));
+        assert_eq!(runs.next(), Some(Scanline { y: 1, x: 0..3 }));
+        assert_eq!(runs.next(), None);
+    }
+}
@@ -0,0 +1,727 @@
+//! Anti-aliased primitive rendering
+//!
+//! Wrapping a [`Line`](../line/struct.Line.html) or [`Circle`](../circle/struct.Circle.html) with
+//! [`Antialias::antialiased`](./trait.Antialias.html#tymethod.antialiased) draws it with smooth
+//! edges. Edge pixels are [blended](../../pixelcolor/blend/trait.Blend.html) between the stroke or
+//! fill color and a background color according to how much of the pixel the shape covers.
+//!
+//! Text can be wrapped too. Fonts with 2 or 4 bits per pixel (see
+//! [`FONT_IMAGE_BPP`](../../fonts/font_builder/trait.FontBuilderConf.html#associatedconstant.FONT_IMAGE_BPP))
+//! blend each glyph pixel between the stroke color and the fill color, or the background color
+//! if the text has no fill.
+//!
+//! Displays can't be read back, so the background color has to be given up front. It should match
+//! whatever the primitive is drawn over.
+//!
+//! Color types that can't show intermediate colors, like
+//! [`BinaryColor`](../../pixelcolor/enum.BinaryColor.html), are drawn exactly the same as the
+//! aliased primitive. So are lines wider than one pixel and dashed lines or circle strokes.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::egline;
+//! use embedded_graphics::primitives::smooth::Antialias;
+//! # use embedded_graphics::mock_display::Display;
+//! # let mut display = Display::default();
+//!
+//! let line = egline!((0, 0), (20, 7), stroke = Some(255u8));
+//!
+//! display.draw(line.antialiased(0u8));
+//! ```
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Drawable, Pixel};
+use crate::fonts::font_builder::{FontBuilder, FontBuilderConf, FontBuilderIterator};
+use crate::pixelcolor::blend::Blend;
+use crate::pixelcolor::PixelColor;
+use crate::primitives::circle::CircleIterator;
+use crate::primitives::line::LineIterator;
+use crate::primitives::{Circle, Line};
+use crate::style::Styled;
+use crate::transform::Transform;
+
+/// Draw a primitive with anti-aliased edges
+pub trait Antialias<C>: Sized
+where
+    C: PixelColor,
+{
+    /// Wrap the primitive so that it's drawn with smooth edges over `background`
+    fn antialiased(self, background: C) -> Smooth<Self, C>;
+}
+
+impl<C> Antialias<C> for Line<C>
+where
+    C: PixelColor,
+{
+    fn antialiased(self, background: C) -> Smooth<Self, C> {
+        Smooth {
+            primitive: self,
+            background,
+        }
+    }
+}
+
+impl<C> Antialias<C> for Circle<C>
+where
+    C: PixelColor,
+{
+    fn antialiased(self, background: C) -> Smooth<Self, C> {
+        Smooth {
+            primitive: self,
+            background,
+        }
+    }
+}
+
+impl<'a, C, Conf> Antialias<C> for FontBuilder<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    fn antialiased(self, background: C) -> Smooth<Self, C> {
+        Smooth {
+            primitive: self,
+            background,
+        }
+    }
+}
+
+/// An anti-aliased primitive
+///
+/// Created with [`Antialias::antialiased`](./trait.Antialias.html#tymethod.antialiased).
+#[derive(Debug, Clone, Copy)]
+pub struct Smooth<P, C> {
+    /// The wrapped primitive
+    pub primitive: P,
+
+    /// The color edge pixels are blended towards
+    pub background: C,
+}
+
+impl<P, C> Drawable for Smooth<P, C> {}
+
+impl<P, C> Transform for Smooth<P, C>
+where
+    P: Transform,
+    C: Copy,
+{
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            primitive: self.primitive.translate(by),
+            background: self.background,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.primitive.translate_mut(by);
+
+        self
+    }
+}
+
+/// Get the color of an edge pixel, or `None` if it isn't covered at all
+fn edge_pixel<C>(x: i32, y: i32, background: C, color: C, coverage: u8) -> Option<Pixel<C>>
+where
+    C: PixelColor + Blend,
+{
+    if coverage == 0 || x < 0 || y < 0 {
+        None
+    } else {
+        Some(Pixel(
+            Coord::new(x, y).to_unsigned(),
+            background.blend(color, coverage),
+        ))
+    }
+}
+
+impl<C> IntoIterator for Smooth<Line<C>, C>
+where
+    C: PixelColor + Blend,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SmoothLineIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let Line { start, end, style } = self.primitive;
+
+        let mut iter = SmoothLineIterator {
+            aliased: None,
+            color: style.stroke_color.unwrap_or(self.background),
+            background: self.background,
+            steep: false,
+            x: 0,
+            x_end: -1,
+            intery: 0,
+            gradient: 0,
+            pending: None,
+        };
+
+        // Wu's algorithm only draws solid lines one pixel wide
+        if !C::CAN_MIX || style.stroke_width > 1 || style.stroke_dash.is_some() {
+            iter.aliased = Some(self.primitive.into_iter());
+
+            return iter;
+        }
+
+        // Draw nothing for transparent or zero length lines, the same as `Line`
+        if style.stroke_color.is_none() || start == end {
+            return iter;
+        }
+
+        // Walk along the major axis from left to right
+        let steep = (end[1] - start[1]).abs() > (end[0] - start[0]).abs();
+        let (start, end) = if steep {
+            (Coord::new(start[1], start[0]), Coord::new(end[1], end[0]))
+        } else {
+            (start, end)
+        };
+        let (start, end) = if start[0] > end[0] {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let dx = i64::from(end[0] - start[0]);
+        let dy = i64::from(end[1] - start[1]);
+
+        SmoothLineIterator {
+            steep,
+            x: start[0],
+            x_end: end[0],
+            intery: i64::from(start[1]) << 16,
+            gradient: (dy << 16) / dx,
+            ..iter
+        }
+    }
+}
+
+/// Pixel iterator for an anti-aliased line
+///
+/// Uses [Xiaolin Wu's line algorithm](https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm),
+/// which draws two pixels for each step along the major axis and splits the color between them.
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothLineIterator<C>
+where
+    C: PixelColor,
+{
+    /// Set if `C` can't show blended colors or the line is too wide or dashed to smooth
+    aliased: Option<LineIterator<C>>,
+
+    color: C,
+    background: C,
+
+    /// `true` if `x` is the vertical axis
+    steep: bool,
+    x: i32,
+    x_end: i32,
+
+    /// Minor axis position as a 16.16 fixed point number
+    intery: i64,
+    gradient: i64,
+
+    /// The second pixel of the current step
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> SmoothLineIterator<C>
+where
+    C: PixelColor + Blend,
+{
+    fn pixel(&self, x: i32, y: i32, coverage: u8) -> Option<Pixel<C>> {
+        let (x, y) = if self.steep { (y, x) } else { (x, y) };
+
+        edge_pixel(x, y, self.background, self.color, coverage)
+    }
+}
+
+impl<C> Iterator for SmoothLineIterator<C>
+where
+    C: PixelColor + Blend,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(aliased) = self.aliased.as_mut() {
+            return aliased.next();
+        }
+
+        loop {
+            if let Some(pixel) = self.pending.take() {
+                return Some(pixel);
+            }
+
+            if self.x > self.x_end {
+                return None;
+            }
+
+            let x = self.x;
+            let y = (self.intery >> 16) as i32;
+            let frac = ((self.intery >> 8) & 0xff) as u8;
+
+            self.x += 1;
+            self.intery += self.gradient;
+
+            self.pending = self.pixel(x, y + 1, frac);
+
+            if let Some(pixel) = self.pixel(x, y, 255 - frac) {
+                return Some(pixel);
+            }
+        }
+    }
+}
+
+impl<C> IntoIterator for Smooth<Circle<C>, C>
+where
+    C: PixelColor + Blend,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SmoothCircleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let Circle {
+            center,
+            radius,
+            style,
+        } = self.primitive;
+        let extent = (radius + style.stroke_outset()) as i32;
+        let stroke_width = i32::from(style.stroke_width);
+
+        SmoothCircleIterator {
+            aliased: if C::CAN_MIX && style.stroke_dash.is_none() {
+                None
+            } else {
+                Some(self.primitive.into_iter())
+            },
+            center,
+            extent,
+            outer_edge: extent * 256 + 256,
+            inner_edge: (extent - stroke_width) * 256,
+            stroke_color: style.stroke_color.filter(|_| stroke_width > 0),
+            fill_color: style.fill_color,
+            background: self.background,
+            x: -extent - 1,
+            y: -extent - 1,
+        }
+    }
+}
+
+/// Pixel iterator for an anti-aliased circle
+///
+/// The coverage of each pixel is estimated from the distance between its center and the edges of
+/// the stroke.
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothCircleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Set if `C` can't show blended colors or the stroke is dashed
+    aliased: Option<CircleIterator<C>>,
+
+    center: Coord,
+
+    /// Distance from the center to the outside of the stroke
+    extent: i32,
+
+    /// Distance in 1/256ths of a pixel at which the outside of the circle has zero coverage
+    outer_edge: i32,
+
+    /// Distance in 1/256ths of a pixel at which the stroke has zero coverage
+    inner_edge: i32,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+    background: C,
+    x: i32,
+    y: i32,
+}
+
+/// Integer square root, rounded down
+pub(crate) fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    let mut x = n;
+    let mut y = (x + n / x) / 2;
+
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+
+    x
+}
+
+/// Clamp a coverage value in 1/256ths of a pixel to `0..=255`
+fn coverage(value: i32) -> u8 {
+    if value <= 0 {
+        0
+    } else if value >= 255 {
+        255
+    } else {
+        value as u8
+    }
+}
+
+impl<C> Iterator for SmoothCircleIterator<C>
+where
+    C: PixelColor + Blend,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(aliased) = self.aliased.as_mut() {
+            return aliased.next();
+        }
+
+        loop {
+            if self.y > self.extent + 1 {
+                return None;
+            }
+
+            let (tx, ty) = (self.x, self.y);
+
+            self.x += 1;
+            if self.x > self.extent + 1 {
+                self.x = -self.extent - 1;
+                self.y += 1;
+            }
+
+            let distance_sq =
+                (i64::from(tx) * i64::from(tx) + i64::from(ty) * i64::from(ty)) as u64;
+            let distance = isqrt(distance_sq << 16) as i32;
+
+            let outer = coverage(self.outer_edge - distance);
+            let inner = coverage(distance - self.inner_edge);
+
+            let (color, alpha) = match (self.stroke_color, self.fill_color) {
+                (Some(stroke), Some(fill)) => (fill.blend(stroke, inner), outer),
+                (Some(stroke), None) => (stroke, outer.min(inner)),
+                (None, Some(fill)) => (fill, outer),
+                (None, None) => return None,
+            };
+
+            let pixel = edge_pixel(
+                self.center[0] + tx,
+                self.center[1] + ty,
+                self.background,
+                color,
+                alpha,
+            );
+
+            if pixel.is_some() {
+                return pixel;
+            }
+        }
+    }
+}
+
+impl<'a, C, Conf> IntoIterator for Smooth<FontBuilder<'a, C, Conf>, C>
+where
+    C: PixelColor + Blend,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SmoothTextIterator<'a, C, Conf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let style = *self.primitive.current_style();
+
+        SmoothTextIterator {
+            glyphs: (&self.primitive).into_iter(),
+            color: style.stroke_color.unwrap_or(1.into()),
+            background: style.fill_color.unwrap_or(self.background),
+            opaque: style.fill_color.is_some(),
+        }
+    }
+}
+
+/// Pixel iterator for anti-aliased text
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothTextIterator<'a, C, Conf>
+where
+    C: PixelColor,
+{
+    glyphs: FontBuilderIterator<'a, C, Conf>,
+    color: C,
+    background: C,
+
+    /// `true` if pixels the glyphs don't cover are drawn in the fill color
+    opaque: bool,
+}
+
+impl<'a, C, Conf> Iterator for SmoothTextIterator<'a, C, Conf>
+where
+    C: PixelColor + Blend,
+    Conf: FontBuilderConf,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.glyphs.next_frame() {
+            return Some(pixel);
+        }
+
+        loop {
+            let (point, coverage, text_line) = self.glyphs.next_coverage()?;
+            if let Some(color) = text_line {
+                break Some(Pixel(point, color));
+            }
+
+            // Draw the same pixels as the aliased text if `C` can't show blended colors
+            let coverage = match coverage {
+                _ if C::CAN_MIX => coverage,
+                128..=255 => 255,
+                _ => 0,
+            };
+
+            let color = match coverage {
+                0 if !self.opaque => continue,
+                0 => self.background,
+                255 => self.color,
+                _ => self.background.blend(self.color, coverage),
+            };
+
+            break Some(Pixel(point, color));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::Font;
+    use crate::mock_display::{Display, MockDisplay};
+    use crate::pixelcolor::BinaryColor;
+    use crate::style::{DashPattern, StrokeAlignment, WithStyle};
+    use crate::Drawing;
+
+    /// 2 bit per pixel font with one row of pixels per glyph
+    ///
+    /// `a` covers its four pixels by 0, 1/3, 2/3 and all, and every other character is solid.
+    #[derive(Debug, Clone, Copy)]
+    enum Gray2Conf {}
+    impl FontBuilderConf for Gray2Conf {
+        const FONT_IMAGE: &'static [u8] = &[0b00_01_10_11, 0b11_11_11_11];
+        const CHAR_HEIGHT: u32 = 1;
+        const CHAR_WIDTH: u32 = 4;
+        const FONT_IMAGE_WIDTH: u32 = 8;
+        const FONT_IMAGE_BPP: u32 = 2;
+        fn char_offset(c: char) -> u32 {
+            if c == 'a' {
+                0
+            } else {
+                1
+            }
+        }
+    }
+
+    /// 4 bit per pixel font with a single glyph
+    #[derive(Debug, Clone, Copy)]
+    enum Gray4Conf {}
+    impl FontBuilderConf for Gray4Conf {
+        const FONT_IMAGE: &'static [u8] = &[0x0f, 0x80];
+        const CHAR_HEIGHT: u32 = 1;
+        const CHAR_WIDTH: u32 = 4;
+        const FONT_IMAGE_WIDTH: u32 = 4;
+        const FONT_IMAGE_BPP: u32 = 4;
+        fn char_offset(_: char) -> u32 {
+            0
+        }
+    }
+
+    type Gray2<'a, C> = FontBuilder<'a, C, Gray2Conf>;
+
+    fn line(start: (i32, i32), end: (i32, i32)) -> Line<u8> {
+        Line::new(Coord::new(start.0, start.1), Coord::new(end.0, end.1)).stroke(Some(255))
+    }
+
+    #[test]
+    fn straight_lines_are_solid() {
+        let mut display = Display::default();
+        display.draw(line((1, 2), (4, 2)).antialiased(0));
+
+        assert_eq!(display.0[2][..6], [0, 255, 255, 255, 255, 0]);
+        assert!(display.0[1]
+            .iter()
+            .chain(display.0[3].iter())
+            .all(|c| *c == 0));
+    }
+
+    #[test]
+    fn diagonal_line_splits_coverage() {
+        let mut display = Display::default();
+        display.draw(line((0, 0), (4, 2)).antialiased(0));
+
+        // Endpoints are exact, and x = 1 is halfway between rows 0 and 1
+        assert_eq!(display.0[0][..5], [255, 127, 0, 0, 0]);
+        assert_eq!(display.0[1][..5], [0, 128, 255, 127, 0]);
+        assert_eq!(display.0[2][..5], [0, 0, 0, 128, 255]);
+    }
+
+    #[test]
+    fn reversed_line_is_the_same() {
+        let mut a = Display::default();
+        let mut b = Display::default();
+
+        a.draw(line((3, 0), (0, 9)).antialiased(0));
+        b.draw(line((0, 9), (3, 0)).antialiased(0));
+
+        assert_eq!(a, b);
+        assert_eq!(a.0[0][3], 255);
+        assert_eq!(a.0[9][0], 255);
+    }
+
+    #[test]
+    fn transparent_line() {
+        let l: Line<u8> = Line::new(Coord::new(0, 0), Coord::new(5, 5));
+
+        assert_eq!(l.antialiased(0).into_iter().count(), 0);
+    }
+
+    #[test]
+    fn binary_color_is_not_smoothed() {
+        let l = Line::new(Coord::new(0, 0), Coord::new(7, 3)).stroke(Some(BinaryColor::On));
+
+        assert!(l
+            .antialiased(BinaryColor::Off)
+            .into_iter()
+            .eq(l.into_iter()));
+
+        let c = Circle::new(Coord::new(5, 5), 4).fill(Some(BinaryColor::On));
+
+        assert!(c
+            .antialiased(BinaryColor::Off)
+            .into_iter()
+            .eq(c.into_iter()));
+    }
+
+    #[test]
+    fn wide_lines_are_not_smoothed() {
+        let l = line((2, 3), (20, 9)).stroke_width(5);
+
+        assert!(l.antialiased(0).into_iter().eq(l.into_iter()));
+    }
+
+    #[test]
+    fn dashed_primitives_are_not_smoothed() {
+        let l = line((2, 3), (20, 9)).stroke_dash(Some(DashPattern::new(&[2, 2])));
+        let c = Circle::new(Coord::new(8, 8), 6)
+            .stroke(Some(255u8))
+            .stroke_dash(Some(DashPattern::new(&[2, 2])));
+
+        assert!(l.antialiased(0).into_iter().eq(l.into_iter()));
+        assert!(c.antialiased(0).into_iter().eq(c.into_iter()));
+    }
+
+    #[test]
+    fn circle_edges_are_blended() {
+        let mut display = Display::default();
+        display.draw(
+            Circle::new(Coord::new(6, 6), 4)
+                .stroke(None)
+                .fill(Some(255u8))
+                .antialiased(0),
+        );
+
+        assert_eq!(display.0[6][6], 255);
+        assert_eq!(display.0[6][10], 255);
+        assert_eq!(display.0[6][11], 0);
+        assert_eq!(display.0[2][9], 0);
+        assert!(display.0[2][8] > 0 && display.0[2][8] < 255);
+    }
+
+    #[test]
+    fn circle_stroke_over_fill() {
+        let mut display = MockDisplay::<u8>::default();
+        display.draw(
+            Circle::new(Coord::new(6, 6), 4)
+                .stroke(Some(200u8))
+                .fill(Some(100u8))
+                .antialiased(0),
+        );
+
+        assert_eq!(display.0[6][6], 100);
+        assert_eq!(display.0[6][10], 200);
+    }
+
+    #[test]
+    fn circle_stroke_alignment() {
+        let circle = Circle::new(Coord::new(8, 8), 4)
+            .stroke(Some(200u8))
+            .stroke_width(2)
+            .fill(Some(100u8));
+
+        for &(alignment, outside) in [
+            (StrokeAlignment::Inside, 12),
+            (StrokeAlignment::Center, 13),
+            (StrokeAlignment::Outside, 14),
+        ]
+        .iter()
+        {
+            let mut display = Display::default();
+            display.draw(circle.stroke_alignment(alignment).antialiased(0));
+
+            // The fill always ends at the radius, and the stroke reaches out to its alignment
+            assert_eq!(display.0[8][8 + 4 - 2], 100, "{:?}", alignment);
+            assert_eq!(display.0[8][outside], 200, "{:?}", alignment);
+            assert_eq!(display.0[8][outside + 2], 0, "{:?}", alignment);
+        }
+    }
+
+    #[test]
+    fn text_coverage_is_blended() {
+        let mut display = Display::default();
+        display.0[0][0] = 7;
+        display.draw(Gray2::render_str("ab").stroke(Some(255u8)).antialiased(0));
+
+        // Uncovered pixels are left alone
+        assert_eq!(display.0[0][..9], [7, 85, 170, 255, 255, 255, 255, 255, 0]);
+    }
+
+    #[test]
+    fn text_fill_is_background() {
+        let mut display = Display::default();
+        display.draw(
+            Gray2::render_str("a")
+                .stroke(Some(250u8))
+                .fill(Some(100u8))
+                .antialiased(0),
+        );
+
+        assert_eq!(display.0[0][..5], [100, 150, 200, 250, 0]);
+    }
+
+    #[test]
+    fn four_bit_glyphs() {
+        let text: FontBuilder<'_, u8, Gray4Conf> = FontBuilder::render_str("x").stroke(Some(255));
+
+        let mut display = Display::default();
+        display.draw(text.antialiased(0));
+        assert_eq!(display.0[0][..4], [0, 255, 136, 0]);
+
+        // Without antialiasing, only pixels that are at least half covered are drawn
+        let mut display = Display::default();
+        display.draw(text);
+        assert_eq!(display.0[0][..4], [0, 255, 255, 0]);
+    }
+
+    #[test]
+    fn binary_color_text_is_not_smoothed() {
+        let text = Gray2::render_str("a b").stroke(Some(BinaryColor::On));
+
+        assert!(text
+            .antialiased(BinaryColor::Off)
+            .into_iter()
+            .eq(text.into_iter()));
+        assert_eq!(text.into_iter().count(), 10);
+    }
+
+    #[test]
+    fn isqrt_values() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(1 << 40), 1 << 20);
+    }
+}
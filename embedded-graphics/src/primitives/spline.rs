@@ -0,0 +1,539 @@
+//! The spline primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::bezier::div_round;
+use super::edges::{edge_bounds, EdgeStroke, PointPairs};
+use super::thick::{stroke_margin, ThickStrokePixels};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{Intersects, Primitive, Rectangle};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Default number of line segments drawn between each pair of neighbouring points
+pub const DEFAULT_SUBDIVISIONS: u32 = 8;
+
+/// Upper limit on the number of subdivisions, which keeps the integer math from overflowing
+const MAX_SUBDIVISIONS: u32 = 256;
+
+/// Smooth curve through a list of points
+///
+/// The curve is a uniform
+/// [Catmull-Rom spline](https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline),
+/// which passes through every point in turn and bends smoothly at each of them. This makes it a
+/// good fit for plotting sensor readings, where a [`Polyline`](../polyline/struct.Polyline.html)
+/// through the same points looks jagged. The curve can overshoot the points slightly where the data
+/// changes direction sharply.
+///
+/// Each part of the curve between two neighbouring points is drawn as `subdivisions` straight line
+/// segments, so higher values give smoother curves at the cost of more work.
+///
+/// Splines are stroked like polylines, so they can be drawn with any stroke width, line join and
+/// stroke cap. Dash patterns are only applied to strokes one pixel wide.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Spline;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let readings = [
+///     Coord::new(0, 12),
+///     Coord::new(5, 4),
+///     Coord::new(10, 9),
+///     Coord::new(15, 3),
+///     Coord::new(22, 10),
+/// ];
+///
+/// let curve = Spline::new(&readings).subdivisions(4).stroke(Some(1u8));
+///
+/// display.draw(curve);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Spline<'a, C: PixelColor> {
+    /// Points the curve passes through
+    pub points: &'a [Coord],
+
+    /// Offset added to every point
+    pub offset: Coord,
+
+    /// Number of line segments between each pair of neighbouring points
+    pub subdivisions: u32,
+
+    /// Spline style
+    pub style: Style<C>,
+}
+
+impl<'a, C> Spline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a new spline through a list of points
+    pub fn new(points: &'a [Coord]) -> Self {
+        Spline {
+            points,
+            offset: Coord::new(0, 0),
+            subdivisions: DEFAULT_SUBDIVISIONS,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the number of line segments between each pair of neighbouring points
+    ///
+    /// Values are limited to between 1, which draws the same lines as a polyline, and 256.
+    pub fn subdivisions(mut self, subdivisions: u32) -> Self {
+        self.subdivisions = subdivisions;
+
+        self
+    }
+
+    /// Iterate over the end points of the line segments this curve is drawn with
+    ///
+    /// The curve passes through every point of the spline, so every `subdivisions`th point
+    /// returned is one of them, starting with the first.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::primitives::Spline;
+    ///
+    /// let points = [Coord::new(0, 0), Coord::new(10, 10), Coord::new(20, 0)];
+    /// let spline: Spline<u8> = Spline::new(&points).subdivisions(4);
+    ///
+    /// let mut curve = spline.curve_points();
+    ///
+    /// assert_eq!(curve.next(), Some(Coord::new(0, 0)));
+    /// assert_eq!(curve.nth(3), Some(Coord::new(10, 10)));
+    /// assert_eq!(curve.last(), Some(Coord::new(20, 0)));
+    /// ```
+    pub fn curve_points(&self) -> SplinePoints<'a> {
+        SplinePoints {
+            points: self.points,
+            offset: self.offset,
+            subdivisions: self.subdivisions.clamp(1, MAX_SUBDIVISIONS),
+            segment: 0,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the points of a flattened spline
+#[derive(Debug, Copy, Clone)]
+pub struct SplinePoints<'a> {
+    points: &'a [Coord],
+    offset: Coord,
+    subdivisions: u32,
+    /// Index of the point the current part of the curve starts at
+    segment: usize,
+    /// Position along the current part, in subdivisions
+    index: u32,
+}
+
+impl SplinePoints<'_> {
+    /// Point `index` in `points`, with the first and last points repeated past either end
+    fn control(&self, index: isize) -> Coord {
+        let last = self.points.len() as isize - 1;
+
+        self.points[index.clamp(0, last) as usize]
+    }
+
+    /// Evaluate the part of the curve starting at point `segment` at `t = index / subdivisions`
+    fn point_at(&self, segment: usize, index: u32) -> Coord {
+        let i = segment as isize;
+        let [p0, p1, p2, p3] = [
+            self.control(i - 1),
+            self.control(i),
+            self.control(i + 1),
+            self.control(i + 2),
+        ];
+
+        let n = i64::from(self.subdivisions);
+        let t = i64::from(index);
+
+        // `2 * n³ * q(t)` for the Catmull-Rom polynomial
+        // `q(t) = (2p1 + (p2 - p0)t + (2p0 - 5p1 + 4p2 - p3)t² + (3p1 - p0 - 3p2 + p3)t³) / 2`
+        let axis = |axis: usize| {
+            let (a, b, c, d) = (
+                i64::from(p0[axis]),
+                i64::from(p1[axis]),
+                i64::from(p2[axis]),
+                i64::from(p3[axis]),
+            );
+
+            let value = 2 * b * n * n * n
+                + (c - a) * t * n * n
+                + (2 * a - 5 * b + 4 * c - d) * t * t * n
+                + (3 * b - a - 3 * c + d) * t * t * t;
+
+            div_round(value, 2 * n * n * n) as i32
+        };
+
+        Coord::new(axis(0), axis(1)) + self.offset
+    }
+}
+
+impl Iterator for SplinePoints<'_> {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.points.is_empty() {
+            return None;
+        }
+
+        // The first point, then `subdivisions` points for each part of the curve
+        if self.segment == 0 && self.index == 0 {
+            self.index = 1;
+
+            return Some(self.points[0] + self.offset);
+        }
+
+        if self.segment + 1 >= self.points.len() {
+            return None;
+        }
+
+        let point = self.point_at(self.segment, self.index);
+
+        if self.index == self.subdivisions {
+            self.segment += 1;
+            self.index = 1;
+        } else {
+            self.index += 1;
+        }
+
+        Some(point)
+    }
+}
+
+impl<'a, C> Primitive for Spline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Intersects<C> for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
+impl<'a, C> Dimensions for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        edge_bounds(PointPairs::new(self.curve_points())).map_or(self.offset, |(tl, _)| tl)
+    }
+
+    fn bottom_right(&self) -> Coord {
+        edge_bounds(PointPairs::new(self.curve_points())).map_or(self.offset, |(_, br)| br)
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<'a, C> WithStyle<C> for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<'a, C> Styled<C> for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<'a, C> IntoIterator for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SplineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C> IntoIterator for &Spline<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SplineIterator<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let width = self.style.stroke_width;
+
+        SplineIterator {
+            thin: if width == 1 {
+                Some(EdgeStroke::new(
+                    PointPairs::new(self.curve_points()),
+                    self.style,
+                ))
+            } else {
+                None
+            },
+            thick: if width > 1 {
+                Some(ThickStrokePixels::new(self.curve_points(), &self.style))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Pixel iterator for each pixel along the spline
+#[derive(Debug, Clone)]
+pub struct SplineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    /// Bresenham stroke for splines one pixel wide
+    thin: Option<EdgeStroke<C, PointPairs<SplinePoints<'a>>>>,
+    /// Rasterizer for wider splines
+    thick: Option<ThickStrokePixels<C, SplinePoints<'a>>>,
+}
+
+impl<'a, C> Iterator for SplineIterator<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(thin) = &mut self.thin {
+            thin.next()
+        } else if let Some(thick) = &mut self.thick {
+            thick.next()
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C> Drawable for Spline<'a, C> where C: PixelColor {}
+
+impl<'a, C> Transform for Spline<'a, C>
+where
+    C: PixelColor,
+{
+    /// Translate the spline by (x, y) pixels, returning a new `Spline`. For a mutating transform,
+    /// see `translate_mut`.
+    ///
+    /// The points are borrowed and left untouched; the translation is stored in the spline's
+    /// `offset` instead.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Spline;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let points = [Coord::new(5, 10), Coord::new(15, 20), Coord::new(25, 10)];
+    /// let spline: Spline<u8> = Spline::new(&points);
+    /// let moved = spline.translate(Coord::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left(), Coord::new(15, 20));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            offset: self.offset + by,
+            ..*self
+        }
+    }
+
+    /// Translate the spline by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.offset += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::Polyline;
+    use crate::Drawing;
+
+    #[test]
+    fn passes_through_every_point() {
+        let points = [
+            Coord::new(1, 12),
+            Coord::new(6, 2),
+            Coord::new(11, 9),
+            Coord::new(20, 4),
+        ];
+        let spline: Spline<u8> = Spline::new(&points).subdivisions(5);
+
+        assert_eq!(spline.curve_points().count(), 1 + 3 * 5);
+
+        for (i, point) in spline.curve_points().step_by(5).enumerate() {
+            assert_eq!(point, points[i]);
+        }
+    }
+
+    #[test]
+    fn one_subdivision_is_a_polyline() {
+        let points = [
+            Coord::new(1, 12),
+            Coord::new(6, 2),
+            Coord::new(11, 9),
+            Coord::new(20, 4),
+        ];
+
+        let mut spline = Display::default();
+        spline.draw(Spline::new(&points).subdivisions(1).stroke(Some(1u8)));
+
+        let mut polyline = Display::default();
+        polyline.draw(Polyline::new(&points).stroke(Some(1u8)));
+
+        assert_eq!(spline, polyline);
+    }
+
+    #[test]
+    fn collinear_points_stay_straight() {
+        let points = [Coord::new(0, 0), Coord::new(8, 4), Coord::new(16, 8)];
+        let spline: Spline<u8> = Spline::new(&points).subdivisions(4);
+
+        // On the line, give or take rounding
+        assert!(spline.curve_points().all(|p| (p[1] * 2 - p[0]).abs() <= 1));
+    }
+
+    #[test]
+    fn smooth_through_peak() {
+        let points = [Coord::new(0, 10), Coord::new(8, 2), Coord::new(16, 10)];
+        let spline: Spline<u8> = Spline::new(&points).subdivisions(4);
+
+        // Symmetric about the peak, give or take rounding, rising to meet it and falling after it
+        let curve: [Coord; 9] = {
+            let mut curve = [Coord::new(0, 0); 9];
+            for (slot, point) in curve.iter_mut().zip(spline.curve_points()) {
+                *slot = point;
+            }
+            curve
+        };
+
+        for i in 0..4 {
+            assert!(curve[i][1] > curve[i + 1][1]);
+            assert_eq!(curve[i][1], curve[8 - i][1]);
+            assert!((curve[i][0] + curve[8 - i][0] - 16).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn short_lists() {
+        let empty: Spline<u8> = Spline::new(&[]);
+        assert_eq!(empty.curve_points().count(), 0);
+        assert_eq!(empty.stroke(Some(1)).into_iter().count(), 0);
+
+        let single = [Coord::new(3, 3)];
+        let single: Spline<u8> = Spline::new(&single);
+        assert_eq!(single.curve_points().count(), 1);
+    }
+
+    #[test]
+    fn dimensions_include_overshoot() {
+        let points = [
+            Coord::new(0, 10),
+            Coord::new(4, 10),
+            Coord::new(8, 0),
+            Coord::new(12, 0),
+        ];
+        let spline: Spline<u8> = Spline::new(&points);
+
+        assert!(spline.top_left()[1] < 0);
+        assert!(spline.bottom_right()[1] > 10);
+        assert_eq!(spline.top_left()[0], 0);
+        assert_eq!(spline.bottom_right()[0], 12);
+    }
+}
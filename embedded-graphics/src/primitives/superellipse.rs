@@ -0,0 +1,528 @@
+//! The superellipse primitive
+
+use super::super::drawable::*;
+use super::super::transform::*;
+use super::edges::{is_aligned_border, is_stroke_pixel, styled_pixel, FillArea};
+use crate::coord::{Coord, ToUnsigned};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::{
+    ContainsPoint, Intersects, Offset, Primitive, Rectangle, StrokeContainsPoint,
+};
+use crate::style::WithStyle;
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
+
+/// Exponent of a squircle, the default shape of a new superellipse
+pub const SQUIRCLE: u32 = 4;
+
+/// Superellipse primitive
+///
+/// The shape covered by `|x / radius_x|ⁿ + |y / radius_y|ⁿ < 1` around the center, where `n` is
+/// the exponent. An exponent of 2 gives an ellipse, and larger exponents flatten the sides and
+/// tighten the corners towards a rectangle. The default exponent of 4, the
+/// [squircle](./constant.SQUIRCLE.html), is a popular shape for icon backgrounds. An exponent of 1
+/// gives a diamond.
+///
+/// Like an [`EllipseSector`](../ellipse/struct.EllipseSector.html), the radii are extended by half
+/// a pixel so a radius of `r` covers `r` pixels on either side of the center. The stroke is drawn
+/// inside the outline unless the style's
+/// [`stroke_alignment`](../../style/struct.Style.html#structfield.stroke_alignment) says
+/// otherwise. Dash patterns are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Superellipse;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// // App icon background
+/// let icon = Superellipse::new(Coord::new(8, 8), 7, 7)
+///     .stroke(Some(1u8))
+///     .fill(Some(2u8));
+///
+/// // A flatter, wider badge
+/// let badge = Superellipse::new(Coord::new(18, 8), 5, 3).exponent(6).fill(Some(3u8));
+///
+/// display.draw(icon);
+/// display.draw(badge);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Superellipse<C: PixelColor> {
+    /// Center point
+    pub center: Coord,
+
+    /// Horizontal radius
+    pub radius_x: u32,
+
+    /// Vertical radius
+    pub radius_y: u32,
+
+    /// Exponent, at least 1
+    pub exponent: u32,
+
+    /// Superellipse style
+    pub style: Style<C>,
+}
+
+impl<C> Superellipse<C>
+where
+    C: PixelColor,
+{
+    /// Create a new squircle with the given radii
+    pub fn new(center: Coord, radius_x: u32, radius_y: u32) -> Self {
+        Superellipse {
+            center,
+            radius_x,
+            radius_y,
+            exponent: SQUIRCLE,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the exponent, where 2 is an ellipse and larger values are more rectangular
+    ///
+    /// An exponent of zero is treated as one.
+    pub fn exponent(mut self, exponent: u32) -> Self {
+        self.exponent = exponent;
+
+        self
+    }
+
+    fn shape(&self) -> SuperellipseShape {
+        SuperellipseShape {
+            center: self.center,
+            width: 2 * u64::from(self.radius_x) + 1,
+            height: 2 * u64::from(self.radius_y) + 1,
+            exponent: self.exponent.max(1),
+        }
+    }
+
+    /// Bounding box including the stroke, returned as `(top_left, bottom_right)`
+    fn bounds(&self) -> (Coord, Coord) {
+        let outset = self.style.stroke_outset();
+        let radius = Coord::new(
+            (self.radius_x + outset) as i32,
+            (self.radius_y + outset) as i32,
+        );
+
+        (self.center - radius, self.center + radius)
+    }
+}
+
+/// Fixed point representation of 1.0 used by the coverage test
+const UNIT: u64 = 1 << 24;
+
+/// Coverage test for the pixels of a superellipse
+#[derive(Debug, Copy, Clone)]
+struct SuperellipseShape {
+    center: Coord,
+
+    /// Width and height in half pixels, so the radii are extended by half a pixel
+    width: u64,
+    height: u64,
+
+    exponent: u32,
+}
+
+impl SuperellipseShape {
+    /// `(|offset| / (size / 2))ⁿ` as a fraction of [`UNIT`], or `None` if it's `1.0` or more
+    fn term(&self, offset: i32, size: u64) -> Option<u64> {
+        let ratio = 2 * u64::from(offset.unsigned_abs()) * UNIT / size;
+
+        if ratio >= UNIT {
+            return None;
+        }
+
+        Some((0..self.exponent).fold(UNIT, |power, _| power * ratio / UNIT))
+    }
+
+    /// Returns `true` if the superellipse covers the pixel at `point`
+    fn contains(&self, point: Coord) -> bool {
+        let x = self.term(point[0].wrapping_sub(self.center[0]), self.width);
+        let y = self.term(point[1].wrapping_sub(self.center[1]), self.height);
+
+        match (x, y) {
+            (Some(x), Some(y)) => x + y < UNIT,
+            _ => false,
+        }
+    }
+}
+
+impl<C> Primitive for Superellipse<C> where C: PixelColor {}
+
+impl<C> Intersects<C> for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        area.overlaps(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<C> ContainsPoint for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Coord) -> bool {
+        self.shape().contains(point)
+    }
+}
+
+impl<C> StrokeContainsPoint for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn stroke_contains(&self, point: Coord) -> bool {
+        let shape = self.shape();
+
+        is_stroke_pixel(
+            |p| shape.contains(p),
+            &self.style,
+            point,
+            self.style.stroke_outset() as i32,
+        )
+    }
+}
+
+impl<C> Offset for Superellipse<C>
+where
+    C: PixelColor,
+{
+    /// Both radii change by `distance`, keeping the exponent
+    fn offset(&self, distance: i32) -> Self {
+        Self {
+            radius_x: (self.radius_x as i32 + distance).max(0) as u32,
+            radius_y: (self.radius_y as i32 + distance).max(0) as u32,
+            ..*self
+        }
+    }
+}
+
+impl<C> Dimensions for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.bounds().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bounds().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        (self.bottom_right() - self.top_left()).to_unsigned()
+    }
+}
+
+impl<C> WithStyle<C> for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn style(mut self, style: Style<C>) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    fn stroke(mut self, color: Option<C>) -> Self {
+        self.style.stroke_color = color;
+
+        self
+    }
+
+    fn stroke_width(mut self, width: u8) -> Self {
+        self.style.stroke_width = width;
+
+        self
+    }
+
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
+    fn fill(mut self, color: Option<C>) -> Self {
+        self.style.fill_color = color;
+
+        self
+    }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Superellipse<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
+}
+
+impl<C> IntoIterator for Superellipse<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SuperellipseIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<C> IntoIterator for &Superellipse<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SuperellipseIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let visible = self.style.has_fill()
+            || (self.style.stroke_color.is_some() && self.style.stroke_width > 0);
+        let (tl, br) = self.bounds();
+
+        SuperellipseIterator {
+            shape: self.shape(),
+            area: FillArea::new(Some((tl, br + Coord::new(1, 1))).filter(|_| visible)),
+            style: self.style,
+            pending: None,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the superellipse fill and border
+#[derive(Debug, Clone, Copy)]
+pub struct SuperellipseIterator<C>
+where
+    C: PixelColor,
+{
+    shape: SuperellipseShape,
+    area: FillArea,
+    style: Style<C>,
+
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
+}
+
+impl<C> Iterator for SuperellipseIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
+        }
+
+        let shape = self.shape;
+        let style = self.style;
+        let width = i32::from(style.stroke_width);
+        let outset = style.stroke_outset() as i32;
+
+        let (pixel, over) = self.area.find_map(|p| {
+            let stroke = style.stroke_color.filter(|_| {
+                width > 0 && is_aligned_border(|p| shape.contains(p), p, width, outset)
+            });
+
+            styled_pixel(&style, p, stroke, shape.contains(p))
+        })?;
+        self.pending = over;
+
+        Some(pixel)
+    }
+}
+
+impl<C> Clip for SuperellipseIterator<C>
+where
+    C: PixelColor,
+{
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
+    }
+}
+
+impl<C> Drawable for Superellipse<C> where C: PixelColor {}
+
+impl<C> Transform for Superellipse<C>
+where
+    C: PixelColor,
+{
+    /// Translate the superellipse's center by (x, y) pixels, returning a new `Superellipse`. For a
+    /// mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Superellipse;
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// let icon: Superellipse<u8> = Superellipse::new(Coord::new(10, 10), 8, 8);
+    /// let moved = icon.translate(Coord::new(5, 5));
+    ///
+    /// assert_eq!(moved.center, Coord::new(15, 15));
+    /// ```
+    fn translate(&self, by: Coord) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the superellipse's center by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::EllipseSector;
+    use crate::Drawing;
+
+    #[test]
+    fn exponent_two_is_an_ellipse() {
+        let superellipse = Superellipse::new(Coord::new(11, 7), 10, 5)
+            .exponent(2)
+            .fill(Some(1u8));
+        let ellipse = EllipseSector::new(Coord::new(11, 7), 10, 5, 0, 360).fill(Some(1u8));
+
+        assert!(superellipse.into_iter().eq(ellipse.into_iter()));
+    }
+
+    #[test]
+    fn squircle_is_between_ellipse_and_square() {
+        let center = Coord::new(8, 8);
+        let mut ellipse = Display::default();
+        let mut squircle = Display::default();
+        let mut boxy = Display::default();
+
+        ellipse.draw(Superellipse::new(center, 6, 6).exponent(2).fill(Some(1u8)));
+        squircle.draw(Superellipse::new(center, 6, 6).fill(Some(1u8)));
+        boxy.draw(Superellipse::new(center, 6, 6).exponent(20).fill(Some(1u8)));
+
+        assert!(ellipse.count(1) < squircle.count(1));
+        assert!(squircle.count(1) < boxy.count(1));
+        assert!(boxy.count(1) <= 13 * 13);
+
+        // Reaches the radius along both axes, but not into the corners
+        assert_eq!(squircle.0[8][2], 1);
+        assert_eq!(squircle.0[2][8], 1);
+        assert_eq!(squircle.0[8][1], 0);
+        assert_eq!(squircle.0[2][2], 0);
+    }
+
+    #[test]
+    fn diamond() {
+        let mut display = Display::default();
+        display.draw(
+            Superellipse::new(Coord::new(5, 5), 3, 3)
+                .exponent(1)
+                .fill(Some(1u8)),
+        );
+
+        assert_eq!(display.count(1), 25);
+        assert_eq!(display.0[5][2], 1);
+        assert_eq!(display.0[4][2], 0);
+    }
+
+    #[test]
+    fn stroke_inside_fill() {
+        let shape = Superellipse::new(Coord::new(12, 8), 7, 6)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut display = Display::default();
+        display.draw(shape);
+
+        let mut filled = Display::default();
+        filled.draw(shape.stroke(None));
+
+        assert_eq!(display.count(1) + display.count(2), filled.count(2));
+        assert_eq!(display.0[8][5], 1);
+        assert_eq!(display.0[8][6], 2);
+    }
+
+    #[test]
+    fn dimensions() {
+        let shape: Superellipse<u8> = Superellipse::new(Coord::new(10, 8), 6, 4);
+
+        assert_eq!(shape.top_left(), Coord::new(4, 4));
+        assert_eq!(shape.bottom_right(), Coord::new(16, 12));
+        assert_eq!(shape.size(), UnsignedCoord::new(12, 8));
+
+        let outside = shape
+            .stroke(Some(1))
+            .stroke_width(2)
+            .stroke_alignment(StrokeAlignment::Outside);
+        assert_eq!(outside.top_left(), Coord::new(2, 2));
+    }
+
+    #[test]
+    fn contains() {
+        let shape: Superellipse<u8> = Superellipse::new(Coord::new(10, 8), 6, 4);
+
+        assert!(shape.contains(Coord::new(10, 8)));
+        assert!(shape.contains(Coord::new(4, 8)));
+        assert!(!shape.contains(Coord::new(3, 8)));
+        assert!(!shape.contains(Coord::new(4, 4)));
+        assert!(!shape.contains(Coord::new(-1000, 8)));
+    }
+}
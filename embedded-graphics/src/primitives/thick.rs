@@ -0,0 +1,316 @@
+//! Rasterization of strokes wider than one pixel
+//!
+//! A thick stroke is drawn by testing whether each pixel in its bounding box is covered by one of
+//! its segments, corners or end caps. Pixels are sampled at integer coordinates, so a stroke with an
+//! odd width is centered on the pixels of the equivalent one pixel wide line.
+
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::primitives::edges::FillArea;
+use crate::primitives::smooth::isqrt;
+use crate::style::{LineJoin, StrokeCap, Style};
+
+/// Subpixel precision used for corner geometry
+const SUBPIXEL: i64 = 256;
+
+/// Maximum distance of a miter's tip from its vertex, in multiples of half the stroke width
+const MITER_LIMIT: i64 = 4;
+
+type Vector = (i64, i64);
+
+fn vector(from: Coord, to: Coord) -> Vector {
+    (
+        i64::from(to[0]) - i64::from(from[0]),
+        i64::from(to[1]) - i64::from(from[1]),
+    )
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+fn cross(a: Vector, b: Vector) -> i64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+/// Returns `true` if `point` lies inside or on the edge of a convex polygon
+fn in_convex(polygon: &[Vector], point: Vector) -> bool {
+    let mut positive = false;
+    let mut negative = false;
+
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let side = cross((b.0 - a.0, b.1 - a.1), (point.0 - a.0, point.1 - a.1));
+
+        positive |= side > 0;
+        negative |= side < 0;
+    }
+
+    !(positive && negative)
+}
+
+/// Furthest a stroke `width` pixels wide can reach past its vertices, in both directions
+pub(crate) fn stroke_margin(width: u8) -> Coord {
+    // Miters are the furthest a stroke can extend from its vertices
+    let margin = (i64::from(width) * MITER_LIMIT / 2 + 1) as i32;
+
+    Coord::new(margin, margin)
+}
+
+/// Coverage test for a thick stroke along a series of connected vertices
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ThickStroke<V> {
+    vertices: V,
+    width: i64,
+    join: LineJoin,
+    cap: StrokeCap,
+}
+
+impl<V> ThickStroke<V>
+where
+    V: Iterator<Item = Coord> + Clone,
+{
+    pub(crate) fn new(vertices: V, width: u8, join: LineJoin, cap: StrokeCap) -> Self {
+        Self {
+            vertices,
+            width: i64::from(width),
+            join,
+            cap,
+        }
+    }
+
+    /// Bounding box of every pixel the stroke can cover, returned as `(top_left, bottom_right)`
+    ///
+    /// The bottom right corner is exclusive.
+    pub(crate) fn bounds(&self) -> Option<(Coord, Coord)> {
+        let margin = stroke_margin(self.width as u8);
+
+        self.vertices
+            .clone()
+            .fold(None, |bounds, v| {
+                let (tl, br) = bounds.unwrap_or((v, v));
+
+                Some((
+                    Coord::new(tl[0].min(v[0]), tl[1].min(v[1])),
+                    Coord::new(br[0].max(v[0]), br[1].max(v[1])),
+                ))
+            })
+            .map(|(tl, br)| (tl - margin, br + margin + Coord::new(1, 1)))
+    }
+
+    /// Returns `true` if the stroke covers the pixel at `point`
+    pub(crate) fn contains(&self, point: Coord) -> bool {
+        let mut vertices = self.vertices.clone();
+
+        let mut current = match vertices.next() {
+            Some(vertex) => vertex,
+            None => return false,
+        };
+        let mut previous = None;
+
+        for next in vertices {
+            // Repeated vertices have no direction, so skip them
+            if next == current {
+                continue;
+            }
+
+            if self.in_segment(current, next, point) {
+                return true;
+            }
+
+            let covered = match previous {
+                Some(previous) => self.in_join(previous, current, next, point),
+                None => self.in_cap(next, current, point),
+            };
+
+            if covered {
+                return true;
+            }
+
+            previous = Some(current);
+            current = next;
+        }
+
+        previous.is_some_and(|previous| self.in_cap(previous, current, point))
+    }
+
+    /// Length of `v` in subpixels
+    fn length(v: Vector) -> i64 {
+        isqrt((dot(v, v) * SUBPIXEL * SUBPIXEL) as u64) as i64
+    }
+
+    /// Returns `true` if a point `perpendicular / |d|` pixels to the side of a segment with
+    /// direction `d` lies within the stroke
+    fn in_band(&self, perpendicular: i64, length: i64) -> bool {
+        let offset = 2 * SUBPIXEL * perpendicular;
+
+        offset >= -self.width * length && offset < self.width * length
+    }
+
+    fn in_segment(&self, start: Coord, end: Coord, point: Coord) -> bool {
+        let d = vector(start, end);
+        let p = vector(start, point);
+        let along = dot(d, p);
+
+        along >= 0 && along <= dot(d, d) && self.in_band(cross(d, p), Self::length(d))
+    }
+
+    fn in_circle(&self, center: Coord, point: Coord) -> bool {
+        let p = vector(center, point);
+
+        4 * dot(p, p) <= self.width * self.width
+    }
+
+    /// Cap at `end` of the segment from `start` to `end`
+    fn in_cap(&self, start: Coord, end: Coord, point: Coord) -> bool {
+        match self.cap {
+            StrokeCap::Butt => false,
+            StrokeCap::Round => self.in_circle(end, point),
+            StrokeCap::Square => {
+                let d = vector(start, end);
+                let p = vector(end, point);
+                let along = dot(d, p);
+                let length = Self::length(d);
+
+                along > 0
+                    && 2 * SUBPIXEL * along <= self.width * length
+                    && self.in_band(cross(d, p), length)
+            }
+        }
+    }
+
+    /// Join at `vertex` between the segments from `previous` and to `next`
+    fn in_join(&self, previous: Coord, vertex: Coord, next: Coord, point: Coord) -> bool {
+        if self.join == LineJoin::Round {
+            return self.in_circle(vertex, point);
+        }
+
+        let d1 = vector(previous, vertex);
+        let d2 = vector(vertex, next);
+        let turn = cross(d1, d2).signum();
+
+        // Straight through, or doubling back on itself
+        if turn == 0 {
+            return false;
+        }
+
+        // Offsets from the vertex to the outer corners of both segments, in subpixels
+        let half_width = self.width * SUBPIXEL / 2;
+        let outer_normal = |d: Vector| {
+            let length = Self::length(d);
+
+            (
+                turn * d.1 * half_width * SUBPIXEL / length,
+                -turn * d.0 * half_width * SUBPIXEL / length,
+            )
+        };
+        let n1 = outer_normal(d1);
+        let n2 = outer_normal(d2);
+
+        let v = (
+            i64::from(vertex[0]) * SUBPIXEL,
+            i64::from(vertex[1]) * SUBPIXEL,
+        );
+        let p = (
+            i64::from(point[0]) * SUBPIXEL,
+            i64::from(point[1]) * SUBPIXEL,
+        );
+        let corner1 = (v.0 + n1.0, v.1 + n1.1);
+        let corner2 = (v.0 + n2.0, v.1 + n2.1);
+
+        let sum = (n1.0 + n2.0, n1.1 + n2.1);
+        let sum_sq = dot(sum, sum);
+
+        // The miter's tip is `1 / cos(θ / 2)` half widths from the vertex, where θ is the angle
+        // between the two normals and `|n1 + n2| = 2 * half_width * cos(θ / 2)`
+        let within_limit = MITER_LIMIT * MITER_LIMIT * sum_sq >= 4 * half_width * half_width;
+
+        if self.join == LineJoin::Miter && within_limit {
+            let scale = 2 * half_width * half_width;
+            let tip = (v.0 + sum.0 * scale / sum_sq, v.1 + sum.1 * scale / sum_sq);
+
+            in_convex(&[v, corner1, tip, corner2], p)
+        } else {
+            in_convex(&[v, corner1, corner2], p)
+        }
+    }
+}
+
+/// Iterator over the on screen pixels covered by a thick stroke
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ThickStrokePoints<V> {
+    stroke: ThickStroke<V>,
+    area: FillArea,
+}
+
+impl<V> ThickStrokePoints<V>
+where
+    V: Iterator<Item = Coord> + Clone,
+{
+    pub(crate) fn new(vertices: V, width: u8, join: LineJoin, cap: StrokeCap) -> Self {
+        let stroke = ThickStroke::new(vertices, width, join, cap);
+
+        Self {
+            area: FillArea::new(stroke.bounds()),
+            stroke,
+        }
+    }
+}
+
+impl<V> Iterator for ThickStrokePoints<V>
+where
+    V: Iterator<Item = Coord> + Clone,
+{
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke = &self.stroke;
+
+        self.area.find(|p| stroke.contains(*p))
+    }
+}
+
+/// Pixel iterator for a thick stroke
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ThickStrokePixels<C, V> {
+    points: ThickStrokePoints<V>,
+    color: Option<C>,
+}
+
+impl<C, V> ThickStrokePixels<C, V>
+where
+    C: PixelColor,
+    V: Iterator<Item = Coord> + Clone,
+{
+    pub(crate) fn new(vertices: V, style: &Style<C>) -> Self {
+        Self {
+            points: ThickStrokePoints::new(
+                vertices,
+                style.stroke_width,
+                style.line_join,
+                style.stroke_cap,
+            ),
+            color: style.stroke_color,
+        }
+    }
+
+    /// Returns `true` if the stroke covers the pixel at `point`, whether or not it was returned yet
+    pub(crate) fn covers(&self, point: Coord) -> bool {
+        self.color.is_some() && self.points.stroke.contains(point)
+    }
+}
+
+impl<C, V> Iterator for ThickStrokePixels<C, V>
+where
+    C: PixelColor,
+    V: Iterator<Item = Coord> + Clone,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.color?;
+
+        self.points.next().map(|p| Pixel(p.to_unsigned(), color))
+    }
+}
@@ -4,11 +4,17 @@ use super::super::drawable::*;
 use super::super::transform::*;
 use crate::coord::{Coord, ToUnsigned};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::line::{Line, LineIterator};
-use crate::primitives::Primitive;
-use crate::style::Style;
+use crate::primitives::edges::{styled_pixel, FillArea};
+use crate::primitives::line::is_on_line;
+use crate::primitives::smooth::isqrt;
+use crate::primitives::thick::stroke_margin;
+use crate::primitives::{ContainsPoint, Intersects, Offset, Primitive, Rectangle};
 use crate::style::WithStyle;
-use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use crate::style::{
+    DashPattern, DrawMode, FillPattern, Gradient, LineJoin, StrokeAlignment, StrokeCap,
+    StrokeOverlap, Style, Styled,
+};
+use crate::unsignedcoord::UnsignedCoord;
 
 /// Triangle primitive
 ///
@@ -56,6 +62,17 @@ pub struct Triangle<C: PixelColor> {
 
 impl<C> Primitive for Triangle<C> where C: PixelColor {}
 
+impl<C> Intersects<C> for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn intersects(&self, area: &Rectangle<C>) -> bool {
+        let margin = stroke_margin(self.style.stroke_width);
+
+        area.overlaps(self.top_left() - margin, self.bottom_right() + margin)
+    }
+}
+
 impl<C> Dimensions for Triangle<C>
 where
     C: PixelColor,
@@ -138,27 +155,187 @@ where
         self
     }
 
+    fn stroke_dash(mut self, pattern: Option<DashPattern>) -> Self {
+        self.style.stroke_dash = pattern;
+
+        self
+    }
+
+    fn line_join(mut self, join: LineJoin) -> Self {
+        self.style.line_join = join;
+
+        self
+    }
+
+    fn stroke_cap(mut self, cap: StrokeCap) -> Self {
+        self.style.stroke_cap = cap;
+
+        self
+    }
+
+    fn stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.style.stroke_alignment = alignment;
+
+        self
+    }
+
+    fn stroke_corners(mut self, length: Option<u32>) -> Self {
+        self.style.stroke_corners = length;
+
+        self
+    }
+
+    fn stroke_overlap(mut self, overlap: StrokeOverlap) -> Self {
+        self.style.stroke_overlap = overlap;
+
+        self
+    }
+
+    fn draw_mode(mut self, mode: DrawMode) -> Self {
+        self.style.draw_mode = mode;
+
+        self
+    }
+
     fn fill(mut self, color: Option<C>) -> Self {
         self.style.fill_color = color;
 
         self
     }
+
+    fn fill_gradient(mut self, gradient: Option<Gradient<C>>) -> Self {
+        self.style.fill_gradient = gradient;
+
+        self
+    }
+
+    fn fill_pattern(mut self, pattern: Option<FillPattern<C>>) -> Self {
+        self.style.fill_pattern = pattern;
+
+        self
+    }
+}
+
+impl<C> Styled<C> for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn current_style(&self) -> &Style<C> {
+        &self.style
+    }
 }
 
-fn sort_two_yx(p1: Coord, p2: Coord) -> (Coord, Coord) {
-    if p1[1] < p2[1] || (p1[1] == p2[1] && p1[0] < p2[0]) {
-        (p1, p2)
-    } else {
-        (p2, p1)
+impl<C> ContainsPoint for Triangle<C>
+where
+    C: PixelColor,
+{
+    /// Points on the triangle's edges count as inside, matching the drawn fill which includes the
+    /// edge pixels
+    fn contains(&self, point: Coord) -> bool {
+        let side = |a: Coord, b: Coord| {
+            (i64::from(b[0]) - i64::from(a[0])) * (i64::from(point[1]) - i64::from(a[1]))
+                - (i64::from(b[1]) - i64::from(a[1])) * (i64::from(point[0]) - i64::from(a[0]))
+        };
+
+        let sides = [
+            side(self.p1, self.p2),
+            side(self.p2, self.p3),
+            side(self.p3, self.p1),
+        ];
+
+        sides.iter().all(|&s| s >= 0) || sides.iter().all(|&s| s <= 0)
+    }
+}
+
+impl<C> Offset for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn offset(&self, distance: i32) -> Self {
+        let vertices = [self.p1, self.p2, self.p3];
+        let orientation = orientation(vertices);
+
+        if orientation == 0 {
+            return *self;
+        }
+
+        // Each side as the line `normal · p = c`, with the normal pointing out of the triangle.
+        // `c` is scaled by 256 to keep some precision after moving the line by `distance`.
+        let mut sides = [(0i128, 0i128, 0i128, 0i128); 3];
+        for (i, side) in sides.iter_mut().enumerate() {
+            let (a, b) = (vertices[i], vertices[(i + 1) % 3]);
+            let nx = i128::from((b[1] - a[1]) * orientation);
+            let ny = i128::from((a[0] - b[0]) * orientation);
+            let length = isqrt(((nx * nx + ny * ny) as u64) << 16) as i128;
+            let c = 256 * (nx * i128::from(a[0]) + ny * i128::from(a[1]))
+                + i128::from(distance) * length;
+
+            *side = (nx, ny, c, length);
+        }
+
+        // Each vertex is where the two sides next to it meet
+        let mut offset = [Coord::new(0, 0); 3];
+        for (i, vertex) in offset.iter_mut().enumerate() {
+            let (ax, ay, ac, _) = sides[(i + 2) % 3];
+            let (bx, by, bc, _) = sides[i];
+            let det = 256 * (ax * by - ay * bx);
+
+            *vertex = Coord::new(
+                div_round(ac * by - bc * ay, det),
+                div_round(ax * bc - bx * ac, det),
+            );
+        }
+
+        // Shrunk past the point where the sides meet, which flips the direction of the sides.
+        // Collapse to the center of the inscribed circle instead.
+        let flipped = (0..3).any(|i| {
+            let side = vertices[(i + 1) % 3] - vertices[i];
+            let offset_side = offset[(i + 1) % 3] - offset[i];
+
+            i64::from(side[0]) * i64::from(offset_side[0])
+                + i64::from(side[1]) * i64::from(offset_side[1])
+                <= 0
+        });
+
+        if flipped {
+            let weights = [sides[1].3, sides[2].3, sides[0].3];
+            let total: i128 = weights.iter().sum();
+            let center = |axis: usize| {
+                let sum: i128 = (0..3)
+                    .map(|i| weights[i] * i128::from(vertices[i][axis]))
+                    .sum();
+
+                div_round(sum, total)
+            };
+            let incenter = Coord::new(center(0), center(1));
+
+            offset = [incenter; 3];
+        }
+
+        Self {
+            p1: offset[0],
+            p2: offset[1],
+            p3: offset[2],
+            ..*self
+        }
     }
 }
 
-fn sort_yx(p1: Coord, p2: Coord, p3: Coord) -> (Coord, Coord, Coord) {
-    let (y1, y2) = sort_two_yx(p1, p2);
-    let (y1, y3) = sort_two_yx(p3, y1);
-    let (y2, y3) = sort_two_yx(y3, y2);
+/// Sign of the cross product of two of a triangle's sides, which tells whether its vertices are
+/// listed clockwise or counter clockwise
+fn orientation(vertices: [Coord; 3]) -> i32 {
+    let [a, b, c] = vertices;
+    let cross = (i64::from(b[0]) - i64::from(a[0])) * (i64::from(c[1]) - i64::from(a[1]))
+        - (i64::from(b[1]) - i64::from(a[1])) * (i64::from(c[0]) - i64::from(a[0]));
 
-    (y1, y2, y3)
+    cross.signum() as i32
+}
+
+/// Divide, rounding to the nearest integer
+fn div_round(n: i128, d: i128) -> i32 {
+    let (n, d) = if d < 0 { (-n, -d) } else { (n, d) };
+
+    (2 * n + d).div_euclid(2 * d) as i32
 }
 
 impl<C> IntoIterator for Triangle<C>
@@ -181,169 +358,72 @@ where
     type IntoIter = TriangleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let (v1, v2, v3) = sort_yx(self.p1, self.p2, self.p3);
-
-        let mut line_a = Line::new(v1, v2)
-            .stroke(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let mut line_b = Line::new(v1, v3)
-            .stroke(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let mut line_c = Line::new(v2, v3)
-            .stroke(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let next_ac = line_a
-            .next()
-            .or_else(|| line_c.next())
-            .map(|p| p.0.to_signed());
-        let next_b = line_b.next().map(|p| p.0.to_signed());
+        let visible = self.style.stroke_color.is_some() || self.style.has_fill();
+        let bounds = (self.top_left(), self.bottom_right() + Coord::new(1, 1));
 
         TriangleIterator {
-            line_a,
-            line_b,
-            line_c,
-            cur_ac: None,
-            cur_b: None,
-            next_ac,
-            next_b,
-            x: 0,
-            min_y: v1[1],
-            max_y: v3[1],
-            style: self.style,
+            triangle: *self,
+            area: FillArea::new(Some(bounds).filter(|_| visible)),
+            pending: None,
         }
     }
 }
 
-enum IterState {
-    Border(Coord),
-    LeftRight(Coord, Coord),
-    None,
-}
-
-/// Pixel iterator for each pixel in the triangle border
+/// Pixel iterator for each pixel in the triangle fill and border
+///
+/// The border is made of one pixel wide lines between the vertices. Without a stroke color the
+/// border pixels are filled instead. How the fill and stroke share the pixels under the border
+/// depends on the style's [`StrokeOverlap`](../../style/enum.StrokeOverlap.html).
 #[derive(Debug, Clone, Copy)]
 pub struct TriangleIterator<C: PixelColor>
 where
     C: PixelColor,
 {
-    line_a: LineIterator<C>,
-    line_b: LineIterator<C>,
-    line_c: LineIterator<C>,
-    cur_ac: Option<Coord>,
-    cur_b: Option<Coord>,
-    next_ac: Option<Coord>,
-    next_b: Option<Coord>,
-    x: i32,
-    max_y: i32,
-    min_y: i32,
-    style: Style<C>,
+    triangle: Triangle<C>,
+    area: FillArea,
+    /// Stroke pixel to draw over the fill pixel that was just returned
+    pending: Option<Pixel<C>>,
 }
 
-impl<C> TriangleIterator<C>
+impl<C> Iterator for TriangleIterator<C>
 where
     C: PixelColor,
 {
-    fn update_ac(&mut self) -> IterState {
-        if let Some(ac) = self.next_ac {
-            self.cur_ac = Some(ac);
-            self.next_ac = self
-                .line_a
-                .next()
-                .or_else(|| self.line_c.next())
-                .map(|p| p.0.to_signed());
-            self.x = 0;
-            IterState::Border(ac)
-        } else {
-            IterState::None
-        }
-    }
+    type Item = Pixel<C>;
 
-    fn update_b(&mut self) -> IterState {
-        if let Some(b) = self.next_b {
-            self.cur_b = Some(b);
-            self.next_b = self.line_b.next().map(|p| p.0.to_signed());
-            self.x = 0;
-            IterState::Border(b)
-        } else {
-            IterState::None
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.pending.take() {
+            return Some(pixel);
         }
-    }
 
-    fn points(&mut self) -> IterState {
-        match (self.cur_ac, self.cur_b) {
-            // Point of ac line or b line is missing
-            (None, _) => self.update_ac(),
-            (_, None) => self.update_b(),
-            // Both points are present
-            (Some(ac), Some(b)) => {
-                match (self.next_ac, self.next_b) {
-                    (Some(n_ac), Some(n_b)) => {
-                        // If y component differs, take new points from edge until both side have
-                        // the same y
-                        if n_ac[1] < n_b[1] {
-                            self.update_ac()
-                        } else if n_ac[1] > n_b[1] {
-                            self.update_b()
-                        } else {
-                            let (l, r) = sort_two_yx(ac, b);
-                            IterState::LeftRight(l, r)
-                        }
-                    }
-                    (None, Some(_)) => self.update_b(),
-                    (Some(_), None) => self.update_ac(),
-                    (None, None) => {
-                        let (l, r) = sort_two_yx(ac, b);
-                        IterState::LeftRight(l, r)
-                    }
-                }
+        let Triangle { p1, p2, p3, style } = self.triangle;
+
+        for p in &mut self.area {
+            let on_edge = [(p1, p2), (p2, p3), (p3, p1)]
+                .iter()
+                .any(|&(start, end)| is_on_line(start, end, p));
+
+            let stroke = style.stroke_color.filter(|_| on_edge);
+
+            if let Some((pixel, over)) =
+                styled_pixel(&style, p, stroke, on_edge || self.triangle.contains(p))
+            {
+                self.pending = over;
+
+                return Some(pixel);
             }
         }
+
+        None
     }
 }
 
-impl<C> Iterator for TriangleIterator<C>
+impl<C> Clip for TriangleIterator<C>
 where
     C: PixelColor,
 {
-    type Item = Pixel<C>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
-            return None;
-        }
-
-        loop {
-            match self.points() {
-                IterState::Border(coord) => {
-                    // Draw edges of the triangle
-                    if let Some(color) = self.style.stroke_color.or_else(|| self.style.fill_color) {
-                        if coord[0] >= 0 && coord[1] >= 0 {
-                            return Some(Pixel(coord.to_unsigned(), color));
-                        }
-                    }
-                }
-                IterState::LeftRight(l, r) => {
-                    // Fill the space between the left and right points
-                    if let Some(color) = self.style.fill_color {
-                        if l[0] >= 0 && l[1] >= 0 && r[0] >= 0 && r[1] >= 0 && l[0] + self.x < r[0]
-                        {
-                            let coord = UnsignedCoord::new((l[0] + self.x) as u32, l[1] as u32);
-                            self.x += 1;
-                            return Some(Pixel(coord, color));
-                        } else if l[0] + self.x >= r[0] {
-                            // We reached the right edge, move on to next row
-                            self.cur_ac = None;
-                            self.cur_b = None;
-                        }
-                    } else {
-                        // We don't want to fill the triangle
-                        self.cur_ac = None;
-                        self.cur_b = None;
-                    }
-                }
-                IterState::None => return None,
-            }
-        }
+    fn clip(&mut self, top_left: Coord, bottom_right: Coord) {
+        self.area.clip(top_left, bottom_right);
     }
 }
 
@@ -407,6 +487,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::MockDisplay;
     use crate::unsignedcoord::UnsignedCoord;
 
     #[test]
@@ -444,14 +525,10 @@ mod tests {
                 .style(Style::stroke(1))
                 .into_iter();
 
-        // Nodes are returned twice. first line a and b yield the same point.
-        // After that line a ends where line c starts.
+        // Pixels shared by overlapping edges are only returned once
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 2), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 2), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 3), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 3), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 4), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 4), 1.into())));
         assert_eq!(tri.next(), None);
     }
 
@@ -462,11 +539,8 @@ mod tests {
                 .style(Style::stroke(1))
                 .into_iter();
 
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 2), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 2), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(3, 2), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(3, 2), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(4, 2), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(4, 2), 1.into())));
         assert_eq!(tri.next(), None);
     }
@@ -480,9 +554,91 @@ mod tests {
 
         // Only the bottom of the triangle should be visible
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(0, 0), 1.into())));
-        assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 0), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(1, 0), 1.into())));
         assert_eq!(tri.next(), Some(Pixel(UnsignedCoord::new(2, 0), 1.into())));
         assert_eq!(tri.next(), None);
     }
+
+    #[test]
+    fn gradient_fill_covers_edges() {
+        let tri = Triangle::new(Coord::new(2, 2), Coord::new(10, 2), Coord::new(2, 10));
+        let solid = tri.fill(Some(1u8));
+        let gradient = tri.fill_gradient(Some(Gradient::radial(Coord::new(2, 2), 8, 0, 255)));
+
+        assert_eq!(solid.into_iter().count(), gradient.into_iter().count());
+
+        let corner = gradient
+            .into_iter()
+            .find(|p| p.0 == UnsignedCoord::new(2, 2))
+            .unwrap();
+        let edge = gradient
+            .into_iter()
+            .find(|p| p.0 == UnsignedCoord::new(10, 2))
+            .unwrap();
+
+        assert_eq!(corner.1, 0);
+        assert_eq!(edge.1, 255);
+    }
+
+    #[test]
+    fn offset() {
+        let tri: Triangle<u8> =
+            Triangle::new(Coord::new(0, 0), Coord::new(10, 0), Coord::new(0, 10));
+
+        let grown = tri.offset(1);
+        assert_eq!(
+            (grown.p1, grown.p2, grown.p3),
+            (Coord::new(-1, -1), Coord::new(12, -1), Coord::new(-1, 12))
+        );
+
+        let shrunk = tri.offset(-1);
+        assert_eq!(
+            (shrunk.p1, shrunk.p2, shrunk.p3),
+            (Coord::new(1, 1), Coord::new(8, 1), Coord::new(1, 8))
+        );
+
+        // Vertex order doesn't change which way is out
+        let reversed: Triangle<u8> =
+            Triangle::new(Coord::new(0, 10), Coord::new(10, 0), Coord::new(0, 0));
+        assert_eq!(reversed.offset(1).p3, Coord::new(-1, -1));
+
+        // Collapses to the center of the inscribed circle
+        let collapsed = tri.offset(-5);
+        assert_eq!(collapsed.p1, Coord::new(3, 3));
+        assert_eq!(collapsed.p1, collapsed.p2);
+        assert_eq!(collapsed.p1, collapsed.p3);
+    }
+
+    #[test]
+    fn each_pixel_drawn_once() {
+        let tri = Triangle::new(Coord::new(2, 2), Coord::new(20, 6), Coord::new(7, 14))
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+
+        let mut times_drawn = MockDisplay::default();
+        for Pixel(p, _) in tri {
+            times_drawn.0[p[1] as usize][p[0] as usize] += 1u8;
+        }
+        assert_eq!(times_drawn.0.iter().flatten().max(), Some(&1));
+
+        // Each stroke pixel follows the fill pixel underneath it
+        let over_fill = tri.stroke_overlap(StrokeOverlap::OverFill);
+        let stroked = tri.into_iter().filter(|p| p.1 == 1).count();
+        assert_eq!(
+            over_fill.into_iter().count(),
+            tri.into_iter().count() + stroked
+        );
+
+        let mut pixels = over_fill.into_iter();
+        while let Some(Pixel(p, color)) = pixels.next() {
+            if color == 1 {
+                continue;
+            }
+
+            // A fill pixel on the border is immediately followed by the stroke
+            if tri.into_iter().any(|s| s == Pixel(p, 1)) {
+                assert_eq!(pixels.next(), Some(Pixel(p, 1)));
+            }
+        }
+    }
 }
@@ -0,0 +1,255 @@
+//! Operations on the pixels already drawn to a display
+//!
+//! These need to read pixels back from the display, so they only work with displays that implement
+//! [`ReadPixel`](./trait.ReadPixel.html), usually ones backed by a framebuffer.
+
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::unsignedcoord::UnsignedCoord;
+use crate::Drawing;
+
+/// A display whose pixels can be read back
+pub trait ReadPixel<C>
+where
+    C: PixelColor,
+{
+    /// Color of the pixel at `point`, or `None` if the point is outside the display
+    fn pixel(&self, point: UnsignedCoord) -> Option<C>;
+}
+
+/// Error returned by [`flood_fill`](./fn.flood_fill.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloodFillError {
+    /// The seed point lies outside the display
+    SeedOutOfBounds,
+
+    /// The scratch buffer ran out of space
+    ///
+    /// The region is left partially filled.
+    ScratchFull,
+}
+
+/// Point waiting to be filled, stored in the scratch buffer passed to
+/// [`flood_fill`](./fn.flood_fill.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FloodSeed {
+    x: u32,
+    y: u32,
+}
+
+impl FloodSeed {
+    /// An unused seed, for initializing scratch buffers
+    pub const EMPTY: FloodSeed = FloodSeed { x: 0, y: 0 };
+}
+
+/// Fill the region of same colored pixels around `seed` with `color`
+///
+/// Pixels are part of the region if they have the same color as the seed point and are connected to
+/// it horizontally or vertically, so a region can't leak through a diagonal gap in its outline.
+///
+/// The fill works one horizontal span at a time, drawing each span in a single call to
+/// [`draw`](../trait.Drawing.html#tymethod.draw). Spans still waiting to be filled are remembered in
+/// `scratch` instead of on the stack, so memory use is fixed up front. Simple shapes need only a
+/// few entries, but each concave bend in the outline can add one more. If `scratch` runs out,
+/// [`FloodFillError::ScratchFull`](./enum.FloodFillError.html#variant.ScratchFull) is returned.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::raster::{flood_fill, FloodSeed};
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// display.draw(egrectangle!((2, 2), (20, 12), stroke = Some(1u8)));
+///
+/// let mut scratch = [FloodSeed::EMPTY; 32];
+/// flood_fill(&mut display, UnsignedCoord::new(10, 6), 2u8, &mut scratch)
+///     .expect("Scratch buffer too small");
+/// ```
+pub fn flood_fill<D, C>(
+    display: &mut D,
+    seed: UnsignedCoord,
+    color: C,
+    scratch: &mut [FloodSeed],
+) -> Result<(), FloodFillError>
+where
+    D: Drawing<C> + ReadPixel<C>,
+    C: PixelColor + PartialEq,
+{
+    let target = display.pixel(seed).ok_or(FloodFillError::SeedOutOfBounds)?;
+
+    if target == color {
+        return Ok(());
+    }
+
+    let in_region =
+        |display: &D, x: u32, y: u32| display.pixel(UnsignedCoord::new(x, y)) == Some(target);
+
+    let mut stack = SeedStack {
+        seeds: scratch,
+        len: 0,
+    };
+    stack.push(seed[0], seed[1])?;
+
+    while let Some(FloodSeed { x, y }) = stack.pop() {
+        // Already filled through another seed in the same span
+        if !in_region(display, x, y) {
+            continue;
+        }
+
+        let mut left = x;
+        while left > 0 && in_region(display, left - 1, y) {
+            left -= 1;
+        }
+
+        let mut right = x;
+        while right < u32::MAX && in_region(display, right + 1, y) {
+            right += 1;
+        }
+
+        display.draw((left..=right).map(|x| Pixel(UnsignedCoord::new(x, y), color)));
+
+        // Queue one seed for each run of region pixels directly above and below the span
+        let neighbours = [y.checked_sub(1), y.checked_add(1)];
+
+        for &row in neighbours.iter().flatten() {
+            let mut in_run = false;
+
+            for x in left..=right {
+                let inside = in_region(display, x, row);
+
+                if inside && !in_run {
+                    stack.push(x, row)?;
+                }
+
+                in_run = inside;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stack of seeds stored in a borrowed buffer
+struct SeedStack<'a> {
+    seeds: &'a mut [FloodSeed],
+    len: usize,
+}
+
+impl SeedStack<'_> {
+    fn push(&mut self, x: u32, y: u32) -> Result<(), FloodFillError> {
+        let slot = self
+            .seeds
+            .get_mut(self.len)
+            .ok_or(FloodFillError::ScratchFull)?;
+
+        *slot = FloodSeed { x, y };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<FloodSeed> {
+        self.len = self.len.checked_sub(1)?;
+
+        Some(self.seeds[self.len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Polygon, Rectangle};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn fills_inside_outline() {
+        let mut display = Display::default();
+        display.draw(Rectangle::new(Coord::new(2, 2), Coord::new(10, 8)).stroke(Some(1u8)));
+
+        let mut scratch = [FloodSeed::EMPTY; 8];
+        flood_fill(&mut display, UnsignedCoord::new(5, 5), 2, &mut scratch).unwrap();
+
+        assert_eq!(display.count(2), 7 * 5);
+        assert_eq!(display.0[1][1], 0);
+        assert_eq!(display.0[2][2], 1);
+    }
+
+    #[test]
+    fn matches_filled_shape() {
+        let vertices = [
+            Coord::new(1, 1),
+            Coord::new(20, 1),
+            Coord::new(20, 14),
+            Coord::new(14, 14),
+            Coord::new(14, 5),
+            Coord::new(7, 5),
+            Coord::new(7, 14),
+            Coord::new(1, 14),
+        ];
+        let outline = Polygon::new(&vertices).stroke(Some(1u8));
+
+        let mut filled = Display::default();
+        filled.draw(outline.fill(Some(2u8)));
+
+        let mut flooded = Display::default();
+        flooded.draw(outline);
+
+        let mut scratch = [FloodSeed::EMPTY; 16];
+        flood_fill(&mut flooded, UnsignedCoord::new(3, 10), 2, &mut scratch).unwrap();
+
+        assert_eq!(flooded, filled);
+    }
+
+    #[test]
+    fn fills_outside_of_shapes() {
+        let mut display = Display::default();
+        display.draw(Circle::new(Coord::new(12, 8), 5).stroke(Some(1u8)));
+
+        let mut scratch = [FloodSeed::EMPTY; 32];
+        flood_fill(&mut display, UnsignedCoord::new(0, 0), 2, &mut scratch).unwrap();
+
+        // Everything but the circle and its inside
+        assert_eq!(display.0[8][12], 0);
+        assert_eq!(display.0[15][23], 2);
+        assert_eq!(
+            display.count(0) + display.count(1) + display.count(2),
+            24 * 16
+        );
+        assert!(display.count(0) > 50);
+    }
+
+    #[test]
+    fn same_color_is_a_noop() {
+        let mut display = Display::default();
+        let mut scratch = [FloodSeed::EMPTY; 0];
+
+        assert_eq!(
+            flood_fill(&mut display, UnsignedCoord::new(5, 5), 0, &mut scratch),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut display = Display::default();
+        let mut scratch = [FloodSeed::EMPTY; 1];
+
+        assert_eq!(
+            flood_fill(&mut display, UnsignedCoord::new(30, 5), 1, &mut scratch),
+            Err(FloodFillError::SeedOutOfBounds)
+        );
+        assert_eq!(
+            flood_fill(&mut display, UnsignedCoord::new(5, 5), 1, &mut scratch),
+            Err(FloodFillError::ScratchFull)
+        );
+
+        // A larger buffer is enough for the whole display
+        let mut display = Display::default();
+        let mut scratch = [FloodSeed::EMPTY; 2];
+        flood_fill(&mut display, UnsignedCoord::new(5, 5), 1, &mut scratch).unwrap();
+        assert_eq!(display.count(1), 24 * 16);
+    }
+}
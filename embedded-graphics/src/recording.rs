@@ -0,0 +1,278 @@
+//! Recording draw operations to replay later
+//!
+//! A [`RecordingDisplay`](./struct.RecordingDisplay.html) doesn't show anything. Everything drawn
+//! to it is stored as a list of [`DrawCommand`](./enum.DrawCommand.html)s in a buffer owned by the
+//! caller, which can be replayed onto any other display later. This allows rendering to be
+//! deferred until a display is free, a frame to be drawn several times, or draw operations to be
+//! sent to a display on the other end of a serial link.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::recording::{DrawCommand, RecordingDisplay};
+//! use embedded_graphics::{egcircle, egline};
+//! # use embedded_graphics::mock_display::Display;
+//! # let mut display = Display::default();
+//!
+//! let mut commands = [DrawCommand::default(); 64];
+//! let mut recording = RecordingDisplay::new(&mut commands);
+//!
+//! recording.draw(egline!((0, 0), (9, 0), stroke = Some(1u8)));
+//! recording.draw(egcircle!((12, 8), 3, fill = Some(2u8)));
+//! assert!(!recording.is_truncated());
+//!
+//! // The line is stored as a single command
+//! assert_eq!(recording.commands()[0], DrawCommand::Run(Coord::new(0, 0), 10, 1));
+//!
+//! recording.replay(&mut display);
+//! ```
+
+use crate::coord::Coord;
+use crate::drawable::Pixel;
+use crate::pixelcolor::PixelColor;
+use crate::unsignedcoord::{ToSigned, UnsignedCoord};
+use crate::Drawing;
+
+/// A single draw operation stored by a [`RecordingDisplay`](./struct.RecordingDisplay.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawCommand<C> {
+    /// Set one pixel to a color
+    Pixel(UnsignedCoord, C),
+
+    /// Set a horizontal run of pixels to a color, given as its leftmost point and its length, like
+    /// the runs passed to [`Drawing::fill_runs`](../trait.Drawing.html#method.fill_runs)
+    Run(Coord, u32, C),
+}
+
+/// An empty run, for initializing command buffers
+impl<C> Default for DrawCommand<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        DrawCommand::Run(Coord::new(0, 0), 0, C::from(0))
+    }
+}
+
+/// Display that stores everything drawn to it as a list of commands
+///
+/// Pixels drawn one after another along a row in the same color are merged into a single
+/// [`Run`](./enum.DrawCommand.html#variant.Run), so filled shapes and lines take up far fewer
+/// commands than pixels. Runs drawn with [`fill_runs`](../trait.Drawing.html#method.fill_runs) are
+/// stored as they are. Once the buffer is full, further commands are dropped and
+/// [`is_truncated`](#method.is_truncated) returns `true`.
+#[derive(Debug)]
+pub struct RecordingDisplay<'a, C> {
+    commands: &'a mut [DrawCommand<C>],
+    len: usize,
+    truncated: bool,
+}
+
+impl<'a, C> RecordingDisplay<'a, C>
+where
+    C: PixelColor + PartialEq,
+{
+    /// Create a recording that stores its commands in `commands`
+    pub fn new(commands: &'a mut [DrawCommand<C>]) -> Self {
+        Self {
+            commands,
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Get the commands recorded so far, in the order they were drawn
+    pub fn commands(&self) -> &[DrawCommand<C>] {
+        &self.commands[..self.len]
+    }
+
+    /// Whether commands were dropped because the buffer was full
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Remove every recorded command to start a new recording
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    /// Draw the recorded commands to `target`
+    ///
+    /// Consecutive pixels and consecutive runs are each drawn with a single call to `target`.
+    pub fn replay<D>(&self, target: &mut D)
+    where
+        D: Drawing<C>,
+    {
+        let mut commands = self.commands();
+
+        while let Some(first) = commands.first() {
+            let is_pixel = |command: &DrawCommand<C>| match command {
+                DrawCommand::Pixel(..) => true,
+                DrawCommand::Run(..) => false,
+            };
+            let pixels = is_pixel(first);
+            let len = commands
+                .iter()
+                .position(|command| is_pixel(command) != pixels)
+                .unwrap_or(commands.len());
+            let (group, rest) = commands.split_at(len);
+
+            if pixels {
+                target.draw(group.iter().filter_map(|command| match *command {
+                    DrawCommand::Pixel(point, color) => Some(Pixel(point, color)),
+                    DrawCommand::Run(..) => None,
+                }));
+            } else {
+                target.fill_runs(group.iter().filter_map(|command| match *command {
+                    DrawCommand::Run(start, length, color) => Some((start, length, color)),
+                    DrawCommand::Pixel(..) => None,
+                }));
+            }
+
+            commands = rest;
+        }
+    }
+
+    /// Add a command to the end of the list, or drop it if the buffer is full
+    fn push(&mut self, command: DrawCommand<C>) {
+        if self.len < self.commands.len() {
+            self.commands[self.len] = command;
+            self.len += 1;
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    /// Extend the last command to cover `point` if it ends just left of it in the same color
+    fn extend_last(&mut self, point: UnsignedCoord, color: C) -> bool {
+        let max = i32::MAX as u32;
+        if self.len == 0 || self.truncated || point[0] > max || point[1] > max {
+            return false;
+        }
+
+        let last = &mut self.commands[self.len - 1];
+        let point = point.to_signed();
+
+        match *last {
+            DrawCommand::Pixel(previous, c)
+                if c == color && previous.to_signed() + Coord::new(1, 0) == point =>
+            {
+                *last = DrawCommand::Run(previous.to_signed(), 2, color);
+
+                true
+            }
+            DrawCommand::Run(start, length, c)
+                if c == color
+                    && start[1] == point[1]
+                    && i64::from(start[0]) + i64::from(length) == i64::from(point[0]) =>
+            {
+                *last = DrawCommand::Run(start, length + 1, color);
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a, C> Drawing<C> for RecordingDisplay<'a, C>
+where
+    C: PixelColor + PartialEq,
+{
+    fn draw<T>(&mut self, item: T)
+    where
+        T: IntoIterator<Item = Pixel<C>>,
+    {
+        for Pixel(point, color) in item {
+            if !self.extend_last(point, color) {
+                self.push(DrawCommand::Pixel(point, color));
+            }
+        }
+    }
+
+    fn fill_runs<I>(&mut self, runs: I)
+    where
+        I: IntoIterator<Item = (Coord, u32, C)>,
+    {
+        for (start, length, color) in runs {
+            if length > 0 {
+                self.push(DrawCommand::Run(start, length, color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Circle, Rectangle, Triangle};
+    use crate::style::WithStyle;
+
+    #[test]
+    fn replay_matches_drawing() {
+        let circle = Circle::new(Coord::new(10, 8), 6)
+            .stroke(Some(1u8))
+            .fill(Some(2u8));
+        let triangle =
+            Triangle::new(Coord::new(1, 1), Coord::new(22, 4), Coord::new(8, 14)).fill(Some(3u8));
+        let runs = [(Coord::new(-3, 2), 8, 4u8), (Coord::new(20, 15), 10, 5)];
+
+        let mut expected = Display::default();
+        expected.draw(triangle);
+        expected.fill_runs(runs.iter().cloned());
+        expected.draw(circle);
+
+        let mut commands = [DrawCommand::default(); 256];
+        let mut recording = RecordingDisplay::new(&mut commands);
+        recording.draw(triangle);
+        recording.fill_runs(runs.iter().cloned());
+        recording.draw(circle);
+        assert!(!recording.is_truncated());
+
+        let mut display = Display::default();
+        recording.replay(&mut display);
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn merges_pixels_into_runs() {
+        let mut commands = [DrawCommand::default(); 8];
+        let mut recording = RecordingDisplay::new(&mut commands);
+
+        recording.draw(Rectangle::new(Coord::new(2, 1), Coord::new(5, 2)).fill(Some(1u8)));
+        recording.draw(
+            [
+                Pixel(UnsignedCoord::new(6, 2), 1u8),
+                Pixel(UnsignedCoord::new(7, 2), 2),
+                Pixel(UnsignedCoord::new(9, 2), 2),
+            ]
+            .iter()
+            .cloned(),
+        );
+
+        assert_eq!(
+            recording.commands(),
+            [
+                DrawCommand::Run(Coord::new(2, 1), 4, 1),
+                DrawCommand::Run(Coord::new(2, 2), 5, 1),
+                DrawCommand::Pixel(UnsignedCoord::new(7, 2), 2),
+                DrawCommand::Pixel(UnsignedCoord::new(9, 2), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn full_buffer_truncates() {
+        let mut commands = [DrawCommand::default(); 2];
+        let mut recording = RecordingDisplay::new(&mut commands);
+
+        recording.draw(Rectangle::new(Coord::new(0, 0), Coord::new(3, 2)).fill(Some(1u8)));
+        assert!(recording.is_truncated());
+        assert_eq!(recording.commands().len(), 2);
+
+        recording.clear();
+        assert!(!recording.is_truncated());
+        assert_eq!(recording.commands(), []);
+    }
+}
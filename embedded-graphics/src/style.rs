@@ -1,6 +1,11 @@
 //! Styling struct to customise the look of objects.
 
+use crate::coord::Coord;
+use crate::pixelcolor::blend::Blend;
+use crate::pixelcolor::raw::IntoStorage;
 use crate::pixelcolor::PixelColor;
+use crate::primitives::smooth::isqrt;
+use core::ops::{BitAnd, BitOr, BitXor, Not};
 
 /// Style properties for an object
 #[derive(Debug, Copy, Clone)]
@@ -10,6 +15,18 @@ pub struct Style<P: PixelColor> {
     /// For fonts, this is the background colour of the text
     pub fill_color: Option<P>,
 
+    /// Gradient used to fill the object
+    ///
+    /// Takes precedence over `fill_color` when set. Has no effect on fonts, lines, polylines or
+    /// curves.
+    pub fill_gradient: Option<Gradient<P>>,
+
+    /// Pattern used to fill the object
+    ///
+    /// Takes precedence over both `fill_gradient` and `fill_color` when set. Has no effect on
+    /// fonts, lines, polylines or curves.
+    pub fill_pattern: Option<FillPattern<P>>,
+
     /// Stroke (border/line) color of the object
     ///
     /// For fonts, this is the foreground colour of the text
@@ -19,6 +36,46 @@ pub struct Style<P: PixelColor> {
     ///
     /// Set the stroke width for an object. Has no effect on fonts.
     pub stroke_width: u8,
+
+    /// Stroke dash pattern
+    ///
+    /// Draw the stroke as a series of dashes instead of a solid line. Has no effect on fonts,
+    /// triangles or ring sectors.
+    pub stroke_dash: Option<DashPattern>,
+
+    /// Shape of the corners where segments of a thick stroke meet
+    ///
+    /// Only used by polylines with a stroke width greater than one.
+    pub line_join: LineJoin,
+
+    /// Shape of the open ends of a thick stroke
+    ///
+    /// Only used by lines and polylines with a stroke width greater than one.
+    pub stroke_cap: StrokeCap,
+
+    /// Where a closed outline's stroke sits relative to the shape's edge
+    ///
+    /// Only used by circles, elliptical arcs and sectors, ring sectors, superellipses and capsules.
+    /// Other shapes always draw their stroke inside.
+    pub stroke_alignment: StrokeAlignment,
+
+    /// Only draw the stroke this many pixels along each side from every corner, leaving the middle
+    /// of each side out
+    ///
+    /// Draws corner brackets like the ones in camera viewfinders and selection boxes. Only used by
+    /// rectangles. `None` draws the whole outline.
+    pub stroke_corners: Option<u32>,
+
+    /// Whether the fill is also drawn underneath the stroke
+    ///
+    /// Has no effect on fonts, lines, polylines or curves.
+    pub stroke_overlap: StrokeOverlap,
+
+    /// How the object's pixels are combined with the ones already on the display
+    ///
+    /// Only used when the object is drawn with
+    /// [`RasterOp::draw_styled`](../adapter/struct.RasterOp.html#method.draw_styled).
+    pub draw_mode: DrawMode,
 }
 
 impl<P> Style<P>
@@ -32,6 +89,35 @@ where
             ..Style::default()
         }
     }
+
+    /// Color used to fill the pixel at `point`, in screen coordinates
+    ///
+    /// Returns the color of the fill pattern at that point if there is one, then the color of the
+    /// fill gradient, and finally the fill color. `None` means the pixel isn't filled, which can
+    /// happen for pixels in the gaps of a pattern without a background.
+    pub fn fill_color_at(&self, point: Coord) -> Option<P> {
+        if let Some(pattern) = self.fill_pattern {
+            return pattern.color_at(point);
+        }
+
+        self.fill_gradient
+            .map(|gradient| gradient.color_at(point))
+            .or(self.fill_color)
+    }
+
+    /// Returns `true` if the object has a fill color, gradient or pattern
+    pub(crate) fn has_fill(&self) -> bool {
+        self.fill_color.is_some() || self.fill_gradient.is_some() || self.fill_pattern.is_some()
+    }
+
+    /// Number of pixels the stroke reaches past the edge of a closed shape
+    pub(crate) fn stroke_outset(&self) -> u32 {
+        if self.stroke_color.is_some() {
+            self.stroke_alignment.outset(self.stroke_width)
+        } else {
+            0
+        }
+    }
 }
 
 impl<P> Default for Style<P>
@@ -41,14 +127,588 @@ where
     fn default() -> Self {
         Self {
             fill_color: None,
+            fill_gradient: None,
+            fill_pattern: None,
             stroke_color: None,
             stroke_width: 1,
+            stroke_dash: None,
+            line_join: LineJoin::default(),
+            stroke_cap: StrokeCap::default(),
+            stroke_alignment: StrokeAlignment::default(),
+            stroke_corners: None,
+            stroke_overlap: StrokeOverlap::default(),
+            draw_mode: DrawMode::default(),
+        }
+    }
+}
+
+/// A repeating pattern of dashes and gaps for a stroke
+///
+/// The pattern is a list of segment lengths in pixels, alternating between dashes and gaps and
+/// starting with a dash. A list with an odd number of segments is repeated twice to make a whole
+/// pattern, so `&[2]` draws two pixels on and two off. Segments can be zero pixels long, and a
+/// pattern without any gaps is drawn solid.
+///
+/// Positions along the stroke are measured in pixels from its start. Lines start at their start
+/// point, rectangles at their top left corner going clockwise, and circles at their rightmost
+/// point going clockwise. Gaps in a stroke are filled with the fill color, if there is one.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::{egline, egrectangle};
+/// use embedded_graphics::style::DashPattern;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let selection = egrectangle!(
+///     (2, 2),
+///     (20, 12),
+///     stroke = Some(1u8),
+///     stroke_dash = Some(DashPattern::new(&[3, 2]))
+/// );
+/// let center_line = egline!(
+///     (0, 8),
+///     (23, 8),
+///     stroke = Some(1u8),
+///     stroke_dash = Some(DashPattern::DASH_DOT)
+/// );
+///
+/// display.draw(selection);
+/// display.draw(center_line);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DashPattern {
+    segments: &'static [u32],
+}
+
+impl DashPattern {
+    /// A dotted line, alternating single pixels on and off
+    pub const DOTTED: DashPattern = DashPattern::new(&[1, 1]);
+
+    /// A dashed line, alternating four pixels on and four off
+    pub const DASHED: DashPattern = DashPattern::new(&[4, 4]);
+
+    /// A line of dashes with a dot between each of them
+    pub const DASH_DOT: DashPattern = DashPattern::new(&[6, 2, 1, 2]);
+
+    /// Create a new pattern from the lengths of its dashes and gaps, starting with a dash
+    pub const fn new(segments: &'static [u32]) -> Self {
+        Self { segments }
+    }
+
+    /// Lengths of the dashes and gaps of the pattern, starting with a dash
+    pub fn segments(&self) -> &'static [u32] {
+        self.segments
+    }
+
+    /// Returns `true` if the pixel at `position` along the stroke is part of a dash
+    pub fn is_dash(&self, position: u32) -> bool {
+        let length = self
+            .segments
+            .iter()
+            .fold(0u64, |length, &segment| length + u64::from(segment));
+
+        // Odd patterns swap dashes and gaps every time they repeat
+        let period = if self.segments.len() % 2 == 1 {
+            2 * length
+        } else {
+            length
+        };
+
+        if period == 0 {
+            return true;
+        }
+
+        let mut position = u64::from(position) % period;
+        for (index, &segment) in self.segments.iter().cycle().enumerate() {
+            if position < u64::from(segment) {
+                return index % 2 == 0;
+            }
+
+            position -= u64::from(segment);
+        }
+
+        true
+    }
+}
+
+/// Shape of a [`Gradient`](./struct.Gradient.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GradientShape {
+    /// Colors change along the line from `start` to `end` and stay constant across it
+    ///
+    /// Points before `start` or past `end` use the first or last color respectively.
+    Linear {
+        /// Position of the first color
+        start: Coord,
+
+        /// Position of the last color
+        end: Coord,
+    },
+
+    /// Colors change with the distance from `center`
+    ///
+    /// Points further than `radius` from the center use the last color.
+    Radial {
+        /// Position of the first color
+        center: Coord,
+
+        /// Distance from the center at which the last color is reached
+        radius: u32,
+    },
+}
+
+/// A two color gradient used to fill shapes
+///
+/// Gradients are positioned in screen coordinates, so moving a shape with
+/// [`translate`](../transform/trait.Transform.html#tymethod.translate) doesn't move its gradient.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::style::Gradient;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let button = egrectangle!(
+///     (2, 2),
+///     (20, 12),
+///     stroke = Some(1u8),
+///     fill_gradient = Some(Gradient::linear(
+///         Coord::new(0, 2),
+///         Coord::new(0, 12),
+///         200u8,
+///         50u8,
+///     ))
+/// );
+///
+/// display.draw(button);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Gradient<P> {
+    /// Shape of the gradient
+    pub shape: GradientShape,
+
+    /// Color at the start or center of the gradient
+    pub from: P,
+
+    /// Color at the end or edge of the gradient
+    pub to: P,
+
+    blend: fn(P, P, u8) -> P,
+}
+
+impl<P> Gradient<P>
+where
+    P: PixelColor,
+{
+    /// Create a gradient from `from` at `start` to `to` at `end`
+    pub fn linear(start: Coord, end: Coord, from: P, to: P) -> Self
+    where
+        P: Blend,
+    {
+        Self {
+            shape: GradientShape::Linear { start, end },
+            from,
+            to,
+            blend: P::blend,
+        }
+    }
+
+    /// Create a gradient from `from` at `center` to `to` at `radius` pixels away from it
+    pub fn radial(center: Coord, radius: u32, from: P, to: P) -> Self
+    where
+        P: Blend,
+    {
+        Self {
+            shape: GradientShape::Radial { center, radius },
+            from,
+            to,
+            blend: P::blend,
+        }
+    }
+
+    /// Color of the gradient at `point`
+    pub fn color_at(&self, point: Coord) -> P {
+        (self.blend)(self.from, self.to, self.position(point))
+    }
+
+    /// Position of `point` along the gradient, rounded to the nearest step from `0` at the first
+    /// color to `255` at the last
+    fn position(&self, point: Coord) -> u8 {
+        let position = match self.shape {
+            GradientShape::Linear { start, end } => {
+                let d = (
+                    i64::from(end[0]) - i64::from(start[0]),
+                    i64::from(end[1]) - i64::from(start[1]),
+                );
+                let p = (
+                    i64::from(point[0]) - i64::from(start[0]),
+                    i64::from(point[1]) - i64::from(start[1]),
+                );
+                let length_sq = d.0 * d.0 + d.1 * d.1;
+
+                if length_sq == 0 {
+                    return 0;
+                }
+
+                ((d.0 * p.0 + d.1 * p.1) * 255 + length_sq / 2).div_euclid(length_sq)
+            }
+            GradientShape::Radial { center, radius } => {
+                if radius == 0 {
+                    return 255;
+                }
+
+                let dx = i64::from(point[0]) - i64::from(center[0]);
+                let dy = i64::from(point[1]) - i64::from(center[1]);
+
+                // Distance in 1/256ths of a pixel so that the gradient stays smooth near the center
+                let distance = isqrt(((dx * dx + dy * dy) as u64) << 16) as i64;
+
+                let radius = i64::from(radius) << 8;
+
+                (distance * 255 + radius / 2) / radius
+            }
+        };
+
+        position.clamp(0, 255) as u8
+    }
+}
+
+/// Distance in pixels between the lines of a [`Hatch`](./enum.Hatch.html)
+pub const HATCH_SPACING: i32 = 4;
+
+/// Built in patterns for a [`FillPattern`](./struct.FillPattern.html)
+///
+/// Patterns are aligned to the screen, so neighbouring shapes with the same pattern line up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Hatch {
+    /// Horizontal lines, one every `HATCH_SPACING` rows
+    Horizontal,
+
+    /// Vertical lines, one every `HATCH_SPACING` columns
+    Vertical,
+
+    /// Diagonal lines running from bottom left to top right
+    ForwardDiagonal,
+
+    /// Diagonal lines running from top left to bottom right
+    BackwardDiagonal,
+
+    /// Horizontal and vertical lines
+    Cross,
+
+    /// Diagonal lines in both directions
+    DiagonalCross,
+
+    /// Single pixels on every other row and column
+    Dots,
+
+    /// Alternating pixels, like the squares of a chess board
+    Checkerboard,
+}
+
+impl Hatch {
+    /// Returns `true` if the pixel at `point` is part of the pattern
+    pub fn is_set(self, point: Coord) -> bool {
+        let (x, y) = (point[0], point[1]);
+        let on_line = |v: i32| v.rem_euclid(HATCH_SPACING) == 0;
+
+        match self {
+            Hatch::Horizontal => on_line(y),
+            Hatch::Vertical => on_line(x),
+            Hatch::ForwardDiagonal => on_line(x + y),
+            Hatch::BackwardDiagonal => on_line(x - y),
+            Hatch::Cross => on_line(x) || on_line(y),
+            Hatch::DiagonalCross => on_line(x + y) || on_line(x - y),
+            Hatch::Dots => x.rem_euclid(2) == 0 && y.rem_euclid(2) == 0,
+            Hatch::Checkerboard => (x + y).rem_euclid(2) == 0,
+        }
+    }
+}
+
+/// Shape of the pixels set in a [`FillPattern`](./struct.FillPattern.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PatternTile {
+    /// One of the built in hatch patterns
+    Hatch(Hatch),
+
+    /// A 1 bit per pixel image repeated across the fill
+    ///
+    /// The data uses the same layout as [`Image1BPP`](../image/type.Image1BPP.html): each row is
+    /// padded to a whole number of bytes, with the most significant bit on the left. The tile is
+    /// repeated from the screen origin.
+    Bitmap {
+        /// Image data
+        data: &'static [u8],
+
+        /// Width of the tile in pixels
+        width: u32,
+
+        /// Height of the tile in pixels
+        height: u32,
+    },
+}
+
+impl PatternTile {
+    /// Returns `true` if the pixel at `point` is part of the pattern
+    pub fn is_set(self, point: Coord) -> bool {
+        match self {
+            PatternTile::Hatch(hatch) => hatch.is_set(point),
+            PatternTile::Bitmap {
+                data,
+                width,
+                height,
+            } => {
+                if width == 0 || height == 0 {
+                    return false;
+                }
+
+                let x = point[0].rem_euclid(width as i32) as usize;
+                let y = point[1].rem_euclid(height as i32) as usize;
+                let bytes_in_row = (width as usize).div_ceil(8);
+
+                data.get(y * bytes_in_row + x / 8)
+                    .is_some_and(|byte| (byte >> (7 - x % 8)) & 1 == 1)
+            }
+        }
+    }
+}
+
+/// A two color pattern used to fill shapes
+///
+/// Pixels that are set in the pattern's tile are drawn in the foreground color, and the rest in the
+/// background color. Without a background color, the shape shows through to whatever was drawn
+/// under it. Patterns let shapes be told apart on displays that can't show many colors.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::style::{FillPattern, Hatch};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let series_a = egrectangle!(
+///     (2, 4),
+///     (6, 15),
+///     stroke = Some(BinaryColor::On),
+///     fill_pattern = Some(FillPattern::hatch(Hatch::ForwardDiagonal, BinaryColor::On))
+/// );
+/// let series_b = egrectangle!(
+///     (8, 8),
+///     (12, 15),
+///     stroke = Some(BinaryColor::On),
+///     fill_pattern = Some(FillPattern::hatch(Hatch::Dots, BinaryColor::On))
+/// );
+///
+/// display.draw(series_a);
+/// display.draw(series_b);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FillPattern<P> {
+    /// Pixels that are set in the pattern
+    pub tile: PatternTile,
+
+    /// Color of the pixels that are set
+    pub foreground: P,
+
+    /// Color of the pixels that aren't set, or `None` to leave them transparent
+    pub background: Option<P>,
+}
+
+impl<P> FillPattern<P>
+where
+    P: PixelColor,
+{
+    /// Create a hatched pattern with a transparent background
+    pub fn hatch(hatch: Hatch, foreground: P) -> Self {
+        Self {
+            tile: PatternTile::Hatch(hatch),
+            foreground,
+            background: None,
+        }
+    }
+
+    /// Create a pattern from a tiled 1 bit per pixel image with a transparent background
+    ///
+    /// See [`PatternTile::Bitmap`](./enum.PatternTile.html#variant.Bitmap) for the data layout.
+    pub fn bitmap(data: &'static [u8], width: u32, height: u32, foreground: P) -> Self {
+        Self {
+            tile: PatternTile::Bitmap {
+                data,
+                width,
+                height,
+            },
+            foreground,
+            background: None,
         }
     }
+
+    /// Set the color of the pixels that aren't part of the pattern
+    pub fn background(mut self, background: Option<P>) -> Self {
+        self.background = background;
+
+        self
+    }
+
+    /// Color of the pattern at `point`, or `None` if the pixel is transparent
+    pub fn color_at(&self, point: Coord) -> Option<P> {
+        if self.tile.is_set(point) {
+            Some(self.foreground)
+        } else {
+            self.background
+        }
+    }
+}
+
+/// Shape of the corners where two segments of a thick stroke meet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Extend the outer edges of both segments until they meet in a sharp point
+    ///
+    /// Very sharp corners would produce extremely long points, so corners with a miter length of
+    /// more than four times the stroke width fall back to a bevel join.
+    #[default]
+    Miter,
+
+    /// Cut the corner off with a straight line between the outer edges of both segments
+    Bevel,
+
+    /// Round the corner off with a circle centered on the vertex
+    Round,
+}
+
+/// Shape of the open ends of a thick stroke
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StrokeCap {
+    /// End the stroke exactly at its end point
+    #[default]
+    Butt,
+
+    /// Extend the stroke past its end point with a half circle
+    Round,
+
+    /// Extend the stroke past its end point by half the stroke width
+    Square,
+}
+
+/// Where a stroke sits relative to the edge of a shape
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StrokeAlignment {
+    /// Draw the stroke inside the edge, so the shape keeps its size
+    #[default]
+    Inside,
+
+    /// Center the stroke on the edge, with any odd pixel on the inside
+    Center,
+
+    /// Draw the stroke outside the edge, around the fill
+    Outside,
+}
+
+impl StrokeAlignment {
+    /// Number of pixels a stroke `width` pixels wide reaches past the edge of the shape
+    pub(crate) fn outset(self, width: u8) -> u32 {
+        let width = u32::from(width);
+
+        match self {
+            StrokeAlignment::Inside => 0,
+            StrokeAlignment::Center => width / 2,
+            StrokeAlignment::Outside => width,
+        }
+    }
+}
+
+/// How the stroke and fill of a shape share the pixels along its outline
+///
+/// With the default [`Exclusive`](#variant.Exclusive) mode every pixel of a filled and stroked
+/// shape is drawn exactly once, either with the stroke color or with the fill. This matters when
+/// the draw target combines each pixel with what is already there, for example XOR drawing or a
+/// display that blends a translucent stroke over the fill.
+///
+/// [`OverFill`](#variant.OverFill) draws the fill underneath the stroke as well, then the stroke
+/// on top, so pixels under the stroke are drawn twice. Pixels are still never drawn more than
+/// once by the fill or more than once by the stroke, even where a path crosses itself.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StrokeOverlap {
+    /// Draw each pixel once, leaving the fill out where the stroke is drawn
+    #[default]
+    Exclusive,
+
+    /// Draw the fill under the whole shape, followed by the stroke on top of it
+    OverFill,
+}
+
+/// How the color of a newly drawn pixel is combined with the color already on the display
+///
+/// Every mode except [`Copy`](#variant.Copy) reads each pixel back before drawing over it, so they
+/// only work on displays that implement [`ReadPixel`](../raster/trait.ReadPixel.html), drawn to
+/// through a [`RasterOp`](../adapter/struct.RasterOp.html) adapter. The bitwise modes work on the
+/// raw [storage value](../pixelcolor/raw/trait.IntoStorage.html) of the colors.
+///
+/// Drawing the same pixels twice with [`Xor`](#variant.Xor) or [`Invert`](#variant.Invert) puts
+/// back whatever was there before, which is how classic monochrome GUIs draw cursors and rubber
+/// band selections without saving the background underneath them.
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::style::DrawMode;
+///
+/// assert_eq!(DrawMode::Xor.apply(BinaryColor::On, BinaryColor::On), BinaryColor::Off);
+/// assert_eq!(DrawMode::And.apply(0b1100u8, 0b1010), 0b1000);
+/// assert_eq!(DrawMode::Invert.apply(BinaryColor::On, BinaryColor::Off), BinaryColor::On);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DrawMode {
+    /// Replace the existing color with the new one
+    #[default]
+    Copy,
+
+    /// Bitwise exclusive or of the new and existing colors
+    Xor,
+
+    /// Bitwise and of the new and existing colors
+    And,
+
+    /// Bitwise or of the new and existing colors
+    Or,
+
+    /// Invert the existing color, ignoring the new one
+    Invert,
+}
+
+impl DrawMode {
+    /// Combine a newly drawn `color` with the `existing` color of a pixel
+    pub fn apply<C>(self, color: C, existing: C) -> C
+    where
+        C: IntoStorage,
+        C::Storage: BitAnd<Output = C::Storage>
+            + BitOr<Output = C::Storage>
+            + BitXor<Output = C::Storage>
+            + Not<Output = C::Storage>,
+    {
+        match self {
+            DrawMode::Copy => color,
+            DrawMode::Xor => C::from_storage(color.into_storage() ^ existing.into_storage()),
+            DrawMode::And => C::from_storage(color.into_storage() & existing.into_storage()),
+            DrawMode::Or => C::from_storage(color.into_storage() | existing.into_storage()),
+            DrawMode::Invert => C::from_storage(!existing.into_storage()),
+        }
+    }
+}
+
+/// Read the style of an object
+pub trait Styled<C>
+where
+    C: PixelColor,
+{
+    /// The object's current style
+    fn current_style(&self) -> &Style<C>;
 }
 
 /// Add a style to an object
-pub trait WithStyle<C>
+pub trait WithStyle<C>: Sized
 where
     C: PixelColor,
 {
@@ -65,8 +725,207 @@ where
     /// A stroke with a width of zero will not be rendered
     fn stroke_width(self, width: u8) -> Self;
 
+    /// Set the stroke dash pattern for the object
+    ///
+    /// `None` draws a solid stroke. This is a noop unless the object overrides it
+    fn stroke_dash(self, _pattern: Option<DashPattern>) -> Self {
+        self
+    }
+
+    /// Set how the corners of a thick stroke are joined
+    ///
+    /// This is a noop unless the object overrides it
+    fn line_join(self, _join: LineJoin) -> Self {
+        self
+    }
+
+    /// Set the shape of the open ends of a thick stroke
+    ///
+    /// This is a noop unless the object overrides it
+    fn stroke_cap(self, _cap: StrokeCap) -> Self {
+        self
+    }
+
+    /// Set where the stroke sits relative to the edge of a closed shape
+    ///
+    /// This is a noop unless the object overrides it
+    fn stroke_alignment(self, _alignment: StrokeAlignment) -> Self {
+        self
+    }
+
+    /// Set how far the stroke reaches from each corner of a rectangle
+    ///
+    /// `None` draws the whole outline. This is a noop unless the object overrides it
+    fn stroke_corners(self, _length: Option<u32>) -> Self {
+        self
+    }
+
+    /// Set whether the fill is also drawn underneath the stroke
+    ///
+    /// This is a noop unless the object overrides it
+    fn stroke_overlap(self, _overlap: StrokeOverlap) -> Self {
+        self
+    }
+
+    /// Set how the object's pixels are combined with the ones already on the display
+    ///
+    /// This is a noop unless the object overrides it
+    fn draw_mode(self, _mode: DrawMode) -> Self {
+        self
+    }
+
     /// Set the fill property of the object's style
     ///
     /// This can be a noop
     fn fill(self, stroke: Option<C>) -> Self;
+
+    /// Set the fill gradient of the object's style
+    ///
+    /// `None` falls back to the fill color. This is a noop unless the object overrides it
+    fn fill_gradient(self, _gradient: Option<Gradient<C>>) -> Self {
+        self
+    }
+
+    /// Set the fill pattern of the object's style
+    ///
+    /// `None` falls back to the fill gradient or color. This is a noop unless the object overrides
+    /// it
+    fn fill_pattern(self, _pattern: Option<FillPattern<C>>) -> Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_pattern() {
+        let on = |pattern: DashPattern| -> [bool; 10] {
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].map(|i| pattern.is_dash(i))
+        };
+        let (x, o) = (true, false);
+
+        assert_eq!(
+            on(DashPattern::new(&[2, 3])),
+            [x, x, o, o, o, x, x, o, o, o]
+        );
+        assert_eq!(on(DashPattern::DOTTED), [x, o, x, o, x, o, x, o, x, o]);
+        assert_eq!(
+            on(DashPattern::new(&[3, 1, 1, 1])),
+            [x, x, x, o, x, o, x, x, x, o]
+        );
+
+        // Odd patterns are repeated twice
+        assert_eq!(on(DashPattern::new(&[2])), [x, x, o, o, x, x, o, o, x, x]);
+        assert_eq!(
+            on(DashPattern::new(&[1, 2, 3])),
+            [x, o, o, x, x, x, o, x, x, o]
+        );
+
+        // Patterns without gaps are solid, and ones without dashes are blank
+        assert_eq!(on(DashPattern::new(&[])), [x; 10]);
+        assert_eq!(on(DashPattern::new(&[0, 0])), [x; 10]);
+        assert_eq!(on(DashPattern::new(&[3, 0])), [x; 10]);
+        assert_eq!(on(DashPattern::new(&[0, 4])), [o; 10]);
+        assert!(DashPattern::new(&[u32::MAX, u32::MAX]).is_dash(u32::MAX - 1));
+    }
+
+    #[test]
+    fn linear_gradient() {
+        let gradient = Gradient::linear(Coord::new(10, 0), Coord::new(20, 0), 0u8, 100u8);
+
+        assert_eq!(gradient.color_at(Coord::new(10, 5)), 0);
+        assert_eq!(gradient.color_at(Coord::new(15, -3)), 50);
+        assert_eq!(gradient.color_at(Coord::new(20, 8)), 100);
+
+        // Clamped outside of the gradient
+        assert_eq!(gradient.color_at(Coord::new(0, 0)), 0);
+        assert_eq!(gradient.color_at(Coord::new(30, 0)), 100);
+    }
+
+    #[test]
+    fn radial_gradient() {
+        let gradient = Gradient::radial(Coord::new(10, 10), 10, 0u8, 100u8);
+
+        assert_eq!(gradient.color_at(Coord::new(10, 10)), 0);
+        assert_eq!(gradient.color_at(Coord::new(16, 18)), 100);
+        assert_eq!(gradient.color_at(Coord::new(13, 14)), 50);
+        assert_eq!(gradient.color_at(Coord::new(30, 10)), 100);
+    }
+
+    #[test]
+    fn hatches() {
+        let row = |hatch: Hatch, y: i32| -> [bool; 5] {
+            [0, 1, 2, 3, 4].map(|x| hatch.is_set(Coord::new(x, y)))
+        };
+
+        assert_eq!(row(Hatch::Horizontal, 4), [true; 5]);
+        assert_eq!(row(Hatch::Horizontal, 5), [false; 5]);
+        assert_eq!(row(Hatch::Vertical, 1), [true, false, false, false, true]);
+        assert_eq!(
+            row(Hatch::ForwardDiagonal, 1),
+            [false, false, false, true, false]
+        );
+        assert_eq!(
+            row(Hatch::BackwardDiagonal, 1),
+            [false, true, false, false, false]
+        );
+        assert_eq!(
+            row(Hatch::Checkerboard, 0),
+            [true, false, true, false, true]
+        );
+        assert_eq!(row(Hatch::Dots, 1), [false; 5]);
+
+        // Patterns continue across the origin
+        assert!(Hatch::Vertical.is_set(Coord::new(-4, 0)));
+        assert!(!Hatch::Vertical.is_set(Coord::new(-1, 0)));
+    }
+
+    #[test]
+    fn bitmap_tile() {
+        // 3x2 tile, each row padded to one byte
+        static TILE: [u8; 2] = [0b1010_0000, 0b0100_0000];
+        let pattern = FillPattern::bitmap(&TILE, 3, 2, 1u8).background(Some(2));
+
+        let row = |y: i32| -> [u8; 6] {
+            [0, 1, 2, 3, 4, 5].map(|x| pattern.color_at(Coord::new(x, y)).unwrap())
+        };
+
+        assert_eq!(row(0), [1, 2, 1, 1, 2, 1]);
+        assert_eq!(row(1), [2, 1, 2, 2, 1, 2]);
+        assert_eq!(row(2), row(0));
+        assert_eq!(pattern.color_at(Coord::new(-1, -1)), Some(2));
+
+        // Missing data is treated as unset
+        assert_eq!(
+            FillPattern::bitmap(&[], 4, 4, 1u8).color_at(Coord::new(0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn fill_color_at() {
+        let style: Style<u8> = Style::default();
+        assert_eq!(style.fill_color_at(Coord::new(0, 0)), None);
+
+        let style = Style {
+            fill_color: Some(5u8),
+            ..Style::default()
+        };
+        assert_eq!(style.fill_color_at(Coord::new(0, 0)), Some(5));
+
+        let style = Style {
+            fill_gradient: Some(Gradient::radial(Coord::new(0, 0), 0, 1u8, 9u8)),
+            ..style
+        };
+        assert_eq!(style.fill_color_at(Coord::new(0, 0)), Some(9));
+
+        let style = Style {
+            fill_pattern: Some(FillPattern::hatch(Hatch::Horizontal, 3u8)),
+            ..style
+        };
+        assert_eq!(style.fill_color_at(Coord::new(0, 0)), Some(3));
+        assert_eq!(style.fill_color_at(Coord::new(0, 1)), None);
+    }
 }
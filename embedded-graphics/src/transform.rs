@@ -1,6 +1,10 @@
 //! Transformations for graphics objects
 
-use crate::coord::Coord;
+use crate::coord::{Coord, ToUnsigned};
+use crate::drawable::{Dimensions, Drawable, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::trig::{cos, sin, DEGREE, ONE};
+use crate::unsignedcoord::UnsignedCoord;
 
 /// Transform operations
 pub trait Transform {
@@ -11,3 +15,535 @@ pub trait Transform {
     /// in place
     fn translate_mut(&mut self, by: Coord) -> &mut Self;
 }
+
+/// Affine transform applied to the pixels of an object as it is drawn
+///
+/// The linear part and the translation are stored in the fixed point format used by the crate's
+/// trigonometry, so building and applying transforms doesn't need floating point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Affine {
+    /// `[[a, b, tx], [c, d, ty]]`, all scaled by `ONE`
+    matrix: [[i64; 3]; 2],
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Affine {
+    /// Transform that leaves every point where it is
+    pub fn identity() -> Self {
+        let one = i64::from(ONE);
+
+        Affine {
+            matrix: [[one, 0, 0], [0, one, 0]],
+        }
+    }
+
+    /// Move every point by a given number of (x, y) pixels
+    pub fn translation(by: Coord) -> Self {
+        let one = i64::from(ONE);
+
+        Affine {
+            matrix: [
+                [one, 0, i64::from(by[0]) * one],
+                [0, one, i64::from(by[1]) * one],
+            ],
+        }
+    }
+
+    /// Rotate clockwise by `degrees` around `center`
+    pub fn rotation(degrees: i32, center: Coord) -> Self {
+        let angle = degrees.saturating_mul(DEGREE);
+        let (sin, cos) = (i64::from(sin(angle)), i64::from(cos(angle)));
+
+        Self::about(center, [[cos, -sin], [sin, cos]])
+    }
+
+    /// Scale by `numerator / denominator` around `center`
+    ///
+    /// A `denominator` of zero is treated as one.
+    pub fn scale(numerator: u32, denominator: u32, center: Coord) -> Self {
+        let factor = i64::from(numerator) * i64::from(ONE) / i64::from(denominator.max(1));
+
+        Self::about(center, [[factor, 0], [0, factor]])
+    }
+
+    /// Transform that applies `self` first, then `next`
+    pub fn then(&self, next: Affine) -> Self {
+        let one = i64::from(ONE);
+        let [[a, b, tx], [c, d, ty]] = self.matrix;
+        let [[na, nb, ntx], [nc, nd, nty]] = next.matrix;
+
+        Affine {
+            matrix: [
+                [
+                    (na * a + nb * c) / one,
+                    (na * b + nb * d) / one,
+                    (na * tx + nb * ty) / one + ntx,
+                ],
+                [
+                    (nc * a + nd * c) / one,
+                    (nc * b + nd * d) / one,
+                    (nc * tx + nd * ty) / one + nty,
+                ],
+            ],
+        }
+    }
+
+    /// Where `point` ends up, rounded to the nearest pixel
+    pub fn transform_point(&self, point: Coord) -> Coord {
+        let one = i64::from(ONE);
+        let (x, y) = self.apply(i64::from(point[0]) * one, i64::from(point[1]) * one);
+
+        Coord::new(round(x) as i32, round(y) as i32)
+    }
+
+    /// Linear part `linear` applied around `center` instead of the origin
+    fn about(center: Coord, linear: [[i64; 2]; 2]) -> Self {
+        let one = i64::from(ONE);
+        let (cx, cy) = (i64::from(center[0]), i64::from(center[1]));
+        let [[a, b], [c, d]] = linear;
+
+        Affine {
+            matrix: [
+                [a, b, cx * one - a * cx - b * cy],
+                [c, d, cy * one - c * cx - d * cy],
+            ],
+        }
+    }
+
+    /// Transform a point given in `ONE` units, returning `ONE` units
+    fn apply(&self, x: i64, y: i64) -> (i64, i64) {
+        let one = i64::from(ONE);
+        let [[a, b, tx], [c, d, ty]] = self.matrix;
+
+        ((a * x + b * y) / one + tx, (c * x + d * y) / one + ty)
+    }
+
+    /// Number of samples along each axis of a source pixel needed to leave no gaps in the output
+    ///
+    /// Samples `1 / n` pixels apart land less than one pixel apart horizontally and vertically once
+    /// transformed, so every destination pixel the object covers is hit at least once.
+    fn samples(&self) -> i64 {
+        let one = i64::from(ONE);
+        let [[a, b, _], [c, d, _]] = self.matrix;
+        let spread = (a.abs() + b.abs()).max(c.abs() + d.abs());
+
+        ((spread + one - 1) / one).clamp(1, 16)
+    }
+}
+
+/// Round a value in `ONE` units to the nearest whole pixel
+fn round(value: i64) -> i64 {
+    let one = i64::from(ONE);
+
+    (value + one / 2).div_euclid(one)
+}
+
+/// Offset of sample `index` of `samples` from the center of its pixel, in `ONE` units
+fn sample_offset(index: i64, samples: i64) -> i64 {
+    (2 * index + 1 - samples) * i64::from(ONE) / (2 * samples)
+}
+
+/// Rotate or scale any drawable object as it is drawn
+///
+/// This is implemented for everything that is [`Drawable`](../drawable/trait.Drawable.html), so
+/// gauge needles, rotated labels and the like can be drawn without working out their vertices by
+/// hand.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Line;
+/// use embedded_graphics::transform::AffineTransform;
+/// # use embedded_graphics::mock_display::Display;
+/// # let mut display = Display::default();
+///
+/// let center = Coord::new(12, 12);
+///
+/// // Needle pointing straight up, turned to 30° past the hour
+/// let needle = Line::new(center, Coord::new(12, 2)).stroke(Some(1u8));
+///
+/// display.draw(needle.rotate(30, center));
+/// ```
+pub trait AffineTransform: Sized {
+    /// Apply `transform` to every pixel of the object as it is drawn
+    fn transform(self, transform: Affine) -> Transformed<Self>;
+
+    /// Rotate the object clockwise by `degrees` around `center`
+    fn rotate(self, degrees: i32, center: Coord) -> Transformed<Self> {
+        self.transform(Affine::rotation(degrees, center))
+    }
+
+    /// Scale the object by `numerator / denominator` around `center`
+    fn scale(self, numerator: u32, denominator: u32, center: Coord) -> Transformed<Self> {
+        self.transform(Affine::scale(numerator, denominator, center))
+    }
+}
+
+impl<T> AffineTransform for T
+where
+    T: Drawable,
+{
+    fn transform(self, transform: Affine) -> Transformed<Self> {
+        Transformed {
+            object: self,
+            transform,
+        }
+    }
+}
+
+/// An object drawn through an [`Affine`](./struct.Affine.html) transform
+///
+/// Each source pixel is split into a small grid of samples which are mapped through the transform
+/// and rounded to the nearest pixel, so rotated and enlarged shapes stay solid. Neighbouring
+/// samples that land on the same pixel are only emitted once, but a pixel may still be drawn more
+/// than once. Pixels that end up left of or above the display are dropped.
+#[derive(Debug, Copy, Clone)]
+pub struct Transformed<T> {
+    /// The object being transformed
+    pub object: T,
+
+    /// Transform applied to the object's pixels
+    pub transform: Affine,
+}
+
+impl<T> Transformed<T> {
+    /// Apply `transform` after the existing transform
+    pub fn transform(mut self, transform: Affine) -> Self {
+        self.transform = self.transform.then(transform);
+
+        self
+    }
+
+    /// Rotate clockwise by `degrees` around `center`, after the existing transform
+    pub fn rotate(self, degrees: i32, center: Coord) -> Self {
+        self.transform(Affine::rotation(degrees, center))
+    }
+
+    /// Scale by `numerator / denominator` around `center`, after the existing transform
+    pub fn scale(self, numerator: u32, denominator: u32, center: Coord) -> Self {
+        self.transform(Affine::scale(numerator, denominator, center))
+    }
+}
+
+impl<T> Dimensions for Transformed<T>
+where
+    T: Dimensions,
+{
+    fn top_left(&self) -> Coord {
+        self.corners().0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.corners().1
+    }
+
+    fn size(&self) -> UnsignedCoord {
+        let (top_left, bottom_right) = self.corners();
+
+        (bottom_right - top_left).abs().to_unsigned()
+    }
+}
+
+impl<T> Transformed<T>
+where
+    T: Dimensions,
+{
+    /// Bounding box of the transformed object, found from the outermost samples of the original
+    fn corners(&self) -> (Coord, Coord) {
+        let one = i64::from(ONE);
+        let samples = self.transform.samples();
+        let (tl, br) = (self.object.top_left(), self.object.bottom_right());
+
+        let near = sample_offset(0, samples);
+        let far = sample_offset(samples - 1, samples);
+        let xs = [
+            i64::from(tl[0].min(br[0])) * one + near,
+            i64::from(tl[0].max(br[0])) * one + far,
+        ];
+        let ys = [
+            i64::from(tl[1].min(br[1])) * one + near,
+            i64::from(tl[1].max(br[1])) * one + far,
+        ];
+
+        let mut min = (i64::MAX, i64::MAX);
+        let mut max = (i64::MIN, i64::MIN);
+
+        for &x in xs.iter() {
+            for &y in ys.iter() {
+                let (x, y) = self.transform.apply(x, y);
+                let (x, y) = (round(x), round(y));
+
+                min = (min.0.min(x), min.1.min(y));
+                max = (max.0.max(x), max.1.max(y));
+            }
+        }
+
+        (
+            Coord::new(min.0 as i32, min.1 as i32),
+            Coord::new(max.0 as i32, max.1 as i32),
+        )
+    }
+}
+
+impl<T> Transform for Transformed<T>
+where
+    T: Clone,
+{
+    /// Translate the transformed object by (x, y) pixels, returning a new object. For a mutating
+    /// transform, see `translate_mut`.
+    fn translate(&self, by: Coord) -> Self {
+        let mut transformed = self.clone();
+        transformed.translate_mut(by);
+
+        transformed
+    }
+
+    /// Translate the transformed object by (x, y) pixels.
+    fn translate_mut(&mut self, by: Coord) -> &mut Self {
+        self.transform = self.transform.then(Affine::translation(by));
+
+        self
+    }
+}
+
+impl<T> Drawable for Transformed<T> where T: Drawable {}
+
+impl<T, C> IntoIterator for Transformed<T>
+where
+    T: IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TransformedIterator<C, T::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TransformedIterator::new(self.object.into_iter(), self.transform)
+    }
+}
+
+impl<'a, T, C> IntoIterator for &'a Transformed<T>
+where
+    &'a T: IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TransformedIterator<C, <&'a T as IntoIterator>::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TransformedIterator::new((&self.object).into_iter(), self.transform)
+    }
+}
+
+/// Pixel iterator for a [`Transformed`](./struct.Transformed.html) object
+#[derive(Debug, Clone)]
+pub struct TransformedIterator<C, I>
+where
+    C: PixelColor,
+{
+    pixels: I,
+    transform: Affine,
+    samples: i64,
+
+    /// Source pixel being sampled and the index of its next sample
+    current: Option<(Pixel<C>, i64)>,
+
+    /// Last pixel emitted, to skip samples that land on the same pixel
+    last: Option<UnsignedCoord>,
+}
+
+impl<C, I> TransformedIterator<C, I>
+where
+    C: PixelColor,
+{
+    fn new(pixels: I, transform: Affine) -> Self {
+        TransformedIterator {
+            pixels,
+            transform,
+            samples: transform.samples(),
+            current: None,
+            last: None,
+        }
+    }
+}
+
+impl<C, I> Iterator for TransformedIterator<C, I>
+where
+    C: PixelColor,
+    I: Iterator<Item = Pixel<C>>,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let one = i64::from(ONE);
+
+        loop {
+            let (Pixel(point, color), index) = match self.current {
+                Some(current) if current.1 < self.samples * self.samples => current,
+                _ => (self.pixels.next()?, 0),
+            };
+            self.current = Some((Pixel(point, color), index + 1));
+
+            let x = i64::from(point[0]) * one + sample_offset(index % self.samples, self.samples);
+            let y = i64::from(point[1]) * one + sample_offset(index / self.samples, self.samples);
+            let (x, y) = self.transform.apply(x, y);
+            let (x, y) = (round(x), round(y));
+
+            if x < 0 || y < 0 || x > i64::from(u32::MAX) || y > i64::from(u32::MAX) {
+                continue;
+            }
+
+            let out = UnsignedCoord::new(x as u32, y as u32);
+
+            if self.last == Some(out) {
+                continue;
+            }
+
+            self.last = Some(out);
+
+            return Some(Pixel(out, color));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::Display;
+    use crate::primitives::{Line, Rectangle};
+    use crate::style::{Style, WithStyle};
+    use crate::Drawing;
+
+    #[test]
+    fn identity_is_unchanged() {
+        let rect = Rectangle::new(Coord::new(2, 3), Coord::new(9, 7)).fill(Some(1u8));
+
+        let mut expected = Display::default();
+        expected.draw(rect);
+
+        let mut display = Display::default();
+        display.draw(rect.transform(Affine::identity()));
+
+        assert_eq!(display, expected);
+        assert_eq!(
+            rect.transform(Affine::identity()).top_left(),
+            Coord::new(2, 3)
+        );
+        assert_eq!(
+            rect.transform(Affine::identity()).bottom_right(),
+            Coord::new(9, 7)
+        );
+    }
+
+    #[test]
+    fn rotate_quarter_turn() {
+        let center = Coord::new(8, 8);
+        let line = Line::new(center, Coord::new(8, 2)).stroke(Some(1u8));
+
+        let mut display = Display::default();
+        display.draw(line.rotate(90, center));
+
+        // Pointing up becomes pointing right
+        for x in 8..=14 {
+            assert_eq!(display.0[8][x], 1);
+        }
+        assert_eq!(display.count(1), 7);
+
+        let rotated = line.rotate(90, center);
+        assert_eq!(rotated.top_left(), Coord::new(8, 8));
+        assert_eq!(rotated.bottom_right(), Coord::new(14, 8));
+    }
+
+    #[test]
+    fn rotated_fill_has_no_holes() {
+        let center = Coord::new(12, 8);
+        let rect = Rectangle::new(Coord::new(8, 5), Coord::new(16, 11)).fill(Some(1u8));
+
+        let mut display = Display::default();
+        display.draw(rect.rotate(30, center));
+
+        // Every pixel well inside the rotated rectangle is set
+        for y in 6..=10 {
+            for x in 10..=14 {
+                assert_eq!(display.0[y][x], 1, "hole at ({}, {})", x, y);
+            }
+        }
+
+        // Rotation keeps the area roughly the same
+        let area = display.count(1) as i32;
+        assert!((area - 9 * 7).abs() < 12, "area {}", area);
+    }
+
+    #[test]
+    fn scale_doubles_size() {
+        let rect = Rectangle::new(Coord::new(1, 1), Coord::new(4, 3)).fill(Some(1u8));
+        let scaled = rect.scale(2, 1, Coord::new(1, 1));
+
+        let mut display = Display::default();
+        display.draw(scaled);
+
+        assert_eq!(display.count(1), 8 * 6);
+        assert_eq!(scaled.top_left(), Coord::new(1, 1));
+        assert_eq!(scaled.bottom_right(), Coord::new(8, 6));
+    }
+
+    #[test]
+    fn bounding_box_matches_pixels() {
+        let center = Coord::new(12, 8);
+        let style = Style::stroke(1u8);
+        let rect = Rectangle::new(Coord::new(9, 6), Coord::new(15, 10)).style(style);
+
+        for &degrees in [0, 15, 30, 45, 100, 200].iter() {
+            let rotated = rect.rotate(degrees, center);
+            let (tl, br) = (rotated.top_left(), rotated.bottom_right());
+
+            let mut min = Coord::new(i32::MAX, i32::MAX);
+            let mut max = Coord::new(i32::MIN, i32::MIN);
+
+            for Pixel(p, _) in rotated {
+                min = Coord::new(min[0].min(p[0] as i32), min[1].min(p[1] as i32));
+                max = Coord::new(max[0].max(p[0] as i32), max[1].max(p[1] as i32));
+            }
+
+            assert_eq!((min, max), (tl, br), "{} degrees", degrees);
+        }
+    }
+
+    #[test]
+    fn chained_transforms_compose() {
+        let center = Coord::new(10, 8);
+        let line = Line::new(center, Coord::new(10, 3)).stroke(Some(1u8));
+
+        let mut twice = Display::default();
+        twice.draw(line.rotate(45, center).rotate(45, center));
+
+        let mut once = Display::default();
+        once.draw(line.rotate(90, center));
+
+        assert_eq!(twice, once);
+    }
+
+    #[test]
+    fn translate() {
+        let rect = Rectangle::new(Coord::new(2, 2), Coord::new(5, 4)).fill(Some(1u8));
+        let moved = rect
+            .rotate(90, Coord::new(2, 2))
+            .translate(Coord::new(6, 1));
+
+        assert_eq!(moved.top_left(), Coord::new(6, 3));
+        assert_eq!(moved.bottom_right(), Coord::new(8, 6));
+    }
+
+    #[test]
+    fn drops_negative_pixels() {
+        let rect = Rectangle::new(Coord::new(0, 0), Coord::new(4, 4)).fill(Some(1u8));
+
+        let mut display = Display::default();
+        display.draw(rect.rotate(180, Coord::new(1, 1)));
+
+        assert_eq!(display.count(1), 9);
+    }
+}
@@ -0,0 +1,192 @@
+//! Fixed point trigonometry for primitives that need angles without relying on floating point
+
+use crate::coord::Coord;
+
+/// Fixed point representation of 1.0 used by [`sin`] and [`cos`]
+pub(crate) const ONE: i32 = 16384;
+
+/// Number of angle units in one degree
+pub(crate) const DEGREE: i32 = 256;
+
+/// `sin(n°) * ONE` for each whole degree in the first quadrant
+const SIN_TABLE: [i32; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563, 2845, 3126, 3406, 3686, 3964, 4240, 4516,
+    4790, 5063, 5334, 5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943, 8192, 8438, 8682,
+    8923, 9162, 9397, 9630, 9860, 10087, 10311, 10531, 10749, 10963, 11174, 11381, 11585, 11786,
+    11982, 12176, 12365, 12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296, 15396, 15491, 15582,
+    15668, 15749, 15826, 15897, 15964, 16026, 16083, 16135, 16182, 16225, 16262, 16294, 16322,
+    16344, 16362, 16374, 16382, 16384,
+];
+
+/// Sine of an angle in the first quadrant, linearly interpolated between table entries
+fn quadrant_sin(angle: i32) -> i32 {
+    let index = (angle / DEGREE) as usize;
+    let fraction = angle % DEGREE;
+
+    if index >= 90 {
+        return ONE;
+    }
+
+    let (a, b) = (SIN_TABLE[index], SIN_TABLE[index + 1]);
+
+    a + (b - a) * fraction / DEGREE
+}
+
+/// Sine of `angle`, given in 1/256ths of a degree, scaled by [`ONE`]
+pub(crate) fn sin(angle: i32) -> i32 {
+    let angle = angle.rem_euclid(360 * DEGREE);
+
+    match angle / (90 * DEGREE) {
+        0 => quadrant_sin(angle),
+        1 => quadrant_sin(180 * DEGREE - angle),
+        2 => -quadrant_sin(angle - 180 * DEGREE),
+        _ => -quadrant_sin(360 * DEGREE - angle),
+    }
+}
+
+/// Cosine of `angle`, given in 1/256ths of a degree, scaled by [`ONE`]
+pub(crate) fn cos(angle: i32) -> i32 {
+    sin(angle + 90 * DEGREE)
+}
+
+/// Point at `radius` from `center` in the direction of `angle`
+///
+/// Angles are measured clockwise from straight up, so an angle of zero points towards the top of
+/// the screen.
+pub(crate) fn polar(center: Coord, radius: u32, angle: i32) -> Coord {
+    let radius = i64::from(radius);
+    let scale = |v: i32| {
+        let v = radius * i64::from(v);
+
+        // Round to nearest, away from zero
+        ((v + v.signum() * i64::from(ONE / 2)) / i64::from(ONE)) as i32
+    };
+
+    Coord::new(center[0] + scale(sin(angle)), center[1] - scale(cos(angle)))
+}
+
+/// Range of directions swept clockwise from a start angle, used to clip shapes to a sector
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Sweep {
+    /// Direction of the start of the sweep, scaled by [`ONE`]
+    start: (i64, i64),
+
+    /// Direction of the end of the sweep, scaled by [`ONE`]
+    end: (i64, i64),
+
+    /// Sweep angle in angle units, between `0` and `360 * DEGREE`
+    angle: i32,
+}
+
+impl Sweep {
+    /// Create a sweep of `sweep` angle units starting at `start`
+    ///
+    /// Negative sweeps go counter clockwise from `start`. Sweeps of a full turn or more cover every
+    /// direction.
+    pub(crate) fn new(start: i32, sweep: i32) -> Self {
+        let (start, sweep) = if sweep < 0 {
+            (start + sweep, -sweep)
+        } else {
+            (start, sweep)
+        };
+        let angle = sweep.min(360 * DEGREE);
+        let direction = |angle: i32| (i64::from(sin(angle)), -i64::from(cos(angle)));
+
+        Self {
+            start: direction(start),
+            end: direction(start + angle),
+            angle,
+        }
+    }
+
+    /// Returns `true` if the direction `(dx, dy)` from the center lies within the sweep
+    ///
+    /// The center itself, `(0, 0)`, is within every sweep that isn't empty.
+    pub(crate) fn contains(&self, dx: i64, dy: i64) -> bool {
+        let cross = |a: (i64, i64), b: (i64, i64)| a.0 * b.1 - a.1 * b.0;
+
+        // Positive when the second direction is clockwise from the first
+        let after_start = cross(self.start, (dx, dy)) >= 0;
+        let before_end = cross((dx, dy), self.end) >= 0;
+
+        if self.angle == 0 {
+            false
+        } else if self.angle >= 360 * DEGREE {
+            true
+        } else if self.angle <= 180 * DEGREE {
+            after_start && before_end
+        } else {
+            // The directions left out form a sweep of less than half a turn
+            after_start || before_end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quadrants() {
+        assert_eq!(sin(0), 0);
+        assert_eq!(sin(90 * DEGREE), ONE);
+        assert_eq!(sin(180 * DEGREE), 0);
+        assert_eq!(sin(270 * DEGREE), -ONE);
+        assert_eq!(sin(-90 * DEGREE), -ONE);
+        assert_eq!(sin(450 * DEGREE), ONE);
+
+        assert_eq!(cos(0), ONE);
+        assert_eq!(cos(180 * DEGREE), -ONE);
+    }
+
+    #[test]
+    fn interpolation() {
+        // sin(30.5°) * 16384 ≈ 8316
+        assert!((sin(30 * DEGREE + DEGREE / 2) - 8316).abs() <= 1);
+        assert_eq!(sin(30 * DEGREE), ONE / 2);
+        assert_eq!(sin(150 * DEGREE), ONE / 2);
+        assert_eq!(sin(210 * DEGREE), -ONE / 2);
+    }
+
+    #[test]
+    fn polar_points() {
+        let center = Coord::new(10, 10);
+
+        assert_eq!(polar(center, 5, 0), Coord::new(10, 5));
+        assert_eq!(polar(center, 5, 90 * DEGREE), Coord::new(15, 10));
+        assert_eq!(polar(center, 5, 180 * DEGREE), Coord::new(10, 15));
+        assert_eq!(polar(center, 5, 270 * DEGREE), Coord::new(5, 10));
+        assert_eq!(polar(center, 10, 45 * DEGREE), Coord::new(17, 3));
+    }
+
+    #[test]
+    fn sweeps() {
+        let quarter = Sweep::new(0, 90 * DEGREE);
+
+        assert!(quarter.contains(0, -5));
+        assert!(quarter.contains(3, -3));
+        assert!(quarter.contains(5, 0));
+        assert!(quarter.contains(0, 0));
+        assert!(!quarter.contains(-1, -5));
+        assert!(!quarter.contains(5, 1));
+        assert!(!quarter.contains(-3, 3));
+
+        // Same quarter, swept the other way from its end
+        assert_eq!(Sweep::new(90 * DEGREE, -90 * DEGREE), quarter);
+
+        let three_quarters = Sweep::new(0, 270 * DEGREE);
+        assert!(three_quarters.contains(-3, 3));
+        assert!(three_quarters.contains(-5, 0));
+        assert!(!three_quarters.contains(-3, -3));
+
+        let half = Sweep::new(90 * DEGREE, 180 * DEGREE);
+        assert!(half.contains(0, 5));
+        assert!(half.contains(5, 0));
+        assert!(half.contains(-5, 0));
+        assert!(!half.contains(0, -5));
+
+        assert!(Sweep::new(45 * DEGREE, 400 * DEGREE).contains(-3, -3));
+        assert!(!Sweep::new(45 * DEGREE, 0).contains(0, 0));
+    }
+}